@@ -291,6 +291,7 @@ export declare class JsCompilation {
   get entrypoints(): ChunkGroup[]
   get chunkGroups(): ChunkGroup[]
   get hash(): string | null
+  getMemoryReport(): JsMemoryReport
   dependencies(): JsDependencies
   pushDiagnostic(diagnostic: JsRspackDiagnostic): void
   pushNativeDiagnostic(diagnostic: ExternalObject<'Diagnostic'>): void
@@ -422,6 +423,7 @@ export declare class JsResolverFactory {
 export declare class JsStats {
   toJson(jsOptions: JsStatsOptions): JsStatsCompilation
   getLogging(acceptedTypes: number): Array<JsStatsLogging>
+  diagnosticsReport(format: "text" | "json" | "sarif"): string
 }
 
 export declare class KnownBuildInfo {
@@ -502,6 +504,9 @@ export type JsVirtualFileStore = VirtualFileStore
 
 export interface AssetInfoRelated {
   sourceMap?: string | null
+  gzip?: string | null
+  brotli?: string | null
+  zstd?: string | null
 }
 
 export declare function async(path: string, request: string): Promise<ResolveResult>
@@ -568,6 +573,7 @@ export declare enum BuiltinPluginName {
   SourceMapDevToolPlugin = 'SourceMapDevToolPlugin',
   EvalSourceMapDevToolPlugin = 'EvalSourceMapDevToolPlugin',
   EvalDevToolModulePlugin = 'EvalDevToolModulePlugin',
+  SourceMapValidationPlugin = 'SourceMapValidationPlugin',
   SideEffectsFlagPlugin = 'SideEffectsFlagPlugin',
   FlagDependencyExportsPlugin = 'FlagDependencyExportsPlugin',
   FlagDependencyUsagePlugin = 'FlagDependencyUsagePlugin',
@@ -586,6 +592,7 @@ export declare enum BuiltinPluginName {
   LibManifestPlugin = 'LibManifestPlugin',
   FlagAllModulesAsUsedPlugin = 'FlagAllModulesAsUsedPlugin',
   HttpExternalsRspackPlugin = 'HttpExternalsRspackPlugin',
+  PackageJsonExternalsRspackPlugin = 'PackageJsonExternalsRspackPlugin',
   CopyRspackPlugin = 'CopyRspackPlugin',
   HtmlRspackPlugin = 'HtmlRspackPlugin',
   SwcJsMinimizerRspackPlugin = 'SwcJsMinimizerRspackPlugin',
@@ -597,6 +604,9 @@ export declare enum BuiltinPluginName {
   RstestPlugin = 'RstestPlugin',
   RslibPlugin = 'RslibPlugin',
   CircularDependencyRspackPlugin = 'CircularDependencyRspackPlugin',
+  I18nRspackPlugin = 'I18nRspackPlugin',
+  CompressionRspackPlugin = 'CompressionRspackPlugin',
+  ImportMapRspackPlugin = 'ImportMapRspackPlugin',
   URLPlugin = 'URLPlugin',
   JsLoaderRspackPlugin = 'JsLoaderRspackPlugin',
   LazyCompilationPlugin = 'LazyCompilationPlugin',
@@ -726,6 +736,13 @@ export interface JsBuildTimeExecutionOption {
   baseUri?: string
 }
 
+export interface JsCacheGenerationMemoryUsage {
+  cache: string
+  generation: number
+  count: number
+  size: number
+}
+
 export interface JsCacheGroupTestCtx {
   module: Module
 }
@@ -753,9 +770,12 @@ export interface JsChunkOptionNameCtx {
  * This matches with:
  * - keep:
  *   - If a string, keep the files under this path
+ * - manifest:
+ *   - If true or a string, record the emitted files in a manifest with this name
  */
 export interface JsCleanOptions {
   keep?: string | RegExp | ((path: string) => boolean)
+  manifest?: boolean | string
 }
 
 export interface JsCodegenerationResult {
@@ -956,6 +976,8 @@ export interface JsLoaderItem {
   normalExecuted: boolean
   pitchExecuted: boolean
   noPitch: boolean
+  pitchDuration?: number
+  normalDuration?: number
 }
 
 export declare enum JsLoaderState {
@@ -963,6 +985,18 @@ export declare enum JsLoaderState {
   Normal = 'Normal'
 }
 
+export interface JsMemoryReport {
+  artifacts: Array<JsMemoryUsage>
+  moduleTypes: Array<JsMemoryUsage>
+  cacheGenerations: Array<JsCacheGenerationMemoryUsage>
+}
+
+export interface JsMemoryUsage {
+  name: string
+  count: number
+  size: number
+}
+
 export interface JsModuleDescriptor {
   identifier: string
   name: string
@@ -1336,6 +1370,7 @@ export interface JsStatsCompilation {
   hash?: string
   modules?: Array<JsStatsModule>
   namedChunkGroups?: Array<JsStatsChunkGroup>
+  profile?: JsStatsProfileSummary
   warnings: Array<JsStatsError>
 }
 
@@ -1360,6 +1395,19 @@ export interface JsStatsGetAssets {
   assetsByChunkName: Array<JsStatsAssetsByChunkName>
 }
 
+export interface JsStatsLoaderProfile {
+  loader: string
+  pitch: number
+  normal: number
+}
+
+export interface JsStatsLoaderProfileSummary {
+  loader: string
+  modules: number
+  pitch: number
+  normal: number
+}
+
 export interface JsStatsLogging {
   name: string
   type: string
@@ -1412,7 +1460,12 @@ export interface JsStatsModuleIssuer {
 
 export interface JsStatsModuleProfile {
   factory: number
+  resolving: number
   building: number
+  loaders: Array<JsStatsLoaderProfile>
+  parsing: number
+  codeGeneration: number
+  hashing: number
 }
 
 export interface JsStatsModuleReason {
@@ -1469,6 +1522,22 @@ export interface JsStatsOptions {
   warnings: boolean
 }
 
+export interface JsStatsPackageProfileSummary {
+  name: string
+  modules: number
+  resolving: number
+  building: number
+  loaders: number
+  parsing: number
+  codeGeneration: number
+  hashing: number
+}
+
+export interface JsStatsProfileSummary {
+  loaders: Array<JsStatsLoaderProfileSummary>
+  packages: Array<JsStatsPackageProfileSummary>
+}
+
 export interface JsStatsSize {
   sourceType: string
   size: number
@@ -1738,7 +1807,7 @@ export interface RawAssetGeneratorDataUrlFnCtx {
 }
 
 export interface RawAssetGeneratorDataUrlOptions {
-  encoding?: "base64" | "false" | undefined
+  encoding?: "base64" | "mini-svg" | "false" | undefined
   mimetype?: string
 }
 
@@ -1750,6 +1819,7 @@ export interface RawAssetGeneratorOptions {
   dataUrl?: RawAssetGeneratorDataUrlOptions | ((source: Buffer, context: RawAssetGeneratorDataUrlFnCtx) => string)
   importMode?: "url" | "preserve"
   binary?: boolean
+  imageOptimization?: boolean
 }
 
 export interface RawAssetInlineGeneratorOptions {
@@ -1758,8 +1828,24 @@ export interface RawAssetInlineGeneratorOptions {
 }
 
 export interface RawAssetParserDataUrl {
-  type: "options"
+  type: "options" | "function"
   options?: RawAssetParserDataUrlOptions
+  function?: (source: Buffer, context: RawAssetParserDataUrlFnCtx) => boolean
+}
+
+export interface RawAssetParserDataUrlFnCtx {
+  filename: string
+  module: RawAssetParserDataUrlFnModule
+}
+
+/**
+ * The module is still building when the condition is evaluated, so only the
+ * information known before parsing is provided.
+ */
+export interface RawAssetParserDataUrlFnModule {
+  identifier: string
+  type: string
+  layer?: string
 }
 
 export interface RawAssetParserDataUrlOptions {
@@ -1777,6 +1863,7 @@ export interface RawAssetResourceGeneratorOptions {
   publicPath?: "auto" | JsFilename
   importMode?: "url" | "preserve"
   binary?: boolean
+  imageOptimization?: boolean
 }
 
 export interface RawBannerPluginOptions {
@@ -1837,6 +1924,20 @@ export interface RawCircularDependencyRspackPluginOptions {
   onEnd?: () => void
 }
 
+export interface RawCompressionAlgorithmOptions {
+  algorithm: "gzip" | "brotli" | "zstd"
+  level?: number
+}
+
+export interface RawCompressionRspackPluginOptions {
+  algorithms: Array<RawCompressionAlgorithmOptions>
+  test?: string | RegExp | (string | RegExp)[]
+  include?: string | RegExp | (string | RegExp)[]
+  exclude?: string | RegExp | (string | RegExp)[]
+  threshold: number
+  minRatio: number
+}
+
 export interface RawConsumeOptions {
   key: string
   import?: string
@@ -1993,6 +2094,7 @@ export interface RawCssAutoGeneratorOptions {
   exportsOnly?: boolean
   localIdentName?: string
   esModule?: boolean
+  emitDts?: boolean
 }
 
 export interface RawCssAutoParserOptions {
@@ -2029,6 +2131,7 @@ export interface RawCssModuleGeneratorOptions {
   exportsOnly?: boolean
   localIdentName?: string
   esModule?: boolean
+  emitDts?: boolean
 }
 
 export interface RawCssModuleParserOptions {
@@ -2036,6 +2139,10 @@ export interface RawCssModuleParserOptions {
   url?: boolean
 }
 
+export interface RawCssModulesPluginOptions {
+  orderConflicts?: "warning" | "error"
+}
+
 export interface RawCssParserOptions {
   namedExports?: boolean
   url?: boolean
@@ -2105,6 +2212,7 @@ export interface RawEnvironment {
 
 export interface RawEsmLibraryPlugin {
   preserveModules?: string
+  format?: "esm" | "commonjs"
 }
 
 export interface RawEvalDevToolModulePluginOptions {
@@ -2258,12 +2366,29 @@ export interface RawHttpUriPluginOptions {
   httpClient: (url: string, headers: Record<string, string>) => Promise<JsHttpResponseRaw>
 }
 
+export interface RawI18nRspackPluginOptions {
+  locales: Record<string, string | Record<string, string>>
+  filename: string
+  localeExpression: string
+  globalName: string
+}
+
 export interface RawIgnorePluginOptions {
   resourceRegExp?: RegExp
   contextRegExp?: RegExp
   checkResource?: (resource: string, context: string) => boolean
 }
 
+export interface RawImportMapRspackPluginOptions {
+  filename?: string
+  imports: Record<string, string>
+  externalUrl?: string
+  chunks: boolean
+  chunkPrefix: string
+  publicPath?: string
+  inject: boolean
+}
+
 export interface RawIncremental {
   silent: boolean
   make: boolean
@@ -2650,16 +2775,19 @@ export interface RawOutputOptions {
   scriptType: "module" | "text/javascript" | false
   environment: RawEnvironment
   compareBeforeEmit: boolean
+  atomicEmit: boolean
 }
 
 export interface RawParserOptions {
-  type: "asset" | "css" | "css/auto" | "css/module" | "javascript" | "javascript/auto" | "javascript/dynamic" | "javascript/esm" | "json"
+  type: "asset" | "css" | "css/auto" | "css/module" | "javascript" | "javascript/auto" | "javascript/dynamic" | "javascript/esm" | "json" | "yaml" | "toml"
   asset?: RawAssetParserOptions
   css?: RawCssParserOptions
   cssAuto?: RawCssAutoParserOptions
   cssModule?: RawCssModuleParserOptions
   javascript?: RawJavascriptParserOptions
   json?: RawJsonParserOptions
+  yaml?: RawYamlParserOptions
+  toml?: RawTomlParserOptions
 }
 
 export interface RawPathData {
@@ -2778,6 +2906,16 @@ export interface RawRslibPluginOptions {
    * @default `false`
    */
   forceNodeShims?: boolean
+  /**
+   * Emit a bundled `.d.ts` of each entry, rolled up from the isolated declarations of its TypeScript modules
+   * @default `false`
+   */
+  emitDts?: boolean
+  /**
+   * The filename template of the bundled `.d.ts`
+   * @default `"[name].d.ts"`
+   */
+  dtsFilename?: string
 }
 
 export interface RawRspackFuture {
@@ -2829,6 +2967,11 @@ export interface RawSizeLimitsPluginOptions {
   maxEntrypointSize?: number
 }
 
+export interface RawSourceMapValidationPluginOptions {
+  test?: string | RegExp | (string | RegExp)[]
+  dumpModules?: string | RegExp | (string | RegExp)[]
+}
+
 export interface RawSplitChunkSizes {
   sizes: Record<string, number>
 }
@@ -2863,6 +3006,7 @@ export interface RawStatsBuildInfo {
 
 export interface RawStatsOptions {
   colors: boolean
+  diagnosticsFormat?: "text" | "json" | "sarif"
 }
 
 export interface RawStorageOptions {
@@ -2893,6 +3037,10 @@ export interface RawSwcJsMinimizerRspackPluginOptions {
   minimizerOptions: RawSwcJsMinimizerOptions
 }
 
+export interface RawTomlParserOptions {
+  exportsDepth?: number
+}
+
 export interface RawToOptions {
   context: string
   absoluteFilename?: string
@@ -2914,6 +3062,10 @@ export interface RawTrustedTypes {
   onPolicyCreationFailure?: string
 }
 
+export interface RawYamlParserOptions {
+  exportsDepth?: number
+}
+
 export interface RealDependencyLocation {
   start: SourcePosition
   end?: SourcePosition
@@ -16,6 +16,7 @@ pub(super) enum BuiltinPluginOptions {
   NodeTargetPlugin,
   ElectronTargetPlugin(rspack_plugin_externals::ElectronTargetContext),
   HttpExternalsRspackPlugin((bool /* css */, bool /* web_async */)),
+  PackageJsonExternalsRspackPlugin,
 
   // Chunk format and loading plugins
   ChunkPrefetchPreloadPlugin,
@@ -39,7 +40,7 @@ pub(super) enum BuiltinPluginOptions {
   JsonModulesPlugin,
   AssetModulesPlugin,
   AsyncWebAssemblyModulesPlugin,
-  CssModulesPlugin,
+  CssModulesPlugin(rspack_plugin_css::CssPluginOptions),
 
   // Entry and runtime plugins
   EntryPlugin(Box<(String /* entry request */, EntryOptions)>),
@@ -128,6 +129,9 @@ impl BuilderContext {
           css, web_async,
         ));
       }
+      BuiltinPluginOptions::PackageJsonExternalsRspackPlugin => {
+        plugins.push(rspack_plugin_externals::PackageJsonExternalsRspackPlugin::default().boxed())
+      }
 
       // Chunk format and loading plugins
       BuiltinPluginOptions::ChunkPrefetchPreloadPlugin => {
@@ -201,8 +205,8 @@ impl BuilderContext {
       BuiltinPluginOptions::AsyncWebAssemblyModulesPlugin => {
         plugins.push(rspack_plugin_wasm::AsyncWasmPlugin::default().boxed());
       }
-      BuiltinPluginOptions::CssModulesPlugin => {
-        plugins.push(rspack_plugin_css::CssPlugin::default().boxed());
+      BuiltinPluginOptions::CssModulesPlugin(options) => {
+        plugins.push(rspack_plugin_css::CssPlugin::new(options).boxed());
       }
 
       // Entry and runtime plugins
@@ -23,6 +23,9 @@ pub struct ExternalsPresets {
 
   /// Treat `NW.js` legacy `nw.gui` module as external and load it via `require()` when used.
   pub(crate) nwjs: Option<bool>,
+
+  /// Treat `dependencies` and `peerDependencies` of the nearest `package.json`, including their deep imports, as external and load them with the type of the output library.
+  pub(crate) dependencies: Option<bool>,
 }
 
 impl ExternalsPresets {
@@ -57,4 +60,8 @@ impl ExternalsPresets {
   pub fn nwjs(&self) -> bool {
     self.nwjs.unwrap_or(false)
   }
+
+  pub fn dependencies(&self) -> bool {
+    self.dependencies.unwrap_or(false)
+  }
 }
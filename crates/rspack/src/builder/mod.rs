@@ -50,13 +50,15 @@ use rspack_core::{
   ModuleType, NodeDirnameOption, NodeFilenameOption, NodeGlobalOption, NodeOption, Optimization,
   OutputOptions, ParseOption, ParserOptions, ParserOptionsMap, PathInfo, PublicPath, Resolve,
   RspackFuture, RuleSetCondition, RuleSetLogicalConditions, SideEffectOption, StatsOptions,
-  TrustedTypes, UnsafeCachePredicate, UsedExportsOption, WasmLoading, WasmLoadingType,
+  TomlParserOptions, TrustedTypes, UnsafeCachePredicate, UsedExportsOption, WasmLoading,
+  WasmLoadingType, YamlParserOptions,
   incremental::{IncrementalOptions, IncrementalPasses},
 };
 use rspack_error::{Error, Result};
 use rspack_fs::{IntermediateFileSystem, ReadableFileSystem, WritableFileSystem};
 use rspack_hash::{HashDigest, HashFunction, HashSalt};
 use rspack_paths::{AssertUtf8, Utf8PathBuf};
+use rspack_plugin_css::{CssOrderConflictsMode, CssPluginOptions};
 use rspack_regex::RspackRegex;
 use rustc_hash::{FxHashMap as HashMap, FxHashSet as HashSet};
 use serde_json::json;
@@ -965,7 +967,12 @@ impl CompilerOptionsBuilder {
     if css {
       builder_context
         .plugins
-        .push(BuiltinPluginOptions::CssModulesPlugin);
+        .push(BuiltinPluginOptions::CssModulesPlugin(CssPluginOptions {
+          order_conflicts: experiments_builder
+            .css_order_conflicts
+            .take()
+            .unwrap_or_default(),
+        }));
     }
     let future_defaults = expect!(experiments_builder.future_defaults);
     let output_module = expect!(experiments_builder.output_module);
@@ -1072,15 +1079,18 @@ impl CompilerOptionsBuilder {
     );
     w!(externals_presets.nwjs, tp.nwjs());
 
-    w!(self.externals_type, {
-      if let Some(library) = &output.library {
-        library.library_type.clone()
+    if self.externals_type.is_none() {
+      self.externals_type = Some(if let Some(library) = &output.library {
+        library
+          .library_type
+          .parse()
+          .map_err(|e: Error| BuilderError::Option("externals_type".to_string(), e.to_string()))?
       } else if output.module {
-        "module-import".to_string()
+        ExternalType::ModuleImport
       } else {
-        "var".to_string()
-      }
-    });
+        ExternalType::Var
+      });
+    }
 
     // apply externals plugin
     if let Some(externals) = &mut self.externals {
@@ -1088,7 +1098,7 @@ impl CompilerOptionsBuilder {
       builder_context
         .plugins
         .push(BuiltinPluginOptions::ExternalsPlugin((
-          expect!(self.externals_type.clone()),
+          expect!(self.externals_type),
           externals,
           false,
         )));
@@ -1140,7 +1150,7 @@ impl CompilerOptionsBuilder {
       builder_context
         .plugins
         .push(BuiltinPluginOptions::ExternalsPlugin((
-          "node-commonjs".to_string(),
+          ExternalType::NodeCommonjs,
           vec!["nw.gui".to_string().into()],
           false,
         )));
@@ -1156,6 +1166,12 @@ impl CompilerOptionsBuilder {
         )));
     }
 
+    if externals_presets.dependencies() {
+      builder_context
+        .plugins
+        .push(BuiltinPluginOptions::PackageJsonExternalsRspackPlugin);
+    }
+
     // apply node defaults
     let node = f!(self.node.take(), <Option<NodeOption>>::builder)
       .build(&target_properties, output_module)?;
@@ -1250,7 +1266,13 @@ impl CompilerOptionsBuilder {
       .push(BuiltinPluginOptions::WorkerPlugin);
 
     // TODO: stats plugins
-    let stats = d!(self.stats.take(), StatsOptions { colors: true });
+    let stats = d!(
+      self.stats.take(),
+      StatsOptions {
+        colors: true,
+        ..Default::default()
+      }
+    );
 
     let amd = self.amd.take();
 
@@ -1746,6 +1768,32 @@ impl ModuleOptionsBuilder {
       );
     }
 
+    if !parser.contains_key("yaml") {
+      parser.insert(
+        "yaml".to_string(),
+        ParserOptions::Yaml(YamlParserOptions {
+          exports_depth: if matches!(mode, Mode::Development) {
+            Some(1)
+          } else {
+            Some(u32::MAX)
+          },
+        }),
+      );
+    }
+
+    if !parser.contains_key("toml") {
+      parser.insert(
+        "toml".to_string(),
+        ParserOptions::Toml(TomlParserOptions {
+          exports_depth: if matches!(mode, Mode::Development) {
+            Some(1)
+          } else {
+            Some(u32::MAX)
+          },
+        }),
+      );
+    }
+
     let generator = self.generator.get_or_insert(GeneratorOptionsMap::default());
     if !generator.contains_key("json") {
       generator.insert(
@@ -1794,6 +1842,7 @@ impl ModuleOptionsBuilder {
           local_ident_name: Some("[uniqueName]-[id]-[local]".into()),
 
           es_module: Some(true),
+          emit_dts: Some(false),
         }),
       );
 
@@ -1804,6 +1853,7 @@ impl ModuleOptionsBuilder {
           exports_convention: Some(CssExportsConvention::default()),
           local_ident_name: Some("[uniqueName]-[id]-[local]".into()),
           es_module: Some(true),
+          emit_dts: Some(false),
         }),
       );
     }
@@ -2140,6 +2190,8 @@ pub struct OutputOptionsBuilder {
   pathinfo: Option<PathInfo>,
   /// Set the clean option.
   clean: Option<CleanOptions>,
+  /// Set the clean manifest.
+  clean_manifest: Option<String>,
   /// Set the public path.
   public_path: Option<PublicPath>,
   /// Set the asset module filename.
@@ -2228,6 +2280,8 @@ pub struct OutputOptionsBuilder {
   environment: Option<Environment>,
   /// Set the compare before emit.
   compare_before_emit: Option<bool>,
+  /// Set the atomic emit.
+  atomic_emit: Option<bool>,
 }
 
 impl From<OutputOptions> for OutputOptionsBuilder {
@@ -2236,6 +2290,7 @@ impl From<OutputOptions> for OutputOptionsBuilder {
       path: Some(value.path),
       pathinfo: Some(value.pathinfo),
       clean: Some(value.clean),
+      clean_manifest: value.clean_manifest,
       public_path: Some(value.public_path),
       asset_module_filename: Some(value.asset_module_filename),
       wasm_loading: Some(value.wasm_loading),
@@ -2278,6 +2333,7 @@ impl From<OutputOptions> for OutputOptionsBuilder {
       devtool_fallback_module_filename_template: None,
       environment: Some(value.environment),
       compare_before_emit: Some(value.compare_before_emit),
+      atomic_emit: Some(value.atomic_emit),
       enabled_chunk_loading_types: None,
       enabled_wasm_loading_types: None,
     }
@@ -2290,6 +2346,7 @@ impl From<&mut OutputOptionsBuilder> for OutputOptionsBuilder {
       path: value.path.take(),
       pathinfo: value.pathinfo.take(),
       clean: value.clean.take(),
+      clean_manifest: value.clean_manifest.take(),
       public_path: value.public_path.take(),
       asset_module_filename: value.asset_module_filename.take(),
       wasm_loading: value.wasm_loading.take(),
@@ -2336,6 +2393,7 @@ impl From<&mut OutputOptionsBuilder> for OutputOptionsBuilder {
         .take(),
       environment: value.environment.take(),
       compare_before_emit: value.compare_before_emit.take(),
+      atomic_emit: value.atomic_emit.take(),
     }
   }
 }
@@ -2368,6 +2426,15 @@ impl OutputOptionsBuilder {
     self
   }
 
+  /// Record the content hashes of emitted files in a manifest with this name in the output directory.
+  ///
+  /// Unchanged files are not written again, and the files no longer emitted are removed
+  /// according to the clean option instead of cleaning the output directory before emitting.
+  pub fn clean_manifest(&mut self, name: impl Into<String>) -> &mut Self {
+    self.clean_manifest = Some(name.into());
+    self
+  }
+
   /// This option determines the URL prefix of the referenced resource, such as: image, file, etc.
   pub fn public_path(&mut self, public_path: PublicPath) -> &mut Self {
     self.public_path = Some(public_path);
@@ -2652,6 +2719,14 @@ impl OutputOptionsBuilder {
     self
   }
 
+  /// Set whether to emit into a staging directory and rename it to the output directory after emitting.
+  ///
+  /// Default set to `false`.
+  pub fn atomic_emit(&mut self, atomic_emit: bool) -> &mut Self {
+    self.atomic_emit = Some(atomic_emit);
+    self
+  }
+
   /// Build [`OutputOptions`] from builder.
   ///
   /// [`OutputOptions`]: rspack_core::options::OutputOptions
@@ -3119,6 +3194,7 @@ impl OutputOptionsBuilder {
       path,
       pathinfo,
       clean,
+      clean_manifest: self.clean_manifest.take(),
       asset_module_filename,
       public_path,
       wasm_loading,
@@ -3157,6 +3233,7 @@ impl OutputOptionsBuilder {
       script_type,
       environment,
       compare_before_emit: self.compare_before_emit.take().unwrap_or(true),
+      atomic_emit: self.atomic_emit.take().unwrap_or(false),
     })
   }
 }
@@ -3671,6 +3748,8 @@ pub struct ExperimentsBuilder {
   future_defaults: Option<bool>,
   /// Whether to enable css.
   css: Option<bool>,
+  /// How conflicting orders of css modules between chunks are reported.
+  css_order_conflicts: Option<CssOrderConflictsMode>,
   /// Whether to enable parallel code splitting.
   parallel_code_splitting: Option<bool>,
   /// Whether to enable async web assembly.
@@ -3689,6 +3768,7 @@ impl From<Experiments> for ExperimentsBuilder {
       output_module: None,
       future_defaults: None,
       css: Some(value.css),
+      css_order_conflicts: None,
       async_web_assembly: None,
     }
   }
@@ -3704,6 +3784,7 @@ impl From<&mut ExperimentsBuilder> for ExperimentsBuilder {
       output_module: value.output_module.take(),
       future_defaults: value.future_defaults.take(),
       css: value.css.take(),
+      css_order_conflicts: value.css_order_conflicts.take(),
       parallel_code_splitting: value.parallel_code_splitting.take(),
       async_web_assembly: value.async_web_assembly.take(),
     }
@@ -3741,6 +3822,12 @@ impl ExperimentsBuilder {
     self
   }
 
+  /// Set how conflicting orders of css modules between chunks are reported.
+  pub fn css_order_conflicts(&mut self, css_order_conflicts: CssOrderConflictsMode) -> &mut Self {
+    self.css_order_conflicts = Some(css_order_conflicts);
+    self
+  }
+
   /// Set whether to enable async web assembly.
   pub fn async_web_assembly(&mut self, async_web_assembly: bool) -> &mut Self {
     self.async_web_assembly = Some(async_web_assembly);
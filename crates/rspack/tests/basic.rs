@@ -52,4 +52,209 @@ mod tests {
     })
     .await;
   }
+
+  #[tokio::test(flavor = "multi_thread")]
+  async fn basic_atomic_emit() {
+    use std::sync::Arc;
+
+    use rspack_core::OutputOptions;
+    use rspack_fs::{MemoryFileSystem, WritableFileSystem};
+    use rspack_tasks::within_compiler_context_for_testing;
+    within_compiler_context_for_testing(async {
+      let fs = Arc::new(MemoryFileSystem::default());
+      fs.create_dir_all(Utf8Path::new("/dist")).await.unwrap();
+      fs.write(Utf8Path::new("/dist/stale.js"), b"")
+        .await
+        .unwrap();
+      // a backup directory left by a build crashed while swapping
+      fs.create_dir_all(Utf8Path::new("/.dist.rspack-backup"))
+        .await
+        .unwrap();
+
+      let mut compiler = Compiler::builder()
+        .context(Utf8Path::new(env!("CARGO_MANIFEST_DIR")).join("tests/fixtures/basic"))
+        .entry("main", "./src/index.js")
+        .output(
+          OutputOptions::builder()
+            .path("/dist")
+            .atomic_emit(true)
+            .clean_manifest(".rspack-manifest.json"),
+        )
+        .output_filesystem(fs.clone())
+        .build()
+        .unwrap();
+
+      compiler.build().await.unwrap();
+
+      assert_eq!(
+        fs.read_file(Utf8Path::new("/dist/main.js")).await.unwrap(),
+        b"console.log(123);"
+      );
+      // the output directory is replaced by the staging directory
+      assert!(fs.stat(Utf8Path::new("/dist/stale.js")).await.is_err());
+      assert!(
+        fs.stat(Utf8Path::new("/dist/.rspack-manifest.json"))
+          .await
+          .is_ok_and(|stat| stat.is_file)
+      );
+      assert!(
+        fs.stat(Utf8Path::new("/.dist.rspack-staging"))
+          .await
+          .is_err()
+      );
+      assert!(
+        fs.stat(Utf8Path::new("/.dist.rspack-backup"))
+          .await
+          .is_err()
+      );
+    })
+    .await;
+  }
+
+  #[tokio::test(flavor = "multi_thread")]
+  async fn basic_stats_json() {
+    use rspack_core::{ExtendedStatsOptions, Stats};
+    use rspack_tasks::within_compiler_context_for_testing;
+    within_compiler_context_for_testing(async {
+      let mut compiler = Compiler::builder()
+        .context(Utf8Path::new(env!("CARGO_MANIFEST_DIR")).join("tests/fixtures/basic"))
+        .entry("main", "./src/index.js")
+        .build()
+        .unwrap();
+
+      compiler.build().await.unwrap();
+
+      let options = ExtendedStatsOptions {
+        modules: true,
+        ..Default::default()
+      };
+      let json = Stats::new(&compiler.compilation)
+        .to_json_string(&options)
+        .await
+        .unwrap();
+      let stats: serde_json::Value = serde_json::from_str(&json).unwrap();
+
+      assert_eq!(stats["version"], "5.75.0");
+      assert!(stats["rspackVersion"].is_string());
+      assert!(stats["time"].is_u64());
+      assert!(stats["builtAt"].is_u64());
+      assert!(stats["publicPath"].is_string());
+      assert_eq!(stats["outputPath"], compiler.options.output.path.as_str());
+      assert_eq!(stats["children"], serde_json::json!([]));
+      assert_eq!(stats["assets"][0]["name"], "main.js");
+      assert_eq!(stats["assetsByChunkName"]["main"][0], "main.js");
+      assert_eq!(stats["chunks"][0]["names"][0], "main");
+      assert_eq!(stats["chunks"][0]["runtime"][0], "main");
+      assert_eq!(stats["modules"][0]["name"], "./src/index.js");
+      assert_eq!(stats["modules"][0]["moduleType"], "javascript/auto");
+      assert!(stats["modules"][0]["sizes"]["javascript"].is_number());
+      assert_eq!(stats["errors"], serde_json::json!([]));
+      assert_eq!(stats["errorsCount"], 0);
+    })
+    .await;
+  }
+
+  #[tokio::test(flavor = "multi_thread")]
+  async fn basic_overlay_fs() {
+    use std::{sync::Arc, time::SystemTime};
+
+    use rspack_fs::{NativeFileSystem, OverlayFileSystem};
+    use rspack_paths::ArcPath;
+    use rspack_tasks::within_compiler_context_for_testing;
+    use rspack_watcher::{
+      EventAggregateHandler, EventHandler, FsEventKind, FsWatcher, FsWatcherOptions,
+    };
+    use rustc_hash::FxHashSet as HashSet;
+    use tokio::sync::mpsc::{UnboundedSender, unbounded_channel};
+
+    struct RebuildHandler(UnboundedSender<(HashSet<String>, HashSet<String>)>);
+
+    impl EventAggregateHandler for RebuildHandler {
+      fn on_event_handle(&self, changed_files: HashSet<String>, deleted_files: HashSet<String>) {
+        let _ = self.0.send((changed_files, deleted_files));
+      }
+    }
+
+    struct NoopHandler;
+
+    impl EventHandler for NoopHandler {}
+
+    within_compiler_context_for_testing(async {
+      let context = Utf8Path::new(env!("CARGO_MANIFEST_DIR")).join("tests/fixtures/basic");
+      let entry = context.join("src/virtual.js");
+      let fs = Arc::new(OverlayFileSystem::new(Arc::new(NativeFileSystem::new(
+        false,
+      ))));
+      fs.write_file(&entry, b"console.log(\"virtual\");".to_vec())
+        .unwrap();
+
+      let mut watcher = FsWatcher::new(
+        FsWatcherOptions {
+          aggregate_timeout: Some(10),
+          ..Default::default()
+        },
+        Default::default(),
+      );
+      let trigger = watcher.event_trigger().unwrap();
+      fs.on_change(move |path| {
+        trigger.trigger_event(&ArcPath::from(path.as_std_path()), FsEventKind::Change);
+      });
+
+      let mut compiler = Compiler::builder()
+        .context(context)
+        .entry("virtual", "./src/virtual.js")
+        .input_filesystem(fs.clone())
+        .build()
+        .unwrap();
+
+      compiler.build().await.unwrap();
+
+      let errors: Vec<_> = compiler.compilation.get_errors().collect();
+      assert!(errors.is_empty());
+      let asset = &compiler.compilation.assets().get("virtual.js").unwrap();
+      assert_eq!(
+        asset.source.as_ref().unwrap().source().into_string_lossy(),
+        "console.log(\"virtual\");"
+      );
+
+      // the in-memory files don't exist on disk, so they are watched as missing paths
+      let (missing, files): (Vec<_>, Vec<_>) = compiler
+        .compilation
+        .file_dependencies
+        .iter()
+        .cloned()
+        .partition(|path| Utf8Path::from_path(path).is_some_and(|path| fs.contains(path)));
+      let (tx, mut rx) = unbounded_channel();
+      watcher
+        .watch(
+          (files.into_iter(), std::iter::empty()),
+          (std::iter::empty(), std::iter::empty()),
+          (missing.into_iter(), std::iter::empty()),
+          SystemTime::now(),
+          Box::new(RebuildHandler(tx)),
+          Box::new(NoopHandler),
+        )
+        .await;
+
+      fs.write_file(&entry, b"console.log(\"updated\");".to_vec())
+        .unwrap();
+      let (changed_files, deleted_files) = rx.recv().await.unwrap();
+      assert!(changed_files.contains(entry.as_str()));
+      compiler
+        .rebuild(
+          changed_files.into_iter().collect(),
+          deleted_files.into_iter().collect(),
+        )
+        .await
+        .unwrap();
+      watcher.close().await.unwrap();
+
+      let asset = &compiler.compilation.assets().get("virtual.js").unwrap();
+      assert_eq!(
+        asset.source.as_ref().unwrap().source().into_string_lossy(),
+        "console.log(\"updated\");"
+      );
+    })
+    .await;
+  }
 }
@@ -1,5 +1,5 @@
 use rspack::builder::Builder;
-use rspack_core::{Compiler, Optimization};
+use rspack_core::{Compiler, LibraryOptions, Optimization, OutputOptions};
 
 macro_rules! assert_snapshot {
   ($compiler:expr) => {
@@ -19,3 +19,19 @@ async fn options() {
 
   assert_snapshot!(Compiler::builder().target(vec![]).build());
 }
+
+#[tokio::test(flavor = "multi_thread")]
+async fn externals_type() {
+  assert_snapshot!(
+    Compiler::builder()
+      .output(OutputOptions::builder().library(LibraryOptions {
+        name: None,
+        export: None,
+        library_type: "unknown".to_string(),
+        umd_named_define: None,
+        auxiliary_comment: None,
+        amd_container: None,
+      }))
+      .build()
+  );
+}
@@ -0,0 +1,119 @@
+use rspack::builder::{Builder as _, Devtool};
+use rspack_core::{
+  Compiler, ExternalItem, ExternalItemValue, ExternalType, LibraryName, LibraryNonUmdObject,
+  LibraryOptions, Mode, OutputOptions,
+};
+use rspack_paths::Utf8Path;
+use rspack_tasks::within_compiler_context_for_testing;
+use rustc_hash::FxHashMap as HashMap;
+
+const LIBRARY_TYPES: [&str; 16] = [
+  "var",
+  "assign",
+  "assign-properties",
+  "this",
+  "window",
+  "self",
+  "global",
+  "commonjs",
+  "commonjs-static",
+  "commonjs2",
+  "commonjs-module",
+  "amd",
+  "amd-require",
+  "umd",
+  "umd2",
+  "system",
+];
+
+/// The external value of `lib` and a snippet expected in the rendered external module.
+fn external_of(external_type: ExternalType) -> (&'static str, &'static str) {
+  match external_type {
+    ExternalType::Var
+    | ExternalType::Assign
+    | ExternalType::Promise
+    | ExternalType::Const
+    | ExternalType::Let
+    | ExternalType::AssignProperties
+    | ExternalType::ModernModule => ("lib", "= lib;"),
+    ExternalType::This | ExternalType::Window | ExternalType::SelfObject | ExternalType::Global => {
+      ("lib", "[\"lib\"]")
+    }
+    ExternalType::Commonjs
+    | ExternalType::Commonjs2
+    | ExternalType::CommonjsModule
+    | ExternalType::CommonjsStatic
+    | ExternalType::CommonjsImport
+    | ExternalType::NodeCommonjs => ("lib", "require(\"lib\")"),
+    ExternalType::Amd
+    | ExternalType::AmdRequire
+    | ExternalType::Umd
+    | ExternalType::Umd2
+    | ExternalType::Jsonp
+    | ExternalType::System => ("lib", "__rspack_external_"),
+    ExternalType::Import | ExternalType::ModuleImport | ExternalType::Module => {
+      ("lib", "import(\"lib\")")
+    }
+    ExternalType::Script => (
+      "lib@https://example.com/lib.js",
+      "https://example.com/lib.js",
+    ),
+    ExternalType::Asset => ("lib", "= \"lib\";"),
+    ExternalType::CssImport => unreachable!("css-import externals are only used by CSS @import"),
+  }
+}
+
+#[tokio::test(flavor = "multi_thread")]
+async fn external_types_for_library_types() {
+  within_compiler_context_for_testing(async move {
+    for library_type in LIBRARY_TYPES {
+      for external_type in ExternalType::ALL {
+        if external_type == ExternalType::CssImport {
+          continue;
+        }
+        let (value, expected) = external_of(external_type);
+        let mut externals = HashMap::default();
+        externals.insert(
+          "lib".to_string(),
+          ExternalItemValue::String(value.to_string()),
+        );
+
+        let mut compiler = Compiler::builder()
+          .context(Utf8Path::new(env!("CARGO_MANIFEST_DIR")).join("tests/fixtures/externals"))
+          .entry("main", "./src/index.js")
+          .mode(Mode::Development)
+          .devtool(Devtool::False)
+          .externals(ExternalItem::Object(externals))
+          .externals_type(external_type)
+          .output(OutputOptions::builder().library(LibraryOptions {
+            name: Some(LibraryName::NonUmdObject(LibraryNonUmdObject::String(
+              "MyLibrary".to_string(),
+            ))),
+            export: None,
+            library_type: library_type.to_string(),
+            umd_named_define: None,
+            auxiliary_comment: None,
+            amd_container: None,
+          }))
+          .build()
+          .unwrap();
+
+        compiler.build().await.unwrap();
+
+        let errors: Vec<_> = compiler.compilation.get_errors().collect();
+        assert!(
+          errors.is_empty(),
+          "{external_type} externals in {library_type} library: {errors:?}"
+        );
+
+        let asset = &compiler.compilation.assets().get("main.js").unwrap();
+        let source = asset.source.as_ref().unwrap().source().into_string_lossy();
+        assert!(
+          source.contains(expected),
+          "{external_type} externals in {library_type} library should render `{expected}`"
+        );
+      }
+    }
+  })
+  .await;
+}
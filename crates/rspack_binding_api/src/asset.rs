@@ -16,18 +16,25 @@ use rustc_hash::FxHashSet;
 #[napi(object)]
 pub struct AssetInfoRelated {
   pub source_map: Option<Either<String, Null>>,
+  pub gzip: Option<Either<String, Null>>,
+  pub brotli: Option<Either<String, Null>>,
+  pub zstd: Option<Either<String, Null>>,
+}
+
+fn into_related_filename(value: Option<Either<String, Null>>) -> Option<String> {
+  match value {
+    Some(Either::A(string)) => Some(string),
+    Some(Either::B(_)) | None => None,
+  }
 }
 
 impl From<AssetInfoRelated> for rspack_core::AssetInfoRelated {
   fn from(i: AssetInfoRelated) -> Self {
     Self {
-      source_map: match i.source_map {
-        Some(either) => match either {
-          Either::A(string) => Some(string),
-          Either::B(_) => None,
-        },
-        None => None,
-      },
+      source_map: into_related_filename(i.source_map),
+      gzip: into_related_filename(i.gzip),
+      brotli: into_related_filename(i.brotli),
+      zstd: into_related_filename(i.zstd),
     }
   }
 }
@@ -209,6 +216,9 @@ impl From<rspack_core::AssetInfoRelated> for AssetInfoRelated {
   fn from(related: rspack_core::AssetInfoRelated) -> Self {
     Self {
       source_map: related.source_map.map(Either::A),
+      gzip: related.gzip.map(Either::A),
+      brotli: related.brotli.map(Either::A),
+      zstd: related.zstd.map(Either::A),
     }
   }
 }
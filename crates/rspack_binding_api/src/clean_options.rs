@@ -1,26 +1,43 @@
 use std::{str::FromStr, sync::Arc};
 
-use ::napi::bindgen_prelude::Either3;
+use ::napi::{Either, bindgen_prelude::Either3};
 use napi_derive::napi;
 use rspack_core::CleanOptions;
 use rspack_napi::{napi, threadsafe_function::ThreadsafeFunction};
 use rspack_paths::Utf8PathBuf;
 use rspack_regex::RspackRegex;
 
+const DEFAULT_MANIFEST_NAME: &str = ".rspack-manifest.json";
+
 /// File clean options
 ///
 /// This matches with:
 /// - keep:
 ///   - If a string, keep the files under this path
+/// - manifest:
+///   - If true or a string, record the emitted files in a manifest with this name
 #[napi(object, object_to_js = false)]
 #[derive(Debug)]
 pub struct JsCleanOptions {
   #[napi(ts_type = "string | RegExp | ((path: string) => boolean)")]
   pub keep: Option<Either3<String, RspackRegex, ThreadsafeFunction<String, bool>>>,
+  #[napi(ts_type = "boolean | string")]
+  pub manifest: Option<Either<bool, String>>,
+}
+
+impl JsCleanOptions {
+  pub fn manifest_name(&self) -> Option<String> {
+    match &self.manifest {
+      Some(Either::A(true)) => Some(DEFAULT_MANIFEST_NAME.to_string()),
+      Some(Either::B(name)) => Some(name.clone()),
+      Some(Either::A(false)) | None => None,
+    }
+  }
 }
 
 impl From<JsCleanOptions> for CleanOptions {
   fn from(value: JsCleanOptions) -> Self {
+    let manifest_enabled = value.manifest_name().is_some();
     match value.keep {
       Some(Either3::A(path)) => {
         CleanOptions::KeepPath(Utf8PathBuf::from_str(&path).expect("should be a valid path"))
@@ -30,7 +47,8 @@ impl From<JsCleanOptions> for CleanOptions {
         let func = func.clone();
         Box::pin(async move { func.call_with_sync(path).await })
       })),
-      None => CleanOptions::CleanAll(false),
+      // the files no longer emitted are removed with a manifest
+      None => CleanOptions::CleanAll(manifest_enabled),
     }
   }
 }
@@ -14,8 +14,8 @@ use entries::JsEntries;
 use napi_derive::napi;
 use rspack_collections::{DatabaseItem, IdentifierSet};
 use rspack_core::{
-  BindingCell, BoxDependency, Compilation, CompilationId, EntryOptions, FactorizeInfo,
-  ModuleIdentifier, Reflector, rspack_sources::BoxSource,
+  BindingCell, BoxDependency, CacheGenerationMemoryUsage, Compilation, CompilationId, EntryOptions,
+  FactorizeInfo, MemoryReport, MemoryUsage, ModuleIdentifier, Reflector, rspack_sources::BoxSource,
 };
 use rspack_error::{Diagnostic, Severity, ToStringResultToRspackResultExt};
 use rspack_napi::napi::bindgen_prelude::*;
@@ -408,6 +408,13 @@ impl JsCompilation {
     Ok(compilation.get_hash().map(|hash| hash.to_owned()))
   }
 
+  #[napi]
+  pub fn get_memory_report(&self) -> Result<JsMemoryReport> {
+    let compilation = self.as_ref()?;
+
+    Ok(compilation.memory_report().into())
+  }
+
   #[napi]
   pub fn dependencies(&'static self) -> Result<JsDependencies> {
     let compilation = self.as_ref()?;
@@ -1041,6 +1048,63 @@ pub struct JsExecuteModuleResult {
   pub error: Option<String>,
 }
 
+#[napi(object)]
+pub struct JsMemoryUsage {
+  pub name: String,
+  pub count: u32,
+  pub size: f64,
+}
+
+impl From<MemoryUsage> for JsMemoryUsage {
+  fn from(value: MemoryUsage) -> Self {
+    Self {
+      name: value.name,
+      count: value.count as u32,
+      size: value.size as f64,
+    }
+  }
+}
+
+#[napi(object)]
+pub struct JsCacheGenerationMemoryUsage {
+  pub cache: String,
+  pub generation: u32,
+  pub count: u32,
+  pub size: f64,
+}
+
+impl From<CacheGenerationMemoryUsage> for JsCacheGenerationMemoryUsage {
+  fn from(value: CacheGenerationMemoryUsage) -> Self {
+    Self {
+      cache: value.cache,
+      generation: value.generation,
+      count: value.count as u32,
+      size: value.size as f64,
+    }
+  }
+}
+
+#[napi(object)]
+pub struct JsMemoryReport {
+  pub artifacts: Vec<JsMemoryUsage>,
+  pub module_types: Vec<JsMemoryUsage>,
+  pub cache_generations: Vec<JsCacheGenerationMemoryUsage>,
+}
+
+impl From<MemoryReport> for JsMemoryReport {
+  fn from(value: MemoryReport) -> Self {
+    Self {
+      artifacts: value.artifacts.into_iter().map(Into::into).collect(),
+      module_types: value.module_types.into_iter().map(Into::into).collect(),
+      cache_generations: value
+        .cache_generations
+        .into_iter()
+        .map(Into::into)
+        .collect(),
+    }
+  }
+}
+
 #[napi(object)]
 #[derive(Clone, Debug)]
 pub struct JsBuildTimeExecutionOption {
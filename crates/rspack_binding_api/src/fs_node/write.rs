@@ -57,6 +57,17 @@ impl WritableFileSystem for NodeFileSystem {
       .to_fs_result()
   }
 
+  async fn rename(&self, from: &Utf8Path, to: &Utf8Path) -> Result<()> {
+    let from = from.as_str().to_string();
+    let to = to.as_str().to_string();
+    self
+      .0
+      .rename
+      .call_with_promise((from, to).into())
+      .await
+      .to_fs_result()
+  }
+
   async fn remove_file(&self, file: &Utf8Path) -> Result<()> {
     let file = file.as_str().to_string();
     self
@@ -145,17 +156,6 @@ impl WritableFileSystem for NodeFileSystem {
 
 #[async_trait]
 impl IntermediateFileSystemExtras for NodeFileSystem {
-  async fn rename(&self, from: &Utf8Path, to: &Utf8Path) -> Result<()> {
-    let from = from.as_str().to_string();
-    let to = to.as_str().to_string();
-    self
-      .0
-      .rename
-      .call_with_promise((from, to).into())
-      .await
-      .to_fs_result()
-  }
-
   async fn create_read_stream(&self, file: &Utf8Path) -> Result<Box<dyn ReadStream>> {
     let reader = NodeReadStream::try_new(file, self.0.clone()).await?;
     Ok(Box::new(reader))
@@ -99,11 +99,7 @@ mod trace_event;
 mod utils;
 mod virtual_modules;
 
-use std::{
-  cell::RefCell,
-  mem::ManuallyDrop,
-  sync::{Arc, RwLock},
-};
+use std::{cell::RefCell, mem::ManuallyDrop, sync::Arc};
 
 use napi::{CallContext, bindgen_prelude::*};
 pub use raw_options::{CustomPluginBuilder, register_custom_plugin};
@@ -112,7 +108,7 @@ use rspack_core::{
   BoxDependency, Compilation, CompilerId, EntryOptions, ModuleIdentifier, PluginExt,
 };
 use rspack_error::Diagnostic;
-use rspack_fs::{IntermediateFileSystem, NativeFileSystem, ReadableFileSystem};
+use rspack_fs::{IntermediateFileSystem, NativeFileSystem, OverlayFileSystem, ReadableFileSystem};
 use rspack_tasks::{CURRENT_COMPILER_CONTEXT, CompilerContext, within_compiler_context_sync};
 use rustc_hash::FxHashMap;
 use swc_core::common::util::take::Take;
@@ -134,9 +130,7 @@ use crate::{
   resolver_factory::JsResolverFactory,
   trace_event::RawTraceEvent,
   utils::callbackify,
-  virtual_modules::{
-    JsVirtualFileStore, TrieVirtualFileStore, VirtualFileStore, VirtualFileSystem,
-  },
+  virtual_modules::JsVirtualFileStore,
 };
 
 // Export expected @rspack/core version
@@ -169,7 +163,7 @@ struct JsCompiler {
   include_dependencies_map: FxHashMap<String, FxHashMap<EntryOptions, BoxDependency>>,
   entry_dependencies_map: FxHashMap<String, FxHashMap<EntryOptions, BoxDependency>>,
   compiler_context: Arc<CompilerContext>,
-  virtual_file_store: Option<Arc<RwLock<dyn VirtualFileStore>>>,
+  virtual_file_store: Option<Arc<OverlayFileSystem>>,
 }
 
 #[napi]
@@ -252,29 +246,15 @@ impl JsCompiler {
           })
         });
 
-      let mut virtual_file_store: Option<Arc<RwLock<dyn VirtualFileStore>>> = None;
+      let mut virtual_file_store: Option<Arc<OverlayFileSystem>> = None;
       if let Some(list) = virtual_files {
-        let store = {
-          let mut store = TrieVirtualFileStore::new();
-          for f in list {
-            store.write_file(f.path.as_str().into(), f.content.into());
-          }
-          Arc::new(RwLock::new(store))
-        };
-        input_file_system = input_file_system
-          .map(|real_fs| {
-            let binding: Arc<dyn ReadableFileSystem> =
-              Arc::new(VirtualFileSystem::new(real_fs, store.clone()));
-            binding
-          })
-          .or_else(|| {
-            let binding: Arc<dyn ReadableFileSystem> = Arc::new(VirtualFileSystem::new(
-              Arc::new(NativeFileSystem::new(pnp)),
-              store.clone(),
-            ));
-            Some(binding)
-          });
-        virtual_file_store = Some(store);
+        let real_fs = input_file_system
+          .take()
+          .unwrap_or_else(|| Arc::new(NativeFileSystem::new(pnp)));
+        let overlay_fs = Arc::new(OverlayFileSystem::new(real_fs));
+        JsVirtualFileStore::new(overlay_fs.clone()).batch_write_virtual_files_sync(list);
+        input_file_system = Some(overlay_fs.clone());
+        virtual_file_store = Some(overlay_fs);
       }
 
       resolver_factory_reference.update_options(
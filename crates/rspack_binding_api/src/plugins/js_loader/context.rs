@@ -24,6 +24,10 @@ pub struct JsLoaderItem {
   pub pitch_executed: bool,
 
   pub no_pitch: bool,
+
+  // timings in microseconds, accumulated on the JS side
+  pub pitch_duration: Option<i64>,
+  pub normal_duration: Option<i64>,
 }
 
 impl From<&rspack_loader_runner::LoaderItem<RunnerContext>> for JsLoaderItem {
@@ -37,6 +41,9 @@ impl From<&rspack_loader_runner::LoaderItem<RunnerContext>> for JsLoaderItem {
       pitch_executed: value.pitch_executed(),
 
       no_pitch: false,
+
+      pitch_duration: Some(value.pitch_duration().as_micros() as i64),
+      normal_duration: Some(value.normal_duration().as_micros() as i64),
     }
   }
 }
@@ -56,6 +63,8 @@ where
         pitch_executed: false,
         normal_executed: false,
         no_pitch: false,
+        pitch_duration: None,
+        normal_duration: None,
       };
     }
     Self {
@@ -65,6 +74,8 @@ where
       pitch_executed: false,
       normal_executed: false,
       no_pitch: false,
+      pitch_duration: None,
+      normal_duration: None,
     }
   }
 }
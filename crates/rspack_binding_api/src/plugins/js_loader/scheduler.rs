@@ -1,3 +1,5 @@
+use std::time::Duration;
+
 use napi::Either;
 use rspack_core::{
   AdditionalData, BUILTIN_LOADER_PREFIX, LoaderContext, NormalModuleLoaderShouldYield,
@@ -154,6 +156,12 @@ pub(crate) fn merge_loader_context(
       if from.pitch_executed {
         to.set_pitch_executed()
       }
+      if let Some(duration) = from.pitch_duration {
+        to.set_pitch_duration(Duration::from_micros(duration.max(0) as u64));
+      }
+      if let Some(duration) = from.normal_duration {
+        to.set_normal_duration(Duration::from_micros(duration.max(0) as u64));
+      }
       to.set_data(from.data);
       // JS loader should always be considered as finished
       to.set_finish_called();
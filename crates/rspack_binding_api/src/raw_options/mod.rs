@@ -82,7 +82,7 @@ impl TryFrom<RawOptions> for CompilerOptions {
       experiments.incremental.passes = IncrementalPasses::empty();
     }
     let optimization = value.optimization.try_into()?;
-    let stats = value.stats.into();
+    let stats = value.stats.try_into()?;
     let node = value
       .node
       .map(|n| {
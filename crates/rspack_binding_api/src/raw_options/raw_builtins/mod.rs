@@ -1,16 +1,20 @@
 mod raw_banner;
 mod raw_bundle_info;
 mod raw_circular_dependency;
+mod raw_compression;
 mod raw_context_replacement;
 mod raw_copy;
 mod raw_css_chunking;
 mod raw_css_extract;
+mod raw_css_modules;
 mod raw_dll;
 mod raw_esm_lib;
 mod raw_html;
 mod raw_http_uri;
+mod raw_i18n;
 mod raw_ids;
 mod raw_ignore;
+mod raw_import_map;
 mod raw_lazy_compilation;
 mod raw_lightning_css_minimizer;
 mod raw_limit_chunk_count;
@@ -19,6 +23,7 @@ mod raw_normal_replacement;
 mod raw_progress;
 mod raw_runtime_chunk;
 mod raw_size_limits;
+mod raw_source_map_validation;
 mod raw_sri;
 mod raw_swc_js_minimizer;
 
@@ -43,12 +48,13 @@ use rspack_ids::{
 use rspack_plugin_asset::AssetPlugin;
 use rspack_plugin_banner::BannerPlugin;
 use rspack_plugin_circular_dependencies::CircularDependencyRspackPlugin;
+use rspack_plugin_compression::CompressionRspackPlugin;
 use rspack_plugin_copy::{CopyRspackPlugin, CopyRspackPluginOptions};
 use rspack_plugin_css::CssPlugin;
 use rspack_plugin_css_chunking::CssChunkingPlugin;
 use rspack_plugin_devtool::{
   EvalDevToolModulePlugin, EvalSourceMapDevToolPlugin, SourceMapDevToolModuleOptionsPlugin,
-  SourceMapDevToolModuleOptionsPluginOptions, SourceMapDevToolPlugin,
+  SourceMapDevToolModuleOptionsPluginOptions, SourceMapDevToolPlugin, SourceMapValidationPlugin,
 };
 use rspack_plugin_dll::{
   DllEntryPlugin, DllReferenceAgencyPlugin, FlagAllModulesAsUsedPlugin, LibManifestPlugin,
@@ -56,13 +62,16 @@ use rspack_plugin_dll::{
 use rspack_plugin_dynamic_entry::DynamicEntryPlugin;
 use rspack_plugin_ensure_chunk_conditions::EnsureChunkConditionsPlugin;
 use rspack_plugin_entry::EntryPlugin;
-use rspack_plugin_esm_library::EsmLibraryPlugin;
+use rspack_plugin_esm_library::{EsmLibraryPlugin, LibraryFormat};
 use rspack_plugin_externals::{
-  ExternalsPlugin, electron_target_plugin, http_externals_rspack_plugin, node_target_plugin,
+  ExternalsPlugin, PackageJsonExternalsRspackPlugin, electron_target_plugin,
+  http_externals_rspack_plugin, node_target_plugin,
 };
 use rspack_plugin_hmr::HotModuleReplacementPlugin;
 use rspack_plugin_html::HtmlRspackPlugin;
+use rspack_plugin_i18n::I18nRspackPlugin;
 use rspack_plugin_ignore::IgnorePlugin;
+use rspack_plugin_import_map::ImportMapRspackPlugin;
 use rspack_plugin_javascript::{
   FlagDependencyExportsPlugin, FlagDependencyUsagePlugin, InferAsyncModulesPlugin,
   InlineExportsPlugin, JsPlugin, MangleExportsPlugin, ModuleConcatenationPlugin,
@@ -106,13 +115,17 @@ use self::{
   raw_banner::RawBannerPluginOptions,
   raw_bundle_info::{RawBundlerInfoModeWrapper, RawBundlerInfoPluginOptions},
   raw_circular_dependency::RawCircularDependencyRspackPluginOptions,
+  raw_compression::RawCompressionRspackPluginOptions,
   raw_context_replacement::RawContextReplacementPluginOptions,
   raw_copy::RawCopyRspackPluginOptions,
   raw_css_chunking::RawCssChunkingPluginOptions,
   raw_css_extract::RawCssExtractPluginOption,
+  raw_css_modules::RawCssModulesPluginOptions,
   raw_dll::{RawDllEntryPluginOptions, RawLibManifestPluginOptions},
   raw_html::RawHtmlRspackPluginOptions,
+  raw_i18n::RawI18nRspackPluginOptions,
   raw_ignore::RawIgnorePluginOptions,
+  raw_import_map::RawImportMapRspackPluginOptions,
   raw_lazy_compilation::{JsBackend, RawLazyCompilationOption},
   raw_limit_chunk_count::RawLimitChunkCountPluginOptions,
   raw_mf::{
@@ -122,6 +135,7 @@ use self::{
   raw_normal_replacement::RawNormalModuleReplacementPluginOptions,
   raw_runtime_chunk::RawRuntimeChunkOptions,
   raw_size_limits::RawSizeLimitsPluginOptions,
+  raw_source_map_validation::RawSourceMapValidationPluginOptions,
   raw_swc_js_minimizer::RawSwcJsMinimizerRspackPluginOptions,
 };
 use crate::{
@@ -195,6 +209,7 @@ pub enum BuiltinPluginName {
   SourceMapDevToolPlugin,
   EvalSourceMapDevToolPlugin,
   EvalDevToolModulePlugin,
+  SourceMapValidationPlugin,
   SideEffectsFlagPlugin,
   FlagDependencyExportsPlugin,
   FlagDependencyUsagePlugin,
@@ -216,6 +231,7 @@ pub enum BuiltinPluginName {
   // rspack specific plugins
   // naming format follow XxxRspackPlugin
   HttpExternalsRspackPlugin,
+  PackageJsonExternalsRspackPlugin,
   CopyRspackPlugin,
   HtmlRspackPlugin,
   SwcJsMinimizerRspackPlugin,
@@ -227,6 +243,9 @@ pub enum BuiltinPluginName {
   RstestPlugin,
   RslibPlugin,
   CircularDependencyRspackPlugin,
+  I18nRspackPlugin,
+  CompressionRspackPlugin,
+  ImportMapRspackPlugin,
   URLPlugin,
 
   // rspack js adapter plugins
@@ -373,12 +392,12 @@ impl<'a> BuiltinPlugin<'a> {
           .map(|e| RawExternalItemWrapper(e).try_into())
           .collect::<Result<Vec<_>>>()
           .map_err(|report| napi::Error::from_reason(report.to_string()))?;
-        let plugin = ExternalsPlugin::new(
-          plugin_options.r#type,
-          externals,
-          plugin_options.place_in_initial,
-        )
-        .boxed();
+        let r#type = plugin_options
+          .r#type
+          .parse()
+          .map_err(|report: rspack_error::Error| napi::Error::from_reason(report.to_string()))?;
+        let plugin =
+          ExternalsPlugin::new(r#type, externals, plugin_options.place_in_initial).boxed();
         plugins.push(plugin);
       }
       BuiltinPluginName::NodeTargetPlugin => plugins.push(node_target_plugin()),
@@ -416,8 +435,17 @@ impl<'a> BuiltinPlugin<'a> {
       BuiltinPluginName::EsmLibraryPlugin => {
         let options = downcast_into::<RawEsmLibraryPlugin>(self.options)
           .map_err(|report| napi::Error::from_reason(report.to_string()))?;
-        plugins
-          .push(EsmLibraryPlugin::new(options.preserve_modules.as_deref().map(Into::into)).boxed());
+        let format = options
+          .format
+          .as_deref()
+          .map(LibraryFormat::try_from)
+          .transpose()
+          .map_err(|report| napi::Error::from_reason(report.to_string()))?
+          .unwrap_or_default();
+        plugins.push(
+          EsmLibraryPlugin::new(options.preserve_modules.as_deref().map(Into::into), format)
+            .boxed(),
+        );
       }
       BuiltinPluginName::ArrayPushCallbackChunkFormatPlugin => {
         plugins.push(ArrayPushCallbackChunkFormatPlugin::default().boxed());
@@ -478,7 +506,10 @@ impl<'a> BuiltinPlugin<'a> {
           ContainerReferencePlugin::new(
             downcast_into::<RawContainerReferencePluginOptions>(self.options)
               .map_err(|report| napi::Error::from_reason(report.to_string()))?
-              .into(),
+              .try_into()
+              .map_err(|report: rspack_error::Error| {
+                napi::Error::from_reason(report.to_string())
+              })?,
           )
           .boxed(),
         );
@@ -604,6 +635,11 @@ impl<'a> BuiltinPlugin<'a> {
           .boxed(),
         );
       }
+      BuiltinPluginName::SourceMapValidationPlugin => {
+        let options = downcast_into::<RawSourceMapValidationPluginOptions>(self.options)
+          .map_err(|report| napi::Error::from_reason(report.to_string()))?;
+        plugins.push(SourceMapValidationPlugin::new(options.into()).boxed());
+      }
       BuiltinPluginName::SideEffectsFlagPlugin => {
         plugins.push(SideEffectsFlagPlugin::default().boxed())
       }
@@ -630,7 +666,16 @@ impl<'a> BuiltinPlugin<'a> {
       BuiltinPluginName::ModuleConcatenationPlugin => {
         plugins.push(ModuleConcatenationPlugin::default().boxed())
       }
-      BuiltinPluginName::CssModulesPlugin => plugins.push(CssPlugin::default().boxed()),
+      BuiltinPluginName::CssModulesPlugin => plugins.push(
+        CssPlugin::new(
+          downcast_into::<Option<RawCssModulesPluginOptions>>(self.options)
+            .map_err(|report| napi::Error::from_reason(report.to_string()))?
+            .unwrap_or_default()
+            .try_into()
+            .map_err(|report: rspack_error::Error| napi::Error::from_reason(report.to_string()))?,
+        )
+        .boxed(),
+      ),
       BuiltinPluginName::APIPlugin => plugins.push(APIPlugin::default().boxed()),
       BuiltinPluginName::RuntimeChunkPlugin => plugins.push(
         RuntimeChunkPlugin::new(
@@ -657,6 +702,9 @@ impl<'a> BuiltinPlugin<'a> {
         let plugin = http_externals_rspack_plugin(plugin_options.css, plugin_options.web_async);
         plugins.push(plugin);
       }
+      BuiltinPluginName::PackageJsonExternalsRspackPlugin => {
+        plugins.push(PackageJsonExternalsRspackPlugin::default().boxed())
+      }
       BuiltinPluginName::SwcJsMinimizerRspackPlugin => {
         let plugin = SwcJsMinimizerRspackPlugin::new(
           downcast_into::<RawSwcJsMinimizerRspackPluginOptions>(self.options)
@@ -829,6 +877,25 @@ impl<'a> BuiltinPlugin<'a> {
           }
         }
       }
+      BuiltinPluginName::I18nRspackPlugin => {
+        let options = downcast_into::<RawI18nRspackPluginOptions>(self.options)
+          .map_err(|report| napi::Error::from_reason(report.to_string()))?;
+        plugins.push(I18nRspackPlugin::new(options.into()).boxed());
+      }
+      BuiltinPluginName::CompressionRspackPlugin => plugins.push(
+        CompressionRspackPlugin::new(
+          downcast_into::<RawCompressionRspackPluginOptions>(self.options)
+            .map_err(|report| napi::Error::from_reason(report.to_string()))?
+            .try_into()
+            .map_err(|report: rspack_error::Error| napi::Error::from_reason(report.to_string()))?,
+        )
+        .boxed(),
+      ),
+      BuiltinPluginName::ImportMapRspackPlugin => {
+        let options = downcast_into::<RawImportMapRspackPluginOptions>(self.options)
+          .map_err(|report| napi::Error::from_reason(report.to_string()))?;
+        plugins.push(ImportMapRspackPlugin::new(options.into()).boxed());
+      }
       BuiltinPluginName::ModuleInfoHeaderPlugin => {
         let verbose = downcast_into::<bool>(self.options)
           .map_err(|report| napi::Error::from_reason(report.to_string()))?;
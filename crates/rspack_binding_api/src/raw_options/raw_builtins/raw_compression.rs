@@ -0,0 +1,54 @@
+use napi_derive::napi;
+use rspack_error::Result;
+use rspack_plugin_compression::{
+  CompressionAlgorithm, CompressionAlgorithmOptions, CompressionRspackPluginOptions,
+};
+
+use crate::asset_condition::{RawAssetConditions, into_asset_conditions};
+
+#[derive(Debug)]
+#[napi(object, object_to_js = false)]
+pub struct RawCompressionAlgorithmOptions {
+  #[napi(ts_type = "\"gzip\" | \"brotli\" | \"zstd\"")]
+  pub algorithm: String,
+  pub level: Option<u32>,
+}
+
+#[derive(Debug)]
+#[napi(object, object_to_js = false)]
+pub struct RawCompressionRspackPluginOptions {
+  pub algorithms: Vec<RawCompressionAlgorithmOptions>,
+  #[napi(ts_type = "string | RegExp | (string | RegExp)[]")]
+  pub test: Option<RawAssetConditions>,
+  #[napi(ts_type = "string | RegExp | (string | RegExp)[]")]
+  pub include: Option<RawAssetConditions>,
+  #[napi(ts_type = "string | RegExp | (string | RegExp)[]")]
+  pub exclude: Option<RawAssetConditions>,
+  pub threshold: u32,
+  pub min_ratio: f64,
+}
+
+impl TryFrom<RawCompressionRspackPluginOptions> for CompressionRspackPluginOptions {
+  type Error = rspack_error::Error;
+
+  fn try_from(value: RawCompressionRspackPluginOptions) -> Result<Self> {
+    let algorithms = value
+      .algorithms
+      .into_iter()
+      .map(|options| {
+        Ok(CompressionAlgorithmOptions {
+          algorithm: CompressionAlgorithm::try_from(options.algorithm.as_str())?,
+          level: options.level,
+        })
+      })
+      .collect::<Result<Vec<_>>>()?;
+    Ok(Self {
+      algorithms,
+      test: value.test.map(into_asset_conditions),
+      include: value.include.map(into_asset_conditions),
+      exclude: value.exclude.map(into_asset_conditions),
+      threshold: value.threshold as usize,
+      min_ratio: value.min_ratio,
+    })
+  }
+}
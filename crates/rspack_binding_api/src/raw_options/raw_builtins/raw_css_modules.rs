@@ -0,0 +1,25 @@
+use napi_derive::napi;
+use rspack_error::Result;
+use rspack_plugin_css::{CssOrderConflictsMode, CssPluginOptions};
+
+#[derive(Debug, Default)]
+#[napi(object, object_to_js = false)]
+pub struct RawCssModulesPluginOptions {
+  #[napi(ts_type = r#""warning" | "error""#)]
+  pub order_conflicts: Option<String>,
+}
+
+impl TryFrom<RawCssModulesPluginOptions> for CssPluginOptions {
+  type Error = rspack_error::Error;
+
+  fn try_from(value: RawCssModulesPluginOptions) -> Result<Self> {
+    Ok(Self {
+      order_conflicts: value
+        .order_conflicts
+        .as_deref()
+        .map(CssOrderConflictsMode::try_from)
+        .transpose()?
+        .unwrap_or_default(),
+    })
+  }
+}
@@ -1,4 +1,6 @@
 #[napi(object, object_to_js = false)]
 pub struct RawEsmLibraryPlugin {
   pub preserve_modules: Option<String>,
+  #[napi(ts_type = "\"esm\" | \"commonjs\"")]
+  pub format: Option<String>,
 }
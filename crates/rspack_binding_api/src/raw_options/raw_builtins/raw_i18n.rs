@@ -0,0 +1,37 @@
+use std::collections::HashMap;
+
+use napi::Either;
+use napi_derive::napi;
+use rspack_plugin_i18n::{I18nCatalog, I18nLocale, I18nRspackPluginOptions};
+
+#[napi(object, object_to_js = false)]
+pub struct RawI18nRspackPluginOptions {
+  #[napi(ts_type = "Record<string, string | Record<string, string>>")]
+  pub locales: HashMap<String, Either<String, HashMap<String, String>>>,
+  pub filename: String,
+  pub locale_expression: String,
+  pub global_name: String,
+}
+
+impl From<RawI18nRspackPluginOptions> for I18nRspackPluginOptions {
+  fn from(value: RawI18nRspackPluginOptions) -> Self {
+    let mut locales = value
+      .locales
+      .into_iter()
+      .map(|(name, catalog)| I18nLocale {
+        name,
+        catalog: match catalog {
+          Either::A(file) => I18nCatalog::File(file),
+          Either::B(messages) => I18nCatalog::Messages(messages.into_iter().collect()),
+        },
+      })
+      .collect::<Vec<_>>();
+    locales.sort_unstable_by(|a, b| a.name.cmp(&b.name));
+    Self {
+      locales,
+      filename: value.filename,
+      locale_expression: value.locale_expression,
+      global_name: value.global_name,
+    }
+  }
+}
@@ -0,0 +1,29 @@
+use napi_derive::napi;
+use rspack_plugin_import_map::ImportMapRspackPluginOptions;
+use rustc_hash::FxHashMap as HashMap;
+
+#[derive(Debug)]
+#[napi(object, object_to_js = false)]
+pub struct RawImportMapRspackPluginOptions {
+  pub filename: Option<String>,
+  pub imports: HashMap<String, String>,
+  pub external_url: Option<String>,
+  pub chunks: bool,
+  pub chunk_prefix: String,
+  pub public_path: Option<String>,
+  pub inject: bool,
+}
+
+impl From<RawImportMapRspackPluginOptions> for ImportMapRspackPluginOptions {
+  fn from(value: RawImportMapRspackPluginOptions) -> Self {
+    Self {
+      filename: value.filename,
+      imports: value.imports.into_iter().collect(),
+      external_url: value.external_url,
+      chunks: value.chunks,
+      chunk_prefix: value.chunk_prefix,
+      public_path: value.public_path,
+      inject: value.inject,
+    }
+  }
+}
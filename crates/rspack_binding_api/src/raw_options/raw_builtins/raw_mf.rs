@@ -70,14 +70,16 @@ pub struct RawContainerReferencePluginOptions {
   pub enhanced: bool,
 }
 
-impl From<RawContainerReferencePluginOptions> for ContainerReferencePluginOptions {
-  fn from(value: RawContainerReferencePluginOptions) -> Self {
-    Self {
-      remote_type: value.remote_type,
+impl TryFrom<RawContainerReferencePluginOptions> for ContainerReferencePluginOptions {
+  type Error = rspack_error::Error;
+
+  fn try_from(value: RawContainerReferencePluginOptions) -> rspack_error::Result<Self> {
+    Ok(Self {
+      remote_type: value.remote_type.parse()?,
       remotes: value.remotes.into_iter().map(|e| e.into()).collect(),
       share_scope: value.share_scope,
       enhanced: value.enhanced,
-    }
+    })
   }
 }
 
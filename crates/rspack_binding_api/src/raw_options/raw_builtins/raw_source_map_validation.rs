@@ -0,0 +1,22 @@
+use napi_derive::napi;
+use rspack_plugin_devtool::SourceMapValidationPluginOptions;
+
+use crate::asset_condition::{RawAssetConditions, into_asset_conditions};
+
+#[derive(Debug)]
+#[napi(object, object_to_js = false)]
+pub struct RawSourceMapValidationPluginOptions {
+  #[napi(ts_type = "string | RegExp | (string | RegExp)[]")]
+  pub test: Option<RawAssetConditions>,
+  #[napi(ts_type = "string | RegExp | (string | RegExp)[]")]
+  pub dump_modules: Option<RawAssetConditions>,
+}
+
+impl From<RawSourceMapValidationPluginOptions> for SourceMapValidationPluginOptions {
+  fn from(value: RawSourceMapValidationPluginOptions) -> Self {
+    Self {
+      test: value.test.map(into_asset_conditions),
+      dump_modules: value.dump_modules.map(into_asset_conditions),
+    }
+  }
+}
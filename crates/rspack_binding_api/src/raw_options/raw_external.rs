@@ -6,7 +6,7 @@ use napi::{
 };
 use napi_derive::napi;
 use rspack_core::{
-  ExternalItem, ExternalItemFnCtx, ExternalItemFnResult, ExternalItemValue,
+  ExternalItem, ExternalItemFnCtx, ExternalItemFnResult, ExternalItemValue, ExternalType,
   ResolveOptionsWithDependencyType, ResolverFactory,
 };
 use rspack_napi::threadsafe_function::ThreadsafeFunction;
@@ -69,12 +69,17 @@ pub struct RawExternalItemFnResult {
   pub result: Option<RawExternalItemValue>,
 }
 
-impl From<RawExternalItemFnResult> for ExternalItemFnResult {
-  fn from(value: RawExternalItemFnResult) -> Self {
-    Self {
-      external_type: value.external_type,
+impl TryFrom<RawExternalItemFnResult> for ExternalItemFnResult {
+  type Error = rspack_error::Error;
+
+  fn try_from(value: RawExternalItemFnResult) -> rspack_error::Result<Self> {
+    Ok(Self {
+      external_type: value
+        .external_type
+        .map(|t| t.parse::<ExternalType>())
+        .transpose()?,
       result: value.result.map(|v| RawExternalItemValueWrapper(v).into()),
-    }
+    })
   }
 }
 
@@ -230,7 +235,7 @@ impl TryFrom<RawExternalItemWrapper> for ExternalItem {
       )),
       Either4::D(v) => Ok(Self::Fn(Box::new(move |ctx: ExternalItemFnCtx| {
         let v = v.clone();
-        Box::pin(async move { v.call_with_promise(ctx.into()).await.map(|r| r.into()) })
+        Box::pin(async move { v.call_with_promise(ctx.into()).await?.try_into() })
       }))),
     }
   }
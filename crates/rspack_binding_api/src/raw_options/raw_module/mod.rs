@@ -14,7 +14,7 @@ use napi_derive::napi;
 use regex::Regex;
 use rspack_core::{
   AssetGeneratorDataUrl, AssetGeneratorDataUrlFnCtx, AssetGeneratorDataUrlOptions,
-  AssetGeneratorOptions, AssetInlineGeneratorOptions, AssetParserDataUrl,
+  AssetGeneratorOptions, AssetInlineGeneratorOptions, AssetParserDataUrl, AssetParserDataUrlFnCtx,
   AssetParserDataUrlOptions, AssetParserOptions, AssetResourceGeneratorOptions,
   CssAutoGeneratorOptions, CssAutoParserOptions, CssGeneratorOptions, CssModuleGeneratorOptions,
   CssModuleParserOptions, CssParserOptions, DescriptionData, DynamicImportFetchPriority,
@@ -23,7 +23,8 @@ use rspack_core::{
   JavascriptParserOrder, JavascriptParserUrl, JsonGeneratorOptions, JsonParserOptions,
   ModuleNoParseRule, ModuleNoParseRules, ModuleNoParseTestFn, ModuleOptions, ModuleRule,
   ModuleRuleEffect, ModuleRuleEnforce, ModuleRuleUse, ModuleRuleUseLoader, OverrideStrict,
-  ParseOption, ParserOptions, ParserOptionsMap, TypeReexportPresenceMode, UnsafeCachePredicate,
+  ParseOption, ParserOptions, ParserOptionsMap, TomlParserOptions, TypeReexportPresenceMode,
+  UnsafeCachePredicate, YamlParserOptions,
 };
 use rspack_error::error;
 use rspack_napi::threadsafe_function::ThreadsafeFunction;
@@ -190,7 +191,7 @@ pub struct RawModuleRule {
 #[napi(object, object_to_js = false)]
 pub struct RawParserOptions {
   #[napi(
-    ts_type = r#""asset" | "css" | "css/auto" | "css/module" | "javascript" | "javascript/auto" | "javascript/dynamic" | "javascript/esm" | "json""#
+    ts_type = r#""asset" | "css" | "css/auto" | "css/module" | "javascript" | "javascript/auto" | "javascript/dynamic" | "javascript/esm" | "json" | "yaml" | "toml""#
   )]
   pub r#type: String,
   pub asset: Option<RawAssetParserOptions>,
@@ -199,6 +200,8 @@ pub struct RawParserOptions {
   pub css_module: Option<RawCssModuleParserOptions>,
   pub javascript: Option<RawJavascriptParserOptions>,
   pub json: Option<RawJsonParserOptions>,
+  pub yaml: Option<RawYamlParserOptions>,
+  pub toml: Option<RawTomlParserOptions>,
 }
 
 impl From<RawParserOptions> for ParserOptions {
@@ -260,6 +263,18 @@ impl From<RawParserOptions> for ParserOptions {
           .expect("should have an \"json\" when RawParserOptions.type is \"json\"")
           .into(),
       ),
+      "yaml" => Self::Yaml(
+        value
+          .yaml
+          .expect("should have an \"yaml\" when RawParserOptions.type is \"yaml\"")
+          .into(),
+      ),
+      "toml" => Self::Toml(
+        value
+          .toml
+          .expect("should have an \"toml\" when RawParserOptions.type is \"toml\"")
+          .into(),
+      ),
       _ => panic!(
         "Failed to resolve the RawParserOptions.type {}.",
         value.r#type
@@ -412,7 +427,7 @@ impl From<RawJsonGeneratorOptions> for JsonGeneratorOptions {
 }
 
 #[derive(Debug, Default)]
-#[napi(object)]
+#[napi(object, object_to_js = false)]
 pub struct RawAssetParserOptions {
   pub data_url_condition: Option<RawAssetParserDataUrl>,
 }
@@ -426,12 +441,13 @@ impl From<RawAssetParserOptions> for AssetParserOptions {
 }
 
 #[derive(Debug, Default)]
-#[napi(object)]
+#[napi(object, object_to_js = false)]
 pub struct RawAssetParserDataUrl {
-  #[napi(ts_type = r#""options""#)]
+  #[napi(ts_type = r#""options" | "function""#)]
   pub r#type: String,
   pub options: Option<RawAssetParserDataUrlOptions>,
-  // TODO: pub function
+  #[napi(ts_type = "(source: Buffer, context: RawAssetParserDataUrlFnCtx) => boolean")]
+  pub function: Option<ThreadsafeFunction<FnArgs<(Buffer, RawAssetParserDataUrlFnCtx)>, bool>>,
 }
 
 impl From<RawAssetParserDataUrl> for AssetParserDataUrl {
@@ -443,14 +459,53 @@ impl From<RawAssetParserDataUrl> for AssetParserDataUrl {
           .expect("should have an \"options\" when RawAssetParserDataUrl.type is \"options\"")
           .into(),
       ),
+      "function" => {
+        let func = value
+          .function
+          .expect("should have a \"function\" when RawAssetParserDataUrl.type is \"function\"");
+        Self::Func(Arc::new(move |source, ctx| {
+          let func = func.clone();
+          let source = source.into();
+          let ctx = ctx.into();
+          Box::pin(async move { func.call_with_sync((source, ctx).into()).await })
+        }))
+      }
       _ => panic!(
-        "Failed to resolve the RawAssetParserDataUrl.type {}. Expected type is `options`.",
+        "Failed to resolve the RawAssetParserDataUrl.type {}. Expected type is `options` or `function`.",
         value.r#type
       ),
     }
   }
 }
 
+#[napi(object)]
+pub struct RawAssetParserDataUrlFnCtx {
+  pub filename: String,
+  pub module: RawAssetParserDataUrlFnModule,
+}
+
+/// The module is still building when the condition is evaluated, so only the
+/// information known before parsing is provided.
+#[napi(object)]
+pub struct RawAssetParserDataUrlFnModule {
+  pub identifier: String,
+  pub r#type: String,
+  pub layer: Option<String>,
+}
+
+impl From<AssetParserDataUrlFnCtx<'_>> for RawAssetParserDataUrlFnCtx {
+  fn from(value: AssetParserDataUrlFnCtx) -> Self {
+    Self {
+      filename: value.filename,
+      module: RawAssetParserDataUrlFnModule {
+        identifier: value.module_identifier.to_string(),
+        r#type: value.module_type.to_string(),
+        layer: value.module_layer.cloned(),
+      },
+    }
+  }
+}
+
 #[derive(Debug, Clone, Default)]
 #[napi(object)]
 pub struct RawAssetParserDataUrlOptions {
@@ -538,6 +593,34 @@ impl From<RawJsonParserOptions> for JsonParserOptions {
   }
 }
 
+#[derive(Debug, Default)]
+#[napi(object, object_to_js = false)]
+pub struct RawYamlParserOptions {
+  pub exports_depth: Option<u32>,
+}
+
+impl From<RawYamlParserOptions> for YamlParserOptions {
+  fn from(value: RawYamlParserOptions) -> Self {
+    Self {
+      exports_depth: value.exports_depth,
+    }
+  }
+}
+
+#[derive(Debug, Default)]
+#[napi(object, object_to_js = false)]
+pub struct RawTomlParserOptions {
+  pub exports_depth: Option<u32>,
+}
+
+impl From<RawTomlParserOptions> for TomlParserOptions {
+  fn from(value: RawTomlParserOptions) -> Self {
+    Self {
+      exports_depth: value.exports_depth,
+    }
+  }
+}
+
 #[derive(Debug, Default)]
 #[napi(object, object_to_js = false)]
 pub struct RawGeneratorOptions {
@@ -554,14 +637,16 @@ pub struct RawGeneratorOptions {
   pub json: Option<RawJsonGeneratorOptions>,
 }
 
-impl From<RawGeneratorOptions> for GeneratorOptions {
-  fn from(value: RawGeneratorOptions) -> Self {
-    match value.r#type.as_str() {
+impl TryFrom<RawGeneratorOptions> for GeneratorOptions {
+  type Error = rspack_error::Error;
+
+  fn try_from(value: RawGeneratorOptions) -> rspack_error::Result<Self> {
+    Ok(match value.r#type.as_str() {
       "asset" => Self::Asset(
         value
           .asset
           .expect("should have an \"asset\" when RawGeneratorOptions.type is \"asset\"")
-          .into(),
+          .try_into()?,
       ),
       "asset/inline" => Self::AssetInline(
         value
@@ -569,7 +654,7 @@ impl From<RawGeneratorOptions> for GeneratorOptions {
           .expect(
             "should have an \"asset_inline\" when RawGeneratorOptions.type is \"asset/inline\"",
           )
-          .into(),
+          .try_into()?,
       ),
       "asset/resource" => Self::AssetResource(
         value
@@ -607,7 +692,7 @@ impl From<RawGeneratorOptions> for GeneratorOptions {
         r#"Failed to resolve the RawGeneratorOptions.type {}."#,
         value.r#type
       ),
-    }
+    })
   }
 }
 
@@ -628,21 +713,26 @@ pub struct RawAssetGeneratorOptions {
   #[napi(ts_type = r#""url" | "preserve""#)]
   pub import_mode: Option<String>,
   pub binary: Option<bool>,
+  pub image_optimization: Option<bool>,
 }
 
-impl From<RawAssetGeneratorOptions> for AssetGeneratorOptions {
-  fn from(value: RawAssetGeneratorOptions) -> Self {
-    Self {
+impl TryFrom<RawAssetGeneratorOptions> for AssetGeneratorOptions {
+  type Error = rspack_error::Error;
+
+  fn try_from(value: RawAssetGeneratorOptions) -> rspack_error::Result<Self> {
+    Ok(Self {
       emit: value.emit,
       filename: value.filename.map(|i| i.into()),
       output_path: value.output_path.map(|i| i.into()),
       public_path: value.public_path.map(|i| i.into()),
       data_url: value
         .data_url
-        .map(|i| RawAssetGeneratorDataUrlWrapper(i).into()),
+        .map(|i| RawAssetGeneratorDataUrlWrapper(i).try_into())
+        .transpose()?,
       import_mode: value.import_mode.map(|n| n.into()),
       binary: value.binary,
-    }
+      image_optimization: value.image_optimization,
+    })
   }
 }
 
@@ -657,14 +747,17 @@ pub struct RawAssetInlineGeneratorOptions {
   pub binary: Option<bool>,
 }
 
-impl From<RawAssetInlineGeneratorOptions> for AssetInlineGeneratorOptions {
-  fn from(value: RawAssetInlineGeneratorOptions) -> Self {
-    Self {
+impl TryFrom<RawAssetInlineGeneratorOptions> for AssetInlineGeneratorOptions {
+  type Error = rspack_error::Error;
+
+  fn try_from(value: RawAssetInlineGeneratorOptions) -> rspack_error::Result<Self> {
+    Ok(Self {
       data_url: value
         .data_url
-        .map(|i| RawAssetGeneratorDataUrlWrapper(i).into()),
+        .map(|i| RawAssetGeneratorDataUrlWrapper(i).try_into())
+        .transpose()?,
       binary: value.binary,
-    }
+    })
   }
 }
 
@@ -679,6 +772,7 @@ pub struct RawAssetResourceGeneratorOptions {
   #[napi(ts_type = r#""url" | "preserve""#)]
   pub import_mode: Option<String>,
   pub binary: Option<bool>,
+  pub image_optimization: Option<bool>,
 }
 
 impl From<RawAssetResourceGeneratorOptions> for AssetResourceGeneratorOptions {
@@ -690,6 +784,7 @@ impl From<RawAssetResourceGeneratorOptions> for AssetResourceGeneratorOptions {
       public_path: value.public_path.map(|i| i.into()),
       import_mode: value.import_mode.map(|i| i.into()),
       binary: value.binary,
+      image_optimization: value.image_optimization,
     }
   }
 }
@@ -726,34 +821,38 @@ impl From<AssetGeneratorDataUrlFnCtx<'_>> for RawAssetGeneratorDataUrlFnCtx {
   }
 }
 
-impl From<RawAssetGeneratorDataUrlWrapper> for AssetGeneratorDataUrl {
-  fn from(value: RawAssetGeneratorDataUrlWrapper) -> Self {
-    match value.0 {
-      Either::A(a) => Self::Options(a.into()),
+impl TryFrom<RawAssetGeneratorDataUrlWrapper> for AssetGeneratorDataUrl {
+  type Error = rspack_error::Error;
+
+  fn try_from(value: RawAssetGeneratorDataUrlWrapper) -> rspack_error::Result<Self> {
+    Ok(match value.0 {
+      Either::A(a) => Self::Options(a.try_into()?),
       Either::B(b) => Self::Func(Arc::new(move |source, ctx| {
         let b = b.clone();
         let source = source.into();
         let ctx = ctx.into();
         Box::pin(async move { b.call_with_sync((source, ctx).into()).await })
       })),
-    }
+    })
   }
 }
 
 #[derive(Debug, Default)]
 #[napi(object)]
 pub struct RawAssetGeneratorDataUrlOptions {
-  #[napi(ts_type = r#""base64" | "false" | undefined"#)]
+  #[napi(ts_type = r#""base64" | "mini-svg" | "false" | undefined"#)]
   pub encoding: Option<String>,
   pub mimetype: Option<String>,
 }
 
-impl From<RawAssetGeneratorDataUrlOptions> for AssetGeneratorDataUrlOptions {
-  fn from(value: RawAssetGeneratorDataUrlOptions) -> Self {
-    Self {
-      encoding: value.encoding.map(|i| i.into()),
+impl TryFrom<RawAssetGeneratorDataUrlOptions> for AssetGeneratorDataUrlOptions {
+  type Error = rspack_error::Error;
+
+  fn try_from(value: RawAssetGeneratorDataUrlOptions) -> rspack_error::Result<Self> {
+    Ok(Self {
+      encoding: value.encoding.map(|i| i.try_into()).transpose()?,
       mimetype: value.mimetype,
-    }
+    })
   }
 }
 
@@ -781,6 +880,7 @@ pub struct RawCssAutoGeneratorOptions {
   pub exports_only: Option<bool>,
   pub local_ident_name: Option<String>,
   pub es_module: Option<bool>,
+  pub emit_dts: Option<bool>,
 }
 
 impl From<RawCssAutoGeneratorOptions> for CssAutoGeneratorOptions {
@@ -790,6 +890,7 @@ impl From<RawCssAutoGeneratorOptions> for CssAutoGeneratorOptions {
       exports_only: value.exports_only,
       local_ident_name: value.local_ident_name.map(|n| n.into()),
       es_module: value.es_module,
+      emit_dts: value.emit_dts,
     }
   }
 }
@@ -802,6 +903,7 @@ pub struct RawCssModuleGeneratorOptions {
   pub exports_only: Option<bool>,
   pub local_ident_name: Option<String>,
   pub es_module: Option<bool>,
+  pub emit_dts: Option<bool>,
 }
 
 impl From<RawCssModuleGeneratorOptions> for CssModuleGeneratorOptions {
@@ -811,6 +913,7 @@ impl From<RawCssModuleGeneratorOptions> for CssModuleGeneratorOptions {
       exports_only: value.exports_only,
       local_ident_name: value.local_ident_name.map(|n| n.into()),
       es_module: value.es_module,
+      emit_dts: value.emit_dts,
     }
   }
 }
@@ -969,7 +1072,7 @@ impl TryFrom<RawModuleRule> for ModuleRule {
         r#type: module_type,
         layer: value.layer,
         parser: value.parser.map(|raw| raw.into()),
-        generator: value.generator.map(|raw| raw.into()),
+        generator: value.generator.map(|raw| raw.try_into()).transpose()?,
         resolve: value.resolve.map(|raw| raw.try_into()).transpose()?,
         side_effects: value.side_effects,
         enforce,
@@ -1022,7 +1125,7 @@ impl TryFrom<RawModuleOptions> for ModuleOptions {
         .generator
         .map(|x| {
           x.into_iter()
-            .map(|(k, v)| Ok((k, v.into())))
+            .map(|(k, v)| Ok((k, GeneratorOptions::try_from(v)?)))
             .collect::<std::result::Result<GeneratorOptionsMap, rspack_error::Error>>()
         })
         .transpose()?,
@@ -138,6 +138,7 @@ pub struct RawOutputOptions {
   pub script_type: WithFalse<String>,
   pub environment: RawEnvironment,
   pub compare_before_emit: bool,
+  pub atomic_emit: bool,
 }
 
 pub type RawWasmLoading = WithFalse<String>;
@@ -170,15 +171,19 @@ impl TryFrom<RawOutputOptions> for OutputOptions {
       Either::B(s) => PathInfo::String(s),
     };
 
-    let clean = match value.clean {
-      Either::A(b) => CleanOptions::CleanAll(b),
-      Either::B(cop) => cop.into(),
+    let (clean, clean_manifest) = match value.clean {
+      Either::A(b) => (CleanOptions::CleanAll(b), None),
+      Either::B(cop) => {
+        let clean_manifest = cop.manifest_name();
+        (cop.into(), clean_manifest)
+      }
     };
 
     Ok(OutputOptions {
       path: value.path.into(),
       pathinfo,
       clean,
+      clean_manifest,
       public_path: value.public_path.into(),
       asset_module_filename: value.asset_module_filename.into(),
       wasm_loading: value.wasm_loading.into(),
@@ -220,6 +225,7 @@ impl TryFrom<RawOutputOptions> for OutputOptions {
       charset: value.charset,
       chunk_load_timeout: value.chunk_load_timeout,
       compare_before_emit: value.compare_before_emit,
+      atomic_emit: value.atomic_emit,
     })
   }
 }
@@ -1,16 +1,26 @@
 use napi_derive::napi;
-use rspack_core::StatsOptions;
+use rspack_core::{DiagnosticsFormat, StatsOptions};
 
 #[derive(Debug, Default)]
 #[napi(object)]
 pub struct RawStatsOptions {
   pub colors: bool,
+  #[napi(ts_type = r#""text" | "json" | "sarif""#)]
+  pub diagnostics_format: Option<String>,
 }
 
-impl From<RawStatsOptions> for StatsOptions {
-  fn from(value: RawStatsOptions) -> Self {
-    Self {
+impl TryFrom<RawStatsOptions> for StatsOptions {
+  type Error = rspack_error::Error;
+
+  fn try_from(value: RawStatsOptions) -> rspack_error::Result<Self> {
+    Ok(Self {
       colors: value.colors,
-    }
+      diagnostics_format: value
+        .diagnostics_format
+        .as_deref()
+        .map(DiagnosticsFormat::try_from)
+        .transpose()?
+        .unwrap_or_default(),
+    })
   }
 }
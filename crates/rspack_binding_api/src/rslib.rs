@@ -1,5 +1,5 @@
 use derive_more::Debug;
-use rspack_plugin_rslib::RslibPluginOptions;
+use rspack_plugin_rslib::{RslibDtsOptions, RslibPluginOptions};
 
 #[derive(Debug)]
 #[napi(object, object_to_js = false)]
@@ -10,6 +10,12 @@ pub struct RawRslibPluginOptions {
   /// Add shims for javascript/esm modules
   /// @default `false`
   pub force_node_shims: Option<bool>,
+  /// Emit a bundled `.d.ts` of each entry, rolled up from the isolated declarations of its TypeScript modules
+  /// @default `false`
+  pub emit_dts: Option<bool>,
+  /// The filename template of the bundled `.d.ts`
+  /// @default `"[name].d.ts"`
+  pub dts_filename: Option<String>,
 }
 
 impl From<RawRslibPluginOptions> for RslibPluginOptions {
@@ -17,6 +23,11 @@ impl From<RawRslibPluginOptions> for RslibPluginOptions {
     Self {
       intercept_api_plugin: value.intercept_api_plugin.unwrap_or_default(),
       force_node_shims: value.force_node_shims.unwrap_or_default(),
+      dts: value.emit_dts.unwrap_or_default().then(|| RslibDtsOptions {
+        filename: value
+          .dts_filename
+          .unwrap_or_else(|| "[name].d.ts".to_string()),
+      }),
     }
   }
 }
@@ -8,7 +8,8 @@ use napi::{
 use napi_derive::napi;
 use rspack_collections::IdentifierMap;
 use rspack_core::{
-  EntrypointsStatsOption, ExtendedStatsOptions, Stats, StatsChunk, StatsModule, StatsUsedExports,
+  DiagnosticsFormat, EntrypointsStatsOption, ExtendedStatsOptions, Stats, StatsChunk, StatsModule,
+  StatsUsedExports,
   rspack_sources::{RawBufferSource, Source, SourceValue},
 };
 use rspack_error::Severity;
@@ -675,7 +676,12 @@ impl<'a> TryFrom<StatsModule<'a>> for JsStatsModule<'a> {
 pub struct JsStatsModuleProfile {
   // use f64 to make js side as a number type
   pub factory: f64,
+  pub resolving: f64,
   pub building: f64,
+  pub loaders: Vec<JsStatsLoaderProfile>,
+  pub parsing: f64,
+  pub code_generation: f64,
+  pub hashing: f64,
 }
 
 impl From<rspack_core::StatsModuleProfile> for JsStatsModuleProfile {
@@ -683,7 +689,90 @@ impl From<rspack_core::StatsModuleProfile> for JsStatsModuleProfile {
     Self {
       // The time is short and no data will be lost when converting from u64 to f64
       factory: value.factory as f64,
+      resolving: value.resolving as f64,
       building: value.building as f64,
+      loaders: value.loaders.into_iter().map(Into::into).collect(),
+      parsing: value.parsing as f64,
+      code_generation: value.code_generation as f64,
+      hashing: value.hashing as f64,
+    }
+  }
+}
+
+#[napi(object, object_from_js = false)]
+pub struct JsStatsLoaderProfile {
+  pub loader: String,
+  pub pitch: f64,
+  pub normal: f64,
+}
+
+impl From<rspack_core::StatsLoaderProfile> for JsStatsLoaderProfile {
+  fn from(value: rspack_core::StatsLoaderProfile) -> Self {
+    Self {
+      loader: value.loader,
+      pitch: value.pitch as f64,
+      normal: value.normal as f64,
+    }
+  }
+}
+
+#[napi(object, object_from_js = false)]
+pub struct JsStatsProfileSummary {
+  pub loaders: Vec<JsStatsLoaderProfileSummary>,
+  pub packages: Vec<JsStatsPackageProfileSummary>,
+}
+
+impl From<rspack_core::StatsProfileSummary> for JsStatsProfileSummary {
+  fn from(value: rspack_core::StatsProfileSummary) -> Self {
+    Self {
+      loaders: value.loaders.into_iter().map(Into::into).collect(),
+      packages: value.packages.into_iter().map(Into::into).collect(),
+    }
+  }
+}
+
+#[napi(object, object_from_js = false)]
+pub struct JsStatsLoaderProfileSummary {
+  pub loader: String,
+  pub modules: u32,
+  pub pitch: f64,
+  pub normal: f64,
+}
+
+impl From<rspack_core::StatsLoaderProfileSummary> for JsStatsLoaderProfileSummary {
+  fn from(value: rspack_core::StatsLoaderProfileSummary) -> Self {
+    Self {
+      loader: value.loader,
+      modules: value.modules,
+      pitch: value.pitch as f64,
+      normal: value.normal as f64,
+    }
+  }
+}
+
+#[napi(object, object_from_js = false)]
+pub struct JsStatsPackageProfileSummary {
+  pub name: String,
+  pub modules: u32,
+  pub resolving: f64,
+  pub building: f64,
+  pub loaders: f64,
+  pub parsing: f64,
+  pub code_generation: f64,
+  pub hashing: f64,
+}
+
+impl From<rspack_core::StatsPackageProfileSummary> for JsStatsPackageProfileSummary {
+  fn from(value: rspack_core::StatsPackageProfileSummary) -> Self {
+    Self {
+      name: value.name,
+      modules: value.modules,
+      resolving: value.resolving as f64,
+      building: value.building as f64,
+      loaders: value.loaders as f64,
+      parsing: value.parsing as f64,
+      code_generation: value.code_generation as f64,
+      hashing: value.hashing as f64,
     }
   }
 }
@@ -1010,6 +1099,13 @@ impl From<JsStatsOptions> for ExtendedStatsOptions {
       source: value.source,
       used_exports: value.used_exports,
       warnings: value.warnings,
+      // computed by the JavaScript side from the compilation
+      built_at: false,
+      children: false,
+      output_path: false,
+      public_path: false,
+      timings: false,
+      version: false,
     }
   }
 }
@@ -1033,6 +1129,7 @@ pub struct JsStatsCompilation<'a> {
   #[napi(ts_type = "Array<JsStatsModule>")]
   pub modules: Option<napi_value>,
   pub named_chunk_groups: Option<Vec<JsStatsChunkGroup<'a>>>,
+  pub profile: Option<JsStatsProfileSummary>,
   #[napi(ts_type = "Array<JsStatsError>")]
   pub warnings: napi_value,
 }
@@ -1114,6 +1211,8 @@ impl JsStats {
       self.named_chunk_groups(options.chunk_group_auxiliary, options.chunk_group_children)
     });
 
+    let profile = self.inner.get_profile_summary().map(Into::into);
+
     let errors = self.errors(env)?;
 
     let warnings = self.warnings(env)?;
@@ -1127,6 +1226,7 @@ impl JsStats {
       hash,
       modules,
       named_chunk_groups,
+      profile,
       warnings,
     }))
   }
@@ -1227,6 +1327,14 @@ impl JsStats {
       .collect()
   }
 
+  #[napi(ts_args_type = r#"format: "text" | "json" | "sarif""#)]
+  pub fn diagnostics_report(&self, format: String) -> Result<String> {
+    self
+      .inner
+      .emit_diagnostics_report(DiagnosticsFormat::try_from(format.as_str()).to_napi_result()?)
+      .to_napi_result()
+  }
+
   fn hash(&self) -> Option<&str> {
     self.inner.get_hash()
   }
@@ -2,9 +2,9 @@ use std::cell::RefCell;
 
 use napi::bindgen_prelude::BigInt;
 use napi_derive::napi;
-use rspack_tracing::{PerfettoTracer, StdoutTracer, TraceEvent, Tracer};
+use rspack_tracing::{ChromeTracer, PerfettoTracer, StdoutTracer, TraceEvent, Tracer};
 use rspack_util::tracing_preset::{
-  TRACING_ALL_PRESET, TRACING_BENCH_TARGET, TRACING_OVERVIEW_PRESET,
+  TRACING_ALL_PRESET, TRACING_BENCH_TARGET, TRACING_DETAIL_PRESET, TRACING_OVERVIEW_PRESET,
 };
 use rustc_hash::FxHashMap as HashMap;
 use tracing::level_filters::LevelFilter;
@@ -57,6 +57,7 @@ pub(super) fn register_global_trace(
     "OVERVIEW" => TRACING_OVERVIEW_PRESET,
     "ALL" => TRACING_ALL_PRESET,
     "BENCH" => TRACING_BENCH_TARGET,
+    "DETAIL" => TRACING_DETAIL_PRESET.as_str(),
     _ => filter.as_str(),
   };
   GLOBAL_TRACE_STATE.with(|state| {
@@ -65,8 +66,9 @@ pub(super) fn register_global_trace(
       let mut tracer: Box<dyn Tracer> = match layer.as_str() {
         "logger" => Box::new(StdoutTracer),
         "perfetto" => Box::new(PerfettoTracer::default()),
+        "chrome" => Box::new(ChromeTracer::default()),
         _ => anyhow::bail!(
-          "Unexpected layer: {}, supported layers:'logger', 'perfetto' ",
+          "Unexpected layer: {}, supported layers:'logger', 'perfetto', 'chrome' ",
           layer
         ),
       };
@@ -1,3 +1,5 @@
+use std::collections::BTreeMap;
+
 use futures::Future;
 use rspack_collections::Identifier;
 use rspack_error::{Diagnostic, Result};
@@ -21,6 +23,17 @@ impl ChunkRenderCacheArtifact {
       storage.start_next_generation();
     }
   }
+  /// count and retained bytes of the cached sources grouped by generation
+  pub fn memory_usage_by_generation(
+    &self,
+    size_of: impl FnMut(&BoxSource) -> usize,
+  ) -> BTreeMap<u32, (usize, usize)> {
+    self
+      .storage
+      .as_ref()
+      .map(|storage| storage.memory_usage_by_generation(size_of))
+      .unwrap_or_default()
+  }
   pub async fn use_cache<G, F>(
     &self,
     compilation: &Compilation,
@@ -0,0 +1,61 @@
+use std::sync::Arc;
+
+use rspack_error::Result;
+use rspack_loader_runner::LoaderResultCache;
+
+use super::{ScopedCache, storage::Storage};
+
+const SCOPE: &str = "loader_result";
+
+/// The results of pure loaders saved in the persistent cache storage.
+///
+/// Unlike the other scoped caches, the entries which are not used by a compilation are kept,
+/// since a rebuild only runs the loaders of the changed modules, and a module switched back to
+/// a previous content, e.g. after switching branches, should still hit its results. The stale
+/// entries are removed by the expiration of the storage.
+#[derive(Debug)]
+pub struct PersistentLoaderResultCache {
+  storage: Arc<dyn Storage>,
+  cache: ScopedCache,
+}
+
+impl PersistentLoaderResultCache {
+  pub fn new(storage: Arc<dyn Storage>) -> Self {
+    Self {
+      storage,
+      cache: ScopedCache::new(SCOPE),
+    }
+  }
+
+  pub async fn recovery(&self) -> Result<()> {
+    self.cache.recovery(&self.storage).await
+  }
+}
+
+impl LoaderResultCache for PersistentLoaderResultCache {
+  fn get(&self, key: &[u8]) -> Option<Arc<Vec<u8>>> {
+    self.cache.get(key)
+  }
+
+  fn set(&self, key: Vec<u8>, value: Vec<u8>) {
+    self.cache.set(&self.storage, key, value);
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::{super::storage::MemoryStorage, *};
+
+  #[tokio::test]
+  async fn should_recover_loader_results() {
+    let storage: Arc<dyn Storage> = Arc::new(MemoryStorage::default());
+    let cache = PersistentLoaderResultCache::new(storage.clone());
+    cache.recovery().await.expect("should recover");
+    cache.set(b"key".to_vec(), b"value".to_vec());
+
+    let cache = PersistentLoaderResultCache::new(storage);
+    cache.recovery().await.expect("should recover");
+    assert_eq!(cache.get(b"key").as_deref(), Some(&b"value".to_vec()));
+    assert!(cache.get(b"other").is_none());
+  }
+}
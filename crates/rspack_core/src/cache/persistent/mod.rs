@@ -1,6 +1,8 @@
 mod build_dependencies;
 mod cacheable_context;
+mod loader_result_cache;
 mod occasion;
+mod scoped_cache;
 pub mod snapshot;
 pub mod storage;
 
@@ -10,9 +12,11 @@ use std::{
 };
 
 pub use cacheable_context::{CacheableContext, FromContext};
+pub use loader_result_cache::PersistentLoaderResultCache;
 use rspack_fs::{IntermediateFileSystem, ReadableFileSystem};
 use rspack_paths::ArcPathSet;
 use rspack_workspace::rspack_pkg_version;
+pub use scoped_cache::ScopedCache;
 
 use self::{
   build_dependencies::{BuildDeps, BuildDepsOptions},
@@ -26,6 +30,10 @@ use crate::{
   compilation::build_module_graph::{BuildModuleGraphArtifact, BuildModuleGraphArtifactState},
 };
 
+/// Bump this whenever the layout or the meaning of the cached data changes without a change of
+/// the rspack version, e.g. the module profiles storing microseconds instead of milliseconds.
+const CACHE_FORMAT_VERSION: u32 = 1;
+
 #[derive(Debug, Clone, Hash)]
 pub struct PersistentCacheOptions {
   pub build_dependencies: BuildDepsOptions,
@@ -41,6 +49,7 @@ pub struct PersistentCache {
   build_deps: BuildDeps,
   snapshot: Snapshot,
   storage: Arc<dyn Storage>,
+  loader_result_cache: Arc<PersistentLoaderResultCache>,
   make_occasion: MakeOccasion,
   meta_occasion: MetaOccasion,
   async_mode: bool,
@@ -62,6 +71,7 @@ impl PersistentCache {
       compiler_path.hash(&mut hasher);
       option.hash(&mut hasher);
       rspack_pkg_version!().hash(&mut hasher);
+      CACHE_FORMAT_VERSION.hash(&mut hasher);
       compiler_options.name.hash(&mut hasher);
       compiler_options.mode.hash(&mut hasher);
       hex::encode(hasher.finish().to_ne_bytes())
@@ -82,6 +92,7 @@ impl PersistentCache {
         storage.clone(),
       ),
       snapshot: Snapshot::new(option.snapshot.clone(), input_filesystem, storage.clone()),
+      loader_result_cache: Arc::new(PersistentLoaderResultCache::new(storage.clone())),
       storage,
       make_occasion,
       meta_occasion,
@@ -102,6 +113,9 @@ impl PersistentCache {
     if let Err(err) = self.meta_occasion.recovery().await {
       self.warnings.push(err.to_string());
     }
+    if let Err(err) = self.loader_result_cache.recovery().await {
+      self.warnings.push(err.to_string());
+    }
   }
 
   async fn save(&mut self) {
@@ -129,6 +143,8 @@ impl PersistentCache {
 impl Cache for PersistentCache {
   async fn before_compile(&mut self, compilation: &mut Compilation) -> bool {
     self.initialize().await;
+    compilation.persistent_cache_storage = Some(self.storage.clone());
+    compilation.loader_result_cache = Some(self.loader_result_cache.clone());
 
     // rebuild will pass modified_files and removed_files from js side,
     // so only calculate them when build.
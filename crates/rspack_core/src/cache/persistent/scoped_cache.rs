@@ -0,0 +1,97 @@
+use std::sync::Arc;
+
+use dashmap::{DashMap, DashSet};
+use rspack_error::Result;
+use tokio::sync::OnceCell;
+
+use super::storage::Storage;
+
+/// Scoped Cache
+///
+/// A key-value cache whose entries are saved in a scope of the persistent cache storage.
+/// It is used by plugins that want to reuse expensive results across builds, such as
+/// minimizers, so the value is usually derived from a content hash of its inputs.
+///
+/// Entries are recovered from the storage once and kept in memory, and the entries that
+/// are not used during a compilation are removed by [`ScopedCache::remove_unused`].
+#[derive(Debug)]
+pub struct ScopedCache {
+  scope: &'static str,
+  recovered: OnceCell<()>,
+  entries: DashMap<Vec<u8>, Arc<Vec<u8>>>,
+  used: DashSet<Vec<u8>>,
+}
+
+impl ScopedCache {
+  pub fn new(scope: &'static str) -> Self {
+    Self {
+      scope,
+      recovered: OnceCell::new(),
+      entries: Default::default(),
+      used: Default::default(),
+    }
+  }
+
+  /// Recover the entries saved by previous builds, only the first call reads the storage.
+  pub async fn recovery(&self, storage: &Arc<dyn Storage>) -> Result<()> {
+    self
+      .recovered
+      .get_or_try_init(|| async {
+        for (key, value) in storage.load(self.scope).await? {
+          self.entries.insert(key.as_ref().clone(), value);
+        }
+        Ok::<(), rspack_error::Error>(())
+      })
+      .await?;
+    Ok(())
+  }
+
+  pub fn get(&self, key: &[u8]) -> Option<Arc<Vec<u8>>> {
+    let value = self.entries.get(key).map(|value| value.clone())?;
+    self.used.insert(key.to_vec());
+    Some(value)
+  }
+
+  pub fn set(&self, storage: &Arc<dyn Storage>, key: Vec<u8>, value: Vec<u8>) {
+    storage.set(self.scope, key.clone(), value.clone());
+    self.used.insert(key.clone());
+    self.entries.insert(key, Arc::new(value));
+  }
+
+  /// Remove the entries which are not used since the last call from memory and storage.
+  pub fn remove_unused(&self, storage: &Arc<dyn Storage>) {
+    self.entries.retain(|key, _| {
+      let used = self.used.contains(key);
+      if !used {
+        storage.remove(self.scope, key);
+      }
+      used
+    });
+    self.used.clear();
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::{super::storage::MemoryStorage, *};
+
+  #[tokio::test]
+  async fn should_recover_and_remove_unused_entries() {
+    let storage: Arc<dyn Storage> = Arc::new(MemoryStorage::default());
+    let cache = ScopedCache::new("test");
+    cache.recovery(&storage).await.expect("should recover");
+    cache.set(&storage, b"a".to_vec(), b"1".to_vec());
+    cache.set(&storage, b"b".to_vec(), b"2".to_vec());
+    cache.remove_unused(&storage);
+
+    let cache = ScopedCache::new("test");
+    cache.recovery(&storage).await.expect("should recover");
+    assert_eq!(cache.get(b"a").as_deref(), Some(&b"1".to_vec()));
+    cache.remove_unused(&storage);
+
+    let cache = ScopedCache::new("test");
+    cache.recovery(&storage).await.expect("should recover");
+    assert!(cache.get(b"a").is_some());
+    assert!(cache.get(b"b").is_none());
+  }
+}
@@ -123,6 +123,7 @@ impl Task<TaskContext> for AddTask {
       plugin_driver: context.plugin_driver.clone(),
       runtime_template: context.runtime_template.clone(),
       fs: context.fs.clone(),
+      loader_result_cache: context.loader_result_cache.clone(),
       forwarded_ids,
     })])
   }
@@ -1,15 +1,17 @@
 use std::{collections::VecDeque, sync::Arc};
 
 use rspack_fs::ReadableFileSystem;
+use rspack_util::tracing_preset::TRACING_DETAIL_TARGET;
 use rustc_hash::FxHashSet;
+use tracing::{Instrument, trace_span};
 
 use super::{
   TaskContext, lazy::ProcessUnlazyDependenciesTask, process_dependencies::ProcessDependenciesTask,
 };
 use crate::{
   AsyncDependenciesBlock, BoxDependency, BoxModule, BuildContext, BuildResult, CompilationId,
-  CompilerId, CompilerOptions, DependencyParents, ModuleProfile, ResolverFactory, RuntimeTemplate,
-  SharedPluginDriver,
+  CompilerId, CompilerOptions, DependencyParents, LoaderResultCache, ModuleProfile,
+  ResolverFactory, RuntimeTemplate, SharedPluginDriver,
   compilation::build_module_graph::{ForwardedIdSet, HasLazyDependencies, LazyDependencies},
   utils::{
     ResourceId,
@@ -28,6 +30,7 @@ pub struct BuildTask {
   pub runtime_template: Arc<RuntimeTemplate>,
   pub plugin_driver: SharedPluginDriver,
   pub fs: Arc<dyn ReadableFileSystem>,
+  pub loader_result_cache: Option<Arc<dyn LoaderResultCache>>,
   pub forwarded_ids: ForwardedIdSet,
 }
 
@@ -47,6 +50,7 @@ impl Task<TaskContext> for BuildTask {
       mut current_profile,
       mut module,
       fs,
+      loader_result_cache,
       forwarded_ids,
     } = *self;
     if let Some(current_profile) = &mut current_profile {
@@ -59,6 +63,11 @@ impl Task<TaskContext> for BuildTask {
       .call(compiler_id, compilation_id, &mut module)
       .await?;
 
+    let span = trace_span!(
+      target: TRACING_DETAIL_TARGET,
+      "module:build",
+      module = module.identifier().as_str()
+    );
     let result = module
       .build(
         BuildContext {
@@ -69,16 +78,23 @@ impl Task<TaskContext> for BuildTask {
           plugin_driver: plugin_driver.clone(),
           runtime_template: runtime_template.clone(),
           fs: fs.clone(),
+          loader_result_cache,
         },
         None,
       )
+      .instrument(span)
       .await;
 
     if let Some(current_profile) = &mut current_profile {
       current_profile.mark_building_end();
     }
 
-    result.map::<Vec<Box<dyn Task<TaskContext>>>, _>(|build_result| {
+    result.map::<Vec<Box<dyn Task<TaskContext>>>, _>(|mut build_result| {
+      if let Some(current_profile) = &mut current_profile
+        && let Some(build_profile) = build_result.profile.take()
+      {
+        current_profile.set_build_profile(build_profile);
+      }
       vec![Box::new(BuildResultTask {
         module,
         build_result: Box::new(build_result),
@@ -7,8 +7,8 @@ use rustc_hash::FxHashMap as HashMap;
 use super::BuildModuleGraphArtifact;
 use crate::{
   Compilation, CompilationId, CompilerId, CompilerOptions, DependencyTemplate,
-  DependencyTemplateType, DependencyType, ModuleFactory, ResolverFactory, RuntimeTemplate,
-  SharedPluginDriver,
+  DependencyTemplateType, DependencyType, LoaderResultCache, ModuleFactory, ResolverFactory,
+  RuntimeTemplate, SharedPluginDriver,
   incremental::Incremental,
   module_graph::{ModuleGraph, ModuleGraphMut, ModuleGraphPartial},
   old_cache::Cache as OldCache,
@@ -31,6 +31,7 @@ pub struct TaskContext {
   pub dependency_factories: HashMap<DependencyType, Arc<dyn ModuleFactory>>,
   pub dependency_templates: HashMap<DependencyTemplateType, Arc<dyn DependencyTemplate>>,
   pub runtime_template: Arc<RuntimeTemplate>,
+  pub loader_result_cache: Option<Arc<dyn LoaderResultCache>>,
 
   pub artifact: BuildModuleGraphArtifact,
 }
@@ -52,6 +53,7 @@ impl TaskContext {
       intermediate_fs: compilation.intermediate_filesystem.clone(),
       output_fs: compilation.output_filesystem.clone(),
       runtime_template: compilation.runtime_template.clone_without_dojang(),
+      loader_result_cache: compilation.loader_result_cache.clone(),
       artifact,
     }
   }
@@ -115,6 +115,9 @@ impl Task<TaskContext> for FactorizeTask {
 
     if let Some(current_profile) = &mut self.current_profile {
       current_profile.mark_factory_end();
+      if let Some(resolving) = factory_result.as_ref().and_then(|result| result.resolving) {
+        current_profile.set_resolving(resolving);
+      }
     }
 
     let factorize_info = if let Some(unsafe_cache_predicate) = &self.options.module.unsafe_cache
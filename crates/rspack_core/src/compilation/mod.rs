@@ -9,6 +9,7 @@ use std::{
     Arc,
     atomic::{AtomicBool, AtomicU32, Ordering},
   },
+  time::{Duration, Instant, SystemTime},
 };
 
 use build_chunk_graph::{
@@ -35,9 +36,12 @@ use rspack_sources::BoxSource;
 use rspack_tasks::CompilerContext;
 #[cfg(allocative)]
 use rspack_util::allocative;
-use rspack_util::{itoa, tracing_preset::TRACING_BENCH_TARGET};
+use rspack_util::{
+  itoa,
+  tracing_preset::{TRACING_BENCH_TARGET, TRACING_DETAIL_TARGET},
+};
 use rustc_hash::{FxHashMap as HashMap, FxHashSet as HashSet, FxHasher};
-use tracing::instrument;
+use tracing::{Instrument, instrument};
 use ustr::Ustr;
 
 use crate::{
@@ -49,13 +53,14 @@ use crate::{
   CompilationLogging, CompilerOptions, ConcatenationScope, DependenciesDiagnosticsArtifact,
   DependencyCodeGeneration, DependencyTemplate, DependencyTemplateType, DependencyType,
   DerefOption, Entry, EntryData, EntryOptions, EntryRuntime, Entrypoint, ExecuteModuleId, Filename,
-  ImportPhase, ImportVarMap, ImportedByDeferModulesArtifact, Logger, MemoryGCStorage,
-  ModuleFactory, ModuleGraph, ModuleGraphCacheArtifact, ModuleGraphMut, ModuleGraphPartial,
-  ModuleGraphRef, ModuleIdentifier, ModuleIdsArtifact, ModuleStaticCacheArtifact, PathData,
-  ResolverFactory, RuntimeGlobals, RuntimeKeyMap, RuntimeMode, RuntimeModule, RuntimeSpec,
-  RuntimeSpecMap, RuntimeTemplate, SharedPluginDriver, SideEffectsOptimizeArtifact, SourceType,
-  Stats, ValueCacheVersions,
+  ImportPhase, ImportVarMap, ImportedByDeferModulesArtifact, LoaderResultCache, Logger,
+  MemoryGCStorage, ModuleFactory, ModuleGraph, ModuleGraphCacheArtifact, ModuleGraphMut,
+  ModuleGraphPartial, ModuleGraphRef, ModuleIdentifier, ModuleIdsArtifact, ModuleProfile,
+  ModuleStaticCacheArtifact, PathData, ResolverFactory, RuntimeGlobals, RuntimeKeyMap, RuntimeMode,
+  RuntimeModule, RuntimeSpec, RuntimeSpecMap, RuntimeTemplate, SharedPluginDriver,
+  SideEffectsOptimizeArtifact, SourceType, Stats, ValueCacheVersions,
   build_chunk_graph::artifact::BuildChunkGraphArtifact,
+  cache::persistent::storage::Storage,
   compilation::build_module_graph::{
     BuildModuleGraphArtifact, ModuleExecutor, UpdateParam, build_module_graph,
     finish_build_module_graph, update_module_graph,
@@ -283,6 +288,10 @@ pub struct Compilation {
   pub incremental: Incremental,
 
   pub hash: Option<RspackHashDigest>,
+  /// When the compiler started compiling, used by the `time` and `builtAt` of stats.
+  pub start_time: Option<SystemTime>,
+  /// When the compiler finished compiling and emitting.
+  pub end_time: Option<SystemTime>,
 
   pub file_dependencies: ArcPathIndexSet,
   pub context_dependencies: ArcPathIndexSet,
@@ -304,6 +313,12 @@ pub struct Compilation {
 
   pub intermediate_filesystem: Arc<dyn IntermediateFileSystem>,
   pub output_filesystem: Arc<dyn WritableFileSystem>,
+  /// The storage of persistent cache, plugins can save their own results in it through [`ScopedCache`].
+  ///
+  /// [`ScopedCache`]: crate::cache::persistent::ScopedCache
+  pub persistent_cache_storage: Option<Arc<dyn Storage>>,
+  /// The cache of the results of pure loaders, only available with persistent cache.
+  pub loader_result_cache: Option<Arc<dyn LoaderResultCache>>,
 
   /// A flag indicating whether the current compilation is being rebuilt.
   ///
@@ -415,6 +430,8 @@ impl Compilation {
       build_chunk_graph_artifact: Default::default(),
 
       hash: None,
+      start_time: None,
+      end_time: None,
 
       file_dependencies: Default::default(),
       context_dependencies: Default::default(),
@@ -435,6 +452,8 @@ impl Compilation {
 
       intermediate_filesystem,
       output_filesystem,
+      persistent_cache_storage: None,
+      loader_result_cache: None,
       is_rebuild,
       compiler_context,
     }
@@ -1164,6 +1183,7 @@ impl Compilation {
           let module = module_graph
             .module_by_identifier(&job.module)
             .expect("should have module");
+          let start = Instant::now();
           let codegen_res = old_cache
             .code_generate_occasion
             .use_cache(&job, || async {
@@ -1181,7 +1201,7 @@ impl Compilation {
             })
             .await;
 
-          (job.module, job.runtimes, codegen_res)
+          (job.module, job.runtimes, codegen_res, start.elapsed())
         })
       })
     })
@@ -1191,7 +1211,9 @@ impl Compilation {
       .map(|res| res.to_rspack_result())
       .collect::<Result<Vec<_>>>()?;
 
-    for (module, runtimes, (codegen_res, from_cache)) in results {
+    let mut timings = Vec::with_capacity(results.len());
+    for (module, runtimes, (codegen_res, from_cache), time) in results {
+      timings.push((module, time));
       if let Some(counter) = cache_counter {
         if from_cache {
           counter.hit();
@@ -1219,9 +1241,38 @@ impl Compilation {
         .insert(module, codegen_res, runtimes);
       self.code_generated_modules.insert(module);
     }
+    self.set_module_profile_timings(timings, ModuleProfile::set_code_generation);
     Ok(())
   }
 
+  /// Sets the time spent on modules during seal to their profiles, replacing the timings of the
+  /// previous compilation.
+  fn set_module_profile_timings(
+    &mut self,
+    timings: Vec<(ModuleIdentifier, Duration)>,
+    set: impl Fn(&mut ModuleProfile, Duration),
+  ) {
+    // modules executed at build time are generated before seal, they have no profile to update
+    if !self.options.profile || self.seal_module_graph_partial.is_none() {
+      return;
+    }
+    // a module is generated and hashed once per runtime, sum them up and replace the timing of
+    // the previous compilation rather than accumulating across rebuilds
+    let mut totals = IdentifierMap::<Duration>::default();
+    for (module, time) in timings {
+      *totals.entry(module).or_default() += time;
+    }
+    let mut module_graph = self.get_seal_module_graph_mut();
+    for (module, time) in totals {
+      if let Some(profile) = module_graph
+        .module_graph_module_by_identifier_mut(&module)
+        .and_then(|mgm| mgm.profile_mut())
+      {
+        set(profile, time);
+      }
+    }
+  }
+
   #[instrument("Compilation:create_module_assets",target=TRACING_BENCH_TARGET, skip_all)]
   async fn create_module_assets(&mut self, _plugin_driver: SharedPluginDriver) {
     let mut chunk_asset_map = vec![];
@@ -1323,10 +1374,17 @@ impl Compilation {
         s.spawn(|(this, plugin_driver, chunk)| async {
           let mut manifests = Vec::new();
           let mut diagnostics = Vec::new();
+          let span = tracing::trace_span!(
+            target: TRACING_DETAIL_TARGET,
+            "chunk:render",
+            chunk = ?this.chunk_by_ukey.expect_get(chunk).id(&this.chunk_ids_artifact),
+            modules = ?this.chunk_graph.get_chunk_modules_identifier(chunk)
+          );
           plugin_driver
             .compilation_hooks
             .render_manifest
             .call(this, chunk, &mut manifests, &mut diagnostics)
+            .instrument(span)
             .await?;
 
           rspack_error::Result::Ok((
@@ -2753,11 +2811,12 @@ impl Compilation {
             let module = mg
               .module_by_identifier(&module_identifier)
               .expect("should have module");
+            let start = Instant::now();
             for runtime in chunk_graph.get_module_runtimes_iter(module_identifier, chunk_by_ukey) {
               let hash = module.get_runtime_hash(compilation, Some(runtime)).await?;
               hashes.set(runtime.clone(), hash);
             }
-            Ok((module_identifier, hashes))
+            Ok((module_identifier, hashes, start.elapsed()))
           },
         );
       }
@@ -2767,14 +2826,17 @@ impl Compilation {
     .map(|r| r.to_rspack_result())
     .collect::<Result<Vec<_>>>()?;
 
+    let mut timings = Vec::with_capacity(results.len());
     for result in results {
-      let (module, hashes) = result?;
+      let (module, hashes, time) = result?;
+      timings.push((module, time));
       if ChunkGraph::set_module_hashes(self, module, hashes)
         && let Some(mut mutations) = self.incremental.mutations_write()
       {
         mutations.add(Mutation::ModuleSetHashes { module });
       }
     }
+    self.set_module_profile_timings(timings, ModuleProfile::set_hashing);
     Ok(())
   }
 
@@ -3097,6 +3159,12 @@ impl AssetInfo {
 #[derive(Debug, Default, Clone)]
 pub struct AssetInfoRelated {
   pub source_map: Option<String>,
+  /// the gzip compressed version of the asset
+  pub gzip: Option<String>,
+  /// the brotli compressed version of the asset
+  pub brotli: Option<String>,
+  /// the zstd compressed version of the asset
+  pub zstd: Option<String>,
 }
 
 impl AssetInfoRelated {
@@ -3104,6 +3172,15 @@ impl AssetInfoRelated {
     if let Some(source_map) = another.source_map {
       self.source_map = Some(source_map);
     }
+    if let Some(gzip) = another.gzip {
+      self.gzip = Some(gzip);
+    }
+    if let Some(brotli) = another.brotli {
+      self.brotli = Some(brotli);
+    }
+    if let Some(zstd) = another.zstd {
+      self.zstd = Some(zstd);
+    }
   }
 }
 
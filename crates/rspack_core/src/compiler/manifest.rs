@@ -0,0 +1,66 @@
+use std::collections::BTreeMap;
+
+use rspack_error::Result;
+use rspack_fs::WritableFileSystem;
+use rspack_paths::{Utf8Path, Utf8PathBuf};
+use serde::{Deserialize, Serialize};
+
+const MANIFEST_VERSION: u32 = 1;
+
+/// The content hashes of the files emitted to the output directory.
+///
+/// It's stored in the output directory when `output.clean.manifest` is enabled, so that the
+/// next build can skip writing unchanged files and remove the files it no longer emits.
+#[derive(Debug, Default, Serialize, Deserialize)]
+pub(super) struct EmitManifest {
+  version: u32,
+  files: BTreeMap<String, String>,
+}
+
+impl EmitManifest {
+  pub(super) fn new() -> Self {
+    Self {
+      version: MANIFEST_VERSION,
+      files: BTreeMap::default(),
+    }
+  }
+
+  /// Reads the manifest of the previous build, a missing or outdated manifest is treated as empty.
+  pub(super) async fn read(fs: &dyn WritableFileSystem, path: &Utf8Path) -> Self {
+    fs.read_file(path)
+      .await
+      .ok()
+      .and_then(|content| serde_json::from_slice::<Self>(&content).ok())
+      .filter(|manifest| manifest.version == MANIFEST_VERSION)
+      .unwrap_or_else(Self::new)
+  }
+
+  /// Writes the manifest to a temporary file first and renames it into place,
+  /// so a crashed build never leaves a truncated manifest.
+  pub(super) async fn write(&self, fs: &dyn WritableFileSystem, path: &Utf8Path) -> Result<()> {
+    let temp_path = Utf8PathBuf::from(format!("{path}.tmp"));
+    let content = serde_json::to_vec(self).expect("should serialize emit manifest");
+    if let Some(dir) = path.parent() {
+      fs.create_dir_all(dir).await?;
+    }
+    fs.write(&temp_path, &content).await?;
+    fs.rename(&temp_path, path).await?;
+    Ok(())
+  }
+
+  pub(super) fn get(&self, filename: &str) -> Option<&str> {
+    self.files.get(filename).map(|hash| hash.as_str())
+  }
+
+  pub(super) fn insert(&mut self, filename: String, hash: String) {
+    self.files.insert(filename, hash);
+  }
+
+  pub(super) fn contains(&self, filename: &str) -> bool {
+    self.files.contains_key(filename)
+  }
+
+  pub(super) fn files(&self) -> impl Iterator<Item = &str> {
+    self.files.keys().map(|filename| filename.as_str())
+  }
+}
@@ -1,20 +1,28 @@
+mod manifest;
 mod rebuild;
 use std::{
+  hash::Hasher,
   mem,
-  sync::{Arc, atomic::AtomicU32},
+  sync::{Arc, LazyLock, atomic::AtomicU32},
+  time::SystemTime,
 };
 
 use futures::future::join_all;
-use rspack_error::Result;
+use rspack_error::{Result, ToStringResultToRspackResultExt};
 use rspack_fs::{IntermediateFileSystem, NativeFileSystem, ReadableFileSystem, WritableFileSystem};
+use rspack_hash::RspackHash;
 use rspack_hook::define_hook;
 use rspack_paths::{Utf8Path, Utf8PathBuf};
 use rspack_sources::BoxSource;
 use rspack_tasks::{CompilerContext, within_compiler_context};
-use rspack_util::{node_path::NodePath, tracing_preset::TRACING_BENCH_TARGET};
+use rspack_util::{
+  node_path::NodePath,
+  tracing_preset::{TRACING_BENCH_TARGET, TRACING_DETAIL_TARGET},
+};
 use rustc_hash::FxHashMap as HashMap;
-use tracing::instrument;
+use tracing::{Instrument, instrument};
 
+use self::manifest::EmitManifest;
 pub use self::rebuild::CompilationRecords;
 use crate::{
   BoxPlugin, CleanOptions, Compilation, CompilationAsset, CompilerOptions, ContextModuleFactory,
@@ -42,6 +50,10 @@ define_hook!(CompilerAfterEmit: Series(compilation: &mut Compilation));
 define_hook!(CompilerAssetEmitted: Series(compilation: &Compilation, filename: &str, info: &AssetEmittedInfo));
 define_hook!(CompilerClose: Series(compilation: &Compilation));
 
+// log the retained memory of each compilation to the `rspack.MemoryReport` logger
+static MEMORY_REPORT: LazyLock<bool> =
+  LazyLock::new(|| std::env::var_os("RSPACK_MEMORY_REPORT").is_some());
+
 #[derive(Debug, Default)]
 pub struct CompilerHooks {
   pub this_compilation: CompilerThisCompilationHook,
@@ -315,6 +327,7 @@ impl Compiler {
   }
   #[instrument("Compiler:compile", target=TRACING_BENCH_TARGET,skip_all)]
   async fn compile(&mut self) -> Result<()> {
+    self.compilation.start_time = Some(SystemTime::now());
     let logger = self.compilation.get_logger("rspack.Compiler");
     let start = logger.time("seal compilation");
     #[cfg(feature = "debug_tool")]
@@ -352,6 +365,13 @@ impl Compiler {
   async fn compile_done(&mut self) -> Result<()> {
     let logger = self.compilation.get_logger("rspack.Compiler");
 
+    if *MEMORY_REPORT {
+      let memory_logger = self.compilation.get_logger("rspack.MemoryReport");
+      for line in self.compilation.memory_report().to_log_lines() {
+        memory_logger.info(line);
+      }
+    }
+
     if matches!(
       self
         .plugin_driver
@@ -361,6 +381,7 @@ impl Compiler {
         .await?,
       Some(false)
     ) {
+      self.compilation.end_time = Some(SystemTime::now());
       return Ok(());
     }
 
@@ -368,6 +389,7 @@ impl Compiler {
     self.emit_assets().await?;
     logger.time_end(start);
 
+    self.compilation.end_time = Some(SystemTime::now());
     Ok(())
   }
 
@@ -381,7 +403,28 @@ impl Compiler {
       )
       .await?;
     let output_path = Utf8Path::new(&output_path_str);
-    self.run_clean_options(output_path).await?;
+    let atomic_emit = self.options.output.atomic_emit;
+    // files are cleaned by the manifest of the previous build after emitting, and the output
+    // directory is replaced as a whole in atomic emit, so it's never cleaned before emitting
+    let manifest = match &self.options.output.clean_manifest {
+      Some(name) => {
+        let manifest_path = output_path.node_join(name);
+        let manifest = EmitManifest::read(&*self.output_filesystem, &manifest_path).await;
+        if !atomic_emit && self.emitted_asset_versions.is_empty() {
+          self
+            .clean_untracked(output_path, &manifest_path, &manifest)
+            .await?;
+        }
+        Some((manifest_path, manifest))
+      }
+      None => {
+        if !atomic_emit {
+          self.run_clean_options(output_path).await?;
+        }
+        None
+      }
+    };
+    let previous_manifest = manifest.as_ref().map(|(_, manifest)| manifest);
 
     self
       .plugin_driver
@@ -390,9 +433,16 @@ impl Compiler {
       .call(&mut self.compilation)
       .await?;
 
+    let staging_path = if atomic_emit {
+      Some(self.create_staging_dir(output_path).await?)
+    } else {
+      None
+    };
+    let emit_path = staging_path.as_deref().unwrap_or(output_path);
+
     let mut new_emitted_asset_versions = HashMap::default();
 
-    rspack_futures::scope(|token| {
+    let results = rspack_futures::scope(|token| {
       self
         .compilation
         .assets()
@@ -407,7 +457,9 @@ impl Compiler {
             new_emitted_asset_versions.insert(filename.clone(), asset.info.version.clone());
           }
 
-          if let Some(old_version) = self.emitted_asset_versions.get(filename)
+          // the staging directory of atomic emit always starts empty
+          if !atomic_emit
+            && let Some(old_version) = self.emitted_asset_versions.get(filename)
             && old_version.as_str() == asset.info.version
             && !old_version.is_empty()
           {
@@ -415,10 +467,17 @@ impl Compiler {
           }
 
           // SAFETY: await immediately and trust caller to poll future entirely
-          let s = unsafe { token.used((&self, filename, asset, output_path)) };
-
-          s.spawn(|(this, filename, asset, output_path)| {
-            this.emit_asset(output_path, filename, asset)
+          let s = unsafe { token.used((&self, filename, asset, emit_path, previous_manifest)) };
+
+          s.spawn(|(this, filename, asset, emit_path, previous_manifest)| {
+            let span = tracing::trace_span!(
+              target: TRACING_DETAIL_TARGET,
+              "asset:emit",
+              asset = filename.as_str()
+            );
+            this
+              .emit_asset(emit_path, filename, asset, previous_manifest)
+              .instrument(span)
           });
         })
     })
@@ -426,6 +485,59 @@ impl Compiler {
 
     self.emitted_asset_versions = new_emitted_asset_versions;
 
+    if let Some(staging_path) = staging_path {
+      // the manifest is written into the staging directory, so it's swapped in with the assets
+      let emit_result = match manifest {
+        Some((_, previous_manifest)) => {
+          let name = self
+            .options
+            .output
+            .clean_manifest
+            .as_deref()
+            .expect("should have clean manifest");
+          let staging_manifest_path = staging_path.node_join(name);
+          self
+            .clean_by_manifest(
+              &staging_path,
+              &staging_manifest_path,
+              previous_manifest,
+              results,
+            )
+            .await
+        }
+        None => results
+          .into_iter()
+          .try_for_each(|result| result.to_rspack_result()?.map(|_| ())),
+      };
+      if let Err(e) = emit_result {
+        // roll back to keep the previous output directory untouched
+        let _ = self.output_filesystem.remove_dir_all(&staging_path).await;
+        return Err(e);
+      }
+      self.swap_output_dir(output_path, &staging_path).await?;
+
+      // the hooks see the files in the output directory after it's replaced
+      for (filename, asset) in self.compilation.assets() {
+        if let Some(source) = asset.get_source() {
+          self
+            .call_asset_emitted(output_path, filename, source)
+            .await?;
+        }
+      }
+      return self
+        .plugin_driver
+        .compiler_hooks
+        .after_emit
+        .call(&mut self.compilation)
+        .await;
+    }
+
+    if let Some((manifest_path, previous_manifest)) = manifest {
+      self
+        .clean_by_manifest(output_path, &manifest_path, previous_manifest, results)
+        .await?;
+    }
+
     self
       .plugin_driver
       .compiler_hooks
@@ -433,12 +545,77 @@ impl Compiler {
       .call(&mut self.compilation)
       .await
   }
+
+  /// Creates an empty staging directory next to the output directory for atomic emit.
+  ///
+  /// The backup directory left by a build which crashed while swapping is restored as the output
+  /// directory when the output directory is missing, and it's removed otherwise.
+  async fn create_staging_dir(&self, output_path: &Utf8Path) -> Result<Utf8PathBuf> {
+    let backup_path = sibling_dir(output_path, "backup");
+    if self.output_filesystem.stat(&backup_path).await.is_ok() {
+      if self.output_filesystem.stat(output_path).await.is_ok() {
+        self.output_filesystem.remove_dir_all(&backup_path).await?;
+      } else {
+        self
+          .output_filesystem
+          .rename(&backup_path, output_path)
+          .await?;
+      }
+    }
+
+    let staging_path = sibling_dir(output_path, "staging");
+    // a staging directory left by a crashed build
+    if self.output_filesystem.stat(&staging_path).await.is_ok() {
+      self.output_filesystem.remove_dir_all(&staging_path).await?;
+    }
+    self.output_filesystem.create_dir_all(&staging_path).await?;
+    Ok(staging_path)
+  }
+
+  /// Replaces the output directory with the staging directory.
+  ///
+  /// The previous output directory is moved aside before the staging directory is renamed into
+  /// place, and it's restored when the rename fails.
+  ///
+  /// The swap takes two renames, so the output directory is missing between them. A build
+  /// crashed in this window leaves the previous output in the backup directory, which is
+  /// restored by [`Self::create_staging_dir`] on the next build.
+  async fn swap_output_dir(&self, output_path: &Utf8Path, staging_path: &Utf8Path) -> Result<()> {
+    let fs = &self.output_filesystem;
+    let backup_path = sibling_dir(output_path, "backup");
+    let has_previous = fs
+      .stat(output_path)
+      .await
+      .is_ok_and(|stat| stat.is_directory);
+    if has_previous {
+      if fs.stat(&backup_path).await.is_ok() {
+        let _ = fs.remove_dir_all(&backup_path).await;
+      }
+      if let Err(e) = fs.rename(output_path, &backup_path).await {
+        let _ = fs.remove_dir_all(staging_path).await;
+        return Err(e.into());
+      }
+    }
+    if let Err(e) = fs.rename(staging_path, output_path).await {
+      if has_previous {
+        let _ = fs.rename(&backup_path, output_path).await;
+      }
+      let _ = fs.remove_dir_all(staging_path).await;
+      return Err(e.into());
+    }
+    if has_previous {
+      fs.remove_dir_all(&backup_path).await?;
+    }
+    Ok(())
+  }
   async fn emit_asset(
     &self,
     output_path: &Utf8Path,
     filename: &str,
     asset: &CompilationAsset,
-  ) -> Result<()> {
+    previous_manifest: Option<&EmitManifest>,
+  ) -> Result<Option<(String, String)>> {
+    let mut manifest_entry = None;
     if let Some(source) = asset.get_source() {
       let (target_file, query) = filename.split_once('?').unwrap_or((filename, ""));
       let file_path = output_path.node_join(target_file);
@@ -467,7 +644,25 @@ impl Compiler {
         .await
         .ok();
 
-      let need_write = if !self.options.output.compare_before_emit {
+      let content_hash = previous_manifest.map(|_| {
+        let mut hasher = RspackHash::new(&self.options.output.hash_function);
+        hasher.write(&content);
+        hasher
+          .digest(&self.options.output.hash_digest)
+          .encoded()
+          .to_string()
+      });
+
+      let need_write = if let Some(content_hash) = &content_hash
+        && previous_manifest.and_then(|manifest| manifest.get(target_file))
+          == Some(content_hash.as_str())
+        && stat
+          .as_ref()
+          .is_some_and(|stat| stat.is_file && stat.size == content.len() as u64)
+      {
+        // do not write when the file is unchanged since the previous build, which keeps its mtime
+        false
+      } else if !self.options.output.compare_before_emit {
         // write when compare_before_emit is false
         true
       } else if !stat.as_ref().is_some_and(|stat| stat.is_file) {
@@ -497,17 +692,134 @@ impl Compiler {
         self.compilation.emitted_assets.insert(filename.to_string());
       }
 
-      let info = AssetEmittedInfo {
-        output_path: output_path.to_owned(),
-        source: source.clone(),
-        target_path: file_path,
-      };
-      self
-        .plugin_driver
-        .compiler_hooks
-        .asset_emitted
-        .call(&self.compilation, filename, &info)
-        .await?;
+      // the hook is called after the staging directory is swapped in for atomic emit
+      if !self.options.output.atomic_emit {
+        self
+          .call_asset_emitted(output_path, filename, source)
+          .await?;
+      }
+
+      manifest_entry = content_hash.map(|content_hash| (target_file.to_string(), content_hash));
+    }
+    Ok(manifest_entry)
+  }
+
+  async fn call_asset_emitted(
+    &self,
+    output_path: &Utf8Path,
+    filename: &str,
+    source: &BoxSource,
+  ) -> Result<()> {
+    let (target_file, _) = filename.split_once('?').unwrap_or((filename, ""));
+    let info = AssetEmittedInfo {
+      output_path: output_path.to_owned(),
+      source: source.clone(),
+      target_path: output_path.node_join(target_file),
+    };
+    self
+      .plugin_driver
+      .compiler_hooks
+      .asset_emitted
+      .call(&self.compilation, filename, &info)
+      .await
+  }
+
+  /// Removes the files of the previous manifest which are not emitted anymore, and
+  /// writes the manifest of this build.
+  ///
+  /// Stale files are removed only after all assets are emitted, so the output directory always
+  /// contains a complete build. When an asset fails to emit, the previous manifest is kept and
+  /// the error is returned.
+  async fn clean_by_manifest(
+    &self,
+    output_path: &Utf8Path,
+    manifest_path: &Utf8Path,
+    previous_manifest: EmitManifest,
+    results: Vec<std::result::Result<Result<Option<(String, String)>>, tokio::task::JoinError>>,
+  ) -> Result<()> {
+    let mut manifest = EmitManifest::new();
+    for result in results {
+      if let Some((filename, content_hash)) = result.to_rspack_result()?? {
+        manifest.insert(filename, content_hash);
+      }
+    }
+    // assets skipped by the incremental emit keep their hashes
+    for filename in self.compilation.assets().keys() {
+      let (target_file, _) = filename.split_once('?').unwrap_or((filename, ""));
+      if !manifest.contains(target_file)
+        && let Some(content_hash) = previous_manifest.get(target_file)
+      {
+        manifest.insert(target_file.to_string(), content_hash.to_string());
+      }
+    }
+
+    let clean_options = &self.options.output.clean;
+    join_all(
+      previous_manifest
+        .files()
+        .filter(|filename| !manifest.contains(filename))
+        .map(|filename| async move {
+          let file_path = output_path.node_join(filename);
+          // the file may have been removed by the user already
+          if !clean_options.keep(filename).await
+            && self.output_filesystem.stat(&file_path).await.is_ok()
+          {
+            self.output_filesystem.remove_file(&file_path).await?;
+          }
+          Ok::<(), rspack_error::Error>(())
+        }),
+    )
+    .await
+    .into_iter()
+    .collect::<Result<Vec<()>>>()?;
+
+    manifest
+      .write(&*self.output_filesystem, manifest_path)
+      .await
+  }
+
+  /// Removes the files in the output directory which are not recorded in the manifest of the
+  /// previous build according to `output.clean`.
+  ///
+  /// The output directory is not cleaned before emitting with a manifest, so this runs on the
+  /// first build to honor `output.clean` while the recorded files still skip unchanged writes.
+  async fn clean_untracked(
+    &self,
+    output_path: &Utf8Path,
+    manifest_path: &Utf8Path,
+    previous_manifest: &EmitManifest,
+  ) -> Result<()> {
+    let clean_options = &self.options.output.clean;
+    let fs = &*self.output_filesystem;
+    if let CleanOptions::CleanAll(false) = clean_options {
+      return Ok(());
+    }
+    if !fs
+      .stat(output_path)
+      .await
+      .is_ok_and(|stat| stat.is_directory)
+    {
+      return Ok(());
+    }
+
+    let mut queue = vec![String::new()];
+    while let Some(dir) = queue.pop() {
+      for item in fs.read_dir(&output_path.node_join(&dir)).await? {
+        let filename = if dir.is_empty() {
+          item
+        } else {
+          format!("{dir}/{item}")
+        };
+        let file_path = output_path.node_join(&filename);
+        if fs.stat(&file_path).await?.is_directory {
+          queue.push(filename);
+        } else if file_path != manifest_path
+          && !previous_manifest.contains(&filename)
+          && !clean_options.keep(&filename).await
+        {
+          fs.remove_file(&file_path).await?;
+        }
+      }
     }
     Ok(())
   }
@@ -599,6 +911,12 @@ impl Compiler {
   }
 }
 
+/// A hidden directory next to the output directory, e.g. `.dist.rspack-staging` for `dist`.
+fn sibling_dir(output_path: &Utf8Path, suffix: &str) -> Utf8PathBuf {
+  let name = output_path.file_name().unwrap_or_default();
+  output_path.with_file_name(format!(".{name}.rspack-{suffix}"))
+}
+
 #[derive(Debug)]
 pub struct CompilationParams {
   pub normal_module_factory: Arc<NormalModuleFactory>,
@@ -737,6 +737,14 @@ impl Module for ConcatenatedModule {
         self.build_info.need_create_require = true;
       }
 
+      // populate i18n message ids
+      if let Some(ids) = &module_build_info.i18n_message_ids {
+        let i18n_message_ids = self.build_info.i18n_message_ids.get_or_insert_default();
+        i18n_message_ids.extend(ids.iter().cloned());
+        i18n_message_ids.sort_unstable();
+        i18n_message_ids.dedup();
+      }
+
       // populate assets
       self
         .build_info
@@ -1054,6 +1054,7 @@ impl Module for ContextModule {
       dependencies,
       blocks,
       optimization_bailouts: vec![],
+      profile: None,
     })
   }
 
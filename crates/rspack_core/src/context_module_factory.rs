@@ -1,4 +1,4 @@
-use std::{borrow::Cow, sync::Arc};
+use std::{borrow::Cow, sync::Arc, time::Instant};
 
 use async_recursion::async_recursion;
 use cow_utils::CowUtils;
@@ -102,15 +102,17 @@ impl ModuleFactory for ContextModuleFactory {
     match self.before_resolve(data).await? {
       BeforeResolveResult::Ignored => return Ok(ModuleFactoryResult::default()),
       BeforeResolveResult::Data(before_resolve_result) => {
+        let start = Instant::now();
         let (factorize_result, context_module_options) =
           self.resolve(data, before_resolve_result).await?;
+        let resolving = start.elapsed();
         if let Some(context_module_options) = context_module_options
           && let Some(factorize_result) = self.after_resolve(data, context_module_options).await?
         {
-          return Ok(factorize_result);
+          return Ok(factorize_result.resolving(resolving));
         }
 
-        Ok(factorize_result)
+        Ok(factorize_result.resolving(resolving))
       }
     }
   }
@@ -341,9 +343,7 @@ impl ContextModuleFactory {
     data.add_missing_dependencies(missing_dependencies);
     // data.add_context_dependencies(context_dependencies);
 
-    let module_factory_result = ModuleFactoryResult {
-      module: Some(module),
-    };
+    let module_factory_result = ModuleFactoryResult::new_with_module(module);
     Ok((module_factory_result, context_module_options))
   }
 
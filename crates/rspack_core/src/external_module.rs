@@ -99,7 +99,7 @@ fn get_namespace_object_export(
 
 fn get_source_for_global_variable_external(
   variable_names: Option<&ExternalRequestValue>,
-  external_type: &ExternalType,
+  external_type: &str,
 ) -> String {
   let object_lookup = if let Some(variable_names) = variable_names {
     property_access(variable_names.iter(), 0)
@@ -179,26 +179,26 @@ fn module_external_fragment_key(base: &str, attributes: &Option<ImportAttributes
  * Resolve the detailed external type from the raw external type.
  * e.g. resolve "module" or "import" from "module-import" type
  */
-fn resolve_external_type<'a>(
-  external_type: &'a str,
-  dependency_meta: &'a DependencyMeta,
-) -> &'a str {
+fn resolve_external_type(
+  external_type: ExternalType,
+  dependency_meta: &DependencyMeta,
+) -> ExternalType {
   match external_type {
-    "commonjs-import" => {
+    ExternalType::CommonjsImport => {
       if let Some(ExternalTypeEnum::Import) = dependency_meta.external_type.as_ref() {
-        "import"
+        ExternalType::Import
       } else {
-        "commonjs"
+        ExternalType::Commonjs
       }
     }
-    "module-import" => {
+    ExternalType::ModuleImport => {
       if let Some(external_type) = dependency_meta.external_type.as_ref() {
         match external_type {
-          ExternalTypeEnum::Import => "import",
-          ExternalTypeEnum::Module => "module",
+          ExternalTypeEnum::Import => ExternalType::Import,
+          ExternalTypeEnum::Module => ExternalType::Module,
         }
       } else {
-        "module"
+        ExternalType::Module
       }
     }
 
@@ -253,7 +253,7 @@ impl ExternalModule {
       dependencies: Vec::new(),
       blocks: Vec::new(),
       id: Identifier::from({
-        let resolved_type = resolve_external_type(external_type.as_str(), &dependency_meta);
+        let resolved_type = resolve_external_type(external_type, &dependency_meta);
         let request_str = serde_json::to_string(&request).expect("invalid json to_string");
         let attrs_str = dependency_meta
           .attributes
@@ -290,33 +290,33 @@ impl ExternalModule {
     &mut self.user_request
   }
 
-  pub fn get_external_type(&self) -> &ExternalType {
-    &self.external_type
+  pub fn get_external_type(&self) -> ExternalType {
+    self.external_type
   }
 
   pub fn get_request(&self) -> &ExternalRequestValue {
     match &self.request {
       ExternalRequest::Single(request) => request,
-      ExternalRequest::Map(map) => &map[&self.external_type],
+      ExternalRequest::Map(map) => &map[self.external_type.as_str()],
     }
   }
 
-  fn get_request_and_external_type(&self) -> (Option<&ExternalRequestValue>, &ExternalType) {
+  fn get_request_and_external_type(&self) -> (Option<&ExternalRequestValue>, ExternalType) {
     match &self.request {
-      ExternalRequest::Single(request) => (Some(request), &self.external_type),
-      ExternalRequest::Map(map) => (map.get(&self.external_type), &self.external_type),
+      ExternalRequest::Single(request) => (Some(request), self.external_type),
+      ExternalRequest::Map(map) => (map.get(self.external_type.as_str()), self.external_type),
     }
   }
 
-  fn resolve_external_type(&self) -> &str {
-    resolve_external_type(self.external_type.as_str(), &self.dependency_meta)
+  fn resolve_external_type(&self) -> ExternalType {
+    resolve_external_type(self.external_type, &self.dependency_meta)
   }
 
   fn get_source(
     &self,
     compilation: &Compilation,
     request: Option<&ExternalRequestValue>,
-    external_type: &ExternalType,
+    external_type: ExternalType,
     runtime: Option<&RuntimeSpec>,
     concatenation_scope: Option<&mut ConcatenationScope>,
   ) -> Result<(BoxSource, ChunkInitFragments, RuntimeGlobals)> {
@@ -328,29 +328,32 @@ impl ExternalModule {
     let module_graph_cache = &compilation.module_graph_cache_artifact;
 
     let source = match resolved_external_type {
-      "this" => format!(
+      ExternalType::This => format!(
         "{} = (function() {{ return {}; }}());",
         get_namespace_object_export(concatenation_scope, supports_const),
-        get_source_for_global_variable_external(request, external_type),
+        get_source_for_global_variable_external(request, external_type.as_str()),
       ),
-      "window" | "self" => format!(
+      ExternalType::Window | ExternalType::SelfObject => format!(
         "{} = {};",
         get_namespace_object_export(concatenation_scope, supports_const),
-        get_source_for_global_variable_external(request, external_type)
+        get_source_for_global_variable_external(request, external_type.as_str())
       ),
-      "global" => format!(
+      ExternalType::Global => format!(
         "{} = {};",
         get_namespace_object_export(concatenation_scope, supports_const),
         get_source_for_global_variable_external(request, &compilation.options.output.global_object)
       ),
-      "commonjs" | "commonjs2" | "commonjs-module" | "commonjs-static" => {
+      ExternalType::Commonjs
+      | ExternalType::Commonjs2
+      | ExternalType::CommonjsModule
+      | ExternalType::CommonjsStatic => {
         format!(
           "{} = {};",
           get_namespace_object_export(concatenation_scope, supports_const),
           get_source_for_commonjs(request)
         )
       }
-      "node-commonjs" => {
+      ExternalType::NodeCommonjs => {
         let need_prefix = compilation
           .options
           .output
@@ -399,7 +402,12 @@ impl ExternalModule {
           )
         }
       }
-      "amd" | "amd-require" | "umd" | "umd2" | "system" | "jsonp" => {
+      ExternalType::Amd
+      | ExternalType::AmdRequire
+      | ExternalType::Umd
+      | ExternalType::Umd2
+      | ExternalType::System
+      | ExternalType::Jsonp => {
         let id = ChunkGraph::get_module_id(&compilation.module_ids_artifact, self.identifier())
           .map(|s| s.as_str())
           .expect("should have module id");
@@ -422,12 +430,16 @@ impl ExternalModule {
           external_variable
         )
       }
-      "import" => format!(
+      ExternalType::Import => format!(
         "{} = {};",
         get_namespace_object_export(concatenation_scope, supports_const),
         get_source_for_import(request, compilation, &self.dependency_meta.attributes)
       ),
-      "var" | "promise" | "const" | "let" | "assign" => {
+      ExternalType::Var
+      | ExternalType::Promise
+      | ExternalType::Const
+      | ExternalType::Let
+      | ExternalType::Assign => {
         let external_variable = if let Some(request) = request {
           get_request_string(request)
         } else {
@@ -453,7 +465,7 @@ impl ExternalModule {
           external_variable
         )
       }
-      "module" => {
+      ExternalType::Module => {
         if compilation.options.output.module
           && let Some(request) = request
         {
@@ -534,7 +546,7 @@ impl ExternalModule {
                           .module_by_identifier(&m.id)
                           .expect("should have module")
                           .as_external_module()
-                          && external_module.resolve_external_type() == "module"
+                          && external_module.resolve_external_type() == ExternalType::Module
                         {
                           safe_to_optimize = false;
                           break 'outer;
@@ -696,7 +708,7 @@ impl ExternalModule {
           )
         }
       }
-      "script" if request.is_some() => {
+      ExternalType::Script if request.is_some() => {
         let request = request.expect("request should be some");
         let url_and_global = extract_url_and_global(request.primary())?;
         runtime_requirements.insert(RuntimeGlobals::LOAD_SCRIPT);
@@ -726,7 +738,14 @@ if(typeof {global} !== "undefined") return resolve();
             .render_runtime_globals(&RuntimeGlobals::LOAD_SCRIPT)
         )
       }
-      _ => {
+      // `module-import` and `commonjs-import` are resolved to the other types before rendering
+      ExternalType::Script
+      | ExternalType::ModuleImport
+      | ExternalType::CommonjsImport
+      | ExternalType::Asset
+      | ExternalType::CssImport
+      | ExternalType::AssignProperties
+      | ExternalType::ModernModule => {
         let external_variable = if let Some(request) = request {
           get_request_string(request)
         } else {
@@ -797,8 +816,13 @@ impl Module for ExternalModule {
     _mg: &ModuleGraph,
     _cg: &ChunkGraph,
   ) -> Option<Cow<'static, str>> {
-    match self.external_type.as_ref() {
-      "amd" | "umd" | "amd-require" | "umd2" | "system" | "jsonp" => {
+    match self.external_type {
+      ExternalType::Amd
+      | ExternalType::Umd
+      | ExternalType::AmdRequire
+      | ExternalType::Umd2
+      | ExternalType::System
+      | ExternalType::Jsonp => {
         // return `${this.externalType} externals can't be concatenated`;
         Some(format!("{} externals can't be concatenated", self.external_type).into())
       }
@@ -811,14 +835,14 @@ impl Module for ExternalModule {
   }
 
   fn source_types(&self, _module_graph: &ModuleGraph) -> &[SourceType] {
-    if self.external_type == "asset"
+    if self.external_type == ExternalType::Asset
       && self
         .dependency_meta
         .source_type
         .is_some_and(|t| t == SourceType::CssUrl)
     {
       EXTERNAL_MODULE_CSS_URL_SOURCE_TYPES
-    } else if self.external_type == "css-import" {
+    } else if self.external_type == ExternalType::CssImport {
       EXTERNAL_MODULE_CSS_SOURCE_TYPES
     } else {
       EXTERNAL_MODULE_JS_SOURCE_TYPES
@@ -826,8 +850,15 @@ impl Module for ExternalModule {
   }
 
   fn chunk_condition(&self, chunk_key: &ChunkUkey, compilation: &Compilation) -> Option<bool> {
-    match self.external_type.as_str() {
-      "css-import" | "module" | "import" | "module-import" if !self.place_in_initial => Some(true),
+    match self.external_type {
+      ExternalType::CssImport
+      | ExternalType::Module
+      | ExternalType::Import
+      | ExternalType::ModuleImport
+        if !self.place_in_initial =>
+      {
+        Some(true)
+      }
       _ => Some(
         compilation
           .chunk_graph
@@ -863,19 +894,19 @@ impl Module for ExternalModule {
     let resolved_external_type = self.resolve_external_type();
     let request = match &self.request {
       ExternalRequest::Single(request) => Some(request),
-      ExternalRequest::Map(map) => map.get(&self.external_type),
+      ExternalRequest::Map(map) => map.get(self.external_type.as_str()),
     };
     let mut can_mangle = false;
     let mut exports_type = BuildMetaExportsType::Dynamic;
     match resolved_external_type {
-      "this" => self.build_info.strict = false,
-      "system" => {
+      ExternalType::This => self.build_info.strict = false,
+      ExternalType::System => {
         if !request.is_some_and(|r| r.has_rest()) {
           exports_type = BuildMetaExportsType::Namespace;
           can_mangle = true;
         }
       }
-      "module" => {
+      ExternalType::Module => {
         if self.build_info.module {
           if !request.is_some_and(|r| r.has_rest()) {
             exports_type = BuildMetaExportsType::Namespace;
@@ -889,8 +920,8 @@ impl Module for ExternalModule {
           }
         }
       }
-      "script" | "promise" => self.build_meta.has_top_level_await = true,
-      "import" => {
+      ExternalType::Script | ExternalType::Promise => self.build_meta.has_top_level_await = true,
+      ExternalType::Import => {
         self.build_meta.has_top_level_await = true;
         if !request.is_some_and(|r| r.has_rest()) {
           exports_type = BuildMetaExportsType::Namespace;
@@ -907,6 +938,7 @@ impl Module for ExternalModule {
       ))],
       blocks: Vec::new(),
       optimization_bailouts: vec![],
+      profile: None,
     })
   }
 
@@ -919,8 +951,8 @@ impl Module for ExternalModule {
   ) -> Result<CodeGenerationResult> {
     let mut cgr = CodeGenerationResult::default();
     let (request, external_type) = self.get_request_and_external_type();
-    match self.external_type.as_str() {
-      "asset" if request.is_some() => {
+    match self.external_type {
+      ExternalType::Asset if request.is_some() => {
         let request = request.expect("request should be some");
         cgr.add(
           SourceType::JavaScript,
@@ -934,7 +966,7 @@ impl Module for ExternalModule {
           .data
           .insert(CodeGenerationDataUrl::new(request.primary().to_string()));
       }
-      "css-import" if request.is_some() => {
+      ExternalType::CssImport if request.is_some() => {
         let request = request.expect("request should be some");
         cgr.add(
           SourceType::Css,
@@ -30,6 +30,8 @@ pub mod external_module;
 pub use external_module::*;
 mod logger;
 pub use logger::*;
+mod memory_report;
+pub use memory_report::{CacheGenerationMemoryUsage, MemoryReport, MemoryUsage};
 pub mod cache;
 mod normal_module;
 pub mod old_cache;
@@ -102,8 +104,8 @@ pub mod reserved_names;
 
 use rspack_cacheable::{cacheable, with::AsPreset};
 pub use rspack_loader_runner::{
-  AdditionalData, BUILTIN_LOADER_PREFIX, ParseMeta, ResourceData, ResourceParsedData, Scheme,
-  get_scheme, parse_resource,
+  AdditionalData, BUILTIN_LOADER_PREFIX, LoaderResultCache, ParseMeta, ResourceData,
+  ResourceParsedData, Scheme, get_scheme, parse_resource,
 };
 pub use rspack_macros::{impl_runtime_module, impl_source_map_config};
 pub use rspack_sources;
@@ -185,6 +187,8 @@ impl From<&ModuleType> for SourceType {
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
 pub enum ModuleType {
   Json,
+  Yaml,
+  Toml,
   Css,
   CssModule,
   CssAuto,
@@ -257,6 +261,8 @@ impl ModuleType {
       ModuleType::CssAuto => "css/auto",
 
       ModuleType::Json => "json",
+      ModuleType::Yaml => "yaml",
+      ModuleType::Toml => "toml",
 
       ModuleType::WasmSync => "webassembly/sync",
       ModuleType::WasmAsync => "webassembly/async",
@@ -297,6 +303,8 @@ impl From<&str> for ModuleType {
       "css/auto" => Self::CssAuto,
 
       "json" => Self::Json,
+      "yaml" => Self::Yaml,
+      "toml" => Self::Toml,
 
       "webassembly/sync" => Self::WasmSync,
       "webassembly/async" => Self::WasmAsync,
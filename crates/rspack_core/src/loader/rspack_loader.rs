@@ -2,7 +2,9 @@ use std::sync::Arc;
 
 use rspack_error::{Diagnostic, Result};
 use rspack_fs::ReadableFileSystem;
-use rspack_loader_runner::{Content, LoaderContext, LoaderRunnerPlugin, ResourceData};
+use rspack_loader_runner::{
+  Content, LoaderContext, LoaderResultCache, LoaderRunnerPlugin, ResourceData,
+};
 use rspack_sources::SourceMap;
 use rustc_hash::FxHashSet as HashSet;
 
@@ -11,6 +13,7 @@ use crate::{RunnerContext, SharedPluginDriver, utils::extract_source_map};
 pub struct RspackLoaderRunnerPlugin {
   pub plugin_driver: SharedPluginDriver,
   pub extract_source_map: Option<bool>,
+  pub loader_result_cache: Option<Arc<dyn LoaderResultCache>>,
 }
 
 #[async_trait::async_trait]
@@ -30,6 +33,10 @@ impl LoaderRunnerPlugin for RspackLoaderRunnerPlugin {
       .await
   }
 
+  fn loader_result_cache(&self) -> Option<&Arc<dyn LoaderResultCache>> {
+    self.loader_result_cache.as_ref()
+  }
+
   async fn process_resource(
     &self,
     resource_data: &ResourceData,
@@ -0,0 +1,291 @@
+use std::{collections::BTreeMap, fmt::Write, mem::size_of_val, sync::Arc};
+
+use rspack_sources::BoxSource;
+use rustc_hash::{FxHashMap as HashMap, FxHashSet as HashSet};
+
+use crate::{BoxModule, CodeGenerationResult, Compilation, ModuleGraphRef};
+
+/// Retained bytes of a group of values in a [MemoryReport].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct MemoryUsage {
+  pub name: String,
+  pub count: usize,
+  pub size: usize,
+}
+
+/// Retained bytes of the items of a memory cache which were last accessed in `generation`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct CacheGenerationMemoryUsage {
+  pub cache: String,
+  pub generation: u32,
+  pub count: usize,
+  pub size: usize,
+}
+
+/// Memory retained by a compilation, broken down by artifact, by module type and by memory cache generation.
+///
+/// The sizes are estimated from the inline size of each value and the length of the sources it holds,
+/// so they are a lower bound of the heap usage, but good enough to tell which part of a long running
+/// watch process keeps growing. A source shared by several artifacts is only counted by the first
+/// artifact holding it, so the sizes of the artifacts add up to the retained bytes.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct MemoryReport {
+  pub artifacts: Vec<MemoryUsage>,
+  pub module_types: Vec<MemoryUsage>,
+  pub cache_generations: Vec<CacheGenerationMemoryUsage>,
+}
+
+/// Estimates the bytes retained by sources, the content of a source shared through its `Arc` is
+/// only counted the first time it is seen.
+#[derive(Debug, Default)]
+pub(crate) struct SourceSizes {
+  seen: HashSet<*const ()>,
+}
+
+impl SourceSizes {
+  /// Estimated bytes retained by a source, its inline size plus its content if not counted yet.
+  pub(crate) fn size(&mut self, source: &BoxSource) -> usize {
+    if self.seen.insert(Arc::as_ptr(source).cast::<()>()) {
+      size_of_val(source) + source.size()
+    } else {
+      size_of_val(source)
+    }
+  }
+}
+
+fn module_memory_size(
+  module: &BoxModule,
+  module_graph: &ModuleGraphRef<'_>,
+  source_sizes: &mut SourceSizes,
+) -> usize {
+  let dependencies_size: usize = module
+    .get_dependencies()
+    .iter()
+    .filter_map(|dependency_id| module_graph.dependency_by_id(dependency_id))
+    .map(|dependency| size_of_val(dependency.as_ref()))
+    .sum();
+  size_of_val(module.as_ref())
+    + module
+      .source()
+      .map_or(0, |source| source_sizes.size(source))
+    + dependencies_size
+}
+
+fn code_generation_result_memory_size(
+  result: &CodeGenerationResult,
+  source_sizes: &mut SourceSizes,
+) -> usize {
+  size_of_val(result)
+    + result
+      .inner()
+      .values()
+      .map(|source| source_sizes.size(source))
+      .sum::<usize>()
+}
+
+impl MemoryUsage {
+  fn new(name: impl Into<String>) -> Self {
+    Self {
+      name: name.into(),
+      count: 0,
+      size: 0,
+    }
+  }
+
+  fn add(&mut self, size: usize) {
+    self.count += 1;
+    self.size += size;
+  }
+}
+
+impl Compilation {
+  pub fn memory_report(&self) -> MemoryReport {
+    let module_graph = self.get_module_graph();
+    let mut source_sizes = SourceSizes::default();
+
+    let mut module_graph_usage = MemoryUsage::new("moduleGraph");
+    let mut module_types: HashMap<String, MemoryUsage> = HashMap::default();
+    for module in module_graph.modules().values() {
+      let size = module_memory_size(module, &module_graph, &mut source_sizes);
+      module_graph_usage.add(size);
+      let module_type = module.module_type().to_string();
+      module_types
+        .entry(module_type.clone())
+        .or_insert_with(|| MemoryUsage::new(module_type))
+        .add(size);
+    }
+
+    let mut code_generation_usage = MemoryUsage::new("codeGenerationResults");
+    for result in self.code_generation_results.inner().1.values() {
+      code_generation_usage.add(code_generation_result_memory_size(
+        result,
+        &mut source_sizes,
+      ));
+    }
+
+    let mut runtime_modules_usage = MemoryUsage::new("runtimeModules");
+    for source in self.runtime_modules_code_generation_source.values() {
+      runtime_modules_usage.add(source_sizes.size(source));
+    }
+
+    let mut chunk_render_usage = MemoryUsage::new("chunkRenderArtifact");
+    for result in self.chunk_render_artifact.values() {
+      chunk_render_usage.add(
+        result
+          .manifests
+          .iter()
+          .map(|manifest| source_sizes.size(&manifest.source))
+          .sum(),
+      );
+    }
+
+    let mut chunk_render_cache_usage = MemoryUsage::new("chunkRenderCacheArtifact");
+    let mut cache_generations = Vec::new();
+    for (generation, (count, size)) in self
+      .chunk_render_cache_artifact
+      .memory_usage_by_generation(|source| source_sizes.size(source))
+    {
+      chunk_render_cache_usage.count += count;
+      chunk_render_cache_usage.size += size;
+      cache_generations.push(CacheGenerationMemoryUsage {
+        cache: chunk_render_cache_usage.name.clone(),
+        generation,
+        count,
+        size,
+      });
+    }
+
+    let mut assets_usage = MemoryUsage::new("assets");
+    for asset in self.assets().values() {
+      assets_usage.add(
+        asset
+          .get_source()
+          .map_or(0, |source| source_sizes.size(source)),
+      );
+    }
+
+    let mut module_types = module_types.into_values().collect::<Vec<_>>();
+    module_types.sort_by(|a, b| b.size.cmp(&a.size).then_with(|| a.name.cmp(&b.name)));
+
+    MemoryReport {
+      artifacts: vec![
+        module_graph_usage,
+        code_generation_usage,
+        runtime_modules_usage,
+        chunk_render_usage,
+        chunk_render_cache_usage,
+        assets_usage,
+      ],
+      module_types,
+      cache_generations,
+    }
+  }
+}
+
+impl MemoryReport {
+  /// Renders the report as lines for logging, e.g. `moduleGraph: 1024 items, 12.5 MiB`.
+  pub fn to_log_lines(&self) -> Vec<String> {
+    fn format_size(size: usize) -> String {
+      const UNITS: [&str; 4] = ["B", "KiB", "MiB", "GiB"];
+      let mut size = size as f64;
+      let mut unit = 0;
+      while size >= 1024.0 && unit < UNITS.len() - 1 {
+        size /= 1024.0;
+        unit += 1;
+      }
+      if unit == 0 {
+        format!("{size} {}", UNITS[unit])
+      } else {
+        format!("{size:.1} {}", UNITS[unit])
+      }
+    }
+
+    let mut lines = Vec::new();
+    let mut write_usages = |title: &str, usages: &[MemoryUsage]| {
+      let mut line = format!("{title}:");
+      for usage in usages {
+        let _ = write!(
+          line,
+          "\n  {}: {} items, {}",
+          usage.name,
+          usage.count,
+          format_size(usage.size)
+        );
+      }
+      lines.push(line);
+    };
+    write_usages("artifacts", &self.artifacts);
+    write_usages("module types", &self.module_types);
+
+    let mut caches: BTreeMap<&str, Vec<&CacheGenerationMemoryUsage>> = BTreeMap::new();
+    for usage in &self.cache_generations {
+      caches.entry(&usage.cache).or_default().push(usage);
+    }
+    for (cache, generations) in caches {
+      let mut line = format!("{cache} generations:");
+      for usage in generations {
+        let _ = write!(
+          line,
+          "\n  {}: {} items, {}",
+          usage.generation,
+          usage.count,
+          format_size(usage.size)
+        );
+      }
+      lines.push(line);
+    }
+    lines
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use rspack_sources::{RawStringSource, SourceExt};
+
+  use super::*;
+
+  #[test]
+  fn should_count_shared_sources_once() {
+    let source = RawStringSource::from("const a = 1;").boxed();
+    let mut source_sizes = SourceSizes::default();
+    assert_eq!(
+      source_sizes.size(&source),
+      size_of_val(&source) + source.size()
+    );
+    assert_eq!(source_sizes.size(&source.clone()), size_of_val(&source));
+    assert_eq!(
+      source_sizes.size(&RawStringSource::from("const a = 1;").boxed()),
+      size_of_val(&source) + source.size()
+    );
+  }
+
+  #[test]
+  fn should_render_log_lines() {
+    let report = MemoryReport {
+      artifacts: vec![MemoryUsage {
+        name: "moduleGraph".to_string(),
+        count: 2,
+        size: 3 * 1024 * 1024,
+      }],
+      module_types: vec![MemoryUsage {
+        name: "javascript/auto".to_string(),
+        count: 2,
+        size: 1536,
+      }],
+      cache_generations: vec![CacheGenerationMemoryUsage {
+        cache: "chunkRenderCacheArtifact".to_string(),
+        generation: 1,
+        count: 1,
+        size: 100,
+      }],
+    };
+    assert_eq!(
+      report.to_log_lines(),
+      vec![
+        "artifacts:\n  moduleGraph: 2 items, 3.0 MiB".to_string(),
+        "module types:\n  javascript/auto: 2 items, 1.5 KiB".to_string(),
+        "chunkRenderCacheArtifact generations:\n  1: 1 items, 100 B".to_string(),
+      ]
+    );
+  }
+}
@@ -28,14 +28,14 @@ use serde::Serialize;
 
 use crate::{
   AsyncDependenciesBlock, BindingCell, BoxDependency, BoxDependencyTemplate, BoxModuleDependency,
-  ChunkGraph, ChunkUkey, CodeGenerationResult, CollectedTypeScriptInfo, Compilation,
+  BuildProfile, ChunkGraph, ChunkUkey, CodeGenerationResult, CollectedTypeScriptInfo, Compilation,
   CompilationAsset, CompilationId, CompilerId, CompilerOptions, ConcatenationScope,
   ConnectionState, Context, ContextModule, DependenciesBlock, DependencyId, ExportProvided,
-  ExternalModule, ModuleGraph, ModuleGraphCacheArtifact, ModuleLayer, ModuleType, NormalModule,
-  PrefetchExportsInfoMode, RawModule, Resolve, ResolverFactory, RuntimeSpec, RuntimeTemplate,
-  SelfModule, SharedPluginDriver, SourceType, concatenated_module::ConcatenatedModule,
-  dependencies_block::dependencies_block_update_hash, get_target,
-  value_cache_versions::ValueCacheVersions,
+  ExternalModule, LoaderResultCache, ModuleGraph, ModuleGraphCacheArtifact, ModuleLayer,
+  ModuleType, NormalModule, PrefetchExportsInfoMode, RawModule, Resolve, ResolverFactory,
+  RuntimeSpec, RuntimeTemplate, SelfModule, SharedPluginDriver, SourceType,
+  concatenated_module::ConcatenatedModule, dependencies_block::dependencies_block_update_hash,
+  get_target, value_cache_versions::ValueCacheVersions,
 };
 
 pub struct BuildContext {
@@ -46,6 +46,7 @@ pub struct BuildContext {
   pub runtime_template: Arc<RuntimeTemplate>,
   pub plugin_driver: SharedPluginDriver,
   pub fs: Arc<dyn ReadableFileSystem>,
+  pub loader_result_cache: Option<Arc<dyn LoaderResultCache>>,
 }
 
 #[cacheable]
@@ -74,6 +75,8 @@ pub struct BuildInfo {
   pub assets: BindingCell<HashMap<String, CompilationAsset>>,
   pub module: bool,
   pub collected_typescript_info: Option<CollectedTypeScriptInfo>,
+  /// The i18n message ids used by the module, sorted and deduplicated.
+  pub i18n_message_ids: Option<Vec<String>>,
   /// Stores external fields from the JS side (Record<string, any>),
   /// while other properties are stored in KnownBuildInfo.
   #[cacheable(with=AsPreset)]
@@ -102,6 +105,7 @@ impl Default for BuildInfo {
       assets: Default::default(),
       module: false,
       collected_typescript_info: None,
+      i18n_message_ids: None,
       extras: Default::default(),
     }
   }
@@ -198,6 +202,8 @@ pub struct BuildResult {
   pub dependencies: Vec<BoxDependency>,
   pub blocks: Vec<Box<AsyncDependenciesBlock>>,
   pub optimization_bailouts: Vec<String>,
+  /// Timings of loaders and parser, only collected when `profile` is enabled.
+  pub profile: Option<BuildProfile>,
 }
 
 #[cacheable]
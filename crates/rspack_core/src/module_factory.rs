@@ -1,4 +1,4 @@
-use std::{fmt::Debug, sync::Arc};
+use std::{fmt::Debug, sync::Arc, time::Duration};
 
 use rspack_error::{Diagnostic, Result};
 use rspack_paths::{ArcPath, ArcPathSet};
@@ -69,12 +69,15 @@ impl ModuleFactoryCreateData {
 #[derive(Debug, Default)]
 pub struct ModuleFactoryResult {
   pub module: Option<BoxModule>,
+  /// Time spent on resolving the request and the loaders of the module.
+  pub resolving: Option<Duration>,
 }
 
 impl ModuleFactoryResult {
   pub fn new_with_module(module: BoxModule) -> Self {
     Self {
       module: Some(module),
+      resolving: None,
     }
   }
 
@@ -82,6 +85,11 @@ impl ModuleFactoryResult {
     self.module = module;
     self
   }
+
+  pub fn resolving(mut self, resolving: Duration) -> Self {
+    self.resolving = Some(resolving);
+    self
+  }
 }
 
 #[async_trait::async_trait]
@@ -77,6 +77,10 @@ impl ModuleGraphModule {
     self.profile.as_ref()
   }
 
+  pub fn profile_mut(&mut self) -> Option<&mut ModuleProfile> {
+    self.profile.as_mut()
+  }
+
   pub fn set_issuer_if_unset(&mut self, issuer: Option<ModuleIdentifier>) {
     if matches!(self.issuer, ModuleIssuer::Unset) {
       self.issuer = ModuleIssuer::from_identifier(issuer);
@@ -1,4 +1,4 @@
-use std::time::Instant;
+use std::time::{Duration, Instant};
 
 use rspack_cacheable::{
   cacheable,
@@ -11,7 +11,7 @@ enum ProfileState {
   #[default]
   Pending,
   Started(Instant),
-  // u64 is enough to store the time consumption
+  // u64 is enough to store the time consumption in microseconds
   Finish(u64),
 }
 
@@ -44,12 +44,16 @@ impl ProfileState {
     match self {
       Self::Started(i) => {
         let time = Instant::now().duration_since(*i);
-        *self = Self::Finish(time.as_millis() as u64)
+        *self = Self::Finish(time.as_micros() as u64)
       }
       _ => panic!("Unable to end an unstarted profiler"),
     }
   }
 
+  fn set(&mut self, time: Duration) {
+    *self = Self::Finish(time.as_micros() as u64)
+  }
+
   fn duration(&self) -> Option<u64> {
     match self {
       Self::Finish(time) => Some(*time),
@@ -60,12 +64,40 @@ impl ProfileState {
 
 // https://github.com/webpack/webpack/blob/4809421990a20dfefa06e6445191e65001e75f88/lib/ModuleProfile.js
 // NOTE: Rspack has different cache design, remove cache related profiles
+// All durations are stored in microseconds and reported in milliseconds.
+
+#[cacheable]
+#[derive(Debug, Clone)]
+pub struct LoaderProfile {
+  /// Loader identifier
+  pub loader: String,
+  /// Microseconds spent in the pitching phase
+  pub pitch: u64,
+  /// Microseconds spent in the normal phase
+  pub normal: u64,
+}
+
+/// Timings reported by [crate::Module::build], only modules with loaders and parser report them.
+#[derive(Debug, Default)]
+pub struct BuildProfile {
+  pub loaders: Vec<LoaderProfile>,
+  pub parsing: Option<Duration>,
+}
 
 #[cacheable]
 #[derive(Debug, Default, Clone)]
 pub struct ModuleProfile {
   factory: ProfileState,
+  resolving: ProfileState,
   building: ProfileState,
+  loaders: Vec<LoaderProfile>,
+  parsing: ProfileState,
+  code_generation: ProfileState,
+  hashing: ProfileState,
+}
+
+fn micros_to_millis(time: u64) -> u64 {
+  time / 1000
 }
 
 impl ModuleProfile {
@@ -85,11 +117,76 @@ impl ModuleProfile {
     self.building.end();
   }
 
+  pub fn set_resolving(&mut self, time: Duration) {
+    self.resolving.set(time);
+  }
+
+  pub fn set_build_profile(&mut self, profile: BuildProfile) {
+    self.loaders = profile.loaders;
+    self.parsing = ProfileState::default();
+    if let Some(time) = profile.parsing {
+      self.parsing.set(time);
+    }
+  }
+
+  /// Total time of the code generation of all the runtimes in the current compilation.
+  pub fn set_code_generation(&mut self, time: Duration) {
+    self.code_generation.set(time);
+  }
+
+  /// Total time of the hashing of all the runtimes in the current compilation.
+  pub fn set_hashing(&mut self, time: Duration) {
+    self.hashing.set(time);
+  }
+
   pub fn factory_duration(&self) -> Option<u64> {
-    self.factory.duration()
+    self.factory.duration().map(micros_to_millis)
+  }
+
+  pub fn resolving_duration(&self) -> Option<u64> {
+    self.resolving.duration().map(micros_to_millis)
   }
 
   pub fn building_duration(&self) -> Option<u64> {
-    self.building.duration()
+    self.building.duration().map(micros_to_millis)
+  }
+
+  pub fn parsing_duration(&self) -> Option<u64> {
+    self.parsing.duration().map(micros_to_millis)
+  }
+
+  pub fn code_generation_duration(&self) -> Option<u64> {
+    self.code_generation.duration().map(micros_to_millis)
+  }
+
+  pub fn hashing_duration(&self) -> Option<u64> {
+    self.hashing.duration().map(micros_to_millis)
+  }
+
+  pub fn loaders(&self) -> &[LoaderProfile] {
+    &self.loaders
+  }
+
+  /// Microseconds spent in each phase, used to aggregate timings of many modules without
+  /// accumulating rounding errors.
+  pub fn raw_durations(&self) -> ModuleProfileDurations {
+    ModuleProfileDurations {
+      factory: self.factory.duration().unwrap_or_default(),
+      resolving: self.resolving.duration().unwrap_or_default(),
+      building: self.building.duration().unwrap_or_default(),
+      parsing: self.parsing.duration().unwrap_or_default(),
+      code_generation: self.code_generation.duration().unwrap_or_default(),
+      hashing: self.hashing.duration().unwrap_or_default(),
+    }
   }
 }
+
+#[derive(Debug, Default, Clone, Copy)]
+pub struct ModuleProfileDurations {
+  pub factory: u64,
+  pub resolving: u64,
+  pub building: u64,
+  pub parsing: u64,
+  pub code_generation: u64,
+  pub hashing: u64,
+}
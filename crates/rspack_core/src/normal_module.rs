@@ -5,6 +5,7 @@ use std::{
     Arc,
     atomic::{AtomicUsize, Ordering},
   },
+  time::Instant,
 };
 
 use dashmap::DashMap;
@@ -34,11 +35,11 @@ use tracing::{Instrument, info_span};
 
 use crate::{
   AsyncDependenciesBlockIdentifier, BoxDependencyTemplate, BoxLoader, BoxModule,
-  BoxModuleDependency, BuildContext, BuildInfo, BuildMeta, BuildResult, ChunkGraph,
+  BoxModuleDependency, BuildContext, BuildInfo, BuildMeta, BuildProfile, BuildResult, ChunkGraph,
   CodeGenerationResult, Compilation, ConcatenationScope, ConnectionState, Context,
   DependenciesBlock, DependencyId, FactoryMeta, GenerateContext, GeneratorOptions, LibIdentOptions,
-  Module, ModuleGraph, ModuleGraphCacheArtifact, ModuleIdentifier, ModuleLayer, ModuleType,
-  OutputOptions, ParseContext, ParseResult, ParserAndGenerator, ParserOptions, Resolve,
+  LoaderProfile, Module, ModuleGraph, ModuleGraphCacheArtifact, ModuleIdentifier, ModuleLayer,
+  ModuleType, OutputOptions, ParseContext, ParseResult, ParserAndGenerator, ParserOptions, Resolve,
   RspackLoaderRunnerPlugin, RunnerContext, RuntimeGlobals, RuntimeSpec, SourceType, contextify,
   diagnostics::ModuleBuildError, get_context, module_update_hash,
 };
@@ -448,6 +449,7 @@ impl Module for NormalModule {
     let plugin = Arc::new(RspackLoaderRunnerPlugin {
       plugin_driver: build_context.plugin_driver.clone(),
       extract_source_map: self.inner().extract_source_map,
+      loader_result_cache: build_context.loader_result_cache.clone(),
     });
 
     let compiler_id = build_context.compiler_id;
@@ -485,6 +487,22 @@ impl Module for NormalModule {
       )
       .await;
 
+    let mut build_profile = build_context
+      .compiler_options
+      .profile
+      .then(|| BuildProfile {
+        loaders: loader_result
+          .loader_timings
+          .iter()
+          .map(|timing| LoaderProfile {
+            loader: timing.request.to_string(),
+            pitch: timing.pitch.as_micros() as u64,
+            normal: timing.normal.as_micros() as u64,
+          })
+          .collect(),
+        parsing: None,
+      });
+
     let inner = self.inner_mut();
     if let Some(err) = err {
       inner.build_info.cacheable = loader_result.cacheable;
@@ -531,6 +549,7 @@ impl Module for NormalModule {
         dependencies: Vec::new(),
         blocks: Vec::new(),
         optimization_bailouts: vec![],
+        profile: build_profile,
       });
     };
 
@@ -599,9 +618,11 @@ impl Module for NormalModule {
         dependencies: Vec::new(),
         blocks: Vec::new(),
         optimization_bailouts: Vec::new(),
+        profile: build_profile,
       });
     }
 
+    let parse_start = Instant::now();
     let (
       ParseResult {
         source,
@@ -619,6 +640,7 @@ impl Module for NormalModule {
         module_context: &inner.context,
         module_identifier: inner.id,
         module_parser_options: inner.parser_options.as_ref(),
+        module_generator_options: inner.generator_options.as_ref(),
         module_type: &inner.module_type,
         module_layer: inner.layer.as_ref(),
         module_user_request: &inner.user_request,
@@ -636,6 +658,9 @@ impl Module for NormalModule {
       })
       .await?
       .split_into_parts();
+    if let Some(build_profile) = &mut build_profile {
+      build_profile.parsing = Some(parse_start.elapsed());
+    }
     if diagnostics.iter().any(|d| d.is_error()) {
       inner.build_meta = Default::default();
     }
@@ -667,6 +692,7 @@ impl Module for NormalModule {
       dependencies,
       blocks,
       optimization_bailouts,
+      profile: build_profile,
     })
   }
 
@@ -1,4 +1,4 @@
-use std::{borrow::Cow, sync::Arc};
+use std::{borrow::Cow, sync::Arc, time::Instant};
 
 use rspack_error::{Result, error};
 use rspack_hook::define_hook;
@@ -768,6 +768,8 @@ impl NormalModuleFactory {
           | ParserOptions::JavascriptEsm(b),
         ) => ParserOptions::Javascript(a.merge_from(b)),
         (ParserOptions::Json(a), ParserOptions::Json(b)) => ParserOptions::Json(a.merge_from(b)),
+        (ParserOptions::Yaml(a), ParserOptions::Yaml(b)) => ParserOptions::Yaml(a.merge_from(b)),
+        (ParserOptions::Toml(a), ParserOptions::Toml(b)) => ParserOptions::Toml(a.merge_from(b)),
         (global, _) => global,
       },
     );
@@ -858,8 +860,9 @@ impl NormalModuleFactory {
       }
     }
 
+    let start = Instant::now();
     if let Some(result) = self.resolve_normal_module(data).await? {
-      return Ok(result);
+      return Ok(result.resolving(start.elapsed()));
     }
 
     Err(error!(
@@ -1,7 +1,8 @@
-use std::{fmt::Debug, sync::Arc};
+use std::{fmt, str::FromStr, sync::Arc};
 
 use futures::future::BoxFuture;
-use rspack_error::Result;
+use rspack_cacheable::cacheable;
+use rspack_error::{Error, Result};
 use rspack_regex::RspackRegex;
 use rustc_hash::FxHashMap as HashMap;
 
@@ -77,4 +78,134 @@ impl From<RspackRegex> for ExternalItem {
   }
 }
 
-pub type ExternalType = String;
+/// How an external module is loaded at runtime, see `externalsType`.
+#[cacheable]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum ExternalType {
+  Var,
+  Module,
+  Assign,
+  This,
+  Window,
+  SelfObject,
+  Global,
+  Commonjs,
+  Commonjs2,
+  CommonjsModule,
+  CommonjsStatic,
+  Amd,
+  AmdRequire,
+  Umd,
+  Umd2,
+  Jsonp,
+  System,
+  Promise,
+  Import,
+  ModuleImport,
+  Script,
+  NodeCommonjs,
+  CommonjsImport,
+  Const,
+  Let,
+  /// Used by externals of `url()` and `new URL()` requests
+  Asset,
+  /// Used by externals of CSS `@import`
+  CssImport,
+  /// Library types which default `externalsType`, rendered like `var`
+  AssignProperties,
+  ModernModule,
+}
+
+impl ExternalType {
+  pub const ALL: [ExternalType; 29] = [
+    Self::Var,
+    Self::Module,
+    Self::Assign,
+    Self::This,
+    Self::Window,
+    Self::SelfObject,
+    Self::Global,
+    Self::Commonjs,
+    Self::Commonjs2,
+    Self::CommonjsModule,
+    Self::CommonjsStatic,
+    Self::Amd,
+    Self::AmdRequire,
+    Self::Umd,
+    Self::Umd2,
+    Self::Jsonp,
+    Self::System,
+    Self::Promise,
+    Self::Import,
+    Self::ModuleImport,
+    Self::Script,
+    Self::NodeCommonjs,
+    Self::CommonjsImport,
+    Self::Const,
+    Self::Let,
+    Self::Asset,
+    Self::CssImport,
+    Self::AssignProperties,
+    Self::ModernModule,
+  ];
+
+  pub fn as_str(&self) -> &'static str {
+    match self {
+      Self::Var => "var",
+      Self::Module => "module",
+      Self::Assign => "assign",
+      Self::This => "this",
+      Self::Window => "window",
+      Self::SelfObject => "self",
+      Self::Global => "global",
+      Self::Commonjs => "commonjs",
+      Self::Commonjs2 => "commonjs2",
+      Self::CommonjsModule => "commonjs-module",
+      Self::CommonjsStatic => "commonjs-static",
+      Self::Amd => "amd",
+      Self::AmdRequire => "amd-require",
+      Self::Umd => "umd",
+      Self::Umd2 => "umd2",
+      Self::Jsonp => "jsonp",
+      Self::System => "system",
+      Self::Promise => "promise",
+      Self::Import => "import",
+      Self::ModuleImport => "module-import",
+      Self::Script => "script",
+      Self::NodeCommonjs => "node-commonjs",
+      Self::CommonjsImport => "commonjs-import",
+      Self::Const => "const",
+      Self::Let => "let",
+      Self::Asset => "asset",
+      Self::CssImport => "css-import",
+      Self::AssignProperties => "assign-properties",
+      Self::ModernModule => "modern-module",
+    }
+  }
+}
+
+impl fmt::Display for ExternalType {
+  fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+    f.write_str(self.as_str())
+  }
+}
+
+impl FromStr for ExternalType {
+  type Err = Error;
+
+  fn from_str(s: &str) -> Result<Self> {
+    Self::ALL
+      .into_iter()
+      .find(|t| t.as_str() == s)
+      .ok_or_else(|| {
+        let expected = Self::ALL
+          .iter()
+          .map(|t| format!("\"{t}\""))
+          .collect::<Vec<_>>()
+          .join(", ");
+        Error::error(format!(
+          "Invalid external type \"{s}\", expected one of {expected}"
+        ))
+      })
+  }
+}
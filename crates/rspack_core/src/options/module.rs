@@ -9,14 +9,16 @@ use bitflags::bitflags;
 use derive_more::Debug;
 use futures::future::BoxFuture;
 use rspack_cacheable::{cacheable, with::Unsupported};
-use rspack_error::Result;
+use rspack_error::{Result, error};
 use rspack_macros::MergeFrom;
 use rspack_regex::RspackRegex;
 use rspack_util::{MergeFrom, try_all, try_any};
 use rustc_hash::FxHashMap as HashMap;
 use tokio::sync::OnceCell;
 
-use crate::{Compilation, Filename, Module, ModuleType, PublicPath, Resolve};
+use crate::{
+  Compilation, Filename, Module, ModuleIdentifier, ModuleLayer, ModuleType, PublicPath, Resolve,
+};
 
 #[derive(Debug, Default)]
 pub struct ParserOptionsMap(HashMap<String, ParserOptions>);
@@ -59,6 +61,8 @@ pub enum ParserOptions {
   JavascriptEsm(JavascriptParserOptions),
   JavascriptDynamic(JavascriptParserOptions),
   Json(JsonParserOptions),
+  Yaml(YamlParserOptions),
+  Toml(TomlParserOptions),
   Unknown,
 }
 
@@ -87,6 +91,8 @@ impl ParserOptions {
     JavascriptParserOptions
   );
   get_variant!(get_json, Json, JsonParserOptions);
+  get_variant!(get_yaml, Yaml, YamlParserOptions);
+  get_variant!(get_toml, Toml, TomlParserOptions);
 }
 
 #[cacheable]
@@ -318,11 +324,83 @@ pub struct AssetParserOptions {
   pub data_url_condition: Option<AssetParserDataUrl>,
 }
 
+/// The module is still building when the condition is evaluated, so only the
+/// information known before parsing is provided.
+pub struct AssetParserDataUrlFnCtx<'a> {
+  pub filename: String,
+  pub module_identifier: ModuleIdentifier,
+  pub module_type: &'a ModuleType,
+  pub module_layer: Option<&'a ModuleLayer>,
+}
+
+pub type AssetParserDataUrlFn =
+  Arc<dyn Fn(Vec<u8>, AssetParserDataUrlFnCtx) -> BoxFuture<'static, Result<bool>> + Sync + Send>;
+
 #[cacheable]
-#[derive(Debug, Clone, MergeFrom)]
 pub enum AssetParserDataUrl {
   Options(AssetParserDataUrlOptions),
-  // TODO: Function
+  Func(#[cacheable(with=Unsupported)] AssetParserDataUrlFn),
+}
+
+impl AssetParserDataUrl {
+  pub fn max_size(&self) -> Option<f64> {
+    match self {
+      Self::Options(i) => i.max_size,
+      Self::Func(_) => None,
+    }
+  }
+}
+
+impl fmt::Debug for AssetParserDataUrl {
+  fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+    match self {
+      Self::Options(i) => i.fmt(f),
+      Self::Func(_) => "Func(...)".fmt(f),
+    }
+  }
+}
+
+impl Clone for AssetParserDataUrl {
+  fn clone(&self) -> Self {
+    match self {
+      Self::Options(i) => Self::Options(i.clone()),
+      Self::Func(i) => Self::Func(i.clone()),
+    }
+  }
+}
+
+impl MergeFrom for AssetParserDataUrl {
+  fn merge_from(self, other: &Self) -> Self {
+    match (self, other) {
+      (Self::Options(a), Self::Options(b)) => Self::Options(a.merge_from(b)),
+      // a function can't be merged with the options, the later one takes precedence
+      (_, other) => other.clone(),
+    }
+  }
+}
+
+#[test]
+fn test_merge_asset_parser_data_url() {
+  let options = |max_size| AssetParserDataUrl::Options(AssetParserDataUrlOptions { max_size });
+  assert_eq!(
+    options(Some(4096.0)).merge_from(&options(None)).max_size(),
+    Some(4096.0)
+  );
+  assert_eq!(
+    options(Some(4096.0))
+      .merge_from(&options(Some(8192.0)))
+      .max_size(),
+    Some(8192.0)
+  );
+  let func = AssetParserDataUrl::Func(Arc::new(|_, _| Box::pin(async { Ok(true) })));
+  assert!(matches!(
+    options(Some(4096.0)).merge_from(&func),
+    AssetParserDataUrl::Func(_)
+  ));
+  assert!(matches!(
+    func.merge_from(&options(Some(4096.0))),
+    AssetParserDataUrl::Options(_)
+  ));
 }
 
 #[cacheable]
@@ -409,6 +487,18 @@ pub struct JsonParserOptions {
   pub parse: ParseOption,
 }
 
+#[cacheable]
+#[derive(Debug, Clone, MergeFrom)]
+pub struct YamlParserOptions {
+  pub exports_depth: Option<u32>,
+}
+
+#[cacheable]
+#[derive(Debug, Clone, MergeFrom)]
+pub struct TomlParserOptions {
+  pub exports_depth: Option<u32>,
+}
+
 #[derive(Debug, Default)]
 pub struct GeneratorOptionsMap(HashMap<String, GeneratorOptions>);
 
@@ -506,6 +596,13 @@ impl GeneratorOptions {
       .and_then(|x| x.emit)
       .or_else(|| self.get_asset_resource().and_then(|x| x.emit))
   }
+
+  pub fn asset_image_optimization(&self) -> Option<bool> {
+    self
+      .get_asset()
+      .and_then(|x| x.image_optimization)
+      .or_else(|| self.get_asset_resource().and_then(|x| x.image_optimization))
+  }
 }
 
 #[cacheable]
@@ -572,6 +669,7 @@ pub struct AssetResourceGeneratorOptions {
   pub public_path: Option<PublicPath>,
   pub import_mode: Option<AssetGeneratorImportMode>,
   pub binary: Option<bool>,
+  pub image_optimization: Option<bool>,
 }
 
 impl From<AssetGeneratorOptions> for AssetResourceGeneratorOptions {
@@ -583,6 +681,7 @@ impl From<AssetGeneratorOptions> for AssetResourceGeneratorOptions {
       public_path: value.public_path,
       import_mode: value.import_mode,
       binary: value.binary,
+      image_optimization: value.image_optimization,
     }
   }
 }
@@ -597,6 +696,7 @@ pub struct AssetGeneratorOptions {
   pub data_url: Option<AssetGeneratorDataUrl>,
   pub import_mode: Option<AssetGeneratorImportMode>,
   pub binary: Option<bool>,
+  pub image_optimization: Option<bool>,
 }
 
 pub struct AssetGeneratorDataUrlFnCtx<'a> {
@@ -651,6 +751,9 @@ pub struct AssetGeneratorDataUrlOptions {
 pub enum DataUrlEncoding {
   None,
   Base64,
+  /// Only escapes the characters required by data urls like `mini-svg-data-uri`,
+  /// which is usually smaller than base64 for SVG images.
+  MiniSvg,
 }
 
 impl fmt::Display for DataUrlEncoding {
@@ -658,20 +761,35 @@ impl fmt::Display for DataUrlEncoding {
     match self {
       DataUrlEncoding::None => write!(f, ""),
       DataUrlEncoding::Base64 => write!(f, "base64"),
+      DataUrlEncoding::MiniSvg => write!(f, "mini-svg"),
     }
   }
 }
 
-impl From<String> for DataUrlEncoding {
-  fn from(value: String) -> Self {
+impl TryFrom<String> for DataUrlEncoding {
+  type Error = rspack_error::Error;
+
+  fn try_from(value: String) -> Result<Self> {
     match value.as_str() {
-      "base64" => Self::Base64,
-      "false" => Self::None,
-      _ => unreachable!("DataUrlEncoding should be base64 or false"),
+      "base64" => Ok(Self::Base64),
+      "false" => Ok(Self::None),
+      "mini-svg" => Ok(Self::MiniSvg),
+      _ => Err(error!(
+        "Invalid data url encoding \"{value}\", expected \"base64\", \"mini-svg\" or false"
+      )),
     }
   }
 }
 
+#[test]
+fn test_data_url_encoding_from_string() {
+  assert!(matches!(
+    DataUrlEncoding::try_from("mini-svg".to_string()),
+    Ok(DataUrlEncoding::MiniSvg)
+  ));
+  assert!(DataUrlEncoding::try_from("base32".to_string()).is_err());
+}
+
 #[cacheable]
 #[derive(Debug, Clone, MergeFrom)]
 pub struct CssGeneratorOptions {
@@ -686,6 +804,7 @@ pub struct CssAutoGeneratorOptions {
   pub exports_only: Option<bool>,
   pub local_ident_name: Option<LocalIdentName>,
   pub es_module: Option<bool>,
+  pub emit_dts: Option<bool>,
 }
 
 impl From<CssGeneratorOptions> for CssAutoGeneratorOptions {
@@ -705,6 +824,7 @@ pub struct CssModuleGeneratorOptions {
   pub exports_only: Option<bool>,
   pub local_ident_name: Option<LocalIdentName>,
   pub es_module: Option<bool>,
+  pub emit_dts: Option<bool>,
 }
 
 impl From<CssGeneratorOptions> for CssModuleGeneratorOptions {
@@ -33,6 +33,9 @@ pub struct OutputOptions {
   pub path: Utf8PathBuf,
   pub pathinfo: PathInfo,
   pub clean: CleanOptions,
+  /// The name of the manifest of emitted files in the output directory, when set, unchanged files
+  /// are not written again and the files no longer emitted are removed according to `clean`.
+  pub clean_manifest: Option<String>,
   pub public_path: PublicPath,
   pub asset_module_filename: Filename,
   pub wasm_loading: WasmLoading,
@@ -71,6 +74,9 @@ pub struct OutputOptions {
   pub script_type: String,
   pub environment: Environment,
   pub compare_before_emit: bool,
+  /// Emit into a staging directory next to the output directory, and replace the output
+  /// directory with it before calling the `asset_emitted` and then the `after_emit` hooks.
+  pub atomic_emit: bool,
 }
 
 impl From<&OutputOptions> for RspackHash {
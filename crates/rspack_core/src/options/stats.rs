@@ -1,4 +1,31 @@
+/// The format of the diagnostics report, see [`crate::Stats::emit_diagnostics_report`].
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub enum DiagnosticsFormat {
+  /// Human readable text rendered with source frames.
+  #[default]
+  Text,
+  Json,
+  /// [SARIF 2.1.0](https://docs.oasis-open.org/sarif/sarif/v2.1.0/sarif-v2.1.0.html), which is consumed by code scanning tools.
+  Sarif,
+}
+
+impl TryFrom<&str> for DiagnosticsFormat {
+  type Error = rspack_error::Error;
+
+  fn try_from(value: &str) -> rspack_error::Result<Self> {
+    match value {
+      "text" => Ok(Self::Text),
+      "json" => Ok(Self::Json),
+      "sarif" => Ok(Self::Sarif),
+      _ => Err(rspack_error::error!(
+        "Unknown diagnostics format \"{value}\", expected \"text\", \"json\" or \"sarif\""
+      )),
+    }
+  }
+}
+
 #[derive(Debug, Default)]
 pub struct StatsOptions {
   pub colors: bool,
+  pub diagnostics_format: DiagnosticsFormat,
 }
@@ -16,9 +16,9 @@ use swc_core::{atoms::Atom, common::Span};
 use crate::{
   AsyncDependenciesBlock, BoxDependency, BoxDependencyTemplate, BoxLoader, BoxModuleDependency,
   BuildInfo, BuildMeta, ChunkGraph, CodeGenerationData, Compilation, CompilerOptions,
-  ConcatenationScope, Context, EvaluatedInlinableValue, FactoryMeta, Module, ModuleGraph,
-  ModuleIdentifier, ModuleLayer, ModuleType, NormalModule, ParserOptions, RuntimeGlobals,
-  RuntimeSpec, RuntimeTemplate, SourceType,
+  ConcatenationScope, Context, EvaluatedInlinableValue, FactoryMeta, GeneratorOptions, Module,
+  ModuleGraph, ModuleIdentifier, ModuleLayer, ModuleType, NormalModule, ParserOptions,
+  RuntimeGlobals, RuntimeSpec, RuntimeTemplate, SourceType,
 };
 
 #[derive(Debug)]
@@ -30,6 +30,7 @@ pub struct ParseContext<'a> {
   pub module_layer: Option<&'a ModuleLayer>,
   pub module_user_request: &'a str,
   pub module_parser_options: Option<&'a ParserOptions>,
+  pub module_generator_options: Option<&'a GeneratorOptions>,
   pub module_source_map_kind: SourceMapKind,
   pub module_match_resource: Option<&'a ResourceData>,
   #[debug(skip)]
@@ -55,6 +56,8 @@ pub struct CollectedTypeScriptInfo {
 
 pub const COLLECTED_TYPESCRIPT_INFO_PARSE_META_KEY: &str = "rspack-collected-ts-info";
 
+pub const COLLECTED_I18N_MESSAGES_PARSE_META_KEY: &str = "rspack-collected-i18n-messages";
+
 #[cacheable]
 #[derive(Debug, Default, Clone)]
 pub struct TSEnumValue(
@@ -0,0 +1,410 @@
+use std::{cell::RefCell, fmt::Display, io::Write, time::SystemTime};
+
+use rspack_error::{Result, ToStringResultToRspackResultExt};
+use rspack_paths::Utf8Path;
+use rspack_sources::{BoxSource, Source, SourceValue};
+use rspack_util::atom::Atom;
+use rspack_workspace::rspack_pkg_version;
+use rustc_hash::FxHashMap as HashMap;
+use serde::{
+  Serialize, Serializer,
+  ser::{SerializeMap, SerializeSeq},
+};
+
+use super::{
+  EntrypointsStatsOption, ExtendedStatsOptions, Stats, StatsAssetInfoRelated, StatsModuleTrace,
+  StatsSourceTypeSize, StatsUsedExports,
+};
+use crate::{ChunkGroupOrderKey, PublicPath, RuntimeSpec, SourceType};
+
+pub(super) fn serialize_display<T: Display, S: Serializer>(
+  value: &T,
+  serializer: S,
+) -> std::result::Result<S::Ok, S::Error> {
+  serializer.collect_str(value)
+}
+
+pub(super) fn serialize_optional_path<S: Serializer>(
+  path: &Option<&Utf8Path>,
+  serializer: S,
+) -> std::result::Result<S::Ok, S::Error> {
+  match path {
+    Some(path) => serializer.serialize_str(path.as_str()),
+    None => serializer.serialize_none(),
+  }
+}
+
+pub(super) fn serialize_optional_source<S: Serializer>(
+  source: &Option<&BoxSource>,
+  serializer: S,
+) -> std::result::Result<S::Ok, S::Error> {
+  match source.map(|source| source.source()) {
+    Some(SourceValue::String(string)) => serializer.serialize_str(&string),
+    Some(SourceValue::Buffer(bytes)) => serializer.serialize_str(&String::from_utf8_lossy(&bytes)),
+    None => serializer.serialize_none(),
+  }
+}
+
+pub(super) fn serialize_optional_atoms<S: Serializer>(
+  atoms: &Option<Vec<Atom>>,
+  serializer: S,
+) -> std::result::Result<S::Ok, S::Error> {
+  match atoms {
+    Some(atoms) => serializer.collect_seq(atoms.iter().map(|atom| atom.as_str())),
+    None => serializer.serialize_none(),
+  }
+}
+
+/// `related` is an object keyed by the relation name in webpack.
+pub(super) fn serialize_related<S: Serializer>(
+  related: &[StatsAssetInfoRelated],
+  serializer: S,
+) -> std::result::Result<S::Ok, S::Error> {
+  serializer.collect_map(related.iter().map(|related| (related.name, &related.value)))
+}
+
+pub(super) fn serialize_source_type_sizes<S: Serializer>(
+  sizes: &[StatsSourceTypeSize],
+  serializer: S,
+) -> std::result::Result<S::Ok, S::Error> {
+  let mut sizes = sizes
+    .iter()
+    .map(|size| (size.source_type.to_string(), size.size))
+    .collect::<Vec<_>>();
+  sizes.sort_by(|a, b| a.0.cmp(&b.0));
+  serializer.collect_map(sizes)
+}
+
+pub(super) fn serialize_sizes<S: Serializer>(
+  sizes: &HashMap<SourceType, f64>,
+  serializer: S,
+) -> std::result::Result<S::Ok, S::Error> {
+  let mut sizes = sizes
+    .iter()
+    .map(|(source_type, size)| (source_type.to_string(), *size))
+    .collect::<Vec<_>>();
+  sizes.sort_by(|a, b| a.0.cmp(&b.0));
+  serializer.collect_map(sizes)
+}
+
+pub(super) fn serialize_children_by_order<S: Serializer>(
+  children_by_order: &HashMap<ChunkGroupOrderKey, Vec<String>>,
+  serializer: S,
+) -> std::result::Result<S::Ok, S::Error> {
+  let mut children_by_order = children_by_order
+    .iter()
+    .map(|(order, children)| (order.to_string(), children))
+    .collect::<Vec<_>>();
+  children_by_order.sort_by(|a, b| a.0.cmp(&b.0));
+  serializer.collect_map(children_by_order)
+}
+
+pub(super) fn serialize_runtime<S: Serializer>(
+  runtime: &&RuntimeSpec,
+  serializer: S,
+) -> std::result::Result<S::Ok, S::Error> {
+  let mut runtime = runtime.iter().map(|r| r.as_str()).collect::<Vec<_>>();
+  runtime.sort();
+  serializer.collect_seq(runtime)
+}
+
+impl Serialize for StatsUsedExports {
+  fn serialize<S: Serializer>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error> {
+    match self {
+      StatsUsedExports::Vec(exports) => {
+        serializer.collect_seq(exports.iter().map(|export| export.as_str()))
+      }
+      StatsUsedExports::Bool(used) => serializer.serialize_bool(*used),
+      StatsUsedExports::Null => serializer.serialize_none(),
+    }
+  }
+}
+
+/// The module trace of an error is flattened as webpack does.
+impl Serialize for StatsModuleTrace<'_> {
+  fn serialize<S: Serializer>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error> {
+    let mut map = serializer.serialize_map(None)?;
+    map.serialize_entry("originIdentifier", &self.origin.identifier)?;
+    map.serialize_entry("originName", &self.origin.name)?;
+    if let Some(id) = &self.origin.id {
+      map.serialize_entry("originId", id)?;
+    }
+    map.serialize_entry("moduleIdentifier", &self.module.identifier)?;
+    map.serialize_entry("moduleName", &self.module.name)?;
+    if let Some(id) = &self.module.id {
+      map.serialize_entry("moduleId", id)?;
+    }
+    map.serialize_entry("dependencies", &self.dependencies)?;
+    map.end()
+  }
+}
+
+/// The webpack version that stats report for compatibility, the same as the JavaScript side.
+const WEBPACK_VERSION: &str = "5.75.0";
+
+impl Stats<'_> {
+  /// Writes the webpack compatible stats JSON of the compilation to `writer`.
+  ///
+  /// Each section is serialized to the writer as soon as it's collected, and modules and chunks
+  /// are serialized one by one, so the whole JSON is never built in memory. Wrap `writer` in a
+  /// `BufWriter` when writing to a file.
+  pub async fn write_json<W: Write>(
+    &self,
+    options: &ExtendedStatsOptions,
+    writer: W,
+  ) -> Result<()> {
+    let public_path = if options.public_path {
+      Some(match &self.compilation.options.output.public_path {
+        PublicPath::Filename(filename) => {
+          self
+            .compilation
+            .get_path(filename, Default::default())
+            .await?
+        }
+        PublicPath::Auto => "auto".to_string(),
+      })
+    } else {
+      None
+    };
+
+    let mut serializer = serde_json::Serializer::new(writer);
+    let map = serializer.serialize_map(None).to_rspack_result()?;
+    // `get_errors` and `get_warnings` only take `Fn` callbacks
+    let map = RefCell::new(map);
+
+    if options.version {
+      let mut map = map.borrow_mut();
+      map
+        .serialize_entry("version", WEBPACK_VERSION)
+        .to_rspack_result()?;
+      map
+        .serialize_entry("rspackVersion", rspack_pkg_version!())
+        .to_rspack_result()?;
+    }
+
+    if options.hash
+      && let Some(hash) = self.get_hash()
+    {
+      map
+        .borrow_mut()
+        .serialize_entry("hash", hash)
+        .to_rspack_result()?;
+    }
+
+    if options.timings
+      && let (Some(start_time), Some(end_time)) =
+        (self.compilation.start_time, self.compilation.end_time)
+    {
+      let time = end_time
+        .duration_since(start_time)
+        .unwrap_or_default()
+        .as_millis() as u64;
+      map
+        .borrow_mut()
+        .serialize_entry("time", &time)
+        .to_rspack_result()?;
+    }
+
+    if options.built_at
+      && let Some(end_time) = self.compilation.end_time
+    {
+      let built_at = end_time
+        .duration_since(SystemTime::UNIX_EPOCH)
+        .unwrap_or_default()
+        .as_millis() as u64;
+      map
+        .borrow_mut()
+        .serialize_entry("builtAt", &built_at)
+        .to_rspack_result()?;
+    }
+
+    if let Some(public_path) = &public_path {
+      map
+        .borrow_mut()
+        .serialize_entry("publicPath", public_path)
+        .to_rspack_result()?;
+    }
+
+    if options.output_path {
+      map
+        .borrow_mut()
+        .serialize_entry("outputPath", self.compilation.options.output.path.as_str())
+        .to_rspack_result()?;
+    }
+
+    if options.assets {
+      let (assets, assets_by_chunk_name) = self.get_assets();
+      let mut map = map.borrow_mut();
+      map.serialize_entry("assets", &assets).to_rspack_result()?;
+      map
+        .serialize_entry(
+          "assetsByChunkName",
+          &NamedObject(
+            assets_by_chunk_name
+              .iter()
+              .map(|item| (item.name, &item.files))
+              .collect(),
+          ),
+        )
+        .to_rspack_result()?;
+    }
+
+    if options.chunks {
+      map
+        .borrow_mut()
+        .serialize_entry("chunks", &StatsChunks(self, options))
+        .to_rspack_result()?;
+    }
+
+    if options.modules {
+      map
+        .borrow_mut()
+        .serialize_entry("modules", &StatsModules(self, options))
+        .to_rspack_result()?;
+    }
+
+    if matches!(
+      options.entrypoints,
+      EntrypointsStatsOption::Bool(true) | EntrypointsStatsOption::String(_)
+    ) {
+      let entrypoints =
+        self.get_entrypoints(options.chunk_group_auxiliary, options.chunk_group_children);
+      map
+        .borrow_mut()
+        .serialize_entry(
+          "entrypoints",
+          &NamedObject(
+            entrypoints
+              .iter()
+              .map(|group| (group.name, group))
+              .collect(),
+          ),
+        )
+        .to_rspack_result()?;
+    }
+
+    if options.chunk_groups {
+      let named_chunk_groups =
+        self.get_named_chunk_groups(options.chunk_group_auxiliary, options.chunk_group_children);
+      map
+        .borrow_mut()
+        .serialize_entry(
+          "namedChunkGroups",
+          &NamedObject(
+            named_chunk_groups
+              .iter()
+              .map(|group| (group.name, group))
+              .collect(),
+          ),
+        )
+        .to_rspack_result()?;
+    }
+
+    if let Some(profile) = self.get_profile_summary() {
+      map
+        .borrow_mut()
+        .serialize_entry("profile", &profile)
+        .to_rspack_result()?;
+    }
+
+    if options.errors {
+      self
+        .get_errors(|errors| {
+          let mut map = map.borrow_mut();
+          map.serialize_entry("errors", &errors)?;
+          map.serialize_entry("errorsCount", &errors.len())
+        })
+        .to_rspack_result()?;
+    }
+
+    if options.warnings {
+      self
+        .get_warnings(|warnings| {
+          let mut map = map.borrow_mut();
+          map.serialize_entry("warnings", &warnings)?;
+          map.serialize_entry("warningsCount", &warnings.len())
+        })
+        .to_rspack_result()?;
+    }
+
+    if options.children {
+      // child compilations only exist on the JavaScript side
+      map
+        .borrow_mut()
+        .serialize_entry("children", &[] as &[()])
+        .to_rspack_result()?;
+    }
+
+    map.into_inner().end().to_rspack_result()
+  }
+
+  /// Returns the webpack compatible stats JSON of the compilation as a string.
+  pub async fn to_json_string(&self, options: &ExtendedStatsOptions) -> Result<String> {
+    let mut buffer = vec![];
+    self.write_json(options, &mut buffer).await?;
+    String::from_utf8(buffer).to_rspack_result()
+  }
+}
+
+/// Serializes the modules one by one through [`Stats::for_each_module`].
+struct StatsModules<'s, 'c>(&'s Stats<'c>, &'s ExtendedStatsOptions);
+
+impl Serialize for StatsModules<'_, '_> {
+  fn serialize<S: Serializer>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error> {
+    let mut seq = serializer.serialize_seq(None)?;
+    let mut error = None;
+    let result = self.0.for_each_module(self.1, |module| {
+      serialize_element(&mut seq, &mut error, &module)
+    });
+    end_seq(seq, error, result)
+  }
+}
+
+/// Serializes the chunks one by one through [`Stats::for_each_chunk`].
+struct StatsChunks<'s, 'c>(&'s Stats<'c>, &'s ExtendedStatsOptions);
+
+impl Serialize for StatsChunks<'_, '_> {
+  fn serialize<S: Serializer>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error> {
+    let mut seq = serializer.serialize_seq(None)?;
+    let mut error = None;
+    let result = self.0.for_each_chunk(self.1, |chunk| {
+      serialize_element(&mut seq, &mut error, &chunk)
+    });
+    end_seq(seq, error, result)
+  }
+}
+
+/// Keeps the serializer error aside, `for_each_module` and `for_each_chunk` only stop on
+/// an rspack error.
+fn serialize_element<S: SerializeSeq, T: Serialize>(
+  seq: &mut S,
+  error: &mut Option<S::Error>,
+  item: &T,
+) -> Result<()> {
+  seq.serialize_element(item).map_err(|e| {
+    let message = e.to_string();
+    *error = Some(e);
+    rspack_error::error!(message)
+  })
+}
+
+fn end_seq<S: SerializeSeq>(
+  seq: S,
+  error: Option<S::Error>,
+  result: Result<()>,
+) -> std::result::Result<S::Ok, S::Error> {
+  if let Some(error) = error {
+    return Err(error);
+  }
+  result.map_err(serde::ser::Error::custom)?;
+  seq.end()
+}
+
+/// `assetsByChunkName`, `entrypoints` and `namedChunkGroups` are objects keyed by name in webpack.
+struct NamedObject<'a, T>(Vec<(&'a str, &'a T)>);
+
+impl<T: Serialize> Serialize for NamedObject<'_, T> {
+  fn serialize<S: Serializer>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error> {
+    serializer.collect_map(self.0.iter().copied())
+  }
+}
@@ -1,3 +1,5 @@
+use std::borrow::Cow;
+
 use either::Either;
 use itertools::Itertools;
 use rayon::iter::{
@@ -5,9 +7,13 @@ use rayon::iter::{
   ParallelIterator,
 };
 use rspack_collections::{DatabaseItem, IdentifierSet};
-use rspack_error::{Diagnostic, Display, Result, StdioDisplayer, StringDisplayer};
+use rspack_error::{
+  Diagnostic, DiagnosticRecord, Display, JsonDisplayer, Result, SarifDisplayer, StdioDisplayer,
+  StringDisplayer,
+};
 use rustc_hash::FxHashMap as HashMap;
 
+mod json;
 mod utils;
 pub use utils::*;
 mod r#struct;
@@ -15,11 +21,25 @@ pub use r#struct::*;
 
 use crate::{
   BoxModule, BoxRuntimeModule, Chunk, ChunkGraph, ChunkGroupOrderKey, ChunkGroupUkey, ChunkUkey,
-  Compilation, LogType, ModuleGraph, ModuleGraphCacheArtifact, ModuleIdentifier,
+  Compilation, DiagnosticsFormat, LogType, ModuleGraph, ModuleGraphCacheArtifact, ModuleIdentifier,
   PrefetchExportsInfoMode, ProvidedExports, RuntimeSpec, SourceType, UsedExports,
-  compilation::build_module_graph::ExecutedRuntimeModule,
+  compilation::build_module_graph::ExecutedRuntimeModule, contextify,
 };
 
+/// How many modules or chunks [`Stats::for_each_module`] and [`Stats::for_each_chunk`]
+/// generate in parallel before handing them out.
+const STATS_BATCH_SIZE: usize = 256;
+
+/// A module listed by [`Stats::for_each_module`], the stats of it are generated on demand.
+enum StatsModuleSource<'a> {
+  Module {
+    module: &'a BoxModule,
+    executed: bool,
+  },
+  RuntimeModule(&'a ModuleIdentifier, &'a BoxRuntimeModule),
+  ExecutedRuntimeModule(ModuleIdentifier),
+}
+
 #[derive(Debug, Clone)]
 pub struct Stats<'compilation> {
   pub compilation: &'compilation Compilation,
@@ -31,6 +51,12 @@ impl<'compilation> Stats<'compilation> {
   }
 
   pub fn emit_diagnostics(&self) -> Result<()> {
+    let format = self.compilation.options.stats.diagnostics_format;
+    if format != DiagnosticsFormat::Text {
+      let report = self.emit_diagnostics_report(format)?;
+      eprintln!("{report}");
+      return Ok(());
+    }
     let displayer = StdioDisplayer::default();
     displayer.emit_batch_diagnostic(self.compilation.get_warnings())?;
     displayer.emit_batch_diagnostic(self.compilation.get_errors())
@@ -42,6 +68,50 @@ impl<'compilation> Stats<'compilation> {
     let errors = displayer.emit_batch_diagnostic(self.compilation.get_errors())?;
     Ok(format!("{warnings}{errors}"))
   }
+
+  /// Renders the errors and warnings in `format`. The machine readable formats also have
+  /// the resource and the module trace of the module reporting each diagnostic.
+  pub fn emit_diagnostics_report(&self, format: DiagnosticsFormat) -> Result<String> {
+    match format {
+      DiagnosticsFormat::Text => self.emit_diagnostics_string(true),
+      DiagnosticsFormat::Json => JsonDisplayer.render(&self.get_diagnostic_records()),
+      DiagnosticsFormat::Sarif => SarifDisplayer.render(&self.get_diagnostic_records()),
+    }
+  }
+
+  fn get_diagnostic_records(&self) -> Vec<DiagnosticRecord> {
+    let module_graph = self.compilation.get_module_graph();
+    let options = &self.compilation.options;
+    self
+      .compilation
+      .get_errors_sorted()
+      .chain(self.compilation.get_warnings_sorted())
+      .map(|diagnostic| {
+        let mut record = DiagnosticRecord::from(diagnostic);
+        let Some(module) = diagnostic
+          .module_identifier
+          .and_then(|identifier| self.compilation.module_by_identifier(&identifier))
+        else {
+          return record;
+        };
+        if record.file.is_none() {
+          record.file = module
+            .name_for_condition()
+            .map(|resource| contextify(&options.context, &resource));
+        }
+        record.module_trace = get_module_trace(
+          diagnostic.module_identifier,
+          &module_graph,
+          self.compilation,
+          options,
+        )
+        .into_iter()
+        .map(|trace| trace.origin.name.into_owned())
+        .collect();
+        record
+      })
+      .collect()
+  }
 }
 
 impl Stats<'_> {
@@ -76,6 +146,18 @@ impl Stats<'_> {
               value: vec![source_map.as_str()],
             })
           }
+          for (name, compressed) in [
+            ("gzip", &asset.info.related.gzip),
+            ("brotli", &asset.info.related.brotli),
+            ("zstd", &asset.info.related.zstd),
+          ] {
+            if let Some(compressed) = compressed {
+              related.push(StatsAssetInfoRelated {
+                name,
+                value: vec![compressed.as_str()],
+              })
+            }
+          }
           (
             name,
             StatsAsset {
@@ -303,163 +385,179 @@ impl Stats<'_> {
     Ok(f(modules))
   }
 
-  #[allow(clippy::too_many_arguments)]
-  pub fn get_chunks<T>(
+  /// Calls `f` with the stats of each module in the same order as [`Stats::get_modules`].
+  ///
+  /// Only the sort keys of all modules are collected up front, the stats are generated in
+  /// batches, so the stats of all modules are never held in memory at once.
+  pub fn for_each_module(
     &self,
     options: &ExtendedStatsOptions,
-    f: impl Fn(Vec<StatsChunk>) -> T,
-  ) -> Result<T> {
+    mut f: impl FnMut(StatsModule) -> Result<()>,
+  ) -> Result<()> {
     let module_graph = self.compilation.get_module_graph();
     let module_graph_cache = &self.compilation.module_graph_cache_artifact;
-    let chunk_graph = &self.compilation.chunk_graph;
-    let context = &self.compilation.options.context;
-    let chunk_group_by_ukey = &self.compilation.chunk_group_by_ukey;
+    let module_executor = self.compilation.module_executor.as_ref();
+    let executor_module_graph =
+      module_executor.map(|executor| executor.make_artifact.get_module_graph());
+    let executor_module_graph_cache = ModuleGraphCacheArtifact::default();
 
-    let orders = [ChunkGroupOrderKey::Prefetch, ChunkGroupOrderKey::Preload];
+    // module$visible of runtime modules, which are never built
+    let is_visible = |identifier: &ModuleIdentifier| {
+      self.compilation.code_generated_modules.contains(identifier) || options.cached_modules
+    };
 
-    let mut chunks: Vec<StatsChunk> = self
-      .compilation
-      .chunk_by_ukey
-      .values()
+    let mut modules = module_graph
+      .modules()
+      .into_values()
       .par_bridge()
-      .map(|c| -> Result<_> {
-        let files: Vec<_> = {
-          let mut vec = c.files().iter().map(|s| s.as_str()).collect::<Vec<_>>();
-          vec.sort_unstable();
-          vec
-        };
+      .map(|module| {
+        let built = self
+          .compilation
+          .build_module_graph_artifact
+          .built_modules()
+          .contains(&module.identifier());
+        (
+          self.get_module_sort_key(&module_graph, module, built, options),
+          StatsModuleSource::Module {
+            module,
+            executed: false,
+          },
+        )
+      })
+      .collect::<Vec<_>>();
 
-        let root_modules = chunk_graph
-          .get_chunk_root_modules(&c.ukey(), &module_graph, module_graph_cache)
-          .into_iter()
-          .collect::<IdentifierSet>();
+    if let (Some(executor), Some(executor_module_graph)) = (module_executor, &executor_module_graph)
+    {
+      let executed_modules = executor_module_graph
+        .modules()
+        .into_values()
+        .par_bridge()
+        .map(|module| {
+          let built = executor
+            .make_artifact
+            .built_modules()
+            .contains(&module.identifier());
+          (
+            self.get_module_sort_key(executor_module_graph, module, built, options),
+            StatsModuleSource::Module {
+              module,
+              executed: true,
+            },
+          )
+        })
+        .collect::<Vec<_>>();
+      modules.extend(executed_modules);
+    }
 
-        let mut auxiliary_files = c
-          .auxiliary_files()
-          .iter()
-          .map(|s| s.as_str())
-          .collect::<Vec<_>>();
-        auxiliary_files.sort_unstable();
-
-        let chunk_modules = if options.chunk_modules {
-          let chunk_modules = self
-            .compilation
-            .chunk_graph
-            .get_chunk_modules(&c.ukey(), &module_graph);
-          let mut chunk_modules = chunk_modules
-            .into_iter()
-            .map(|m| {
-              self.get_module(
-                &module_graph,
-                module_graph_cache,
-                m,
-                false,
-                Some(&root_modules),
-                Some(c.runtime()),
-                options,
-              )
-            })
-            .collect::<Result<Vec<_>>>()?;
-          sort_modules(&mut chunk_modules);
-          Some(chunk_modules)
-        } else {
-          None
-        };
+    modules.extend(
+      self
+        .compilation
+        .runtime_modules
+        .iter()
+        .map(|(identifier, module)| {
+          let name = is_visible(identifier).then(|| module.name().as_str().into());
+          (
+            (None, None, name),
+            StatsModuleSource::RuntimeModule(identifier, module),
+          )
+        }),
+    );
+
+    if let Some(executor) = module_executor {
+      modules.extend(executor.executed_runtime_modules.iter().map(|item| {
+        let (identifier, module) = item.pair();
+        let name = is_visible(identifier).then(|| module.name.clone().into());
+        (
+          (None, None, name),
+          StatsModuleSource::ExecutedRuntimeModule(*identifier),
+        )
+      }));
+    }
 
-        let (parents, children, siblings) = options
-          .chunk_relations
-          .then(|| get_chunk_relations(c, self.compilation))
-          .map_or((None, None, None), |(parents, children, siblings)| {
-            (Some(parents), Some(children), Some(siblings))
-          });
+    modules.sort_unstable_by(|(a, _), (b, _)| {
+      compare_modules((a.0, a.1, a.2.as_deref()), (b.0, b.1, b.2.as_deref()))
+    });
 
-        let mut children_by_order = HashMap::<ChunkGroupOrderKey, Vec<String>>::default();
-        let chunk_filter = |_: &ChunkUkey, __: &Compilation| true;
-        for order in &orders {
-          if let Some(order_children) =
-            c.get_child_ids_by_order(order, self.compilation, &chunk_filter)
-          {
-            children_by_order.insert(
-              order.clone(),
-              order_children
-                .into_iter()
-                .map(|id| id.to_string())
-                .collect(),
-            );
+    for batch in modules.chunks(STATS_BATCH_SIZE) {
+      let batch = batch
+        .par_iter()
+        .filter_map(|(_, source)| match source {
+          StatsModuleSource::Module { module, executed } => Some(self.get_module(
+            if *executed {
+              executor_module_graph
+                .as_ref()
+                .expect("should have executor module graph")
+            } else {
+              &module_graph
+            },
+            if *executed {
+              &executor_module_graph_cache
+            } else {
+              module_graph_cache
+            },
+            module,
+            *executed,
+            None,
+            None,
+            options,
+          )),
+          StatsModuleSource::RuntimeModule(identifier, module) => {
+            Some(self.get_runtime_module(identifier, module, options))
           }
-        }
+          StatsModuleSource::ExecutedRuntimeModule(identifier) => module_executor
+            .and_then(|executor| executor.executed_runtime_modules.get(identifier))
+            .map(|module| self.get_executed_runtime_module(identifier, &module, options)),
+        })
+        .collect::<Result<Vec<_>>>()?;
+      for module in batch {
+        f(module)?;
+      }
+    }
+    Ok(())
+  }
 
-        let origins = c
-          .groups()
-          .iter()
-          .sorted()
-          .flat_map(|ukey| {
-            let chunk_group = chunk_group_by_ukey.expect_get(ukey);
-            chunk_group.origins().iter().map(|origin| {
-              let module_identifier = origin.module;
-
-              let module_name = origin
-                .module
-                .map(|identifier| {
-                  module_graph
-                    .module_by_identifier(&identifier)
-                    .map(|module| module.readable_identifier(context))
-                    .unwrap_or_default()
-                })
-                .unwrap_or_default();
-
-              let module_id = origin.module.and_then(|identifier| {
-                ChunkGraph::get_module_id(&self.compilation.module_ids_artifact, identifier)
-                  .cloned()
-              });
-
-              StatsOriginRecord {
-                module: module_identifier,
-                module_id,
-                module_identifier,
-                module_name,
-                loc: origin
-                  .loc
-                  .as_ref()
-                  .map(|loc| loc.to_string())
-                  .unwrap_or_default(),
-                request: origin.request.as_deref().unwrap_or_default(),
-              }
-            })
-          })
-          .collect::<Vec<_>>();
+  /// The fields of a module that [`sort_modules`] compares.
+  fn get_module_sort_key<'a>(
+    &self,
+    module_graph: &ModuleGraph,
+    module: &'a BoxModule,
+    built: bool,
+    options: &ExtendedStatsOptions,
+  ) -> (Option<usize>, Option<u32>, Option<Cow<'a, str>>) {
+    let identifier = module.identifier();
+    // module$visible
+    if built
+      || self
+        .compilation
+        .code_generated_modules
+        .contains(&identifier)
+      || options.cached_modules
+    {
+      (
+        module_graph.get_depth(&identifier),
+        module_graph.get_pre_order_index(&identifier),
+        Some(module.readable_identifier(&self.compilation.options.context)),
+      )
+    } else {
+      (module_graph.get_depth(&identifier), None, None)
+    }
+  }
 
-        let mut id_hints = c.id_name_hints().iter().map(|s| s.as_str()).collect_vec();
-        id_hints.sort_unstable();
-
-        Ok(StatsChunk {
-          r#type: "chunk",
-          files,
-          auxiliary_files,
-          id: c
-            .id(&self.compilation.chunk_ids_artifact)
-            .map(|id| id.as_str()),
-          id_hints,
-          names: c.name().map(|n| vec![n]).unwrap_or_default(),
-          entry: c.has_entry_module(chunk_graph),
-          initial: c.can_be_initial(&self.compilation.chunk_group_by_ukey),
-          size: chunk_graph.get_chunk_modules_size(&c.ukey(), self.compilation),
-          modules: chunk_modules,
-          parents,
-          children,
-          siblings,
-          children_by_order,
-          runtime: c.runtime(),
-          sizes: chunk_graph.get_chunk_modules_sizes(&c.ukey(), self.compilation),
-          reason: c.chunk_reason(),
-          rendered: c.rendered(),
-          origins,
-          hash: c.rendered_hash(
-            &self.compilation.chunk_hashes_artifact,
-            self.compilation.options.output.hash_digest_length,
-          ),
-        })
-      })
+  #[allow(clippy::too_many_arguments)]
+  pub fn get_chunks<T>(
+    &self,
+    options: &ExtendedStatsOptions,
+    f: impl Fn(Vec<StatsChunk>) -> T,
+  ) -> Result<T> {
+    let module_graph = self.compilation.get_module_graph();
+    let module_graph_cache = &self.compilation.module_graph_cache_artifact;
+
+    let mut chunks: Vec<StatsChunk> = self
+      .compilation
+      .chunk_by_ukey
+      .values()
+      .par_bridge()
+      .map(|c| self.get_chunk(c, &module_graph, module_graph_cache, options))
       .collect::<Result<_>>()?;
 
     // make result deterministic
@@ -475,6 +573,196 @@ impl Stats<'_> {
     Ok(f(chunks))
   }
 
+  /// Calls `f` with the stats of each chunk in the same order as [`Stats::get_chunks`].
+  ///
+  /// The stats are generated in batches, so the stats of all chunks are never held in memory at once.
+  pub fn for_each_chunk(
+    &self,
+    options: &ExtendedStatsOptions,
+    mut f: impl FnMut(StatsChunk) -> Result<()>,
+  ) -> Result<()> {
+    let module_graph = self.compilation.get_module_graph();
+    let module_graph_cache = &self.compilation.module_graph_cache_artifact;
+    let chunk_graph = &self.compilation.chunk_graph;
+
+    let mut chunks = self
+      .compilation
+      .chunk_by_ukey
+      .values()
+      .par_bridge()
+      .map(|c| {
+        // same key as `get_chunks`
+        let key = if let Some(id) = c.id(&self.compilation.chunk_ids_artifact) {
+          Either::Left(id.as_str())
+        } else {
+          Either::Right(chunk_graph.get_chunk_modules_size(&c.ukey(), self.compilation) as u32)
+        };
+        (key, c)
+      })
+      .collect::<Vec<_>>();
+    chunks.sort_unstable_by_key(|(key, _)| *key);
+
+    for batch in chunks.chunks(STATS_BATCH_SIZE) {
+      let batch = batch
+        .par_iter()
+        .map(|(_, c)| self.get_chunk(c, &module_graph, module_graph_cache, options))
+        .collect::<Result<Vec<_>>>()?;
+      for chunk in batch {
+        f(chunk)?;
+      }
+    }
+    Ok(())
+  }
+
+  fn get_chunk<'a>(
+    &'a self,
+    c: &'a Chunk,
+    module_graph: &'a ModuleGraph<'a>,
+    module_graph_cache: &'a ModuleGraphCacheArtifact,
+    options: &ExtendedStatsOptions,
+  ) -> Result<StatsChunk<'a>> {
+    let chunk_graph = &self.compilation.chunk_graph;
+    let context = &self.compilation.options.context;
+    let chunk_group_by_ukey = &self.compilation.chunk_group_by_ukey;
+
+    let orders = [ChunkGroupOrderKey::Prefetch, ChunkGroupOrderKey::Preload];
+
+    let files: Vec<_> = {
+      let mut vec = c.files().iter().map(|s| s.as_str()).collect::<Vec<_>>();
+      vec.sort_unstable();
+      vec
+    };
+
+    let root_modules = chunk_graph
+      .get_chunk_root_modules(&c.ukey(), module_graph, module_graph_cache)
+      .into_iter()
+      .collect::<IdentifierSet>();
+
+    let mut auxiliary_files = c
+      .auxiliary_files()
+      .iter()
+      .map(|s| s.as_str())
+      .collect::<Vec<_>>();
+    auxiliary_files.sort_unstable();
+
+    let chunk_modules = if options.chunk_modules {
+      let chunk_modules = self
+        .compilation
+        .chunk_graph
+        .get_chunk_modules(&c.ukey(), module_graph);
+      let mut chunk_modules = chunk_modules
+        .into_iter()
+        .map(|m| {
+          self.get_module(
+            module_graph,
+            module_graph_cache,
+            m,
+            false,
+            Some(&root_modules),
+            Some(c.runtime()),
+            options,
+          )
+        })
+        .collect::<Result<Vec<_>>>()?;
+      sort_modules(&mut chunk_modules);
+      Some(chunk_modules)
+    } else {
+      None
+    };
+
+    let (parents, children, siblings) = options
+      .chunk_relations
+      .then(|| get_chunk_relations(c, self.compilation))
+      .map_or((None, None, None), |(parents, children, siblings)| {
+        (Some(parents), Some(children), Some(siblings))
+      });
+
+    let mut children_by_order = HashMap::<ChunkGroupOrderKey, Vec<String>>::default();
+    let chunk_filter = |_: &ChunkUkey, __: &Compilation| true;
+    for order in &orders {
+      if let Some(order_children) = c.get_child_ids_by_order(order, self.compilation, &chunk_filter)
+      {
+        children_by_order.insert(
+          order.clone(),
+          order_children
+            .into_iter()
+            .map(|id| id.to_string())
+            .collect(),
+        );
+      }
+    }
+
+    let origins = c
+      .groups()
+      .iter()
+      .sorted()
+      .flat_map(|ukey| {
+        let chunk_group = chunk_group_by_ukey.expect_get(ukey);
+        chunk_group.origins().iter().map(|origin| {
+          let module_identifier = origin.module;
+
+          let module_name = origin
+            .module
+            .map(|identifier| {
+              module_graph
+                .module_by_identifier(&identifier)
+                .map(|module| module.readable_identifier(context))
+                .unwrap_or_default()
+            })
+            .unwrap_or_default();
+
+          let module_id = origin.module.and_then(|identifier| {
+            ChunkGraph::get_module_id(&self.compilation.module_ids_artifact, identifier).cloned()
+          });
+
+          StatsOriginRecord {
+            module: module_identifier,
+            module_id,
+            module_identifier,
+            module_name,
+            loc: origin
+              .loc
+              .as_ref()
+              .map(|loc| loc.to_string())
+              .unwrap_or_default(),
+            request: origin.request.as_deref().unwrap_or_default(),
+          }
+        })
+      })
+      .collect::<Vec<_>>();
+
+    let mut id_hints = c.id_name_hints().iter().map(|s| s.as_str()).collect_vec();
+    id_hints.sort_unstable();
+
+    Ok(StatsChunk {
+      r#type: "chunk",
+      files,
+      auxiliary_files,
+      id: c
+        .id(&self.compilation.chunk_ids_artifact)
+        .map(|id| id.as_str()),
+      id_hints,
+      names: c.name().map(|n| vec![n]).unwrap_or_default(),
+      entry: c.has_entry_module(chunk_graph),
+      initial: c.can_be_initial(&self.compilation.chunk_group_by_ukey),
+      size: chunk_graph.get_chunk_modules_size(&c.ukey(), self.compilation),
+      modules: chunk_modules,
+      parents,
+      children,
+      siblings,
+      children_by_order,
+      runtime: c.runtime(),
+      sizes: chunk_graph.get_chunk_modules_sizes(&c.ukey(), self.compilation),
+      reason: c.chunk_reason(),
+      rendered: c.rendered(),
+      origins,
+      hash: c.rendered_hash(
+        &self.compilation.chunk_hashes_artifact,
+        self.compilation.options.output.hash_digest_length,
+      ),
+    })
+  }
+
   fn get_chunk_group<'a>(
     &'a self,
     name: &'a str,
@@ -757,6 +1045,102 @@ impl Stats<'_> {
     self.compilation.get_hash()
   }
 
+  /// Sums up the module profiles by loader and by the package a module belongs to.
+  /// Returns `None` unless `profile` is enabled.
+  pub fn get_profile_summary(&self) -> Option<StatsProfileSummary> {
+    if !self.compilation.options.profile {
+      return None;
+    }
+    let module_graph = self.compilation.get_module_graph();
+    // durations are summed up in microseconds, and converted to milliseconds at last
+    let mut loaders: HashMap<&str, StatsLoaderProfileSummary> = HashMap::default();
+    let mut packages: HashMap<&str, StatsPackageProfileSummary> = HashMap::default();
+    for (identifier, module) in module_graph.modules() {
+      let Some(profile) = module_graph
+        .module_graph_module_by_identifier(&identifier)
+        .and_then(|mgm| mgm.profile())
+      else {
+        continue;
+      };
+
+      for loader in profile.loaders() {
+        // loaders with different options are summed up together
+        let name = loader
+          .loader
+          .split_once('?')
+          .map_or(loader.loader.as_str(), |(name, _)| name);
+        let summary = loaders
+          .entry(name)
+          .or_insert_with(|| StatsLoaderProfileSummary {
+            loader: name.to_string(),
+            ..Default::default()
+          });
+        summary.modules += 1;
+        summary.pitch += loader.pitch;
+        summary.normal += loader.normal;
+      }
+
+      let Some(name) = module
+        .as_normal_module()
+        .and_then(|module| module.resource_resolved_data().description())
+        .and_then(|description| description.json().get("name"))
+        .and_then(|name| name.as_str())
+      else {
+        continue;
+      };
+      let durations = profile.raw_durations();
+      let summary = packages
+        .entry(name)
+        .or_insert_with(|| StatsPackageProfileSummary {
+          name: name.to_string(),
+          ..Default::default()
+        });
+      summary.modules += 1;
+      summary.resolving += durations.resolving;
+      summary.building += durations.building;
+      summary.loaders += profile
+        .loaders()
+        .iter()
+        .map(|loader| loader.pitch + loader.normal)
+        .sum::<u64>();
+      summary.parsing += durations.parsing;
+      summary.code_generation += durations.code_generation;
+      summary.hashing += durations.hashing;
+    }
+
+    let loaders = loaders
+      .into_values()
+      .map(|mut summary| {
+        summary.pitch /= 1000;
+        summary.normal /= 1000;
+        summary
+      })
+      .sorted_by(|a, b| {
+        (b.pitch + b.normal)
+          .cmp(&(a.pitch + a.normal))
+          .then_with(|| a.loader.cmp(&b.loader))
+      })
+      .collect();
+    let packages = packages
+      .into_values()
+      .map(|mut summary| {
+        summary.resolving /= 1000;
+        summary.building /= 1000;
+        summary.loaders /= 1000;
+        summary.parsing /= 1000;
+        summary.code_generation /= 1000;
+        summary.hashing /= 1000;
+        summary
+      })
+      .sorted_by(|a, b| {
+        let total =
+          |s: &StatsPackageProfileSummary| s.resolving + s.building + s.code_generation + s.hashing;
+        total(b).cmp(&total(a)).then_with(|| a.name.cmp(&b.name))
+      })
+      .collect();
+    Some(StatsProfileSummary { loaders, packages })
+  }
+
   #[allow(clippy::too_many_arguments)]
   fn get_module<'a>(
     &'a self,
@@ -911,7 +1295,23 @@ impl Stats<'_> {
         && let Some(factory) = p.factory_duration()
         && let Some(building) = p.building_duration()
       {
-        Some(StatsModuleProfile { factory, building })
+        Some(StatsModuleProfile {
+          factory,
+          resolving: p.resolving_duration().unwrap_or_default(),
+          building,
+          loaders: p
+            .loaders()
+            .iter()
+            .map(|loader| StatsLoaderProfile {
+              loader: loader.loader.clone(),
+              pitch: loader.pitch / 1000,
+              normal: loader.normal / 1000,
+            })
+            .collect(),
+          parsing: p.parsing_duration().unwrap_or_default(),
+          code_generation: p.code_generation_duration().unwrap_or_default(),
+          hashing: p.hashing_duration().unwrap_or_default(),
+        })
       } else {
         None
       };
@@ -4,7 +4,9 @@ use rspack_paths::Utf8Path;
 use rspack_sources::BoxSource;
 use rspack_util::atom::Atom;
 use rustc_hash::FxHashMap as HashMap;
+use serde::Serialize;
 
+use super::json;
 use crate::{ChunkGroupOrderKey, ModuleId, ModuleIdentifier, ModuleType, RuntimeSpec, SourceType};
 
 pub enum EntrypointsStatsOption {
@@ -14,7 +16,9 @@ pub enum EntrypointsStatsOption {
 
 pub struct ExtendedStatsOptions {
   pub assets: bool,
+  pub built_at: bool,
   pub cached_modules: bool,
+  pub children: bool,
   pub chunks: bool,
   pub chunk_group_auxiliary: bool,
   pub chunk_group_children: bool,
@@ -30,10 +34,14 @@ pub struct ExtendedStatsOptions {
   pub module_assets: bool,
   pub nested_modules: bool,
   pub optimization_bailout: bool,
+  pub output_path: bool,
   pub provided_exports: bool,
+  pub public_path: bool,
   pub reasons: bool,
   pub source: bool,
+  pub timings: bool,
   pub used_exports: bool,
+  pub version: bool,
   pub warnings: bool,
 }
 
@@ -46,6 +54,12 @@ impl Default for ExtendedStatsOptions {
       warnings: true,
       assets: true,
       hash: true,
+      version: true,
+      timings: true,
+      built_at: true,
+      public_path: true,
+      output_path: true,
+      children: true,
 
       cached_modules: false,
       chunk_group_auxiliary: false,
@@ -67,23 +81,41 @@ impl Default for ExtendedStatsOptions {
   }
 }
 
-#[derive(Debug)]
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
 pub struct StatsError<'a> {
+  #[serde(skip_serializing_if = "Option::is_none")]
   pub name: Option<String>,
   pub message: String,
+  #[serde(skip_serializing_if = "Option::is_none")]
   pub code: Option<String>,
+  #[serde(skip_serializing_if = "Option::is_none")]
   pub module_identifier: Option<ModuleIdentifier>,
+  #[serde(skip_serializing_if = "Option::is_none")]
   pub module_name: Option<Cow<'a, str>>,
+  #[serde(skip_serializing_if = "Option::is_none")]
   pub module_id: Option<ModuleId>,
+  #[serde(skip_serializing_if = "Option::is_none")]
   pub loc: Option<String>,
+  #[serde(
+    serialize_with = "json::serialize_optional_path",
+    skip_serializing_if = "Option::is_none"
+  )]
   pub file: Option<&'a Utf8Path>,
 
+  #[serde(skip_serializing_if = "Option::is_none")]
   pub chunk_name: Option<&'a str>,
+  #[serde(skip_serializing_if = "Option::is_none")]
   pub chunk_entry: Option<bool>,
+  #[serde(skip_serializing_if = "Option::is_none")]
   pub chunk_initial: Option<bool>,
+  #[serde(skip_serializing_if = "Option::is_none")]
   pub chunk_id: Option<&'a str>,
+  #[serde(skip_serializing_if = "Option::is_none")]
   pub details: Option<String>,
+  #[serde(skip_serializing_if = "Option::is_none")]
   pub stack: Option<String>,
+  #[serde(skip_serializing_if = "Vec::is_empty")]
   pub module_trace: Vec<StatsModuleTrace<'a>>,
 }
 
@@ -101,12 +133,13 @@ pub struct StatsErrorModuleTraceModule<'a> {
   pub id: Option<ModuleId>,
 }
 
-#[derive(Debug)]
+#[derive(Debug, Serialize)]
 pub struct StatsErrorModuleTraceDependency {
   pub loc: String,
 }
 
-#[derive(Debug)]
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
 pub struct StatsAsset<'a> {
   pub r#type: &'static str,
   pub name: &'a str,
@@ -127,19 +160,35 @@ pub struct StatsAssetsByChunkName<'a> {
   pub files: Vec<&'a str>,
 }
 
-#[derive(Debug)]
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
 pub struct StatsAssetInfo<'a> {
+  #[serde(skip_serializing_if = "Option::is_none")]
   pub minimized: Option<bool>,
+  #[serde(skip_serializing_if = "Option::is_none")]
   pub development: Option<bool>,
+  #[serde(skip_serializing_if = "Option::is_none")]
   pub hot_module_replacement: Option<bool>,
+  #[serde(skip_serializing_if = "Option::is_none")]
   pub source_filename: Option<&'a str>,
+  #[serde(skip_serializing_if = "Option::is_none")]
   pub copied: Option<bool>,
+  #[serde(skip_serializing_if = "Option::is_none")]
   pub immutable: Option<bool>,
+  #[serde(skip_serializing_if = "Option::is_none")]
   pub javascript_module: Option<bool>,
+  #[serde(rename = "chunkhash", skip_serializing_if = "Vec::is_empty")]
   pub chunk_hash: Vec<&'a str>,
+  #[serde(rename = "contenthash", skip_serializing_if = "Vec::is_empty")]
   pub content_hash: Vec<&'a str>,
+  #[serde(rename = "fullhash", skip_serializing_if = "Vec::is_empty")]
   pub full_hash: Vec<&'a str>,
+  #[serde(
+    serialize_with = "json::serialize_related",
+    skip_serializing_if = "Vec::is_empty"
+  )]
   pub related: Vec<StatsAssetInfoRelated<'a>>,
+  #[serde(skip_serializing_if = "Option::is_none")]
   pub is_over_size_limit: Option<bool>,
 }
 
@@ -149,43 +198,80 @@ pub struct StatsAssetInfoRelated<'a> {
   pub value: Vec<&'a str>,
 }
 
-#[derive(Debug)]
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
 pub struct StatsModule<'a> {
   pub r#type: &'static str,
+  #[serde(serialize_with = "json::serialize_display")]
   pub module_type: ModuleType,
+  #[serde(skip_serializing_if = "Option::is_none")]
   pub layer: Option<Cow<'a, str>>,
+  #[serde(skip_serializing_if = "Option::is_none")]
   pub identifier: Option<ModuleIdentifier>,
+  #[serde(skip_serializing_if = "Option::is_none")]
   pub name: Option<Cow<'a, str>>,
+  #[serde(skip_serializing_if = "Option::is_none")]
   pub name_for_condition: Option<String>,
+  #[serde(skip_serializing_if = "Option::is_none")]
   pub id: Option<ModuleId>,
+  #[serde(skip_serializing_if = "Option::is_none")]
   pub chunks: Option<Vec<&'a str>>, // has id after the call of chunkIds hook
   pub size: f64,
+  #[serde(serialize_with = "json::serialize_source_type_sizes")]
   pub sizes: Vec<StatsSourceTypeSize>,
+  #[serde(skip_serializing_if = "Option::is_none")]
   pub dependent: Option<bool>,
+  #[serde(skip_serializing_if = "Option::is_none")]
   pub issuer: Option<ModuleIdentifier>,
+  #[serde(skip_serializing_if = "Option::is_none")]
   pub issuer_name: Option<Cow<'a, str>>,
+  #[serde(skip_serializing_if = "Option::is_none")]
   pub issuer_id: Option<ModuleId>,
+  #[serde(skip_serializing_if = "Option::is_none")]
   pub issuer_path: Option<Vec<StatsModuleIssuer<'a>>>,
+  #[serde(skip_serializing_if = "Option::is_none")]
   pub reasons: Option<Vec<StatsModuleReason<'a>>>,
+  #[serde(skip_serializing_if = "Option::is_none")]
   pub assets: Option<Vec<&'a str>>,
+  #[serde(skip_serializing_if = "Option::is_none")]
   pub modules: Option<Vec<StatsModule<'a>>>,
+  #[serde(
+    serialize_with = "json::serialize_optional_source",
+    skip_serializing_if = "Option::is_none"
+  )]
   pub source: Option<&'a BoxSource>,
+  #[serde(skip_serializing_if = "Option::is_none")]
   pub profile: Option<StatsModuleProfile>,
+  #[serde(skip_serializing_if = "Option::is_none")]
   pub orphan: Option<bool>,
+  #[serde(
+    serialize_with = "json::serialize_optional_atoms",
+    skip_serializing_if = "Option::is_none"
+  )]
   pub provided_exports: Option<Vec<Atom>>,
+  #[serde(skip_serializing_if = "Option::is_none")]
   pub used_exports: Option<StatsUsedExports>,
+  #[serde(skip_serializing_if = "Option::is_none")]
   pub optimization_bailout: Option<&'a [String]>,
+  #[serde(skip_serializing_if = "Option::is_none")]
   pub depth: Option<usize>,
+  #[serde(skip_serializing_if = "Option::is_none")]
   pub pre_order_index: Option<u32>,
+  #[serde(skip_serializing_if = "Option::is_none")]
   pub post_order_index: Option<u32>,
   pub built: bool,
   pub code_generated: bool,
   pub build_time_executed: bool,
   pub cached: bool,
+  #[serde(skip_serializing_if = "Option::is_none")]
   pub cacheable: Option<bool>,
+  #[serde(skip_serializing_if = "Option::is_none")]
   pub optional: Option<bool>,
+  #[serde(skip_serializing_if = "Option::is_none")]
   pub failed: Option<bool>,
+  #[serde(skip_serializing_if = "Option::is_none")]
   pub errors: Option<u32>,
+  #[serde(skip_serializing_if = "Option::is_none")]
   pub warnings: Option<u32>,
 }
 
@@ -196,98 +282,177 @@ pub enum StatsUsedExports {
   Null,
 }
 
-#[derive(Debug)]
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
 pub struct StatsModuleProfile {
   pub factory: u64,
+  pub resolving: u64,
   pub building: u64,
+  pub loaders: Vec<StatsLoaderProfile>,
+  pub parsing: u64,
+  pub code_generation: u64,
+  pub hashing: u64,
 }
 
-#[derive(Debug)]
+#[derive(Debug, Serialize)]
+pub struct StatsLoaderProfile {
+  pub loader: String,
+  pub pitch: u64,
+  pub normal: u64,
+}
+
+/// Module timings summed up by loader and by package, sorted by the total time descending.
+#[derive(Debug, Default, Serialize)]
+pub struct StatsProfileSummary {
+  pub loaders: Vec<StatsLoaderProfileSummary>,
+  pub packages: Vec<StatsPackageProfileSummary>,
+}
+
+#[derive(Debug, Default, Serialize)]
+pub struct StatsLoaderProfileSummary {
+  pub loader: String,
+  pub modules: u32,
+  pub pitch: u64,
+  pub normal: u64,
+}
+
+#[derive(Debug, Default, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct StatsPackageProfileSummary {
+  pub name: String,
+  pub modules: u32,
+  pub resolving: u64,
+  pub building: u64,
+  pub loaders: u64,
+  pub parsing: u64,
+  pub code_generation: u64,
+  pub hashing: u64,
+}
+
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
 pub struct StatsOriginRecord<'a> {
+  #[serde(skip_serializing_if = "Option::is_none")]
   pub module: Option<ModuleIdentifier>,
+  #[serde(skip_serializing_if = "Option::is_none")]
   pub module_id: Option<ModuleId>,
+  #[serde(skip_serializing_if = "Option::is_none")]
   pub module_identifier: Option<ModuleIdentifier>,
   pub module_name: Cow<'a, str>,
   pub loc: String,
   pub request: &'a str,
 }
 
-#[derive(Debug)]
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
 pub struct StatsChunk<'a> {
   pub r#type: &'static str,
   pub files: Vec<&'a str>,
   pub auxiliary_files: Vec<&'a str>,
+  #[serde(skip_serializing_if = "Option::is_none")]
   pub id: Option<&'a str>,
   pub entry: bool,
   pub initial: bool,
   pub names: Vec<&'a str>,
   pub size: f64,
+  #[serde(skip_serializing_if = "Option::is_none")]
   pub modules: Option<Vec<StatsModule<'a>>>,
+  #[serde(skip_serializing_if = "Option::is_none")]
   pub parents: Option<Vec<&'a str>>,
+  #[serde(skip_serializing_if = "Option::is_none")]
   pub children: Option<Vec<&'a str>>,
+  #[serde(skip_serializing_if = "Option::is_none")]
   pub siblings: Option<Vec<&'a str>>,
+  #[serde(serialize_with = "json::serialize_children_by_order")]
   pub children_by_order: HashMap<ChunkGroupOrderKey, Vec<String>>,
+  #[serde(serialize_with = "json::serialize_runtime")]
   pub runtime: &'a RuntimeSpec,
+  #[serde(serialize_with = "json::serialize_sizes")]
   pub sizes: HashMap<SourceType, f64>,
+  #[serde(skip_serializing_if = "Option::is_none")]
   pub reason: Option<&'a str>,
   pub rendered: bool,
   pub origins: Vec<StatsOriginRecord<'a>>,
   pub id_hints: Vec<&'a str>,
+  #[serde(skip_serializing_if = "Option::is_none")]
   pub hash: Option<&'a str>,
 }
 
-#[derive(Debug)]
+#[derive(Debug, Serialize)]
 pub struct StatsChunkGroupAsset<'a> {
   pub name: &'a str,
   pub size: usize,
 }
 
-#[derive(Debug)]
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
 pub struct StatsChunkGroup<'a> {
   pub name: &'a str,
   pub chunks: Vec<&'a str>,
   pub assets: Vec<StatsChunkGroupAsset<'a>>,
   pub assets_size: usize,
+  #[serde(skip_serializing_if = "Option::is_none")]
   pub auxiliary_assets: Option<Vec<StatsChunkGroupAsset<'a>>>,
+  #[serde(skip_serializing_if = "Option::is_none")]
   pub auxiliary_assets_size: Option<usize>,
+  #[serde(skip_serializing_if = "Option::is_none")]
   pub children: Option<StatsChunkGroupChildren<'a>>,
+  #[serde(skip_serializing_if = "Option::is_none")]
   pub is_over_size_limit: Option<bool>,
+  #[serde(skip_serializing_if = "Option::is_none")]
   pub child_assets: Option<StatschunkGroupChildAssets<'a>>,
 }
 
-#[derive(Debug)]
+#[derive(Debug, Serialize)]
 pub struct StatsChunkGroupChildren<'a> {
+  #[serde(skip_serializing_if = "Vec::is_empty")]
   pub preload: Vec<StatsChunkGroup<'a>>,
+  #[serde(skip_serializing_if = "Vec::is_empty")]
   pub prefetch: Vec<StatsChunkGroup<'a>>,
 }
 
-#[derive(Debug)]
+#[derive(Debug, Serialize)]
 pub struct StatschunkGroupChildAssets<'a> {
+  #[serde(skip_serializing_if = "Vec::is_empty")]
   pub preload: Vec<&'a str>,
+  #[serde(skip_serializing_if = "Vec::is_empty")]
   pub prefetch: Vec<&'a str>,
 }
 
-#[derive(Debug)]
+#[derive(Debug, Serialize)]
 pub struct StatsModuleIssuer<'s> {
   pub identifier: ModuleIdentifier,
   pub name: Cow<'s, str>,
+  #[serde(skip_serializing_if = "Option::is_none")]
   pub id: Option<ModuleId>,
 }
 
-#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord)]
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Serialize)]
+#[serde(rename_all = "camelCase")]
 pub struct StatsModuleReason<'s> {
+  #[serde(skip_serializing_if = "Option::is_none")]
   pub module_identifier: Option<ModuleIdentifier>,
+  #[serde(skip_serializing_if = "Option::is_none")]
   pub module_name: Option<Cow<'s, str>>,
+  #[serde(skip_serializing_if = "Option::is_none")]
   pub module_id: Option<ModuleId>,
+  #[serde(skip_serializing_if = "Option::is_none")]
   pub module_chunks: Option<u32>,
+  #[serde(skip_serializing_if = "Option::is_none")]
   pub resolved_module_identifier: Option<ModuleIdentifier>,
+  #[serde(skip_serializing_if = "Option::is_none")]
   pub resolved_module_name: Option<Cow<'s, str>>,
+  #[serde(skip_serializing_if = "Option::is_none")]
   pub resolved_module_id: Option<ModuleId>,
 
+  #[serde(skip_serializing_if = "Option::is_none")]
   pub r#type: Option<&'static str>,
+  #[serde(skip_serializing_if = "Option::is_none")]
   pub user_request: Option<&'s str>,
+  #[serde(skip_serializing_if = "Option::is_none")]
   pub explanation: Option<&'static str>,
   pub active: bool,
+  #[serde(skip_serializing_if = "Option::is_none")]
   pub loc: Option<String>,
 }
 
@@ -1,4 +1,4 @@
-use std::borrow::Cow;
+use std::{borrow::Cow, cmp::Ordering};
 
 use itertools::Itertools;
 use rayon::iter::{IntoParallelRefIterator, ParallelIterator};
@@ -24,22 +24,34 @@ pub fn get_asset_size(file: &str, compilation: &Compilation) -> usize {
 
 pub fn sort_modules(modules: &mut [StatsModule]) {
   modules.sort_unstable_by(|a, b| {
-    // align with MODULES_SORTER
-    // https://github.com/webpack/webpack/blob/ab3e93b19ead869727592d09d36f94e649eb9d83/lib/stats/DefaultStatsFactoryPlugin.js#L1546
-    if a.depth != b.depth {
-      a.depth.cmp(&b.depth)
-    } else if a.pre_order_index != b.pre_order_index {
-      a.pre_order_index.cmp(&b.pre_order_index)
-    } else if let (Some(a_name), Some(b_name)) = (&a.name, &b.name)
-      && a_name.len() != b_name.len()
-    {
-      a_name.len().cmp(&b_name.len())
-    } else {
-      a.name.cmp(&b.name)
-    }
+    compare_modules(
+      (a.depth, a.pre_order_index, a.name.as_deref()),
+      (b.depth, b.pre_order_index, b.name.as_deref()),
+    )
   });
 }
 
+/// Compares modules by `(depth, pre_order_index, name)`.
+pub(crate) fn compare_modules(
+  a: (Option<usize>, Option<u32>, Option<&str>),
+  b: (Option<usize>, Option<u32>, Option<&str>),
+) -> Ordering {
+  // align with MODULES_SORTER
+  // https://github.com/webpack/webpack/blob/ab3e93b19ead869727592d09d36f94e649eb9d83/lib/stats/DefaultStatsFactoryPlugin.js#L1546
+  let ((a_depth, a_pre_order_index, a_name), (b_depth, b_pre_order_index, b_name)) = (a, b);
+  if a_depth != b_depth {
+    a_depth.cmp(&b_depth)
+  } else if a_pre_order_index != b_pre_order_index {
+    a_pre_order_index.cmp(&b_pre_order_index)
+  } else if let (Some(a_name), Some(b_name)) = (a_name, b_name)
+    && a_name.len() != b_name.len()
+  {
+    a_name.len().cmp(&b_name.len())
+  } else {
+    a_name.cmp(&b_name)
+  }
+}
+
 pub fn get_stats_module_name_and_id<'s>(
   module: &'s BoxModule,
   compilation: &Compilation,
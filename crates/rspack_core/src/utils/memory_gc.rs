@@ -1,4 +1,7 @@
-use std::sync::atomic::{AtomicU32, Ordering};
+use std::{
+  collections::BTreeMap,
+  sync::atomic::{AtomicU32, Ordering},
+};
 
 use dashmap::DashMap;
 use rspack_collections::{Identifier, IdentifierDashMap};
@@ -49,6 +52,19 @@ where
       CacheData::new(data, self.generation.load(Ordering::Relaxed)),
     );
   }
+  /// count and total size of the items grouped by the generation they were last accessed in
+  pub(crate) fn memory_usage_by_generation(
+    &self,
+    mut size_of: impl FnMut(&Item) -> usize,
+  ) -> BTreeMap<u32, (usize, usize)> {
+    let mut usage: BTreeMap<u32, (usize, usize)> = BTreeMap::new();
+    for cache_data in self.data.iter() {
+      let (count, size) = usage.entry(cache_data.generation).or_default();
+      *count += 1;
+      *size += size_of(&cache_data.item);
+    }
+    usage
+  }
   /// notify storage that the current generation is over and start a new one
   pub(crate) fn start_next_generation(&self) {
     let generation = self.generation.fetch_add(1, Ordering::Relaxed) + 1;
@@ -0,0 +1,31 @@
+use serde_json::Value;
+
+use super::{Display, record::DiagnosticRecord};
+use crate::{Result, ToStringResultToRspackResultExt, diagnostic::Diagnostic};
+
+/// Renders diagnostics as a JSON array, one object per diagnostic.
+#[derive(Default, Debug, Clone)]
+pub struct JsonDisplayer;
+
+impl JsonDisplayer {
+  pub fn render(&self, records: &[DiagnosticRecord]) -> Result<String> {
+    let value = Value::Array(records.iter().map(DiagnosticRecord::to_json).collect());
+    serde_json::to_string_pretty(&value).to_rspack_result()
+  }
+}
+
+impl Display for JsonDisplayer {
+  type Output = Result<String>;
+
+  fn emit_batch_diagnostic<'a>(
+    &self,
+    diagnostics: impl Iterator<Item = &'a Diagnostic>,
+  ) -> Self::Output {
+    let records = diagnostics.map(DiagnosticRecord::from).collect::<Vec<_>>();
+    self.render(&records)
+  }
+
+  fn emit_diagnostic(&self, diagnostic: &Diagnostic) -> Self::Output {
+    self.emit_batch_diagnostic(std::iter::once(diagnostic))
+  }
+}
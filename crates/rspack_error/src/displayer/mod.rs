@@ -1,8 +1,18 @@
+mod json;
+mod record;
 mod renderer;
+mod sarif;
 mod stdio;
 mod string;
 
-pub use self::{renderer::Renderer, stdio::StdioDisplayer, string::StringDisplayer};
+pub use self::{
+  json::JsonDisplayer,
+  record::{DiagnosticRange, DiagnosticRecord},
+  renderer::Renderer,
+  sarif::SarifDisplayer,
+  stdio::StdioDisplayer,
+  string::StringDisplayer,
+};
 use crate::diagnostic::Diagnostic;
 
 pub trait Display {
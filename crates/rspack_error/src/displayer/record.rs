@@ -0,0 +1,184 @@
+use rspack_location::DependencyLocation;
+use serde_json::{Value, json};
+
+use crate::{Diagnostic, Severity, error::Error};
+
+/// A source range, lines are 1-based and columns are 0-based like [`rspack_location::SourcePosition`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct DiagnosticRange {
+  pub start_line: usize,
+  pub start_column: usize,
+  pub end_line: usize,
+  pub end_column: usize,
+}
+
+/// A diagnostic in a machine readable form, which is rendered by [`crate::JsonDisplayer`]
+/// and [`crate::SarifDisplayer`].
+#[derive(Debug, Clone, Default)]
+pub struct DiagnosticRecord {
+  pub code: Option<String>,
+  pub severity: Severity,
+  /// The message of the diagnostic followed by the messages of its causes, without source frames.
+  pub message: String,
+  pub help: Option<String>,
+  pub file: Option<String>,
+  pub range: Option<DiagnosticRange>,
+  pub module_identifier: Option<String>,
+  /// The modules issuing the module of the diagnostic, from the nearest to the entry.
+  pub module_trace: Vec<String>,
+}
+
+impl From<&Diagnostic> for DiagnosticRecord {
+  fn from(diagnostic: &Diagnostic) -> Self {
+    let range = match &diagnostic.loc {
+      Some(DependencyLocation::Real(loc)) => {
+        let end = loc.end.unwrap_or(loc.start);
+        Some(DiagnosticRange {
+          start_line: loc.start.line,
+          start_column: loc.start.column,
+          end_line: end.line,
+          end_column: end.column,
+        })
+      }
+      _ => label_range(&diagnostic.error),
+    };
+    Self {
+      code: diagnostic.code.clone(),
+      severity: diagnostic.severity,
+      message: message_with_causes(&diagnostic.error),
+      help: diagnostic.help.clone(),
+      file: diagnostic.file.as_ref().map(|file| file.to_string()),
+      range,
+      module_identifier: diagnostic
+        .module_identifier
+        .map(|identifier| identifier.to_string()),
+      module_trace: vec![],
+    }
+  }
+}
+
+impl DiagnosticRecord {
+  pub(super) fn severity_str(&self) -> &'static str {
+    match self.severity {
+      Severity::Error => "error",
+      Severity::Warning => "warning",
+    }
+  }
+
+  pub(super) fn to_json(&self) -> Value {
+    let mut value = json!({
+      "severity": self.severity_str(),
+      "message": self.message,
+    });
+    if let Some(code) = &self.code {
+      value["code"] = json!(code);
+    }
+    if let Some(file) = &self.file {
+      value["file"] = json!(file);
+    }
+    if let Some(range) = &self.range {
+      value["range"] = json!({
+        "start": { "line": range.start_line, "column": range.start_column },
+        "end": { "line": range.end_line, "column": range.end_column },
+      });
+    }
+    if let Some(help) = &self.help {
+      value["help"] = json!(help);
+    }
+    if let Some(module_identifier) = &self.module_identifier {
+      value["moduleIdentifier"] = json!(module_identifier);
+    }
+    if !self.module_trace.is_empty() {
+      value["moduleTrace"] = json!(self.module_trace);
+    }
+    value
+  }
+}
+
+fn message_with_causes(error: &Error) -> String {
+  let mut message = error.message.clone();
+  let mut cause = error.source_error.as_deref();
+  while let Some(error) = cause {
+    message.push('\n');
+    message.push_str(&error.message);
+    cause = error.source_error.as_deref();
+  }
+  message
+}
+
+/// Converts the first label of an error with source code to a range.
+fn label_range(error: &Error) -> Option<DiagnosticRange> {
+  let src = error.src.as_deref()?;
+  let label = error.labels.as_ref()?.first()?;
+  let (start_line, start_column) = line_column(src, label.offset)?;
+  let (end_line, end_column) = line_column(src, label.offset + label.len)?;
+  Some(DiagnosticRange {
+    start_line,
+    start_column,
+    end_line,
+    end_column,
+  })
+}
+
+fn line_column(src: &str, offset: usize) -> Option<(usize, usize)> {
+  let before = src.get(..offset)?;
+  let line_start = before.rfind('\n').map_or(0, |index| index + 1);
+  Some((
+    before.matches('\n').count() + 1,
+    before[line_start..].chars().count(),
+  ))
+}
+
+#[cfg(test)]
+mod test {
+  use super::{DiagnosticRange, DiagnosticRecord};
+  use crate::{Diagnostic, Error, JsonDisplayer, Label, SarifDisplayer};
+
+  #[test]
+  fn should_convert_labels_to_range() {
+    let mut error = Error::error("Unexpected token".into());
+    error.code = Some("ModuleParseError".into());
+    error.src = Some("const a = 1;\nconst b = { var };".into());
+    error.labels = Some(vec![Label {
+      name: None,
+      offset: 25,
+      len: 3,
+    }]);
+    error.help = Some("Remove the keyword.".into());
+    let mut record = DiagnosticRecord::from(&Diagnostic::from(error));
+    assert_eq!(
+      record.range,
+      Some(DiagnosticRange {
+        start_line: 2,
+        start_column: 12,
+        end_line: 2,
+        end_column: 15,
+      })
+    );
+
+    record.file = Some("src\\index.js".into());
+    record.module_trace = vec!["./src/main.js".into()];
+    let json: serde_json::Value =
+      serde_json::from_str(&JsonDisplayer.render(std::slice::from_ref(&record)).unwrap()).unwrap();
+    assert_eq!(json[0]["code"], "ModuleParseError");
+    assert_eq!(json[0]["severity"], "error");
+    assert_eq!(json[0]["range"]["start"]["column"], 12);
+    assert_eq!(json[0]["moduleTrace"][0], "./src/main.js");
+
+    let sarif: serde_json::Value =
+      serde_json::from_str(&SarifDisplayer.render(&[record]).unwrap()).unwrap();
+    let result = &sarif["runs"][0]["results"][0];
+    assert_eq!(result["ruleId"], "ModuleParseError");
+    assert_eq!(result["level"], "error");
+    let location = &result["locations"][0]["physicalLocation"];
+    assert_eq!(location["artifactLocation"]["uri"], "src/index.js");
+    assert_eq!(location["region"]["startLine"], 2);
+    assert_eq!(location["region"]["startColumn"], 13);
+    assert_eq!(sarif["runs"][0]["columnKind"], "unicodeCodePoints");
+    assert_eq!(result["properties"]["help"], "Remove the keyword.");
+    assert_eq!(
+      sarif["runs"][0]["tool"]["driver"]["rules"][0]["id"],
+      "ModuleParseError"
+    );
+  }
+}
@@ -0,0 +1,109 @@
+use std::collections::HashSet;
+
+use rspack_workspace::rspack_pkg_version;
+use serde_json::{Value, json};
+
+use super::{Display, record::DiagnosticRecord};
+use crate::{Result, ToStringResultToRspackResultExt, diagnostic::Diagnostic};
+
+const SARIF_SCHEMA: &str = "https://json.schemastore.org/sarif-2.1.0.json";
+// diagnostics without a code are reported under this rule
+const UNKNOWN_RULE_ID: &str = "RspackDiagnostic";
+
+/// Renders diagnostics as a [SARIF 2.1.0](https://docs.oasis-open.org/sarif/sarif/v2.1.0/sarif-v2.1.0.html) log
+/// with a single run.
+#[derive(Default, Debug, Clone)]
+pub struct SarifDisplayer;
+
+impl SarifDisplayer {
+  pub fn render(&self, records: &[DiagnosticRecord]) -> Result<String> {
+    let mut rule_ids = HashSet::default();
+    let mut rules = vec![];
+    let results = records
+      .iter()
+      .map(|record| {
+        let rule_id = record.code.as_deref().unwrap_or(UNKNOWN_RULE_ID);
+        if rule_ids.insert(rule_id) {
+          rules.push(json!({ "id": rule_id }));
+        }
+        sarif_result(rule_id, record)
+      })
+      .collect::<Vec<_>>();
+
+    let log = json!({
+      "$schema": SARIF_SCHEMA,
+      "version": "2.1.0",
+      "runs": [{
+        "tool": {
+          "driver": {
+            "name": "rspack",
+            "informationUri": "https://rspack.rs",
+            "version": rspack_pkg_version!(),
+            "rules": rules,
+          }
+        },
+        // the columns are counted in chars rather than the default UTF-16 code units
+        "columnKind": "unicodeCodePoints",
+        "results": results,
+      }],
+    });
+    serde_json::to_string_pretty(&log).to_rspack_result()
+  }
+}
+
+fn sarif_result(rule_id: &str, record: &DiagnosticRecord) -> Value {
+  let mut result = json!({
+    "ruleId": rule_id,
+    "level": record.severity_str(),
+    "message": { "text": record.message },
+  });
+  if let Some(file) = &record.file {
+    let uri = file
+      .chars()
+      .map(|c| if c == '\\' { '/' } else { c })
+      .collect::<String>();
+    let mut physical_location = json!({
+      "artifactLocation": { "uri": uri },
+    });
+    // SARIF columns are 1-based
+    if let Some(range) = &record.range {
+      physical_location["region"] = json!({
+        "startLine": range.start_line,
+        "startColumn": range.start_column + 1,
+        "endLine": range.end_line,
+        "endColumn": range.end_column + 1,
+      });
+    }
+    result["locations"] = json!([{ "physicalLocation": physical_location }]);
+  }
+  let mut properties = serde_json::Map::new();
+  if let Some(help) = &record.help {
+    properties.insert("help".into(), json!(help));
+  }
+  if let Some(module_identifier) = &record.module_identifier {
+    properties.insert("moduleIdentifier".into(), json!(module_identifier));
+  }
+  if !record.module_trace.is_empty() {
+    properties.insert("moduleTrace".into(), json!(record.module_trace));
+  }
+  if !properties.is_empty() {
+    result["properties"] = Value::Object(properties);
+  }
+  result
+}
+
+impl Display for SarifDisplayer {
+  type Output = Result<String>;
+
+  fn emit_batch_diagnostic<'a>(
+    &self,
+    diagnostics: impl Iterator<Item = &'a Diagnostic>,
+  ) -> Self::Output {
+    let records = diagnostics.map(DiagnosticRecord::from).collect::<Vec<_>>();
+    self.render(&records)
+  }
+
+  fn emit_diagnostic(&self, diagnostic: &Diagnostic) -> Self::Output {
+    self.emit_batch_diagnostic(std::iter::once(diagnostic))
+  }
+}
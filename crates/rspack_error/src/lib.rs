@@ -17,7 +17,10 @@ pub use self::{
   diagnosable::Diagnosable,
   diagnostic::Diagnostic,
   diagnostic_array::{IntoTWithDiagnosticArray, TWithDiagnosticArray},
-  displayer::{Display, Renderer, StdioDisplayer, StringDisplayer},
+  displayer::{
+    DiagnosticRange, DiagnosticRecord, Display, JsonDisplayer, Renderer, SarifDisplayer,
+    StdioDisplayer, StringDisplayer,
+  },
   error::{Error, ErrorData, Label, Severity},
 };
 
@@ -139,14 +139,35 @@ impl MemoryFileSystem {
     Ok(res.into_iter().collect())
   }
 
-  fn _rename_file(&self, from: &Utf8Path, to: &Utf8Path) -> Result<()> {
-    if !self.contains_file(from)? {
-      return Err(new_error("from dir not exist"));
-    }
+  fn _rename(&self, from: &Utf8Path, to: &Utf8Path) -> Result<()> {
     let mut files = self.files.lock().expect("should get lock");
-    let file = files.remove(from).expect("should have file");
-    files.insert(to.into(), file);
-
+    match files.get(from) {
+      None => return Err(new_error("from path not exist")),
+      Some(FileType::File { .. }) => {
+        let file = files.remove(from).expect("should have file");
+        files.insert(to.into(), file);
+      }
+      Some(FileType::Dir(_)) => {
+        if files.contains_key(to) {
+          return Err(new_error("to path already exist"));
+        }
+        let paths = files
+          .keys()
+          .filter(|path| path.starts_with(from))
+          .cloned()
+          .collect::<Vec<_>>();
+        for path in paths {
+          let file = files.remove(&path).expect("should have file");
+          let relative = path.strip_prefix(from).expect("should be a child path");
+          let target = if relative.as_str().is_empty() {
+            to.to_path_buf()
+          } else {
+            to.join(relative)
+          };
+          files.insert(target, file);
+        }
+      }
+    }
     Ok(())
   }
 }
@@ -210,6 +231,10 @@ impl WritableFileSystem for MemoryFileSystem {
     Ok(())
   }
 
+  async fn rename(&self, from: &Utf8Path, to: &Utf8Path) -> Result<()> {
+    self._rename(from, to)
+  }
+
   async fn remove_file(&self, file: &Utf8Path) -> Result<()> {
     self._remove_file(file)
   }
@@ -291,11 +316,6 @@ impl ReadableFileSystem for MemoryFileSystem {
 
 #[async_trait::async_trait]
 impl IntermediateFileSystemExtras for MemoryFileSystem {
-  async fn rename(&self, from: &Utf8Path, to: &Utf8Path) -> Result<()> {
-    self._rename_file(from, to)?;
-    Ok(())
-  }
-
   async fn create_read_stream(&self, file: &Utf8Path) -> Result<Box<dyn ReadStream>> {
     let contents = self.read(file).await?;
     let reader = MemoryReadStream::new(contents);
@@ -544,6 +564,34 @@ mod tests {
         .is_err(),
     );
 
+    // rename
+    assert!(
+      WritableFileSystem::rename(&fs, Utf8Path::new("/a/file2"), Utf8Path::new("/a/file3"))
+        .await
+        .is_err(),
+    );
+    assert!(
+      WritableFileSystem::rename(&fs, Utf8Path::new("/a/b"), Utf8Path::new("/a/file1"))
+        .await
+        .is_err(),
+    );
+    assert!(
+      WritableFileSystem::rename(&fs, Utf8Path::new("/a/b"), Utf8Path::new("/a/b1"))
+        .await
+        .is_ok(),
+    );
+    assert!(
+      WritableFileSystem::stat(&fs, Utf8Path::new("/a/b1/c/d/e"))
+        .await
+        .unwrap()
+        .is_directory
+    );
+    assert!(
+      WritableFileSystem::stat(&fs, Utf8Path::new("/a/b"))
+        .await
+        .is_err(),
+    );
+
     // remove dir
     assert!(
       WritableFileSystem::remove_dir_all(&fs, Utf8Path::new("/a3/b3/c3"))
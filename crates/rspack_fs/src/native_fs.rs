@@ -47,6 +47,10 @@ impl WritableFileSystem for NativeFileSystem {
     fs::write(file, data).to_fs_result()
   }
   #[instrument(skip(self), level = "debug")]
+  async fn rename(&self, from: &Utf8Path, to: &Utf8Path) -> Result<()> {
+    tokio::fs::rename(from, to).await.to_fs_result()
+  }
+  #[instrument(skip(self), level = "debug")]
   async fn remove_file(&self, file: &Utf8Path) -> Result<()> {
     tokio::fs::remove_file(file).await.to_fs_result()
   }
@@ -99,6 +103,10 @@ impl WritableFileSystem for NativeFileSystem {
     fs::write(file, data).to_fs_result()
   }
   #[instrument(skip(self), level = "debug")]
+  async fn rename(&self, from: &Utf8Path, to: &Utf8Path) -> Result<()> {
+    fs::rename(from, to).to_fs_result()
+  }
+  #[instrument(skip(self), level = "debug")]
   async fn remove_file(&self, file: &Utf8Path) -> Result<()> {
     fs::remove_file(file).to_fs_result()
   }
@@ -346,10 +354,6 @@ impl ReadableFileSystem for NativeFileSystem {
 
 #[async_trait::async_trait]
 impl IntermediateFileSystemExtras for NativeFileSystem {
-  #[instrument(skip(self), level = "debug")]
-  async fn rename(&self, from: &Utf8Path, to: &Utf8Path) -> Result<()> {
-    fs::rename(from, to).to_fs_result()
-  }
   #[instrument(skip(self), level = "debug")]
   async fn create_read_stream(&self, file: &Utf8Path) -> Result<Box<dyn ReadStream>> {
     let reader = NativeReadStream::try_new(file)?;
@@ -0,0 +1,374 @@
+use std::{
+  collections::HashMap,
+  fmt::Debug,
+  sync::{Arc, RwLock},
+  time::{SystemTime, UNIX_EPOCH},
+};
+
+use rspack_paths::{Utf8Path, Utf8PathBuf};
+
+use crate::{Error, FileMetadata, FilePermissions, ReadableFileSystem, Result};
+
+fn current_time() -> u64 {
+  SystemTime::now()
+    .duration_since(UNIX_EPOCH)
+    .expect("should get current time")
+    .as_millis() as u64
+}
+
+fn new_error(msg: &str) -> Error {
+  Error::Io(std::io::Error::other(msg))
+}
+
+enum TrieNode {
+  File {
+    content: Vec<u8>,
+    metadata: FileMetadata,
+  },
+  Directory {
+    children: HashMap<String, TrieNode>,
+    metadata: FileMetadata,
+  },
+}
+
+impl TrieNode {
+  fn new_dir(now: u64) -> Self {
+    Self::Directory {
+      children: HashMap::default(),
+      metadata: FileMetadata {
+        is_file: false,
+        is_directory: true,
+        is_symlink: false,
+        atime_ms: now,
+        mtime_ms: now,
+        ctime_ms: now,
+        size: 0,
+      },
+    }
+  }
+
+  fn metadata(&self) -> &FileMetadata {
+    match self {
+      Self::File { metadata, .. } | Self::Directory { metadata, .. } => metadata,
+    }
+  }
+
+  fn get(&self, path: &Utf8Path) -> Option<&TrieNode> {
+    let mut current = self;
+    for part in path.components() {
+      current = match current {
+        Self::File { .. } => return None,
+        Self::Directory { children, .. } => children.get(part.as_str())?,
+      };
+    }
+    Some(current)
+  }
+
+  fn insert(&mut self, path: &Utf8Path, content: Vec<u8>, now: u64) -> Result<()> {
+    let parts = path.components().collect::<Vec<_>>();
+    let Some((name, dirs)) = parts.split_last() else {
+      return Err(new_error("path is empty"));
+    };
+    let mut parent = self;
+    for part in dirs {
+      parent = match parent {
+        Self::File { .. } => return Err(new_error("ancestor directory is a file")),
+        Self::Directory { children, .. } => children
+          .entry(part.as_str().to_string())
+          .or_insert_with(|| Self::new_dir(now)),
+      };
+    }
+    let Self::Directory { children, .. } = parent else {
+      return Err(new_error("ancestor directory is a file"));
+    };
+    let ctime = match children.get(name.as_str()) {
+      Some(Self::Directory { .. }) => return Err(new_error("target path is a directory")),
+      Some(Self::File { metadata, .. }) => metadata.ctime_ms,
+      None => now,
+    };
+    let metadata = FileMetadata {
+      is_file: true,
+      is_directory: false,
+      is_symlink: false,
+      atime_ms: now,
+      mtime_ms: now,
+      ctime_ms: ctime,
+      size: content.len() as u64,
+    };
+    children.insert(name.as_str().to_string(), Self::File { content, metadata });
+    Ok(())
+  }
+
+  fn remove(&mut self, path: &Utf8Path) -> bool {
+    let parts = path.components().collect::<Vec<_>>();
+    let Some((name, dirs)) = parts.split_last() else {
+      return false;
+    };
+    let mut parent = self;
+    for part in dirs {
+      parent = match parent {
+        Self::File { .. } => return false,
+        Self::Directory { children, .. } => match children.get_mut(part.as_str()) {
+          Some(node) => node,
+          None => return false,
+        },
+      };
+    }
+    match parent {
+      Self::Directory { children, .. } => {
+        matches!(children.get(name.as_str()), Some(Self::File { .. }))
+          && children.remove(name.as_str()).is_some()
+      }
+      Self::File { .. } => false,
+    }
+  }
+}
+
+type ChangeListener = Box<dyn Fn(&Utf8Path) + Send + Sync>;
+
+/// A filesystem layering in-memory files over another [`ReadableFileSystem`].
+///
+/// Files written to the overlay shadow the files of the underlying filesystem, and the
+/// directories containing them are merged with the directories of the underlying filesystem.
+/// Use it as the input filesystem of a compiler to provide generated modules without
+/// writing them to disk, both the module resolution and the module building read from it.
+///
+/// Writing or removing a file notifies the listeners registered by [`OverlayFileSystem::on_change`].
+/// Forward the changes to the `FsEventTrigger` of a running `rspack_watcher::FsWatcher` to trigger
+/// an incremental rebuild, the in-memory files don't exist on disk so watch them as missing paths.
+pub struct OverlayFileSystem {
+  base: Arc<dyn ReadableFileSystem>,
+  overlay: RwLock<TrieNode>,
+  listeners: RwLock<Vec<ChangeListener>>,
+}
+
+impl Debug for OverlayFileSystem {
+  fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+    f.debug_struct("OverlayFileSystem")
+      .field("base", &self.base)
+      .finish_non_exhaustive()
+  }
+}
+
+impl OverlayFileSystem {
+  pub fn new(base: Arc<dyn ReadableFileSystem>) -> Self {
+    Self {
+      base,
+      overlay: RwLock::new(TrieNode::new_dir(0)),
+      listeners: Default::default(),
+    }
+  }
+
+  /// Writes an in-memory file, the missing parent directories are created in the overlay.
+  ///
+  /// Fails if `path` or one of its ancestors is already a directory or a file of the overlay.
+  pub fn write_file(&self, path: &Utf8Path, content: Vec<u8>) -> Result<()> {
+    self
+      .overlay
+      .write()
+      .expect("should get overlay lock")
+      .insert(path, content, current_time())?;
+    self.notify(path);
+    Ok(())
+  }
+
+  /// Removes an in-memory file, the file of the underlying filesystem is visible again.
+  ///
+  /// Returns `false` if `path` is not a file of the overlay.
+  pub fn remove_file(&self, path: &Utf8Path) -> bool {
+    let removed = self
+      .overlay
+      .write()
+      .expect("should get overlay lock")
+      .remove(path);
+    if removed {
+      self.notify(path);
+    }
+    removed
+  }
+
+  /// Whether `path` is a file or a directory of the overlay.
+  pub fn contains(&self, path: &Utf8Path) -> bool {
+    self.with_node(path, |_| ()).is_some()
+  }
+
+  /// Registers a listener called with the path of every written or removed file.
+  pub fn on_change(&self, listener: impl Fn(&Utf8Path) + Send + Sync + 'static) {
+    self
+      .listeners
+      .write()
+      .expect("should get listeners lock")
+      .push(Box::new(listener));
+  }
+
+  fn notify(&self, path: &Utf8Path) {
+    for listener in self
+      .listeners
+      .read()
+      .expect("should get listeners lock")
+      .iter()
+    {
+      listener(path);
+    }
+  }
+
+  fn with_node<T>(&self, path: &Utf8Path, f: impl FnOnce(&TrieNode) -> T) -> Option<T> {
+    let overlay = self.overlay.read().expect("should get overlay lock");
+    overlay.get(path).map(f)
+  }
+
+  fn read_file(&self, path: &Utf8Path) -> Option<Vec<u8>> {
+    self
+      .with_node(path, |node| match node {
+        TrieNode::File { content, .. } => Some(content.clone()),
+        TrieNode::Directory { .. } => None,
+      })
+      .flatten()
+  }
+
+  fn read_overlay_dir(&self, dir: &Utf8Path) -> Option<Vec<String>> {
+    self
+      .with_node(dir, |node| match node {
+        TrieNode::File { .. } => None,
+        TrieNode::Directory { children, .. } => Some(children.keys().cloned().collect::<Vec<_>>()),
+      })
+      .flatten()
+  }
+
+  fn merge_dir(list: Result<Vec<String>>, overlay_list: Vec<String>) -> Vec<String> {
+    let mut list = list.unwrap_or_default();
+    for name in overlay_list {
+      if !list.contains(&name) {
+        list.push(name);
+      }
+    }
+    list
+  }
+}
+
+#[async_trait::async_trait]
+impl ReadableFileSystem for OverlayFileSystem {
+  async fn read(&self, path: &Utf8Path) -> Result<Vec<u8>> {
+    if let Some(content) = self.read_file(path) {
+      return Ok(content);
+    }
+    self.base.read(path).await
+  }
+
+  fn read_sync(&self, path: &Utf8Path) -> Result<Vec<u8>> {
+    if let Some(content) = self.read_file(path) {
+      return Ok(content);
+    }
+    self.base.read_sync(path)
+  }
+
+  async fn metadata(&self, path: &Utf8Path) -> Result<FileMetadata> {
+    if let Some(metadata) = self.with_node(path, |node| node.metadata().clone()) {
+      return Ok(metadata);
+    }
+    self.base.metadata(path).await
+  }
+
+  fn metadata_sync(&self, path: &Utf8Path) -> Result<FileMetadata> {
+    if let Some(metadata) = self.with_node(path, |node| node.metadata().clone()) {
+      return Ok(metadata);
+    }
+    self.base.metadata_sync(path)
+  }
+
+  async fn symlink_metadata(&self, path: &Utf8Path) -> Result<FileMetadata> {
+    if let Some(metadata) = self.with_node(path, |node| node.metadata().clone()) {
+      return Ok(metadata);
+    }
+    self.base.symlink_metadata(path).await
+  }
+
+  async fn canonicalize(&self, path: &Utf8Path) -> Result<Utf8PathBuf> {
+    if self.contains(path) {
+      return Ok(path.to_path_buf());
+    }
+    self.base.canonicalize(path).await
+  }
+
+  async fn read_dir(&self, dir: &Utf8Path) -> Result<Vec<String>> {
+    match self.read_overlay_dir(dir) {
+      Some(overlay_list) => Ok(Self::merge_dir(self.base.read_dir(dir).await, overlay_list)),
+      None => self.base.read_dir(dir).await,
+    }
+  }
+
+  fn read_dir_sync(&self, dir: &Utf8Path) -> Result<Vec<String>> {
+    match self.read_overlay_dir(dir) {
+      Some(overlay_list) => Ok(Self::merge_dir(self.base.read_dir_sync(dir), overlay_list)),
+      None => self.base.read_dir_sync(dir),
+    }
+  }
+
+  async fn permissions(&self, path: &Utf8Path) -> Result<Option<FilePermissions>> {
+    if self.contains(path) {
+      return Ok(Some(FilePermissions::from_mode(0o700)));
+    }
+    self.base.permissions(path).await
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use std::sync::{
+    Arc,
+    atomic::{AtomicUsize, Ordering},
+  };
+
+  use rspack_paths::Utf8Path;
+
+  use super::OverlayFileSystem;
+  use crate::{MemoryFileSystem, ReadableFileSystem, WritableFileSystem};
+
+  #[tokio::test]
+  async fn overlay_fs_test() {
+    let base = MemoryFileSystem::default();
+    base.create_dir_all(Utf8Path::new("/src")).await.unwrap();
+    base
+      .write(Utf8Path::new("/src/index.js"), b"disk")
+      .await
+      .unwrap();
+    let fs = OverlayFileSystem::new(Arc::new(base));
+    let changes = Arc::new(AtomicUsize::new(0));
+    let counter = changes.clone();
+    fs.on_change(move |_| {
+      counter.fetch_add(1, Ordering::Relaxed);
+    });
+
+    // the overlay shadows the underlying files
+    fs.write_file(Utf8Path::new("/src/index.js"), b"virtual".to_vec())
+      .unwrap();
+    fs.write_file(Utf8Path::new("/src/generated/entry.js"), b"entry".to_vec())
+      .unwrap();
+    assert_eq!(
+      fs.read(Utf8Path::new("/src/index.js")).await.unwrap(),
+      b"virtual"
+    );
+    assert!(
+      fs.metadata_sync(Utf8Path::new("/src/generated"))
+        .unwrap()
+        .is_directory
+    );
+    let mut list = fs.read_dir_sync(Utf8Path::new("/src")).unwrap();
+    list.sort();
+    assert_eq!(list, vec!["generated", "index.js"]);
+
+    // a directory of the overlay can't be replaced by a file
+    assert!(
+      fs.write_file(Utf8Path::new("/src/generated"), vec![])
+        .is_err()
+    );
+
+    assert!(fs.remove_file(Utf8Path::new("/src/index.js")));
+    assert!(!fs.remove_file(Utf8Path::new("/src/generated")));
+    assert_eq!(
+      fs.read_sync(Utf8Path::new("/src/index.js")).unwrap(),
+      b"disk"
+    );
+    assert_eq!(changes.load(Ordering::Relaxed), 3);
+  }
+}
@@ -2,7 +2,7 @@ use std::fmt::Debug;
 
 use rspack_paths::Utf8Path;
 
-use super::{FileMetadata, Result};
+use super::{Error, FileMetadata, Result};
 use crate::file_metadata::FilePermissions;
 
 #[async_trait::async_trait]
@@ -26,6 +26,22 @@ pub trait WritableFileSystem: Debug + Send + Sync {
   /// This function will create a file if it does not exist, and will entirely replace its contents if it does.
   async fn write(&self, file: &Utf8Path, data: &[u8]) -> Result<()>;
 
+  /// Rename a file or directory to a new name, replacing the original file if `to` already exists.
+  ///
+  /// The default implementation copies the content of a file and removes the original one,
+  /// which is not atomic. Renaming a directory is not supported by it.
+  async fn rename(&self, from: &Utf8Path, to: &Utf8Path) -> Result<()> {
+    if !self.stat(from).await?.is_file {
+      return Err(Error::new(
+        std::io::ErrorKind::Unsupported,
+        &format!("failed to rename {from}: only files can be renamed by this file system"),
+      ));
+    }
+    let data = self.read_file(from).await?;
+    self.write(to, &data).await?;
+    self.remove_file(from).await
+  }
+
   /// Removes a file from the filesystem.
   async fn remove_file(&self, file: &Utf8Path) -> Result<()>;
 
@@ -265,6 +265,17 @@ impl Loader<RunnerContext> for LightningCssLoader {
     self.id
   }
 
+  fn cache_key(&self, loader_context: &LoaderContext<RunnerContext>) -> Option<String> {
+    // visitors are javascript functions, their results can not be cached
+    if self.visitors.is_some() {
+      return None;
+    }
+    Some(format!(
+      "{LIGHTNINGCSS_LOADER_IDENTIFIER}|{:?}|{}|{:?}",
+      loader_context.context.source_map_kind, loader_context.context.options.context, self.config
+    ))
+  }
+
   #[tracing::instrument("loader:lightningcss", skip_all, fields(
     perfetto.track_name = "loader:lightningcss",
     perfetto.process_name = "Loader Analysis",
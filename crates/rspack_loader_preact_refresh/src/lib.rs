@@ -36,6 +36,10 @@ impl Loader<RunnerContext> for PreactRefreshLoader {
     self.identifier
   }
 
+  fn cache_key(&self, _loader_context: &LoaderContext<RunnerContext>) -> Option<String> {
+    Some(PREACT_REFRESH_LOADER_IDENTIFIER.to_string())
+  }
+
   async fn run(&self, loader_context: &mut LoaderContext<RunnerContext>) -> Result<()> {
     let Some(content) = loader_context.take_content() else {
       return Ok(());
@@ -36,6 +36,18 @@ impl Loader<RunnerContext> for ReactRefreshLoader {
     self.identifier
   }
 
+  fn cache_key(&self, loader_context: &LoaderContext<RunnerContext>) -> Option<String> {
+    let supports_arrow_function = loader_context
+      .context
+      .options
+      .output
+      .environment
+      .supports_arrow_function();
+    Some(format!(
+      "{REACT_REFRESH_LOADER_IDENTIFIER}|{supports_arrow_function}"
+    ))
+  }
+
   async fn run(&self, loader_context: &mut LoaderContext<RunnerContext>) -> Result<()> {
     let Some(content) = loader_context.take_content() else {
       return Ok(());
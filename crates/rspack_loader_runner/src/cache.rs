@@ -0,0 +1,157 @@
+use std::{hash::Hash, sync::Arc};
+
+use rspack_cacheable::{cacheable, from_bytes, to_bytes};
+use rspack_hash::{HashDigest, HashFunction, RspackHash};
+use rspack_sources::SourceMap;
+
+use crate::{AdditionalData, Content, LoaderContext};
+
+/// A key-value store of the results of pure loaders, see [`Loader::cache_key`].
+///
+/// The keys are computed by the loader runner from the loader cache key and the input of the loader,
+/// so the same content always hits the same entry no matter how many times the module is rebuilt.
+///
+/// [`Loader::cache_key`]: crate::Loader::cache_key
+pub trait LoaderResultCache: std::fmt::Debug + Send + Sync {
+  fn get(&self, key: &[u8]) -> Option<Arc<Vec<u8>>>;
+  fn set(&self, key: Vec<u8>, value: Vec<u8>);
+}
+
+/// The result of a pure loader saved in [`LoaderResultCache`].
+#[cacheable]
+struct CachedLoaderResult {
+  content: Vec<u8>,
+  is_buffer: bool,
+  source_map: Option<String>,
+  /// Only additional data which holds a single [`serde_json::Value`] is cached.
+  additional_data: Option<String>,
+}
+
+// additional data is an any map, only a json value in it can be serialized
+fn additional_data_to_string(additional_data: &AdditionalData) -> Option<String> {
+  if additional_data.len() != 1 {
+    return None;
+  }
+  additional_data
+    .get::<serde_json::Value>()
+    .map(|value| value.to_string())
+}
+
+/// Computes the cache key of the current loader from its own cache key, the resource and the input
+/// of the loader, returns `None` if the input can not be cached.
+pub(crate) fn cache_key<Context: Send>(
+  loader_context: &LoaderContext<Context>,
+  loader_cache_key: &str,
+) -> Option<Vec<u8>> {
+  let content = loader_context.content.as_ref()?;
+  let source_map = match &loader_context.source_map {
+    Some(source_map) => Some(source_map.to_json().ok()?),
+    None => None,
+  };
+  let additional_data = match &loader_context.additional_data {
+    Some(additional_data) => Some(additional_data_to_string(additional_data)?),
+    None => None,
+  };
+
+  let mut hasher = RspackHash::new(&HashFunction::Xxhash64);
+  loader_cache_key.hash(&mut hasher);
+  loader_context.resource().hash(&mut hasher);
+  content.is_buffer().hash(&mut hasher);
+  content.as_bytes().hash(&mut hasher);
+  source_map.hash(&mut hasher);
+  additional_data.hash(&mut hasher);
+  Some(
+    hasher
+      .digest(&HashDigest::Hex)
+      .encoded()
+      .as_bytes()
+      .to_vec(),
+  )
+}
+
+/// Restores the result of the current loader, returns `false` if the cached value is broken.
+pub(crate) fn restore<Context: Send>(
+  loader_context: &mut LoaderContext<Context>,
+  value: &[u8],
+) -> bool {
+  let Ok(cached) = from_bytes::<CachedLoaderResult, ()>(value, &()) else {
+    return false;
+  };
+  let source_map = match cached.source_map {
+    Some(source_map) => match SourceMap::from_json(&source_map) {
+      Ok(source_map) => Some(source_map),
+      Err(_) => return false,
+    },
+    None => None,
+  };
+  let additional_data = match cached.additional_data {
+    Some(additional_data) => match serde_json::from_str::<serde_json::Value>(&additional_data) {
+      Ok(value) => {
+        let mut additional_data = AdditionalData::new();
+        additional_data.insert(value);
+        Some(additional_data)
+      }
+      Err(_) => return false,
+    },
+    None => None,
+  };
+  let content = if cached.is_buffer {
+    Content::Buffer(cached.content)
+  } else {
+    match String::from_utf8(cached.content) {
+      Ok(content) => Content::String(content),
+      Err(_) => return false,
+    }
+  };
+  loader_context.content = Some(content);
+  loader_context.source_map = source_map;
+  loader_context.additional_data = additional_data;
+  true
+}
+
+/// Serializes the result of the current loader, returns `None` if the result can not be cached.
+pub(crate) fn serialize<Context: Send>(loader_context: &LoaderContext<Context>) -> Option<Vec<u8>> {
+  let content = loader_context.content.as_ref()?;
+  let source_map = match &loader_context.source_map {
+    Some(source_map) => Some(source_map.to_json().ok()?),
+    None => None,
+  };
+  let additional_data = match &loader_context.additional_data {
+    Some(additional_data) => Some(additional_data_to_string(additional_data)?),
+    None => None,
+  };
+  let cached = CachedLoaderResult {
+    content: content.as_bytes().to_vec(),
+    is_buffer: content.is_buffer(),
+    source_map,
+    additional_data,
+  };
+  to_bytes(&cached, &()).ok()
+}
+
+/// The side effects of a loader besides its result, the result is only cached if a loader run
+/// does not change any of them, as they can not be restored from the cache.
+#[derive(PartialEq, Eq)]
+pub(crate) struct SideEffects {
+  cacheable: bool,
+  diagnostics: usize,
+  parse_meta: usize,
+  file_dependencies: usize,
+  context_dependencies: usize,
+  missing_dependencies: usize,
+  build_dependencies: usize,
+}
+
+impl SideEffects {
+  pub(crate) fn new<Context: Send>(loader_context: &LoaderContext<Context>) -> Self {
+    Self {
+      cacheable: loader_context.cacheable,
+      diagnostics: loader_context.diagnostics.len(),
+      parse_meta: loader_context.parse_meta.len(),
+      file_dependencies: loader_context.file_dependencies.len(),
+      context_dependencies: loader_context.context_dependencies.len(),
+      missing_dependencies: loader_context.missing_dependencies.len(),
+      build_dependencies: loader_context.build_dependencies.len(),
+    }
+  }
+}
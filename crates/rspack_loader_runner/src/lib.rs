@@ -1,3 +1,4 @@
+mod cache;
 mod content;
 mod context;
 mod loader;
@@ -5,9 +6,12 @@ mod plugin;
 mod runner;
 mod scheme;
 
+pub use cache::LoaderResultCache;
 pub use content::{AdditionalData, Content, DescriptionData, ParseMeta, ResourceData};
 pub use context::{LoaderContext, State};
-pub use loader::{DisplayWithSuffix, Loader, LoaderItem, ResourceParsedData, parse_resource};
+pub use loader::{
+  DisplayWithSuffix, Loader, LoaderItem, LoaderTiming, ResourceParsedData, parse_resource,
+};
 pub use plugin::LoaderRunnerPlugin;
 pub use rspack_collections::{Identifiable, Identifier};
 pub use runner::{LoaderResult, run_loaders};
@@ -5,6 +5,7 @@ use std::{
     Arc,
     atomic::{AtomicBool, Ordering},
   },
+  time::Duration,
 };
 
 use async_trait::async_trait;
@@ -48,6 +49,19 @@ pub struct LoaderItem<Context: Send> {
   /// This flag is used to align with webpack's behavior:
   /// If nothing is modified in the loader, the loader will reset the content, source map, and additional data.
   finish_called: AtomicBool,
+  /// Time spent in the pitching phase, including the time spent on the JS side.
+  pitch_duration: Duration,
+  /// Time spent in the normal phase, including the time spent on the JS side.
+  normal_duration: Duration,
+}
+
+/// Time spent by a loader on a single resource.
+#[derive(Debug, Clone)]
+pub struct LoaderTiming {
+  /// Loader identifier
+  pub request: Identifier,
+  pub pitch: Duration,
+  pub normal: Duration,
 }
 
 impl<C: Send> LoaderItem<C> {
@@ -125,6 +139,36 @@ impl<C: Send> LoaderItem<C> {
   pub fn set_finish_called(&self) {
     self.finish_called.store(true, Ordering::Relaxed)
   }
+
+  #[inline]
+  pub fn pitch_duration(&self) -> Duration {
+    self.pitch_duration
+  }
+
+  #[inline]
+  pub fn normal_duration(&self) -> Duration {
+    self.normal_duration
+  }
+
+  #[inline]
+  #[doc(hidden)]
+  pub fn set_pitch_duration(&mut self, duration: Duration) {
+    self.pitch_duration = duration;
+  }
+
+  #[inline]
+  #[doc(hidden)]
+  pub fn set_normal_duration(&mut self, duration: Duration) {
+    self.normal_duration = duration;
+  }
+
+  pub fn timing(&self) -> LoaderTiming {
+    LoaderTiming {
+      request: self.request,
+      pitch: self.pitch_duration,
+      normal: self.normal_duration,
+    }
+  }
 }
 
 impl<C: Send> Display for LoaderItem<C> {
@@ -201,6 +245,20 @@ where
   fn r#type(&self) -> Option<&str> {
     None
   }
+
+  /// Declares the loader as pure by returning a key of everything its result depends on besides the
+  /// content, source map and additional data it receives and the resource, e.g. the loader options and
+  /// the compiler options it reads.
+  ///
+  /// The normal stage of a pure loader is skipped when the [`LoaderResultCache`] provided by
+  /// [`LoaderRunnerPlugin::loader_result_cache`] has a result of the same input.
+  /// Returns `None` by default, which means the loader is not pure.
+  ///
+  /// [`LoaderResultCache`]: crate::LoaderResultCache
+  /// [`LoaderRunnerPlugin::loader_result_cache`]: crate::LoaderRunnerPlugin::loader_result_cache
+  fn cache_key(&self, _loader_context: &LoaderContext<Context>) -> Option<String> {
+    None
+  }
 }
 
 impl<C: Send> From<Arc<dyn Loader<C>>> for LoaderItem<C> {
@@ -224,6 +282,8 @@ impl<C: Send> From<Arc<dyn Loader<C>>> for LoaderItem<C> {
         pitch_executed: AtomicBool::new(false),
         normal_executed: AtomicBool::new(false),
         finish_called: AtomicBool::new(false),
+        pitch_duration: Duration::ZERO,
+        normal_duration: Duration::ZERO,
       };
     }
     let ident = loader.identifier();
@@ -243,6 +303,8 @@ impl<C: Send> From<Arc<dyn Loader<C>>> for LoaderItem<C> {
       pitch_executed: AtomicBool::new(false),
       normal_executed: AtomicBool::new(false),
       finish_called: AtomicBool::new(false),
+      pitch_duration: Duration::ZERO,
+      normal_duration: Duration::ZERO,
     }
   }
 }
@@ -6,7 +6,7 @@ use rspack_sources::SourceMap;
 use rustc_hash::FxHashSet as HashSet;
 
 use crate::{
-  LoaderContext,
+  LoaderContext, LoaderResultCache,
   content::{Content, ResourceData},
 };
 
@@ -30,6 +30,11 @@ pub trait LoaderRunnerPlugin: Send + Sync {
     Ok(())
   }
 
+  /// The cache of the results of pure loaders, see [`Loader::cache_key`](crate::Loader::cache_key).
+  fn loader_result_cache(&self) -> Option<&Arc<dyn LoaderResultCache>> {
+    None
+  }
+
   async fn process_resource(
     &self,
     resource_data: &ResourceData,
@@ -1,17 +1,19 @@
-use std::{fmt::Debug, path::PathBuf, sync::Arc};
+use std::{fmt::Debug, path::PathBuf, sync::Arc, time::Instant};
 
 use rspack_error::{Diagnostic, Error, Result, error};
 use rspack_fs::ReadableFileSystem;
 use rspack_paths::Utf8PathBuf;
 use rspack_sources::SourceMap;
+use rspack_util::tracing_preset::TRACING_DETAIL_TARGET;
 use rustc_hash::FxHashSet as HashSet;
-use tracing::{Instrument, info_span};
+use tracing::{Instrument, Span, info_span, trace_span};
 
 use crate::{
   ParseMeta,
+  cache::{self, SideEffects},
   content::{AdditionalData, Content, ResourceData},
   context::{LoaderContext, State},
-  loader::{Loader, LoaderItem},
+  loader::{Loader, LoaderItem, LoaderTiming},
   plugin::LoaderRunnerPlugin,
 };
 
@@ -95,6 +97,18 @@ fn create_loader_context<Context: Send>(
   }
 }
 
+// span of a single invocation of the current loader, only enabled with the detail tracing target
+fn loader_span<Context>(cx: &LoaderContext<Context>, pitch: bool) -> Span {
+  let loader = cx.current_loader().loader().identifier();
+  let loader = loader.as_str();
+  let module = cx.resource();
+  if pitch {
+    trace_span!(target: TRACING_DETAIL_TARGET, "loader:pitch", loader, module)
+  } else {
+    trace_span!(target: TRACING_DETAIL_TARGET, "loader:normal", loader, module)
+  }
+}
+
 #[tracing::instrument("LoaderRunner:run_loaders", skip_all, level = "trace")]
 pub async fn run_loaders<Context: Send>(
   loaders: Vec<Arc<dyn Loader<Context>>>,
@@ -132,7 +146,13 @@ async fn run_loaders_impl<Context: Send>(
           continue;
         }
         let span = info_span!("run_loader:pitch:yield_to_js", resource);
-        if cx.start_yielding().instrument(span).await? {
+        let detail_span = loader_span(cx, true);
+        if cx
+          .start_yielding()
+          .instrument(span)
+          .instrument(detail_span)
+          .await?
+        {
           if cx.content.is_some() {
             cx.state.transition(State::Normal);
             cx.loader_index -= 1;
@@ -147,8 +167,19 @@ async fn run_loaders_impl<Context: Send>(
 
         cx.current_loader().set_pitch_executed();
         let loader = cx.current_loader().loader().clone();
+        let loader_index = cx.loader_index as usize;
         let span = info_span!("run_loader:pitch", resource);
-        loader.pitch(cx).instrument(span).await?;
+        let detail_span = loader_span(cx, true);
+        let start = Instant::now();
+        loader
+          .pitch(cx)
+          .instrument(span)
+          .instrument(detail_span)
+          .await?;
+        // each phase runs once per loader, so this replaces rather than adds to the timing
+        // already reported by the JS side when merging the loader context
+        let loader_item = &mut cx.loader_items[loader_index];
+        loader_item.set_pitch_duration(start.elapsed());
         if cx.content.is_some() {
           cx.state.transition(State::Normal);
           cx.loader_index -= 1;
@@ -172,7 +203,13 @@ async fn run_loaders_impl<Context: Send>(
           continue;
         }
         let span = info_span!("run_loader:yield_to_js", resource);
-        if cx.start_yielding().instrument(span).await? {
+        let detail_span = loader_span(cx, false);
+        if cx
+          .start_yielding()
+          .instrument(span)
+          .instrument(detail_span)
+          .await?
+        {
           continue;
         }
 
@@ -184,13 +221,47 @@ async fn run_loaders_impl<Context: Send>(
         cx.current_loader().set_normal_executed();
         let loader = cx.current_loader().loader().clone();
 
+        // The result of a pure loader is restored from the cache if its input is unchanged.
+        let result_cache = cx
+          .plugin
+          .as_ref()
+          .and_then(|plugin| plugin.loader_result_cache())
+          .cloned();
+        let cache_key = result_cache
+          .as_ref()
+          .and_then(|_| loader.cache_key(cx))
+          .and_then(|loader_cache_key| cache::cache_key(cx, &loader_cache_key));
+        if let (Some(result_cache), Some(cache_key)) = (&result_cache, &cache_key)
+          && let Some(value) = result_cache.get(cache_key)
+          && cache::restore(cx, &value)
+        {
+          cx.current_loader().set_finish_called();
+          continue;
+        }
+        let side_effects = cache_key.as_ref().map(|_| SideEffects::new(cx));
+
+        let loader_index = cx.loader_index as usize;
         let span = info_span!("run_loader:normal", resource);
-        loader.run(cx).instrument(span).await?;
+        let detail_span = loader_span(cx, false);
+        let start = Instant::now();
+        loader
+          .run(cx)
+          .instrument(span)
+          .instrument(detail_span)
+          .await?;
+        let loader_item = &mut cx.loader_items[loader_index];
+        loader_item.set_normal_duration(start.elapsed());
         if !cx.current_loader().finish_called() {
           // If nothing is returned from this loader,
           // we set everything to [None] and move to the next loader.
           // This mocks the behavior of webpack loader-runner.
           cx.finish_with_empty();
+        } else if let (Some(result_cache), Some(cache_key), Some(side_effects)) =
+          (result_cache, cache_key, side_effects)
+          && side_effects == SideEffects::new(cx)
+          && let Some(value) = cache::serialize(cx)
+        {
+          result_cache.set(cache_key, value);
         }
       }
       State::Finished => break,
@@ -225,6 +296,8 @@ pub struct LoaderResult<Context> {
   pub additional_data: Option<AdditionalData>,
   pub parse_meta: ParseMeta,
   pub current_loader: Option<Utf8PathBuf>,
+  /// Time spent by each loader, in the order of the loader chain.
+  pub loader_timings: Vec<LoaderTiming>,
 }
 
 impl<Context: Send> LoaderResult<Context> {
@@ -243,6 +316,11 @@ impl<Context: Send> LoaderResult<Context> {
       source_map: loader_context.source_map,
       additional_data: loader_context.additional_data,
       parse_meta: loader_context.parse_meta,
+      loader_timings: loader_context
+        .loader_items
+        .iter()
+        .map(LoaderItem::timing)
+        .collect(),
       current_loader: (loader_context.loader_index >= 0)
         .then(|| {
           loader_context
@@ -600,4 +678,121 @@ mod test {
       .is_some()
     );
   }
+
+  #[tokio::test]
+  async fn should_restore_result_of_pure_loader_from_cache() {
+    use std::sync::{
+      Mutex,
+      atomic::{AtomicUsize, Ordering},
+    };
+
+    use rustc_hash::FxHashMap as HashMap;
+
+    use crate::LoaderResultCache;
+
+    static RUNS: AtomicUsize = AtomicUsize::new(0);
+
+    #[derive(Debug, Default)]
+    struct TestCache(Mutex<HashMap<Vec<u8>, Arc<Vec<u8>>>>);
+
+    impl LoaderResultCache for TestCache {
+      fn get(&self, key: &[u8]) -> Option<Arc<Vec<u8>>> {
+        self.0.lock().expect("should lock").get(key).cloned()
+      }
+
+      fn set(&self, key: Vec<u8>, value: Vec<u8>) {
+        self
+          .0
+          .lock()
+          .expect("should lock")
+          .insert(key, Arc::new(value));
+      }
+    }
+
+    struct TestCachePlugin {
+      content: &'static str,
+      cache: Arc<dyn LoaderResultCache>,
+    }
+
+    #[async_trait::async_trait]
+    impl LoaderRunnerPlugin for TestCachePlugin {
+      type Context = ();
+
+      fn loader_result_cache(&self) -> Option<&Arc<dyn LoaderResultCache>> {
+        Some(&self.cache)
+      }
+
+      async fn process_resource(
+        &self,
+        _resource_data: &ResourceData,
+        _fs: Arc<dyn ReadableFileSystem>,
+      ) -> Result<Option<(Content, Option<SourceMap>, HashSet<std::path::PathBuf>)>> {
+        Ok(Some((
+          Content::String(self.content.to_string()),
+          None,
+          Default::default(),
+        )))
+      }
+    }
+
+    #[cacheable]
+    struct Pure;
+
+    #[cacheable_dyn]
+    #[async_trait::async_trait]
+    impl Loader<()> for Pure {
+      fn identifier(&self) -> Identifier {
+        "/rspack/pure-loader".into()
+      }
+
+      fn cache_key(&self, _loader_context: &LoaderContext<()>) -> Option<String> {
+        Some("pure-loader".to_string())
+      }
+
+      async fn run(&self, loader_context: &mut LoaderContext<()>) -> Result<()> {
+        RUNS.fetch_add(1, Ordering::Relaxed);
+        let content = loader_context.take_content().expect("should have content");
+        let mut additional_data: AdditionalData = Default::default();
+        additional_data.insert(serde_json::json!({ "pure": true }));
+        loader_context.finish_with((
+          format!("{}!", content.try_into_string()?),
+          None,
+          Some(additional_data),
+        ));
+        Ok(())
+      }
+    }
+
+    let cache: Arc<dyn LoaderResultCache> = Arc::new(TestCache::default());
+    let run = |content: &'static str| {
+      let cache = cache.clone();
+      async move {
+        let (result, err) = run_loaders(
+          vec![Arc::new(Pure) as Arc<dyn Loader>],
+          Arc::new(ResourceData::new_with_resource(
+            "/rspack/main.js".to_owned(),
+          )),
+          Some(Arc::new(TestCachePlugin { content, cache })),
+          (),
+          Arc::new(NativeFileSystem::new(false)),
+        )
+        .await;
+        assert!(err.is_none());
+        let additional_data = result.additional_data.expect("should have additional data");
+        assert_eq!(
+          additional_data.get::<serde_json::Value>(),
+          Some(&serde_json::json!({ "pure": true }))
+        );
+        result.content.try_into_string().expect("should be string")
+      }
+    };
+
+    assert_eq!(run("a").await, "a!");
+    assert_eq!(RUNS.load(Ordering::Relaxed), 1);
+    // the same content is restored from the cache
+    assert_eq!(run("a").await, "a!");
+    assert_eq!(RUNS.load(Ordering::Relaxed), 1);
+    assert_eq!(run("b").await, "b!");
+    assert_eq!(RUNS.load(Ordering::Relaxed), 2);
+  }
 }
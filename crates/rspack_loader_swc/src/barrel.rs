@@ -0,0 +1,454 @@
+use std::{
+  future::Future,
+  pin::Pin,
+  sync::{Arc, Mutex},
+};
+
+use rspack_core::{
+  DependencyCategory, Module, ResolveOptionsWithDependencyType, ResolveResult, Resolver,
+  RunnerContext,
+};
+use rspack_fs::ReadableFileSystem;
+use rspack_javascript_compiler::JavaScriptCompiler;
+use rspack_loader_runner::LoaderContext;
+use rspack_paths::{Utf8Path, Utf8PathBuf};
+use rspack_swc_plugin_import::{
+  BarrelExport, BarrelExports, BarrelImport, ImportOptions, collect_reexports,
+};
+use rustc_hash::{FxHashMap, FxHashSet};
+use swc_core::{
+  base::config::IsModule,
+  common::FileName,
+  ecma::{
+    ast::{EsVersion, ModuleDecl, ModuleItem, Program},
+    parser::{EsSyntax, Syntax, TsSyntax},
+  },
+};
+
+// `export *` chains deeper than this are not followed
+const MAX_STAR_DEPTH: usize = 16;
+
+#[derive(Debug)]
+struct PackageBarrel {
+  exports: Arc<BarrelExports>,
+  file_dependencies: Vec<Utf8PathBuf>,
+  missing_dependencies: Vec<Utf8PathBuf>,
+  /// The modified time of the file dependencies when the barrel was read.
+  file_timestamps: Vec<Option<u64>>,
+}
+
+impl PackageBarrel {
+  /// Whether the barrel is still up to date, a barrel is outdated once any of its file
+  /// dependencies is modified or any of its missing dependencies is created.
+  async fn is_valid(&self, fs: &dyn ReadableFileSystem) -> bool {
+    for (file, timestamp) in self
+      .file_dependencies
+      .iter()
+      .zip(self.file_timestamps.iter())
+    {
+      if file_timestamp(fs, file).await != *timestamp {
+        return false;
+      }
+    }
+    for file in &self.missing_dependencies {
+      if fs.metadata(file).await.is_ok() {
+        return false;
+      }
+    }
+    true
+  }
+}
+
+async fn file_timestamp(fs: &dyn ReadableFileSystem, file: &Utf8Path) -> Option<u64> {
+  fs.metadata(file)
+    .await
+    .ok()
+    .map(|metadata| metadata.mtime_ms)
+}
+
+/// The barrels read by the loader, keyed by the package directory and the package version.
+///
+/// The barrels are kept across compilations, and a barrel is read again once its file
+/// dependencies are changed.
+#[derive(Debug, Default)]
+pub(crate) struct BarrelCache {
+  barrels: Mutex<FxHashMap<(Utf8PathBuf, Option<String>), Arc<PackageBarrel>>>,
+}
+
+impl BarrelCache {
+  async fn get(
+    &self,
+    fs: &dyn ReadableFileSystem,
+    key: &(Utf8PathBuf, Option<String>),
+  ) -> Option<Arc<PackageBarrel>> {
+    let barrel = self
+      .barrels
+      .lock()
+      .expect("should lock barrel cache")
+      .get(key)
+      .cloned()?;
+    if barrel.is_valid(fs).await {
+      return Some(barrel);
+    }
+    self
+      .barrels
+      .lock()
+      .expect("should lock barrel cache")
+      .remove(key);
+    None
+  }
+
+  fn insert(&self, key: (Utf8PathBuf, Option<String>), barrel: Arc<PackageBarrel>) {
+    self
+      .barrels
+      .lock()
+      .expect("should lock barrel cache")
+      .insert(key, barrel);
+  }
+}
+
+/// The barrels of the libraries which may be imported by a module, read before the module is
+/// parsed, and narrowed down to the imported libraries by [ResolvedBarrels::select].
+#[derive(Debug, Default)]
+pub(crate) struct ResolvedBarrels {
+  barrels: FxHashMap<String, Result<Arc<PackageBarrel>, String>>,
+  pub(crate) file_dependencies: Vec<Utf8PathBuf>,
+  pub(crate) missing_dependencies: Vec<Utf8PathBuf>,
+  pub(crate) warnings: Vec<String>,
+}
+
+impl ResolvedBarrels {
+  /// Returns the re-export tables of the barrel libraries imported by the program, and collects
+  /// the dependencies and warnings of them.
+  pub(crate) fn select(&mut self, program: &Program) -> FxHashMap<String, Arc<BarrelExports>> {
+    let Program::Module(module) = program else {
+      return Default::default();
+    };
+    let imported = module
+      .body
+      .iter()
+      .filter_map(|item| match item {
+        ModuleItem::ModuleDecl(ModuleDecl::Import(import)) => {
+          Some(import.src.value.to_string_lossy().to_string())
+        }
+        _ => None,
+      })
+      .collect::<FxHashSet<_>>();
+
+    let mut selected = FxHashMap::default();
+    for (library_name, barrel) in std::mem::take(&mut self.barrels) {
+      if !imported.contains(&library_name) {
+        continue;
+      }
+      match barrel {
+        Ok(barrel) => {
+          self
+            .file_dependencies
+            .extend(barrel.file_dependencies.iter().cloned());
+          self
+            .missing_dependencies
+            .extend(barrel.missing_dependencies.iter().cloned());
+          selected.insert(library_name, barrel.exports.clone());
+        }
+        Err(reason) => self.warnings.push(format!(
+          "Failed to read the barrel file of \"{library_name}\", its imports are kept as is.\nReason: {reason}"
+        )),
+      }
+    }
+    selected
+  }
+}
+
+/// Reads the re-export tables of the barrel libraries which are mentioned by the source.
+///
+/// The source is not parsed yet, a library is read if its name appears as a quoted string, and
+/// the libraries which turn out not to be imported are dropped by [ResolvedBarrels::select].
+pub(crate) async fn resolve_barrels(
+  loader_context: &LoaderContext<RunnerContext>,
+  source: &str,
+  options: &[ImportOptions],
+  cache: &BarrelCache,
+) -> ResolvedBarrels {
+  let mut result = ResolvedBarrels::default();
+  let Some(context) = loader_context
+    .resource_path()
+    .and_then(|path| path.parent())
+  else {
+    return result;
+  };
+  let runner_context = &loader_context.context;
+  let resolver = runner_context
+    .resolver_factory
+    .get(ResolveOptionsWithDependencyType {
+      resolve_options: runner_context
+        .module
+        .get_resolve_options()
+        .map(|options| Box::new(options.as_ref().clone())),
+      resolve_to_context: false,
+      dependency_category: DependencyCategory::Esm,
+    });
+
+  for option in options {
+    let library_name = &option.library_name;
+    if !option.is_barrel()
+      || result.barrels.contains_key(library_name)
+      || !(source.contains(&format!("\"{library_name}\""))
+        || source.contains(&format!("'{library_name}'")))
+    {
+      continue;
+    }
+    let barrel = package_barrel(&resolver, context, library_name, cache).await;
+    result.barrels.insert(library_name.clone(), barrel);
+  }
+  result
+}
+
+async fn package_barrel(
+  resolver: &Resolver,
+  context: &Utf8Path,
+  library_name: &str,
+  cache: &BarrelCache,
+) -> Result<Arc<PackageBarrel>, String> {
+  let mut reader = BarrelReader {
+    resolver,
+    context,
+    package_dir: Default::default(),
+    library_name,
+    has_exports: false,
+    file_dependencies: vec![],
+    missing_dependencies: vec![],
+  };
+  let entry = reader
+    .resolve(context, library_name)
+    .await
+    .ok_or_else(|| format!("Can't resolve \"{library_name}\""))?;
+  let ResolveResult::Resource(entry) = entry else {
+    return Err(format!("\"{library_name}\" is ignored"));
+  };
+  let description_data = entry
+    .description_data
+    .ok_or_else(|| format!("Can't find the package.json of \"{library_name}\""))?;
+  let package_dir = Utf8PathBuf::from_path_buf(description_data.path().to_path_buf())
+    .map_err(|path| format!("Invalid package directory {}", path.display()))?;
+
+  let version = description_data
+    .json()
+    .get("version")
+    .and_then(|version| version.as_str())
+    .map(ToString::to_string);
+  let key = (package_dir.clone(), version);
+  let fs = resolver.inner_fs();
+  if let Some(barrel) = cache.get(fs.as_ref(), &key).await {
+    return Ok(barrel);
+  }
+
+  // packages with `exports` may not expose their submodules, which are only imported through the
+  // subpaths resolved to them
+  reader.has_exports = description_data.json().get("exports").is_some();
+  reader.package_dir = package_dir.clone();
+  let exports = reader.read(&entry.path, 0).await?;
+  let mut file_timestamps = Vec::with_capacity(reader.file_dependencies.len());
+  for file in &reader.file_dependencies {
+    file_timestamps.push(file_timestamp(fs.as_ref(), file).await);
+  }
+  let barrel = Arc::new(PackageBarrel {
+    exports: Arc::new(exports),
+    file_dependencies: reader.file_dependencies,
+    missing_dependencies: reader.missing_dependencies,
+    file_timestamps,
+  });
+  cache.insert(key, barrel.clone());
+  Ok(barrel)
+}
+
+struct BarrelReader<'a> {
+  resolver: &'a Resolver,
+  /// The directory of the importer, where the requests of the package are resolved from.
+  context: &'a Utf8Path,
+  package_dir: Utf8PathBuf,
+  library_name: &'a str,
+  has_exports: bool,
+  file_dependencies: Vec<Utf8PathBuf>,
+  missing_dependencies: Vec<Utf8PathBuf>,
+}
+
+type ReadResult<'a> = Pin<Box<dyn Future<Output = Result<BarrelExports, String>> + Send + 'a>>;
+
+impl<'a> BarrelReader<'a> {
+  async fn resolve(&mut self, context: &Utf8Path, request: &str) -> Option<ResolveResult> {
+    let mut resolve_context = Default::default();
+    let result = self
+      .resolver
+      .resolve_with_context(context.as_std_path(), request, &mut resolve_context)
+      .await
+      .ok();
+    self.file_dependencies.extend(
+      resolve_context
+        .file_dependencies
+        .into_iter()
+        .filter_map(|path| Utf8PathBuf::from_path_buf(path).ok()),
+    );
+    self.missing_dependencies.extend(
+      resolve_context
+        .missing_dependencies
+        .into_iter()
+        .filter_map(|path| Utf8PathBuf::from_path_buf(path).ok()),
+    );
+    result
+  }
+
+  async fn resolve_file(&mut self, context: &Utf8Path, request: &str) -> Option<Utf8PathBuf> {
+    match self.resolve(context, request).await? {
+      ResolveResult::Resource(resource) => Some(resource.path),
+      ResolveResult::Ignored => None,
+    }
+  }
+
+  fn read<'b>(&'b mut self, file: &'b Utf8Path, depth: usize) -> ReadResult<'b>
+  where
+    'a: 'b,
+  {
+    Box::pin(async move {
+      let source = self
+        .resolver
+        .inner_fs()
+        .read(file)
+        .await
+        .map_err(|err| format!("Can't read {file}: {err}"))?;
+      let source = String::from_utf8_lossy(&source).into_owned();
+      self.file_dependencies.push(file.to_path_buf());
+      let syntax = match file.extension() {
+        Some("ts" | "mts") => Syntax::Typescript(TsSyntax::default()),
+        Some("tsx") => Syntax::Typescript(TsSyntax {
+          tsx: true,
+          ..Default::default()
+        }),
+        _ => Syntax::Es(EsSyntax {
+          jsx: true,
+          ..Default::default()
+        }),
+      };
+      let ast = JavaScriptCompiler::new()
+        .parse(
+          FileName::Real(file.to_path_buf().into_std_path_buf()),
+          source,
+          EsVersion::EsNext,
+          syntax,
+          IsModule::Bool(true),
+          None,
+        )
+        .map_err(|_| format!("Can't parse {file}"))?;
+      let Program::Module(module) = ast.get_inner_program() else {
+        return Ok(BarrelExports::default());
+      };
+      let module_reexports = collect_reexports(module);
+      let dir = file
+        .parent()
+        .map(Utf8Path::to_path_buf)
+        .unwrap_or_else(|| self.package_dir.clone());
+
+      let mut exports = BarrelExports::default();
+      // `export *` never re-exports `default`, and is shadowed by the explicit exports
+      for star_source in &module_reexports.star_sources {
+        if depth >= MAX_STAR_DEPTH || !star_source.starts_with('.') {
+          continue;
+        }
+        let Some(star_file) = self.resolve_file(&dir, star_source).await else {
+          continue;
+        };
+        let star_exports = self.read(&star_file, depth + 1).await?;
+        for (name, export) in star_exports {
+          if name != "default" {
+            exports.entry(name).or_insert(export);
+          }
+        }
+      }
+      for (name, export) in module_reexports.reexports {
+        let source = if export.source.starts_with('.') {
+          let Some(file) = self.resolve_file(&dir, &export.source).await else {
+            continue;
+          };
+          match self.request(&file).await {
+            Some(request) => request,
+            None => continue,
+          }
+        } else {
+          // a bare request is kept as is only if the importer resolves it to the same file as the
+          // barrel, e.g. a dependency of the package may not be reachable from the importer
+          let Some(file) = self.resolve_file(&dir, &export.source).await else {
+            continue;
+          };
+          if self
+            .resolve_file(self.context, &export.source)
+            .await
+            .as_deref()
+            != Some(&*file)
+          {
+            continue;
+          }
+          export.source
+        };
+        exports.insert(
+          name,
+          BarrelExport {
+            source,
+            imported: export.imported,
+          },
+        );
+      }
+      // names declared by a module reached through `export *` are imported from that module
+      if depth > 0 {
+        let Some(request) = self.request(file).await else {
+          return Ok(exports);
+        };
+        for name in module_reexports.locals {
+          if name == "default" {
+            continue;
+          }
+          exports.insert(
+            name.clone(),
+            BarrelExport {
+              source: request.clone(),
+              imported: BarrelImport::Named(name),
+            },
+          );
+        }
+      } else {
+        // names declared by the barrel itself can't be rewritten
+        for name in &module_reexports.locals {
+          exports.remove(name);
+        }
+      }
+      Ok(exports)
+    })
+  }
+
+  /// The request importing the file from the package, the names exported by a file which can't be
+  /// imported from the package are kept as is.
+  async fn request(&mut self, file: &Utf8Path) -> Option<String> {
+    let relative = file.strip_prefix(&self.package_dir).ok()?;
+    let relative = relative
+      .components()
+      .map(|component| component.as_str())
+      .collect::<Vec<_>>()
+      .join("/");
+    let request = format!("{}/{relative}", self.library_name);
+    if !self.has_exports {
+      return Some(request);
+    }
+    // the subpath of the file, with or without the extension
+    let mut candidates = vec![request.clone()];
+    if let Some(request) = file
+      .extension()
+      .and_then(|extension| request.strip_suffix(&format!(".{extension}")))
+    {
+      candidates.push(request.to_string());
+    }
+    for candidate in candidates {
+      if self.resolve_file(self.context, &candidate).await.as_deref() == Some(file) {
+        return Some(candidate);
+      }
+    }
+    None
+  }
+}
@@ -0,0 +1,111 @@
+use swc_core::{
+  atoms::Atom,
+  ecma::{
+    ast::{
+      CallExpr, Callee, Expr, JSXAttrName, JSXAttrOrSpread, JSXAttrValue, JSXElementName, JSXExpr,
+      JSXOpeningElement, Lit, MemberProp, Program,
+    },
+    visit::{Visit, VisitWith},
+  },
+};
+
+use crate::options::CollectI18nMessagesOptions;
+
+struct I18nMessagesCollector<'a> {
+  options: &'a CollectI18nMessagesOptions,
+  ids: Vec<String>,
+}
+
+// only static ids can be extracted, `t(key)` with a dynamic key is skipped
+fn static_string(expr: &Expr) -> Option<String> {
+  match expr {
+    Expr::Lit(Lit::Str(str)) => Some(str.value.to_atom_lossy().to_string()),
+    Expr::Tpl(tpl) if tpl.exprs.is_empty() => tpl
+      .quasis
+      .first()
+      .and_then(|quasi| quasi.cooked.as_ref())
+      .map(|cooked| cooked.to_atom_lossy().to_string()),
+    Expr::Paren(paren) => static_string(&paren.expr),
+    _ => None,
+  }
+}
+
+impl I18nMessagesCollector<'_> {
+  fn is_function(&self, name: &Atom) -> bool {
+    self.options.functions.contains(name)
+  }
+
+  fn is_component(&self, name: &Atom) -> bool {
+    self.options.components.contains(name)
+  }
+}
+
+impl Visit for I18nMessagesCollector<'_> {
+  fn visit_call_expr(&mut self, node: &CallExpr) {
+    let is_message_call = match &node.callee {
+      Callee::Expr(callee) => match callee.as_ref() {
+        // t('key')
+        Expr::Ident(ident) => self.is_function(&ident.sym),
+        // i18n.t('key')
+        Expr::Member(member) => {
+          matches!(&member.prop, MemberProp::Ident(prop) if self.is_function(&prop.sym))
+        }
+        _ => false,
+      },
+      _ => false,
+    };
+    if is_message_call
+      && let Some(arg) = node.args.first()
+      && arg.spread.is_none()
+      && let Some(id) = static_string(&arg.expr)
+    {
+      self.ids.push(id);
+    }
+    node.visit_children_with(self);
+  }
+
+  fn visit_jsx_opening_element(&mut self, node: &JSXOpeningElement) {
+    // <FormattedMessage id="key" />
+    if let JSXElementName::Ident(name) = &node.name
+      && self.is_component(&name.sym)
+    {
+      for attr in &node.attrs {
+        let JSXAttrOrSpread::JSXAttr(attr) = attr else {
+          continue;
+        };
+        if !matches!(&attr.name, JSXAttrName::Ident(name) if name.sym == "id") {
+          continue;
+        }
+        let id = match &attr.value {
+          Some(JSXAttrValue::Str(str)) => Some(str.value.to_atom_lossy().to_string()),
+          Some(JSXAttrValue::JSXExprContainer(container)) => match &container.expr {
+            JSXExpr::Expr(expr) => static_string(expr),
+            JSXExpr::JSXEmptyExpr(_) => None,
+          },
+          _ => None,
+        };
+        if let Some(id) = id {
+          self.ids.push(id);
+        }
+      }
+    }
+    node.visit_children_with(self);
+  }
+}
+
+/// Collects the static message ids of the i18n functions and components used by the module,
+/// the result is sorted and deduplicated.
+pub fn collect_i18n_messages(
+  program: &Program,
+  options: &CollectI18nMessagesOptions,
+) -> Vec<String> {
+  let mut collector = I18nMessagesCollector {
+    options,
+    ids: Vec::new(),
+  };
+  program.visit_with(&mut collector);
+  let mut ids = collector.ids;
+  ids.sort_unstable();
+  ids.dedup();
+  ids
+}
@@ -1,3 +1,5 @@
+mod barrel;
+mod collect_i18n_messages;
 mod collect_ts_info;
 mod options;
 mod plugin;
@@ -8,8 +10,11 @@ use std::{default::Default, path::Path};
 use options::SwcCompilerOptionsWithAdditional;
 pub use options::SwcLoaderJsOptions;
 pub use plugin::SwcLoaderPlugin;
-use rspack_cacheable::{cacheable, cacheable_dyn};
-use rspack_core::{COLLECTED_TYPESCRIPT_INFO_PARSE_META_KEY, Mode, RunnerContext};
+use rspack_cacheable::{cacheable, cacheable_dyn, with::Skip};
+use rspack_core::{
+  COLLECTED_I18N_MESSAGES_PARSE_META_KEY, COLLECTED_TYPESCRIPT_INFO_PARSE_META_KEY, Mode,
+  RunnerContext,
+};
 use rspack_error::{Diagnostic, Error, Result};
 use rspack_javascript_compiler::{JavaScriptCompiler, TransformOutput};
 use rspack_loader_runner::{Identifier, Loader, LoaderContext};
@@ -23,7 +28,11 @@ use swc_core::{
   common::{FileName, SyntaxContext},
 };
 
-use crate::collect_ts_info::collect_typescript_info;
+use crate::{
+  barrel::{BarrelCache, ResolvedBarrels, resolve_barrels},
+  collect_i18n_messages::collect_i18n_messages,
+  collect_ts_info::collect_typescript_info,
+};
 
 #[cacheable]
 #[derive(Debug)]
@@ -32,6 +41,9 @@ pub struct SwcLoader {
   identifier: Identifier,
   #[cfg_attr(allocative, allocative(skip))]
   options_with_additional: SwcCompilerOptionsWithAdditional,
+  #[cacheable(with=Skip)]
+  #[cfg_attr(allocative, allocative(skip))]
+  barrel_cache: BarrelCache,
 }
 
 impl SwcLoader {
@@ -39,6 +51,7 @@ impl SwcLoader {
     Ok(Self {
       identifier: SWC_LOADER_IDENTIFIER.into(),
       options_with_additional: raw_options.try_into()?,
+      barrel_cache: Default::default(),
     })
   }
 
@@ -50,7 +63,28 @@ impl SwcLoader {
     self
   }
 
-  fn loader_impl(&self, loader_context: &mut LoaderContext<RunnerContext>) -> Result<()> {
+  async fn resolve_barrels(
+    &self,
+    loader_context: &LoaderContext<RunnerContext>,
+  ) -> ResolvedBarrels {
+    let Some(options) = &self.options_with_additional.rspack_experiments.import else {
+      return Default::default();
+    };
+    let Some(content) = loader_context.content() else {
+      return Default::default();
+    };
+    if !options.iter().any(|options| options.is_barrel()) {
+      return Default::default();
+    }
+    let source = String::from_utf8_lossy(content.as_bytes());
+    resolve_barrels(loader_context, &source, options, &self.barrel_cache).await
+  }
+
+  fn loader_impl(
+    &self,
+    loader_context: &mut LoaderContext<RunnerContext>,
+    mut resolved_barrels: ResolvedBarrels,
+  ) -> Result<()> {
     let resource_path = loader_context
       .resource_path()
       .map(|p| p.to_path_buf())
@@ -111,6 +145,7 @@ impl SwcLoader {
     let is_typescript =
       matches!(swc_options.config.jsc.syntax, Some(syntax) if syntax.typescript());
     let mut collected_ts_info = None;
+    let mut i18n_message_ids = None;
 
     let TransformOutput {
       code,
@@ -122,14 +157,14 @@ impl SwcLoader {
       swc_options,
       Some(loader_context.context.source_map_kind),
       |program, unresolved_mark| {
+        let rspack_experiments = &self.options_with_additional.rspack_experiments;
+        if let Some(options) = &rspack_experiments.collect_i18n_messages {
+          i18n_message_ids = Some(collect_i18n_messages(program, options));
+        }
         if !is_typescript {
           return;
         }
-        let Some(options) = &self
-          .options_with_additional
-          .rspack_experiments
-          .collect_typescript_info
-        else {
+        let Some(options) = &rspack_experiments.collect_typescript_info else {
           return;
         };
         collected_ts_info = Some(collect_typescript_info(
@@ -138,13 +173,32 @@ impl SwcLoader {
           options,
         ));
       },
-      |_| transformer::transform(&self.options_with_additional.rspack_experiments),
+      |program| {
+        let rspack_experiments = &self.options_with_additional.rspack_experiments;
+        transformer::transform(rspack_experiments, resolved_barrels.select(program))
+      },
     )?;
 
     for diagnostic in diagnostics {
       loader_context.emit_diagnostic(Error::warning(diagnostic).into());
     }
 
+    for warning in resolved_barrels.warnings {
+      loader_context.emit_diagnostic(Diagnostic::warn(SWC_LOADER_IDENTIFIER.to_string(), warning));
+    }
+    loader_context.file_dependencies.extend(
+      resolved_barrels
+        .file_dependencies
+        .into_iter()
+        .map(|path| path.into_std_path_buf()),
+    );
+    loader_context.missing_dependencies.extend(
+      resolved_barrels
+        .missing_dependencies
+        .into_iter()
+        .map(|path| path.into_std_path_buf()),
+    );
+
     if let Some(collected_ts_info) = collected_ts_info {
       loader_context.parse_meta.insert(
         COLLECTED_TYPESCRIPT_INFO_PARSE_META_KEY.to_string(),
@@ -152,6 +206,13 @@ impl SwcLoader {
       );
     }
 
+    if let Some(i18n_message_ids) = i18n_message_ids {
+      loader_context.parse_meta.insert(
+        COLLECTED_I18N_MESSAGES_PARSE_META_KEY.to_string(),
+        Box::new(i18n_message_ids),
+      );
+    }
+
     // When compiling target modules, SWC retrieves the source map via sourceMapUrl.
     // The sources paths in the source map are relative to the target module. We need to resolve these paths
     // to absolute paths using the resource path to avoid incorrect project path references.
@@ -190,14 +251,35 @@ impl Loader<RunnerContext> for SwcLoader {
     self.identifier
   }
 
+  fn cache_key(&self, loader_context: &LoaderContext<RunnerContext>) -> Option<String> {
+    let swc_options = &self.options_with_additional.swc_options;
+    // wasm plugins are loaded from files, which are not part of the key
+    if swc_options.config.jsc.experimental.plugins.is_some() {
+      return None;
+    }
+    // barrel files are read from other files, which are not part of the key either
+    if let Some(import) = &self.options_with_additional.rspack_experiments.import
+      && import.iter().any(|options| options.is_barrel())
+    {
+      return None;
+    }
+    Some(format!(
+      "{SWC_LOADER_IDENTIFIER}|{:?}|{:?}|{}",
+      loader_context.context.options.mode,
+      loader_context.context.source_map_kind,
+      self.options_with_additional.raw_options()
+    ))
+  }
+
   #[tracing::instrument("loader:builtin-swc", skip_all, fields(
     perfetto.track_name = "loader:builtin-swc",
     perfetto.process_name = "Loader Analysis",
     resource =loader_context.resource(),
   ))]
   async fn run(&self, loader_context: &mut LoaderContext<RunnerContext>) -> Result<()> {
+    let resolved_barrels = self.resolve_barrels(loader_context).await;
     #[allow(unused_mut)]
-    let mut inner = || self.loader_impl(loader_context);
+    let mut inner = || self.loader_impl(loader_context, resolved_barrels);
     #[cfg(all(debug_assertions, not(target_family = "wasm")))]
     {
       // Adjust stack to avoid stack overflow.
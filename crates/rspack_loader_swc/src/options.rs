@@ -5,9 +5,12 @@ use rspack_cacheable::{
 use rspack_swc_plugin_import::{ImportOptions, RawImportOptions};
 use serde::Deserialize;
 use swc_config::{file_pattern::FilePattern, types::BoolConfig};
-use swc_core::base::config::{
-  Config, ErrorConfig, FileMatcher, InputSourceMap, IsModule, JscConfig, ModuleConfig, Options,
-  SourceMapsConfig,
+use swc_core::{
+  atoms::Atom,
+  base::config::{
+    Config, ErrorConfig, FileMatcher, InputSourceMap, IsModule, JscConfig, ModuleConfig, Options,
+    SourceMapsConfig,
+  },
 };
 
 #[derive(Default, Deserialize, Debug)]
@@ -15,6 +18,7 @@ use swc_core::base::config::{
 pub struct RawRspackExperiments {
   pub import: Option<Vec<RawImportOptions>>,
   pub collect_type_script_info: Option<RawCollectTypeScriptInfoOptions>,
+  pub collect_i18n_messages: Option<RawCollectI18nMessagesOptions>,
 }
 
 #[derive(Default, Deserialize, Debug)]
@@ -24,10 +28,18 @@ pub struct RawCollectTypeScriptInfoOptions {
   pub exported_enum: Option<String>,
 }
 
+#[derive(Default, Deserialize, Debug)]
+#[serde(rename_all = "camelCase", default)]
+pub struct RawCollectI18nMessagesOptions {
+  pub functions: Option<Vec<String>>,
+  pub components: Option<Vec<String>>,
+}
+
 #[derive(Default, Debug)]
 pub(crate) struct RspackExperiments {
   pub(crate) import: Option<Vec<ImportOptions>>,
   pub(crate) collect_typescript_info: Option<CollectTypeScriptInfoOptions>,
+  pub(crate) collect_i18n_messages: Option<CollectI18nMessagesOptions>,
 }
 
 #[derive(Default, Debug)]
@@ -36,6 +48,12 @@ pub(crate) struct CollectTypeScriptInfoOptions {
   pub(crate) exported_enum: Option<CollectingEnumKind>,
 }
 
+#[derive(Debug)]
+pub(crate) struct CollectI18nMessagesOptions {
+  pub(crate) functions: Vec<Atom>,
+  pub(crate) components: Vec<Atom>,
+}
+
 #[derive(Default, Debug)]
 pub(crate) enum CollectingEnumKind {
   All,
@@ -50,6 +68,22 @@ impl From<RawRspackExperiments> for RspackExperiments {
         .import
         .map(|i| i.into_iter().map(|v| v.into()).collect()),
       collect_typescript_info: value.collect_type_script_info.map(|v| v.into()),
+      collect_i18n_messages: value.collect_i18n_messages.map(|v| v.into()),
+    }
+  }
+}
+
+impl From<RawCollectI18nMessagesOptions> for CollectI18nMessagesOptions {
+  fn from(value: RawCollectI18nMessagesOptions) -> Self {
+    Self {
+      functions: value.functions.map_or_else(
+        || vec!["t".into()],
+        |v| v.into_iter().map(Atom::from).collect(),
+      ),
+      components: value.components.map_or_else(
+        || vec!["FormattedMessage".into()],
+        |v| v.into_iter().map(Atom::from).collect(),
+      ),
     }
   }
 }
@@ -125,6 +159,12 @@ pub(crate) struct SwcCompilerOptionsWithAdditional {
   pub(crate) rspack_experiments: RspackExperiments,
 }
 
+impl SwcCompilerOptionsWithAdditional {
+  pub(crate) fn raw_options(&self) -> &str {
+    &self.raw_options
+  }
+}
+
 impl AsRefStrConverter for SwcCompilerOptionsWithAdditional {
   fn as_str(&self) -> &str {
     &self.raw_options
@@ -1,7 +1,4 @@
-use std::{
-  borrow::Cow,
-  sync::{Arc, LazyLock},
-};
+use std::{borrow::Cow, sync::Arc};
 
 use rspack_core::{
   BoxLoader, Context, ModuleRuleUseLoader, NormalModuleFactoryResolveLoader, Plugin, Resolver,
@@ -13,13 +10,19 @@ use tokio::sync::RwLock;
 
 use crate::{SWC_LOADER_IDENTIFIER, SwcLoader};
 
+// Keyed by the loader request and options. The loaders are not shared between compilers, as they
+// hold the caches of a compiler
+type SwcLoaderCache = RwLock<FxHashMap<(Cow<'static, str>, Cow<'static, str>), Arc<SwcLoader>>>;
+
 #[plugin]
 #[derive(Debug)]
-pub struct SwcLoaderPlugin;
+pub struct SwcLoaderPlugin {
+  loaders: SwcLoaderCache,
+}
 
 impl SwcLoaderPlugin {
   pub fn new() -> Self {
-    Self::new_inner()
+    Self::new_inner(Default::default())
   }
 }
 
@@ -43,9 +46,6 @@ impl Plugin for SwcLoaderPlugin {
   }
 }
 
-type SwcLoaderCache<'a> = LazyLock<RwLock<FxHashMap<(Cow<'a, str>, Cow<'a, str>), Arc<SwcLoader>>>>;
-static SWC_LOADER_CACHE: SwcLoaderCache = LazyLock::new(|| RwLock::new(FxHashMap::default()));
-
 #[plugin_hook(NormalModuleFactoryResolveLoader for SwcLoaderPlugin)]
 pub(crate) async fn resolve_loader(
   &self,
@@ -57,7 +57,8 @@ pub(crate) async fn resolve_loader(
   let options = l.options.as_deref().unwrap_or("{}");
 
   if loader_request.starts_with(SWC_LOADER_IDENTIFIER) {
-    if let Some(loader) = SWC_LOADER_CACHE
+    if let Some(loader) = self
+      .loaders
       .read()
       .await
       .get(&(Cow::Borrowed(loader_request), Cow::Borrowed(options)))
@@ -71,7 +72,7 @@ pub(crate) async fn resolve_loader(
         .with_identifier(loader_request.as_str().into()),
     );
 
-    SWC_LOADER_CACHE.write().await.insert(
+    self.loaders.write().await.insert(
       (
         Cow::Owned(loader_request.to_owned()),
         Cow::Owned(options.to_owned()),
@@ -1,4 +1,8 @@
+use std::sync::Arc;
+
 use either::Either;
+use rspack_swc_plugin_import::BarrelExports;
+use rustc_hash::FxHashMap;
 use swc_core::ecma::ast::{Pass, noop_pass};
 
 use crate::options::RspackExperiments;
@@ -22,8 +26,11 @@ macro_rules! either {
 }
 
 #[allow(clippy::too_many_arguments)]
-pub(crate) fn transform(rspack_experiments: &RspackExperiments) -> impl Pass + '_ {
+pub(crate) fn transform(
+  rspack_experiments: &RspackExperiments,
+  barrels: FxHashMap<String, Arc<BarrelExports>>,
+) -> impl Pass + '_ {
   either!(rspack_experiments.import, |options| {
-    rspack_swc_plugin_import::plugin_import(options)
+    rspack_swc_plugin_import::plugin_import_with_barrels(options, barrels)
   })
 }
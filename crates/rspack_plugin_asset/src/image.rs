@@ -0,0 +1,692 @@
+use std::{hash::Hash, io::Cursor, sync::Arc};
+
+use image::{
+  AnimationDecoder, DynamicImage, ImageDecoder, ImageFormat as DecodeFormat, ImageReader,
+  codecs::{
+    avif::AvifEncoder,
+    gif::GifDecoder,
+    jpeg::JpegEncoder,
+    png::{CompressionType, FilterType as PngFilterType, PngEncoder},
+    webp::WebPEncoder,
+  },
+  imageops::FilterType,
+};
+use rspack_cacheable::{cacheable, from_bytes, to_bytes};
+use rspack_core::{
+  ResourceData,
+  cache::persistent::{ScopedCache, storage::Storage},
+};
+use rspack_error::{Result, ToStringResultToRspackResultExt, error};
+use rspack_hash::{HashDigest, HashFunction, RspackHash};
+
+const CACHE_SCOPE: &str = "asset_image";
+// bump it when the output of the optimization changes
+const CACHE_VERSION: &str = "1";
+
+const DEFAULT_QUALITY: u8 = 80;
+const AVIF_SPEED: u8 = 6;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub(crate) enum ImageFormat {
+  Png,
+  Jpeg,
+  Gif,
+  Svg,
+  Webp,
+  Avif,
+}
+
+impl ImageFormat {
+  fn from_extension(extension: &str) -> Option<Self> {
+    [
+      ("png", Self::Png),
+      ("jpg", Self::Jpeg),
+      ("jpeg", Self::Jpeg),
+      ("gif", Self::Gif),
+      ("svg", Self::Svg),
+    ]
+    .into_iter()
+    .find_map(|(ext, format)| extension.eq_ignore_ascii_case(ext).then_some(format))
+  }
+
+  fn from_query_value(value: &str) -> Result<Self> {
+    match value {
+      "png" => Ok(Self::Png),
+      "jpg" | "jpeg" => Ok(Self::Jpeg),
+      "webp" => Ok(Self::Webp),
+      "avif" => Ok(Self::Avif),
+      _ => Err(error!(
+        "Unsupported image format \"{value}\", expected one of \"png\", \"jpeg\", \"webp\" and \"avif\""
+      )),
+    }
+  }
+
+  pub(crate) fn extension(&self) -> &'static str {
+    match self {
+      Self::Png => "png",
+      Self::Jpeg => "jpg",
+      Self::Gif => "gif",
+      Self::Svg => "svg",
+      Self::Webp => "webp",
+      Self::Avif => "avif",
+    }
+  }
+
+  pub(crate) fn mimetype(&self) -> &'static str {
+    match self {
+      Self::Png => "image/png",
+      Self::Jpeg => "image/jpeg",
+      Self::Gif => "image/gif",
+      Self::Svg => "image/svg+xml",
+      Self::Webp => "image/webp",
+      Self::Avif => "image/avif",
+    }
+  }
+
+  fn decode_format(&self) -> Option<DecodeFormat> {
+    match self {
+      Self::Png => Some(DecodeFormat::Png),
+      Self::Jpeg => Some(DecodeFormat::Jpeg),
+      Self::Gif => Some(DecodeFormat::Gif),
+      Self::Webp => Some(DecodeFormat::WebP),
+      Self::Avif => Some(DecodeFormat::Avif),
+      Self::Svg => None,
+    }
+  }
+}
+
+/// The transformation of an image requested by the query of the resource,
+/// e.g. `./image.png?format=webp&width=640`.
+#[derive(Debug, Default, Clone, PartialEq, Eq, Hash)]
+pub(crate) struct ImageTransform {
+  format: Option<ImageFormat>,
+  width: Option<u32>,
+  height: Option<u32>,
+  quality: Option<u8>,
+}
+
+impl ImageTransform {
+  fn from_query(query: &str) -> Result<Self> {
+    let mut transform = Self::default();
+    let query = query.strip_prefix('?').unwrap_or(query);
+    for (key, value) in query
+      .split('&')
+      .filter_map(|parameter| parameter.split_once('='))
+    {
+      match key {
+        "format" => transform.format = Some(ImageFormat::from_query_value(value)?),
+        "width" => transform.width = Some(parse_parameter(key, value)?),
+        "height" => transform.height = Some(parse_parameter(key, value)?),
+        "quality" => {
+          let quality = parse_parameter::<u8>(key, value)?;
+          if !(1..=100).contains(&quality) {
+            return Err(error!(
+              "Invalid image quality \"{value}\", expected a number between 1 and 100"
+            ));
+          }
+          transform.quality = Some(quality);
+        }
+        // the query may be used by other loaders or plugins
+        _ => {}
+      }
+    }
+    Ok(transform)
+  }
+
+  fn is_lossless(&self) -> bool {
+    self.format.is_none() && self.width.is_none() && self.height.is_none()
+  }
+}
+
+fn parse_parameter<T: std::str::FromStr>(key: &str, value: &str) -> Result<T> {
+  value
+    .parse::<T>()
+    .map_err(|_| error!("Invalid image {key} \"{value}\" in the resource query"))
+}
+
+/// An image asset to be optimized.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub(crate) struct ImageRequest {
+  source_format: ImageFormat,
+  transform: ImageTransform,
+}
+
+impl ImageRequest {
+  /// Returns `None` if the resource is not an image supported by the optimization.
+  pub(crate) fn from_resource_data(resource_data: &ResourceData) -> Result<Option<Self>> {
+    let Some(source_format) = resource_data
+      .path()
+      .and_then(|path| path.extension())
+      .and_then(ImageFormat::from_extension)
+    else {
+      return Ok(None);
+    };
+    let transform = resource_data
+      .query()
+      .map(ImageTransform::from_query)
+      .transpose()?
+      .unwrap_or_default();
+    if source_format == ImageFormat::Svg && !transform.is_lossless() {
+      return Err(error!(
+        "SVG images can't be resized or converted to another format"
+      ));
+    }
+    // only the lossless encoding of webp is supported
+    if transform.format.unwrap_or(source_format) == ImageFormat::Webp && transform.quality.is_some()
+    {
+      return Err(error!(
+        "The quality of WebP images can't be set, they are always encoded losslessly"
+      ));
+    }
+    Ok(Some(Self {
+      source_format,
+      transform,
+    }))
+  }
+
+  /// The key of the optimized image in [`ImageCache`].
+  pub(crate) fn cache_key(&self, content: &[u8]) -> Vec<u8> {
+    let mut hasher = RspackHash::new(&HashFunction::Xxhash64);
+    CACHE_VERSION.hash(&mut hasher);
+    self.hash(&mut hasher);
+    content.hash(&mut hasher);
+    hasher
+      .digest(&HashDigest::Hex)
+      .encoded()
+      .as_bytes()
+      .to_vec()
+  }
+
+  /// The format of the output image if it's different from the source image.
+  pub(crate) fn converted_format(&self) -> Option<ImageFormat> {
+    self
+      .transform
+      .format
+      .filter(|format| *format != self.source_format)
+  }
+}
+
+/// The optimized image saved in persistent cache, `None` if the original image is kept.
+#[cacheable]
+struct CachedImage {
+  content: Option<Vec<u8>>,
+}
+
+/// The images optimized by previous builds, keyed by the content hash of the
+/// original image and the requested transformation.
+#[derive(Debug)]
+pub(crate) struct ImageCache(ScopedCache);
+
+impl Default for ImageCache {
+  fn default() -> Self {
+    Self(ScopedCache::new(CACHE_SCOPE))
+  }
+}
+
+impl ImageCache {
+  /// Removes the entries of the images which are not requested by the modules anymore.
+  ///
+  /// Only the changed modules are generated again by a rebuild, so the images of all modules
+  /// are marked as used before removing the unused entries.
+  pub(crate) async fn prune(
+    &self,
+    storage: &Arc<dyn Storage>,
+    keys: impl IntoIterator<Item = Vec<u8>>,
+  ) -> Result<()> {
+    self.0.recovery(storage).await?;
+    for key in keys {
+      self.0.get(&key);
+    }
+    self.0.remove_unused(storage);
+    Ok(())
+  }
+}
+
+/// Optimizes the image, returns `None` if the original image should be kept.
+pub(crate) async fn optimize_image(
+  content: &[u8],
+  request: &ImageRequest,
+  cache: Option<&ImageCache>,
+  storage: Option<&Arc<dyn Storage>>,
+) -> Result<Option<Vec<u8>>> {
+  let Some((cache, storage)) = cache.zip(storage) else {
+    return optimize_image_uncached(content, request);
+  };
+  cache.0.recovery(storage).await?;
+
+  let key = request.cache_key(content);
+  if let Some(cached) = cache
+    .0
+    .get(&key)
+    .and_then(|value| from_bytes::<CachedImage, ()>(&value, &()).ok())
+  {
+    return Ok(cached.content);
+  }
+
+  let optimized = optimize_image_uncached(content, request)?;
+  let cached = CachedImage {
+    content: optimized.clone(),
+  };
+  cache.0.set(
+    storage,
+    key,
+    to_bytes(&cached, &()).expect("should serialize optimized image"),
+  );
+  Ok(optimized)
+}
+
+fn optimize_image_uncached(content: &[u8], request: &ImageRequest) -> Result<Option<Vec<u8>>> {
+  if !request.transform.is_lossless() {
+    return transform_image(content, request).map(Some);
+  }
+  let optimized = match request.source_format {
+    ImageFormat::Png => {
+      oxipng::optimize_from_memory(content, &oxipng::Options::from_preset(2)).ok()
+    }
+    ImageFormat::Jpeg => strip_jpeg_metadata(content),
+    ImageFormat::Gif => strip_gif_extensions(content),
+    ImageFormat::Svg => std::str::from_utf8(content)
+      .ok()
+      .map(|svg| minify_svg(svg).into_bytes()),
+    ImageFormat::Webp | ImageFormat::Avif => None,
+  };
+  // keep the original image if it's smaller, e.g. it's optimized already
+  Ok(optimized.filter(|optimized| optimized.len() < content.len()))
+}
+
+fn transform_image(content: &[u8], request: &ImageRequest) -> Result<Vec<u8>> {
+  let ImageTransform {
+    format,
+    width,
+    height,
+    quality,
+  } = &request.transform;
+  let decode_format = request
+    .source_format
+    .decode_format()
+    .expect("should not transform svg images");
+  if request.source_format == ImageFormat::Gif && is_animated_gif(content)? {
+    return Err(error!(
+      "Animated GIF images can't be resized or converted to another format"
+    ));
+  }
+  let mut decoder = ImageReader::with_format(Cursor::new(content), decode_format)
+    .into_decoder()
+    .to_rspack_result_with_message(|e| format!("Failed to decode the image: {e}"))?;
+  // the EXIF orientation is applied to the pixels as the metadata is not kept in the output
+  let orientation = decoder
+    .orientation()
+    .to_rspack_result_with_message(|e| format!("Failed to decode the image: {e}"))?;
+  let mut image = DynamicImage::from_decoder(decoder)
+    .to_rspack_result_with_message(|e| format!("Failed to decode the image: {e}"))?;
+  image.apply_orientation(orientation);
+  let image = match (width, height) {
+    (Some(width), Some(height)) => image.resize_exact(*width, *height, FilterType::Lanczos3),
+    (Some(width), None) => image.resize(*width, u32::MAX, FilterType::Lanczos3),
+    (None, Some(height)) => image.resize(u32::MAX, *height, FilterType::Lanczos3),
+    (None, None) => image,
+  };
+  let quality = quality.unwrap_or(DEFAULT_QUALITY);
+
+  let mut output = Cursor::new(Vec::new());
+  let encoded = match format.unwrap_or(request.source_format) {
+    ImageFormat::Png => image.write_with_encoder(PngEncoder::new_with_quality(
+      &mut output,
+      CompressionType::Best,
+      PngFilterType::Adaptive,
+    )),
+    // jpeg doesn't support the alpha channel
+    ImageFormat::Jpeg => DynamicImage::ImageRgb8(image.to_rgb8())
+      .write_with_encoder(JpegEncoder::new_with_quality(&mut output, quality)),
+    // only the lossless encoding of webp is supported
+    ImageFormat::Webp => image.write_with_encoder(WebPEncoder::new_lossless(&mut output)),
+    ImageFormat::Avif => image.write_with_encoder(AvifEncoder::new_with_speed_quality(
+      &mut output,
+      AVIF_SPEED,
+      quality,
+    )),
+    ImageFormat::Gif => image.write_to(&mut output, DecodeFormat::Gif),
+    ImageFormat::Svg => unreachable!("should not transform svg images"),
+  };
+  encoded.to_rspack_result_with_message(|e| format!("Failed to encode the image: {e}"))?;
+  Ok(output.into_inner())
+}
+
+fn is_animated_gif(content: &[u8]) -> Result<bool> {
+  let decoder = GifDecoder::new(Cursor::new(content))
+    .to_rspack_result_with_message(|e| format!("Failed to decode the image: {e}"))?;
+  Ok(decoder.into_frames().take(2).count() > 1)
+}
+
+/// Removes the comments and the XMP and Photoshop metadata of a JPEG image,
+/// the segments affecting the rendering such as EXIF orientation and ICC profile are kept.
+fn strip_jpeg_metadata(content: &[u8]) -> Option<Vec<u8>> {
+  const SOI: u8 = 0xD8;
+  const SOS: u8 = 0xDA;
+  const APP1: u8 = 0xE1;
+  const APP13: u8 = 0xED;
+  const COM: u8 = 0xFE;
+  const XMP_IDENTIFIER: &[u8] = b"http://ns.adobe.com/xap/1.0/\0";
+
+  if content.get(..2)? != [0xFF, SOI] {
+    return None;
+  }
+  let mut output = Vec::with_capacity(content.len());
+  output.extend_from_slice(&content[..2]);
+  let mut offset = 2;
+  loop {
+    if *content.get(offset)? != 0xFF {
+      return None;
+    }
+    let marker = *content.get(offset + 1)?;
+    if marker == SOS {
+      // the entropy-coded data and the rest segments are copied as is
+      output.extend_from_slice(&content[offset..]);
+      return Some(output);
+    }
+    let length =
+      u16::from_be_bytes([*content.get(offset + 2)?, *content.get(offset + 3)?]) as usize;
+    if length < 2 {
+      return None;
+    }
+    let segment = content.get(offset..offset + 2 + length)?;
+    let data = &segment[4..];
+    let removable =
+      marker == COM || marker == APP13 || (marker == APP1 && data.starts_with(XMP_IDENTIFIER));
+    if !removable {
+      output.extend_from_slice(segment);
+    }
+    offset += 2 + length;
+  }
+}
+
+/// Removes the comment extensions and the application extensions other than
+/// the animation loop ones of a GIF image.
+fn strip_gif_extensions(content: &[u8]) -> Option<Vec<u8>> {
+  const EXTENSION: u8 = 0x21;
+  const IMAGE: u8 = 0x2C;
+  const TRAILER: u8 = 0x3B;
+  const COMMENT_LABEL: u8 = 0xFE;
+  const APPLICATION_LABEL: u8 = 0xFF;
+  const LOOP_IDENTIFIERS: [&[u8]; 2] = [b"NETSCAPE2.0", b"ANIMEXTS1.0"];
+
+  fn color_table_size(flags: u8) -> usize {
+    if flags & 0x80 == 0 {
+      0
+    } else {
+      3 * (1 << ((flags & 0x07) + 1))
+    }
+  }
+
+  // returns the offset after the sub-blocks starting at the offset
+  fn skip_sub_blocks(content: &[u8], mut offset: usize) -> Option<usize> {
+    loop {
+      let size = *content.get(offset)? as usize;
+      offset += 1 + size;
+      if size == 0 {
+        return Some(offset);
+      }
+    }
+  }
+
+  if !content.starts_with(b"GIF87a") && !content.starts_with(b"GIF89a") {
+    return None;
+  }
+  // header and logical screen descriptor
+  let mut offset = 13 + color_table_size(*content.get(10)?);
+  let mut output = Vec::with_capacity(content.len());
+  output.extend_from_slice(content.get(..offset)?);
+  loop {
+    let start = offset;
+    match *content.get(offset)? {
+      EXTENSION => {
+        let label = *content.get(offset + 1)?;
+        offset = skip_sub_blocks(content, offset + 2)?;
+        let removable = match label {
+          COMMENT_LABEL => true,
+          APPLICATION_LABEL => {
+            let identifier = content.get(start + 3..start + 14)?;
+            !LOOP_IDENTIFIERS.contains(&identifier)
+          }
+          _ => false,
+        };
+        if !removable {
+          output.extend_from_slice(&content[start..offset]);
+        }
+      }
+      IMAGE => {
+        let flags = *content.get(offset + 9)?;
+        // image descriptor, local color table and LZW minimum code size
+        offset += 10 + color_table_size(flags) + 1;
+        offset = skip_sub_blocks(content, offset)?;
+        output.extend_from_slice(content.get(start..offset)?);
+      }
+      TRAILER => {
+        output.push(TRAILER);
+        return Some(output);
+      }
+      _ => return None,
+    }
+  }
+}
+
+// the whitespace in these elements is meaningful
+const WHITESPACE_PRESERVED_ELEMENTS: [&str; 6] = [
+  "text",
+  "tspan",
+  "textPath",
+  "style",
+  "script",
+  "foreignObject",
+];
+
+/// Removes the comments, the XML declaration, the doctype, the metadata and the
+/// indentation between elements of an SVG image.
+fn minify_svg(svg: &str) -> String {
+  let mut output = String::with_capacity(svg.len());
+  let mut rest = svg;
+  let mut preserved_depth = 0usize;
+  while !rest.is_empty() {
+    if let Some(comment) = rest.strip_prefix("<!--") {
+      let end = comment.find("-->").map_or(comment.len(), |end| end + 3);
+      rest = &comment[end..];
+    } else if rest.starts_with("<![CDATA[") {
+      let end = rest.find("]]>").map_or(rest.len(), |end| end + 3);
+      output.push_str(&rest[..end]);
+      rest = &rest[end..];
+    } else if let Some(declaration) = rest.strip_prefix("<?xml")
+      && declaration.starts_with(|c: char| c.is_ascii_whitespace())
+      && let Some(end) = declaration.find("?>")
+    {
+      rest = &declaration[end + 2..];
+    } else if let Some(doctype) = rest.strip_prefix("<!DOCTYPE")
+      && let Some(end) = doctype.find('>')
+      // keep the doctype declaring entities
+      && !doctype[..end].contains('[')
+    {
+      rest = &doctype[end + 1..];
+    } else if tag_name(rest) == Some("metadata")
+      && let Some(end) = rest.find("</metadata>")
+    {
+      rest = &rest[end + "</metadata>".len()..];
+    } else if rest.starts_with('<') {
+      let end = tag_end(rest);
+      let tag = &rest[..end];
+      if let Some(name) = tag_name(tag)
+        && WHITESPACE_PRESERVED_ELEMENTS.contains(&name)
+      {
+        if tag.starts_with("</") {
+          preserved_depth = preserved_depth.saturating_sub(1);
+        } else if !tag.ends_with("/>") {
+          preserved_depth += 1;
+        }
+      }
+      output.push_str(tag);
+      rest = &rest[end..];
+    } else {
+      let end = rest.find('<').unwrap_or(rest.len());
+      let text = &rest[..end];
+      let is_indentation = text.trim().is_empty() && text.contains('\n');
+      if preserved_depth > 0 || !is_indentation {
+        output.push_str(text);
+      }
+      rest = &rest[end..];
+    }
+  }
+  output
+}
+
+/// Returns the name of the start or end tag at the beginning of the input.
+fn tag_name(input: &str) -> Option<&str> {
+  let name = input
+    .strip_prefix("</")
+    .or_else(|| input.strip_prefix('<'))?;
+  let end = name
+    .find(|c: char| c.is_ascii_whitespace() || c == '>' || c == '/')
+    .unwrap_or(name.len());
+  Some(&name[..end])
+}
+
+/// Returns the offset after the tag at the beginning of the input, the `>` in
+/// the quoted attribute values is skipped.
+fn tag_end(input: &str) -> usize {
+  let mut quote = None;
+  for (index, c) in input.char_indices() {
+    match (quote, c) {
+      (None, '"' | '\'') => quote = Some(c),
+      (Some(q), c) if q == c => quote = None,
+      (None, '>') => return index + 1,
+      _ => {}
+    }
+  }
+  input.len()
+}
+
+/// Replaces the extension of the filename with the extension of the format.
+pub(crate) fn with_extension(filename: &str, format: ImageFormat) -> String {
+  let name_start = filename.rfind('/').map_or(0, |index| index + 1);
+  let stem = match filename[name_start..].rfind('.') {
+    Some(index) if index > 0 => &filename[..name_start + index],
+    _ => filename,
+  };
+  format!("{stem}.{}", format.extension())
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  #[test]
+  fn should_parse_transform_from_query() {
+    let transform = ImageTransform::from_query("?format=webp&width=640&quality=75&foo=bar")
+      .expect("should parse");
+    assert_eq!(
+      transform,
+      ImageTransform {
+        format: Some(ImageFormat::Webp),
+        width: Some(640),
+        height: None,
+        quality: Some(75),
+      }
+    );
+    assert!(ImageTransform::from_query("?format=bmp").is_err());
+    assert!(ImageTransform::from_query("?width=auto").is_err());
+    assert!(ImageTransform::from_query("?quality=0").is_err());
+  }
+
+  #[test]
+  fn should_detect_animated_gif() {
+    fn gif(frames: usize) -> Vec<u8> {
+      let mut gif = b"GIF89a".to_vec();
+      gif.extend_from_slice(&[0x01, 0x00, 0x01, 0x00, 0x80, 0x00, 0x00]);
+      // global color table with 2 colors
+      gif.extend_from_slice(&[0x00, 0x00, 0x00, 0xFF, 0xFF, 0xFF]);
+      for _ in 0..frames {
+        gif.extend_from_slice(&[
+          0x2C, 0x00, 0x00, 0x00, 0x00, 0x01, 0x00, 0x01, 0x00, 0x00, 0x02, 0x02, 0x44, 0x01, 0x00,
+        ]);
+      }
+      gif.push(0x3B);
+      gif
+    }
+    assert!(!is_animated_gif(&gif(1)).expect("should decode"));
+    assert!(is_animated_gif(&gif(2)).expect("should decode"));
+  }
+
+  #[test]
+  fn should_minify_svg() {
+    let svg = r#"<?xml version="1.0" encoding="UTF-8"?>
+<!-- Generator: editor -->
+<svg xmlns="http://www.w3.org/2000/svg" viewBox="0 0 10 10">
+  <metadata><rdf:RDF></rdf:RDF></metadata>
+  <title>a > b</title>
+  <text x="0" y="5">a <tspan>b</tspan>
+    c</text>
+  <rect width="10" height="10" data-label="a > b"/>
+</svg>
+"#;
+    assert_eq!(
+      minify_svg(svg),
+      r#"<svg xmlns="http://www.w3.org/2000/svg" viewBox="0 0 10 10"><title>a > b</title><text x="0" y="5">a <tspan>b</tspan>
+    c</text><rect width="10" height="10" data-label="a > b"/></svg>"#
+    );
+  }
+
+  #[test]
+  fn should_strip_jpeg_metadata() {
+    let mut jpeg = vec![0xFF, 0xD8];
+    // APP0 JFIF
+    jpeg.extend_from_slice(&[0xFF, 0xE0, 0x00, 0x07, b'J', b'F', b'I', b'F', 0x00]);
+    // COM
+    jpeg.extend_from_slice(&[0xFF, 0xFE, 0x00, 0x05, b'a', b'b', b'c']);
+    // SOS and the entropy-coded data
+    jpeg.extend_from_slice(&[0xFF, 0xDA, 0x00, 0x02, 0x12, 0x34, 0xFF, 0xD9]);
+
+    let stripped = strip_jpeg_metadata(&jpeg).expect("should strip");
+    let mut expected = vec![0xFF, 0xD8];
+    expected.extend_from_slice(&[0xFF, 0xE0, 0x00, 0x07, b'J', b'F', b'I', b'F', 0x00]);
+    expected.extend_from_slice(&[0xFF, 0xDA, 0x00, 0x02, 0x12, 0x34, 0xFF, 0xD9]);
+    assert_eq!(stripped, expected);
+    assert!(strip_jpeg_metadata(b"not a jpeg").is_none());
+  }
+
+  #[test]
+  fn should_strip_gif_extensions() {
+    let mut gif = b"GIF89a".to_vec();
+    // logical screen descriptor without global color table
+    gif.extend_from_slice(&[0x01, 0x00, 0x01, 0x00, 0x00, 0x00, 0x00]);
+    // comment extension
+    gif.extend_from_slice(&[0x21, 0xFE, 0x03, b'a', b'b', b'c', 0x00]);
+    // loop extension
+    let mut netscape = vec![0x21, 0xFF, 0x0B];
+    netscape.extend_from_slice(b"NETSCAPE2.0");
+    netscape.extend_from_slice(&[0x03, 0x01, 0x00, 0x00, 0x00]);
+    gif.extend_from_slice(&netscape);
+    // image descriptor, LZW minimum code size, data and trailer
+    let image = [
+      0x2C, 0x00, 0x00, 0x00, 0x00, 0x01, 0x00, 0x01, 0x00, 0x00, 0x02, 0x02, 0x44, 0x01, 0x00,
+    ];
+    gif.extend_from_slice(&image);
+    gif.push(0x3B);
+
+    let stripped = strip_gif_extensions(&gif).expect("should strip");
+    let mut expected = b"GIF89a".to_vec();
+    expected.extend_from_slice(&[0x01, 0x00, 0x01, 0x00, 0x00, 0x00, 0x00]);
+    expected.extend_from_slice(&netscape);
+    expected.extend_from_slice(&image);
+    expected.push(0x3B);
+    assert_eq!(stripped, expected);
+  }
+
+  #[test]
+  fn should_replace_extension() {
+    assert_eq!(
+      with_extension("images/logo.png", ImageFormat::Webp),
+      "images/logo.webp"
+    );
+    assert_eq!(
+      with_extension("images.v2/logo", ImageFormat::Avif),
+      "images.v2/logo.avif"
+    );
+  }
+}
@@ -1,32 +1,64 @@
-use std::{borrow::Cow, collections::HashSet, hash::Hasher, path::PathBuf};
+use std::{
+  borrow::Cow,
+  collections::HashSet,
+  hash::Hasher,
+  path::PathBuf,
+  sync::{Arc, LazyLock},
+};
 
 use asset_exports_dependency::AssetExportsDependency;
 use rayon::prelude::*;
-use rspack_cacheable::{cacheable, cacheable_dyn};
+use rspack_cacheable::{cacheable, cacheable_dyn, with::Skip};
 use rspack_core::{
   AssetGeneratorDataUrl, AssetGeneratorDataUrlFnCtx, AssetGeneratorImportMode, AssetInfo,
-  AssetParserDataUrl, BuildMetaDefaultObject, BuildMetaExportsType, ChunkGraph, ChunkUkey,
-  CodeGenerationDataAssetInfo, CodeGenerationDataFilename, CodeGenerationDataUrl,
-  CodeGenerationPublicPathAutoReplace, Compilation, CompilationRenderManifest, CompilerOptions,
-  DependencyType, Filename, GenerateContext, GeneratorOptions, ManifestAssetType, Module,
-  ModuleGraph, NAMESPACE_OBJECT_EXPORT, NormalModule, ParseContext, ParserAndGenerator, PathData,
-  Plugin, PublicPath, RenderManifestEntry, ResourceData, RuntimeGlobals, RuntimeSpec, SourceType,
-  rspack_sources::{BoxSource, RawStringSource, SourceExt},
+  AssetParserDataUrl, AssetParserDataUrlFnCtx, BuildMetaDefaultObject, BuildMetaExportsType,
+  ChunkGraph, ChunkUkey, CodeGenerationDataAssetInfo, CodeGenerationDataFilename,
+  CodeGenerationDataUrl, CodeGenerationPublicPathAutoReplace, Compilation,
+  CompilationAfterCodeGeneration, CompilationParams, CompilationRenderManifest, CompilerClose,
+  CompilerCompilation, CompilerId, CompilerOptions, DependencyType, Filename, GenerateContext,
+  GeneratorOptions, ManifestAssetType, Module, ModuleGraph, ModuleType, NAMESPACE_OBJECT_EXPORT,
+  NormalModule, ParseContext, ParserAndGenerator, PathData, Plugin, PublicPath,
+  RenderManifestEntry, ResourceData, RuntimeGlobals, RuntimeSpec, SourceType,
+  diagnostics::ModuleBuildError,
+  rspack_sources::{BoxSource, RawBufferSource, RawStringSource, SourceExt},
 };
 use rspack_error::{
-  Diagnostic, IntoTWithDiagnosticArray, Result, ToStringResultToRspackResultExt, error,
+  Diagnostic, Error, IntoTWithDiagnosticArray, Result, ToStringResultToRspackResultExt, error,
 };
 use rspack_hash::{RspackHash, RspackHashDigest};
 use rspack_hook::{plugin, plugin_hook};
-use rspack_util::{base64, ext::DynHash, identifier::make_paths_relative};
+use rspack_util::{base64, ext::DynHash, fx_hash::FxDashMap, identifier::make_paths_relative};
+use tokio::sync::OnceCell;
+
+use crate::{
+  image::{ImageCache, ImageRequest, optimize_image, with_extension},
+  mini_svg::encode_mini_svg,
+};
 
 mod asset_exports_dependency;
+mod image;
+mod mini_svg;
 
 pub const AUTO_PUBLIC_PATH_PLACEHOLDER: &str = "__RSPACK_PLUGIN_ASSET_AUTO_PUBLIC_PATH__";
 
+/// The image cache of each compiler, the parser and generator restored from the persistent
+/// cache takes it from here at generate time.
+static IMAGE_CACHE_MAP: LazyLock<FxDashMap<CompilerId, Arc<ImageCache>>> =
+  LazyLock::new(Default::default);
+
 #[plugin]
 #[derive(Debug, Default)]
-pub struct AssetPlugin;
+pub struct AssetPlugin {
+  image_cache: Arc<ImageCache>,
+}
+
+impl AssetPlugin {
+  fn get_image_cache(id: CompilerId) -> Option<Arc<ImageCache>> {
+    IMAGE_CACHE_MAP
+      .get(&id)
+      .map(|image_cache| image_cache.clone())
+  }
+}
 
 static JS_AND_CSS_URL_TYPES: &[SourceType; 2] = &[SourceType::JavaScript, SourceType::CssUrl];
 static JS_TYPES: &[SourceType; 1] = &[SourceType::JavaScript];
@@ -42,6 +74,7 @@ static ASSET_AND_CSS_URL_TYPES: &[SourceType; 2] = &[SourceType::Asset, SourceTy
 static ASSET_TYPES: &[SourceType; 1] = &[SourceType::Asset];
 
 const DEFAULT_ENCODING: &str = "base64";
+const MINI_SVG_ENCODING: &str = "mini-svg";
 
 #[cacheable]
 #[derive(Debug, Clone)]
@@ -83,39 +116,66 @@ pub struct AssetParserAndGenerator {
   emit: bool,
   data_url: DataUrlOptions,
   pub parsed_asset_config: Option<CanonicalizedDataUrlOption>,
+  /// The optimized image, it's shared by the generation of each source type.
+  #[cacheable(with=Skip)]
+  optimized_source: OnceCell<BoxSource>,
 }
 
 impl AssetParserAndGenerator {
-  pub fn with_auto(option: Option<AssetParserDataUrl>, emit: bool) -> Self {
+  fn new(emit: bool, data_url: DataUrlOptions) -> Self {
     Self {
       emit,
-      data_url: DataUrlOptions::Auto(option),
+      data_url,
       parsed_asset_config: None,
+      optimized_source: OnceCell::new(),
     }
   }
 
+  pub fn with_auto(option: Option<AssetParserDataUrl>, emit: bool) -> Self {
+    Self::new(emit, DataUrlOptions::Auto(option))
+  }
+
   pub fn with_inline() -> Self {
-    Self {
-      emit: false,
-      data_url: DataUrlOptions::Inline(true),
-      parsed_asset_config: None,
-    }
+    Self::new(false, DataUrlOptions::Inline(true))
   }
 
   pub fn with_resource(emit: bool) -> Self {
-    Self {
-      emit,
-      data_url: DataUrlOptions::Inline(false),
-      parsed_asset_config: None,
-    }
+    Self::new(emit, DataUrlOptions::Inline(false))
   }
 
   pub fn with_source() -> Self {
-    Self {
-      emit: false,
-      data_url: DataUrlOptions::Source,
-      parsed_asset_config: None,
-    }
+    Self::new(false, DataUrlOptions::Source)
+  }
+
+  /// Returns the optimized image if `imageOptimization` is enabled, otherwise the original source.
+  async fn get_optimized_source(
+    &self,
+    source: &BoxSource,
+    module: &NormalModule,
+    module_generator_options: Option<&GeneratorOptions>,
+    compilation: &Compilation,
+  ) -> Result<BoxSource> {
+    let Some(request) = get_image_request(module, module_generator_options) else {
+      return Ok(source.clone());
+    };
+    self
+      .optimized_source
+      .get_or_try_init(|| async {
+        let image_cache = AssetPlugin::get_image_cache(compilation.compiler_id());
+        let optimized = optimize_image(
+          &source.buffer(),
+          &request,
+          image_cache.as_deref(),
+          compilation.persistent_cache_storage.as_ref(),
+        )
+        .await?;
+        Ok::<_, rspack_error::Error>(match optimized {
+          Some(content) => RawBufferSource::from(content).boxed(),
+          None => source.clone(),
+        })
+      })
+      .await
+      .cloned()
   }
 
   fn decode_data_uri_content(encoding: &str, content: &str, source: &BoxSource) -> Vec<u8> {
@@ -170,12 +230,16 @@ impl AssetParserAndGenerator {
     &self,
     resource_data: &ResourceData,
     data_url: Option<&AssetGeneratorDataUrl>,
+    image_request: Option<&ImageRequest>,
   ) -> Result<String> {
     if let Some(AssetGeneratorDataUrl::Options(data_url)) = data_url
       && let Some(mimetype) = &data_url.mimetype
     {
       return Ok(mimetype.to_owned());
     }
+    if let Some(format) = image_request.and_then(|request| request.converted_format()) {
+      return Ok(format.mimetype().to_owned());
+    }
     if let Some(mimetype) = resource_data.mimetype()
       && let Some(parameters) = resource_data.parameters()
     {
@@ -234,6 +298,13 @@ impl AssetParserAndGenerator {
     if encoding == DEFAULT_ENCODING {
       return Ok(base64::encode_to_string(source.buffer()));
     }
+    if encoding == MINI_SVG_ENCODING {
+      let buffer = source.buffer();
+      return Ok(match std::str::from_utf8(&buffer) {
+        Ok(svg) => encode_mini_svg(svg),
+        Err(_) => urlencoding::encode_binary(&buffer).into_owned(),
+      });
+    }
     Err(error!("Unsupported encoding {encoding}"))
   }
 
@@ -251,6 +322,19 @@ impl AssetParserAndGenerator {
     relative
   }
 
+  /// The source file name used to render `[name]` and `[ext]` of the output filename,
+  /// which has the extension of the converted image format.
+  fn get_output_source_file_name(
+    &self,
+    source_file_name: &str,
+    image_request: Option<&ImageRequest>,
+  ) -> String {
+    match image_request.and_then(|request| request.converted_format()) {
+      Some(format) => with_extension(source_file_name, format),
+      None => source_file_name.to_owned(),
+    }
+  }
+
   async fn get_asset_module_filename(
     &self,
     module: &NormalModule,
@@ -462,29 +546,46 @@ impl ParserAndGenerator for AssetParserAndGenerator {
     build_meta.exports_type = BuildMetaExportsType::Default;
     build_meta.default_object = BuildMetaDefaultObject::False;
     let size = source.size();
+    self.optimized_source = OnceCell::new();
+
+    let mut diagnostics = vec![];
+    if let Err(err) = parse_image_request(
+      parse_context.resource_data,
+      parse_context.module_generator_options,
+    ) {
+      diagnostics.push(Diagnostic::from(Error::from(ModuleBuildError::new(
+        err, None,
+      ))));
+    }
 
     self.parsed_asset_config = match &self.data_url {
       DataUrlOptions::Source => Some(CanonicalizedDataUrlOption::Source),
       DataUrlOptions::Inline(val) => Some(CanonicalizedDataUrlOption::Asset(*val)),
       DataUrlOptions::Auto(option) => {
-        let limit_size = parse_context
+        let data_url_condition = parse_context
           .module_parser_options
-          .and_then(|x| {
-            x.get_asset()
-              .and_then(|x| x.data_url_condition.as_ref())
-              .and_then(|x| match x {
-                AssetParserDataUrl::Options(x) => x.max_size,
-              })
-          })
-          .or_else(|| {
-            option.as_ref().and_then(|x| match x {
-              AssetParserDataUrl::Options(x) => x.max_size,
-            })
-          })
-          .unwrap_or(DEFAULT_MAX_SIZE);
-        Some(CanonicalizedDataUrlOption::Asset(
-          size <= limit_size as usize,
-        ))
+          .and_then(|x| x.get_asset())
+          .and_then(|x| x.data_url_condition.as_ref());
+        if let Some(AssetParserDataUrl::Func(data_url_condition)) =
+          data_url_condition.or(option.as_ref())
+        {
+          let func_ctx = AssetParserDataUrlFnCtx {
+            filename: parse_context.resource_data.resource().to_owned(),
+            module_identifier: parse_context.module_identifier,
+            module_type: parse_context.module_type,
+            module_layer: parse_context.module_layer,
+          };
+          let inline = data_url_condition(source.buffer().to_vec(), func_ctx).await?;
+          Some(CanonicalizedDataUrlOption::Asset(inline))
+        } else {
+          let limit_size = data_url_condition
+            .and_then(|x| x.max_size())
+            .or_else(|| option.as_ref().and_then(|x| x.max_size()))
+            .unwrap_or(DEFAULT_MAX_SIZE);
+          Some(CanonicalizedDataUrlOption::Asset(
+            size <= limit_size as usize,
+          ))
+        }
       }
     };
 
@@ -500,7 +601,7 @@ impl ParserAndGenerator for AssetParserAndGenerator {
         code_generation_dependencies: vec![],
         side_effects_bailout: None,
       }
-      .with_empty_diagnostic(),
+      .with_diagnostic(diagnostics),
     )
   }
 
@@ -521,10 +622,14 @@ impl ParserAndGenerator for AssetParserAndGenerator {
     let module_generator_options = normal_module.get_generator_options();
 
     let import_mode = self.get_import_mode(module_generator_options)?;
+    let image_request = get_image_request(normal_module, module_generator_options);
 
     match generate_context.requested_source_type {
       SourceType::JavaScript | SourceType::CssUrl => {
         let exported_content = if parsed_asset_config.is_inline() {
+          let source = &self
+            .get_optimized_source(source, normal_module, module_generator_options, compilation)
+            .await?;
           let resource_data: &ResourceData = normal_module.resource_resolved_data();
           let data_url = module_generator_options.and_then(|x| x.asset_data_url());
           let encoded_source: String;
@@ -535,12 +640,13 @@ impl ParserAndGenerator for AssetParserAndGenerator {
           {
             encoded_source = custom_data_url;
           } else {
-            let mimetype = self.get_mimetype(resource_data, data_url)?;
+            let mimetype = self.get_mimetype(resource_data, data_url, image_request.as_ref())?;
             let encoding = self.get_encoding(resource_data, data_url);
             let encoded_content = self.get_encoded_content(resource_data, &encoding, source)?;
             encoded_source = format!(
               r#"data:{mimetype}{},{encoded_content}"#,
-              if encoding.is_empty() {
+              // the mini-svg encoded content is a percent-encoded data url without the encoding
+              if encoding.is_empty() || encoding == MINI_SVG_ENCODING {
                 String::new()
               } else {
                 format!(";{encoding}")
@@ -554,17 +660,22 @@ impl ParserAndGenerator for AssetParserAndGenerator {
 
           serde_json::to_string(&encoded_source).to_rspack_result()?
         } else if parsed_asset_config.is_resource() {
+          let source = &self
+            .get_optimized_source(source, normal_module, module_generator_options, compilation)
+            .await?;
           let contenthash = self.hash_for_source(source, &compilation.options);
           let contenthash = contenthash.rendered(compilation.options.output.hash_digest_length);
 
           let source_file_name = self.get_source_file_name(normal_module, compilation);
+          let output_source_file_name =
+            self.get_output_source_file_name(&source_file_name, image_request.as_ref());
           let (original_filename, filename, mut asset_info) = self
             .get_asset_module_filename(
               normal_module,
               module_generator_options,
               compilation,
               Some(contenthash),
-              &source_file_name,
+              &output_source_file_name,
               true,
             )
             .await?;
@@ -587,7 +698,7 @@ impl ParserAndGenerator for AssetParserAndGenerator {
                     normal_module,
                     compilation,
                     Some(contenthash),
-                    &source_file_name,
+                    &output_source_file_name,
                     template,
                   )
                   .await?;
@@ -696,16 +807,21 @@ impl ParserAndGenerator for AssetParserAndGenerator {
             "Inline or Source asset does not have source type `asset`"
           ))
         } else {
-          let contenthash = self.hash_for_source(source, &compilation.options);
+          let source = self
+            .get_optimized_source(source, normal_module, module_generator_options, compilation)
+            .await?;
+          let contenthash = self.hash_for_source(&source, &compilation.options);
           let contenthash = contenthash.rendered(compilation.options.output.hash_digest_length);
           let source_file_name = self.get_source_file_name(normal_module, compilation);
+          let output_source_file_name =
+            self.get_output_source_file_name(&source_file_name, image_request.as_ref());
           let (_, filename, mut asset_info) = self
             .get_asset_module_filename(
               normal_module,
               module_generator_options,
               compilation,
               Some(contenthash),
-              &source_file_name,
+              &output_source_file_name,
               true,
             )
             .await?;
@@ -728,7 +844,7 @@ impl ParserAndGenerator for AssetParserAndGenerator {
             .data
             .insert(CodeGenerationDataAssetInfo::new(asset_info));
 
-          Ok(source.clone().boxed())
+          Ok(source)
         }
       }
       _ => panic!(
@@ -765,7 +881,11 @@ impl ParserAndGenerator for AssetParserAndGenerator {
     {
       data_url_options.dyn_hash(&mut hasher);
     } else if parsed_asset_config.is_resource() {
-      let source_file_name = self.get_source_file_name(module, compilation);
+      let image_request = get_image_request(module, module_generator_options);
+      let source_file_name = self.get_output_source_file_name(
+        &self.get_source_file_name(module, compilation),
+        image_request.as_ref(),
+      );
       let (filename, _, _) = self
         .get_asset_module_filename(
           module,
@@ -849,6 +969,69 @@ async fn render_manifest(
   Ok(())
 }
 
+fn parse_image_request(
+  resource_data: &ResourceData,
+  module_generator_options: Option<&GeneratorOptions>,
+) -> Result<Option<ImageRequest>> {
+  if module_generator_options.and_then(|x| x.asset_image_optimization()) != Some(true) {
+    return Ok(None);
+  }
+  ImageRequest::from_resource_data(resource_data)
+}
+
+/// The query is validated when the module is parsed, so an invalid query is reported once as a
+/// build error of the module, and the image is kept as it is here.
+fn get_image_request(
+  module: &NormalModule,
+  module_generator_options: Option<&GeneratorOptions>,
+) -> Option<ImageRequest> {
+  parse_image_request(module.resource_resolved_data(), module_generator_options)
+    .ok()
+    .flatten()
+}
+
+#[plugin_hook(CompilerCompilation for AssetPlugin)]
+async fn compilation(
+  &self,
+  compilation: &mut Compilation,
+  _params: &mut CompilationParams,
+) -> Result<()> {
+  IMAGE_CACHE_MAP.insert(compilation.compiler_id(), self.image_cache.clone());
+  Ok(())
+}
+
+#[plugin_hook(CompilationAfterCodeGeneration for AssetPlugin)]
+async fn after_code_generation(&self, compilation: &mut Compilation) -> Result<()> {
+  let Some(storage) = &compilation.persistent_cache_storage else {
+    return Ok(());
+  };
+  let module_graph = compilation.get_module_graph();
+  let mut keys = vec![];
+  for module in module_graph.modules().values() {
+    let Some(module) = module.as_normal_module() else {
+      continue;
+    };
+    if !matches!(
+      module.module_type(),
+      ModuleType::Asset | ModuleType::AssetResource
+    ) {
+      continue;
+    }
+    if let Some(request) = get_image_request(module, module.get_generator_options())
+      && let Some(source) = module.source()
+    {
+      keys.push(request.cache_key(&source.buffer()));
+    }
+  }
+  self.image_cache.prune(storage, keys).await
+}
+
+#[plugin_hook(CompilerClose for AssetPlugin)]
+async fn close(&self, compilation: &Compilation) -> Result<()> {
+  IMAGE_CACHE_MAP.remove(&compilation.compiler_id());
+  Ok(())
+}
+
 impl Plugin for AssetPlugin {
   fn name(&self) -> &'static str {
     "asset"
@@ -859,6 +1042,12 @@ impl Plugin for AssetPlugin {
       .compilation_hooks
       .render_manifest
       .tap(render_manifest::new(self));
+    ctx
+      .compilation_hooks
+      .after_code_generation
+      .tap(after_code_generation::new(self));
+    ctx.compiler_hooks.compilation.tap(compilation::new(self));
+    ctx.compiler_hooks.close.tap(close::new(self));
 
     ctx.register_parser_and_generator_builder(
       rspack_core::ModuleType::Asset,
@@ -885,7 +1074,7 @@ impl Plugin for AssetPlugin {
 
     ctx.register_parser_and_generator_builder(
       rspack_core::ModuleType::AssetResource,
-      Box::new(move |_, generator_options| {
+      Box::new(|_, generator_options| {
         let emit = generator_options
           .and_then(|x| x.get_asset_resource())
           .and_then(|x| x.emit);
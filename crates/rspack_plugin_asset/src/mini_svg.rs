@@ -0,0 +1,63 @@
+//! Encodes SVG images to data url content like [mini-svg-data-uri](https://github.com/tigt/mini-svg-data-uri).
+
+use std::fmt::Write;
+
+/// Collapses the whitespaces, uses single quotes and only escapes the characters
+/// that are not allowed in data urls.
+pub(crate) fn encode_mini_svg(svg: &str) -> String {
+  let svg = svg.strip_prefix('\u{feff}').unwrap_or(svg);
+  let mut encoded = String::with_capacity(svg.len());
+  for (index, word) in svg.split_whitespace().enumerate() {
+    if index > 0 {
+      encoded.push(' ');
+    }
+    for byte in word.bytes() {
+      match byte {
+        b'"' => encoded.push('\''),
+        // unescaped by `encodeURIComponent`
+        b'A'..=b'Z'
+        | b'a'..=b'z'
+        | b'0'..=b'9'
+        | b'-'
+        | b'_'
+        | b'.'
+        | b'!'
+        | b'~'
+        | b'*'
+        | b'\''
+        | b'('
+        | b')'
+        // browsers tolerate these characters, and they're frequent
+        | b'='
+        | b':'
+        | b'/' => encoded.push(byte as char),
+        // lowercase hex compresses better
+        _ => write!(encoded, "%{byte:02x}").expect("should write to string"),
+      }
+    }
+  }
+  encoded
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  #[test]
+  fn encode_svg() {
+    assert_eq!(
+      encode_mini_svg(
+        "\u{feff}<svg xmlns=\"http://www.w3.org/2000/svg\">\n  <path fill=\"#fff\" d=\"M0 0h1v1z\"/>\n</svg>\n"
+      ),
+      "%3csvg xmlns='http://www.w3.org/2000/svg'%3e %3cpath fill='%23fff' d='M0 0h1v1z'/%3e %3c/svg%3e"
+    );
+  }
+
+  #[test]
+  fn encode_non_ascii() {
+    assert_eq!(
+      encode_mini_svg("<text>é</text>"),
+      "%3ctext%3e%c3%a9%3c/text%3e"
+    );
+  }
+}
@@ -0,0 +1,288 @@
+use std::{fmt, hash::Hash, io::Write};
+
+use flate2::{Compression, write::GzEncoder};
+use rayon::prelude::*;
+use rspack_core::{
+  AssetInfo, Compilation, CompilationAsset, CompilationProcessAssets, Plugin,
+  cache::persistent::ScopedCache,
+  rspack_sources::{RawBufferSource, SourceExt},
+};
+use rspack_error::{Result, ToStringResultToRspackResultExt};
+use rspack_hash::{HashDigest, HashFunction, RspackHash};
+use rspack_hook::{plugin, plugin_hook};
+use rspack_util::asset_condition::AssetConditions;
+
+const PLUGIN_NAME: &str = "rspack.CompressionRspackPlugin";
+const CACHE_SCOPE: &str = "compression";
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum CompressionAlgorithm {
+  Gzip,
+  Brotli,
+  Zstd,
+}
+
+impl CompressionAlgorithm {
+  fn extension(&self) -> &'static str {
+    match self {
+      Self::Gzip => "gz",
+      Self::Brotli => "br",
+      Self::Zstd => "zst",
+    }
+  }
+
+  fn default_level(&self) -> u32 {
+    match self {
+      Self::Gzip => 9,
+      Self::Brotli => 11,
+      Self::Zstd => 3,
+    }
+  }
+
+  fn compress(&self, input: &[u8], level: u32) -> std::io::Result<Vec<u8>> {
+    match self {
+      Self::Gzip => {
+        let mut encoder = GzEncoder::new(Vec::new(), Compression::new(level));
+        encoder.write_all(input)?;
+        encoder.finish()
+      }
+      Self::Brotli => {
+        let params = brotli::enc::BrotliEncoderParams {
+          quality: level as i32,
+          ..Default::default()
+        };
+        let mut output = Vec::new();
+        brotli::BrotliCompress(&mut &input[..], &mut output, &params)?;
+        Ok(output)
+      }
+      Self::Zstd => zstd::bulk::compress(input, level as i32),
+    }
+  }
+
+  fn set_related(&self, info: &mut AssetInfo, filename: String) {
+    let related = &mut info.related;
+    match self {
+      Self::Gzip => related.gzip = Some(filename),
+      Self::Brotli => related.brotli = Some(filename),
+      Self::Zstd => related.zstd = Some(filename),
+    }
+  }
+}
+
+impl fmt::Display for CompressionAlgorithm {
+  fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+    match self {
+      Self::Gzip => write!(f, "gzip"),
+      Self::Brotli => write!(f, "brotli"),
+      Self::Zstd => write!(f, "zstd"),
+    }
+  }
+}
+
+impl TryFrom<&str> for CompressionAlgorithm {
+  type Error = rspack_error::Error;
+
+  fn try_from(value: &str) -> Result<Self> {
+    match value {
+      "gzip" => Ok(Self::Gzip),
+      "brotli" => Ok(Self::Brotli),
+      "zstd" => Ok(Self::Zstd),
+      _ => Err(rspack_error::error!(
+        "Unsupported compression algorithm `{value}`, expected `gzip`, `brotli` or `zstd`"
+      )),
+    }
+  }
+}
+
+#[derive(Debug)]
+pub struct CompressionAlgorithmOptions {
+  pub algorithm: CompressionAlgorithm,
+  /// The compression level, uses the default level of the algorithm when not set.
+  pub level: Option<u32>,
+}
+
+#[derive(Debug)]
+pub struct CompressionRspackPluginOptions {
+  pub algorithms: Vec<CompressionAlgorithmOptions>,
+  pub test: Option<AssetConditions>,
+  pub include: Option<AssetConditions>,
+  pub exclude: Option<AssetConditions>,
+  /// Only assets bigger than this size in bytes are compressed.
+  pub threshold: usize,
+  /// Only the compressed assets with `compressed size / original size` below this ratio are emitted.
+  pub min_ratio: f64,
+}
+
+#[plugin]
+#[derive(Debug)]
+pub struct CompressionRspackPlugin {
+  options: CompressionRspackPluginOptions,
+  /// The compressed contents keyed by the content hash, the algorithm and the level.
+  cache: ScopedCache,
+}
+
+impl CompressionRspackPlugin {
+  pub fn new(options: CompressionRspackPluginOptions) -> Self {
+    Self::new_inner(options, ScopedCache::new(CACHE_SCOPE))
+  }
+}
+
+struct CompressedAsset {
+  original: String,
+  filename: String,
+  algorithm: CompressionAlgorithm,
+  content: Vec<u8>,
+}
+
+#[plugin_hook(CompilationProcessAssets for CompressionRspackPlugin, stage = Compilation::PROCESS_ASSETS_STAGE_OPTIMIZE_TRANSFER)]
+async fn process_assets(&self, compilation: &mut Compilation) -> Result<()> {
+  let options = &self.options;
+
+  let storage = compilation.persistent_cache_storage.clone();
+  if let Some(storage) = &storage {
+    self.cache.recovery(storage).await?;
+  }
+  let storage = storage.as_ref();
+
+  let compressed_assets = compilation
+    .assets()
+    .par_iter()
+    .filter(|(filename, _)| !is_compressed_filename(filename) && match_object(options, filename))
+    .filter_map(|(filename, asset)| {
+      let source = asset.get_source()?;
+      let content = source.buffer();
+      (content.len() > options.threshold).then(|| (filename, asset.get_info(), content))
+    })
+    .flat_map(|(filename, info, content)| {
+      options
+        .algorithms
+        .par_iter()
+        // skip the algorithms which have compressed the asset already, e.g. by another instance
+        .filter(|algorithm_options| !has_related(info, algorithm_options.algorithm))
+        .map(move |algorithm_options| {
+          let algorithm = algorithm_options.algorithm;
+          let level = algorithm_options
+            .level
+            .unwrap_or_else(|| algorithm.default_level());
+          let cache_key = storage.map(|_| {
+            let mut hasher = RspackHash::new(&HashFunction::Xxhash64);
+            PLUGIN_NAME.hash(&mut hasher);
+            algorithm.hash(&mut hasher);
+            level.hash(&mut hasher);
+            content.hash(&mut hasher);
+            hasher
+              .digest(&HashDigest::Hex)
+              .encoded()
+              .as_bytes()
+              .to_vec()
+          });
+          let compressed =
+            if let Some(cached) = cache_key.as_ref().and_then(|key| self.cache.get(key)) {
+              cached.as_ref().clone()
+            } else {
+              let compressed = algorithm
+                .compress(&content, level)
+                .to_rspack_result_with_message(|e| {
+                  format!("Failed to compress {filename} with {algorithm}: {e}")
+                })?;
+              if let (Some(storage), Some(cache_key)) = (storage, cache_key) {
+                self.cache.set(storage, cache_key, compressed.clone());
+              }
+              compressed
+            };
+          if (compressed.len() as f64) / (content.len() as f64) >= options.min_ratio {
+            return Ok(None);
+          }
+          Ok(Some(CompressedAsset {
+            original: filename.clone(),
+            filename: compressed_filename(filename, algorithm),
+            algorithm,
+            content: compressed,
+          }))
+        })
+    })
+    .collect::<Result<Vec<_>>>()?;
+  if let Some(storage) = storage {
+    self.cache.remove_unused(storage);
+  }
+
+  for compressed in compressed_assets.into_iter().flatten() {
+    let CompressedAsset {
+      original,
+      filename,
+      algorithm,
+      content,
+    } = compressed;
+    let Some(original_asset) = compilation.assets_mut().get_mut(&original) else {
+      continue;
+    };
+    let immutable = original_asset.get_info().immutable;
+    algorithm.set_related(original_asset.get_info_mut(), filename.clone());
+    compilation.emit_asset(
+      filename,
+      CompilationAsset::new(
+        Some(RawBufferSource::from(content).boxed()),
+        AssetInfo {
+          immutable,
+          ..Default::default()
+        },
+      ),
+    );
+  }
+
+  Ok(())
+}
+
+/// Appends the extension of the algorithm to the path of the filename, the query is kept at the end.
+fn compressed_filename(filename: &str, algorithm: CompressionAlgorithm) -> String {
+  match filename.split_once('?') {
+    Some((path, query)) => format!("{path}.{}?{query}", algorithm.extension()),
+    None => format!("{filename}.{}", algorithm.extension()),
+  }
+}
+
+fn is_compressed_filename(filename: &str) -> bool {
+  let filename = filename.split_once('?').map_or(filename, |(path, _)| path);
+  filename.ends_with(".gz") || filename.ends_with(".br") || filename.ends_with(".zst")
+}
+
+fn has_related(info: &AssetInfo, algorithm: CompressionAlgorithm) -> bool {
+  match algorithm {
+    CompressionAlgorithm::Gzip => info.related.gzip.is_some(),
+    CompressionAlgorithm::Brotli => info.related.brotli.is_some(),
+    CompressionAlgorithm::Zstd => info.related.zstd.is_some(),
+  }
+}
+
+fn match_object(options: &CompressionRspackPluginOptions, filename: &str) -> bool {
+  if let Some(condition) = &options.test
+    && !condition.try_match(filename)
+  {
+    return false;
+  }
+  if let Some(condition) = &options.include
+    && !condition.try_match(filename)
+  {
+    return false;
+  }
+  if let Some(condition) = &options.exclude
+    && condition.try_match(filename)
+  {
+    return false;
+  }
+  true
+}
+
+impl Plugin for CompressionRspackPlugin {
+  fn name(&self) -> &'static str {
+    PLUGIN_NAME
+  }
+
+  fn apply(&self, ctx: &mut rspack_core::ApplyContext<'_>) -> Result<()> {
+    ctx
+      .compilation_hooks
+      .process_assets
+      .tap(process_assets::new(self));
+    Ok(())
+  }
+}
@@ -765,6 +765,7 @@ fn set_info(target: &mut AssetInfo, info: Info) {
   if let Some(related) = info.related {
     target.related = AssetInfoRelated {
       source_map: related.source_map,
+      ..Default::default()
     };
   }
 
@@ -20,7 +20,7 @@ pub struct CssImportDependency {
 }
 
 #[cacheable]
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
 pub enum CssLayer {
   Anonymous,
   Named(String),
@@ -56,6 +56,17 @@ impl CssImportDependency {
   pub fn layer(&self) -> Option<&CssLayer> {
     self.layer.as_ref()
   }
+
+  pub fn condition(&self) -> Option<CssImportCondition> {
+    if self.media.is_none() && self.supports.is_none() && self.layer.is_none() {
+      return None;
+    }
+    Some(CssImportCondition {
+      layer: self.layer.clone(),
+      supports: self.supports.clone().map(CssSupports),
+      media: self.media.clone().map(CssMedia),
+    })
+  }
 }
 
 #[cacheable_dyn]
@@ -100,12 +111,26 @@ impl ModuleDependency for CssImportDependency {
   }
 }
 
-#[derive(Clone)]
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
 pub struct CssMedia(pub String);
 
-#[derive(Clone)]
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
 pub struct CssSupports(pub String);
 
+/// The `layer()`, `supports()` and media query conditions of an `@import` rule.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct CssImportCondition {
+  pub layer: Option<CssLayer>,
+  pub supports: Option<CssSupports>,
+  pub media: Option<CssMedia>,
+}
+
+/// Every chain of `@import` conditions a module is imported with, each chain is ordered from
+/// the outermost import to the innermost one, and an empty chain means the module is imported
+/// unconditionally.
+#[derive(Debug, Clone)]
+pub struct CssImportConditions(pub Vec<Vec<CssImportCondition>>);
+
 impl Display for CssMedia {
   fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
     self.0.fmt(f)
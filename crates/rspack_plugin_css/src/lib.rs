@@ -4,4 +4,4 @@ pub mod plugin;
 pub mod runtime;
 mod utils;
 
-pub use plugin::CssPlugin;
+pub use plugin::{CssOrderConflictsMode, CssPlugin, CssPluginOptions};
@@ -0,0 +1,395 @@
+//! Locals declared by at-rules, which are not rewritten by `css-module-lexer`:
+//! - container names of `@container` queries and `container-name`/`container` declarations
+//! - classes and ids of `@scope` roots and limits
+//! - custom `@layer` names
+//!
+//! Like selectors, a name wrapped by `:global(...)` is kept as is, and `:local(...)` is
+//! accepted for symmetry, both wrappers are removed from the output.
+
+#[derive(Debug)]
+pub struct AtRuleLocal<'s> {
+  pub name: &'s str,
+  pub start: u32,
+  pub end: u32,
+}
+
+#[derive(Debug, Default)]
+pub struct AtRuleLocals<'s> {
+  pub locals: Vec<AtRuleLocal<'s>>,
+  /// Ranges of `:global(`, `:local(` and their closing `)` to remove.
+  pub wrappers: Vec<(u32, u32)>,
+}
+
+const CONTAINER_QUERY_KEYWORDS: [&str; 4] = ["not", "and", "or", "none"];
+
+#[derive(PartialEq, Eq)]
+enum Paren {
+  Plain,
+  Global,
+  Local,
+}
+
+pub fn collect_at_rule_locals(source: &str) -> AtRuleLocals<'_> {
+  let mut scanner = Scanner {
+    source,
+    bytes: source.as_bytes(),
+    pos: 0,
+    result: AtRuleLocals::default(),
+  };
+  scanner.scan();
+  scanner.result
+}
+
+struct Scanner<'s> {
+  source: &'s str,
+  bytes: &'s [u8],
+  pos: usize,
+  result: AtRuleLocals<'s>,
+}
+
+fn is_ident_start(b: u8) -> bool {
+  b.is_ascii_alphabetic() || b == b'_' || b == b'\\' || b >= 0x80
+}
+
+fn is_ident_char(b: u8) -> bool {
+  is_ident_start(b) || b.is_ascii_digit() || b == b'-'
+}
+
+impl<'s> Scanner<'s> {
+  fn peek(&self, offset: usize) -> Option<u8> {
+    self.bytes.get(self.pos + offset).copied()
+  }
+
+  fn starts_with_ignore_case(&self, s: &str) -> bool {
+    self
+      .bytes
+      .get(self.pos..self.pos + s.len())
+      .is_some_and(|b| b.eq_ignore_ascii_case(s.as_bytes()))
+  }
+
+  /// Returns the end of the identifier starting at `pos`.
+  fn ident_end(&self, pos: usize) -> Option<usize> {
+    let mut end = pos;
+    if self.bytes.get(end) == Some(&b'-') {
+      end += 1;
+      if self.bytes.get(end) == Some(&b'-') {
+        end += 1;
+      }
+    }
+    if !self.bytes.get(end).is_some_and(|b| is_ident_start(*b)) {
+      return None;
+    }
+    while let Some(&b) = self.bytes.get(end) {
+      if b == b'\\' {
+        end = (end + 2).min(self.bytes.len());
+      } else if is_ident_char(b) {
+        end += 1;
+      } else {
+        break;
+      }
+    }
+    Some(end)
+  }
+
+  fn read_ident(&mut self) -> Option<(&'s str, usize, usize)> {
+    let start = self.pos;
+    let end = self.ident_end(start)?;
+    self.pos = end;
+    Some((&self.source[start..end], start, end))
+  }
+
+  fn push_local(&mut self, name: &'s str, start: usize, end: usize) {
+    self.result.locals.push(AtRuleLocal {
+      name,
+      start: start as u32,
+      end: end as u32,
+    });
+  }
+
+  fn push_wrapper(&mut self, start: usize, end: usize) {
+    self.result.wrappers.push((start as u32, end as u32));
+  }
+
+  fn skip_comment(&mut self) -> bool {
+    if self.peek(0) == Some(b'/') && self.peek(1) == Some(b'*') {
+      self.pos = self.source[self.pos + 2..]
+        .find("*/")
+        .map_or(self.bytes.len(), |i| self.pos + 2 + i + 2);
+      return true;
+    }
+    false
+  }
+
+  fn skip_string(&mut self) -> bool {
+    let Some(quote @ (b'"' | b'\'')) = self.peek(0) else {
+      return false;
+    };
+    self.pos += 1;
+    while let Some(b) = self.peek(0) {
+      self.pos += 1;
+      match b {
+        b'\\' => self.pos = (self.pos + 1).min(self.bytes.len()),
+        b'\n' => break,
+        _ if b == quote => break,
+        _ => {}
+      }
+    }
+    true
+  }
+
+  fn skip_trivia(&mut self) {
+    loop {
+      if self.skip_comment() {
+        continue;
+      }
+      match self.peek(0) {
+        Some(b) if b.is_ascii_whitespace() => self.pos += 1,
+        _ => break,
+      }
+    }
+  }
+
+  /// Returns the wrapper kind if a `:global(` or `:local(` starts at the current position,
+  /// and moves past it.
+  fn read_wrapper_open(&mut self) -> Option<Paren> {
+    let (kind, len) = if self.starts_with_ignore_case(":global(") {
+      (Paren::Global, ":global(".len())
+    } else if self.starts_with_ignore_case(":local(") {
+      (Paren::Local, ":local(".len())
+    } else {
+      return None;
+    };
+    self.push_wrapper(self.pos, self.pos + len);
+    self.pos += len;
+    Some(kind)
+  }
+
+  fn scan(&mut self) {
+    // whether the next identifier may be the property of a declaration
+    let mut declaration_start = true;
+    while let Some(b) = self.peek(0) {
+      if self.skip_comment() {
+        continue;
+      }
+      if self.skip_string() {
+        declaration_start = false;
+        continue;
+      }
+      match b {
+        b'{' | b';' | b'}' => {
+          declaration_start = true;
+          self.pos += 1;
+        }
+        b'\\' => {
+          declaration_start = false;
+          self.pos = (self.pos + 2).min(self.bytes.len());
+        }
+        b'@' => {
+          declaration_start = false;
+          self.pos += 1;
+          let Some((name, ..)) = self.read_ident() else {
+            continue;
+          };
+          if name.eq_ignore_ascii_case("container") {
+            self.scan_container_prelude();
+          } else if name.eq_ignore_ascii_case("scope") {
+            self.scan_scope_prelude();
+          } else if name.eq_ignore_ascii_case("layer") {
+            self.scan_layer_prelude();
+          }
+        }
+        _ if b.is_ascii_whitespace() => self.pos += 1,
+        _ if declaration_start && self.ident_end(self.pos).is_some() => {
+          declaration_start = false;
+          let Some((property, ..)) = self.read_ident() else {
+            continue;
+          };
+          let is_container_name = property.eq_ignore_ascii_case("container-name");
+          if !is_container_name && !property.eq_ignore_ascii_case("container") {
+            continue;
+          }
+          self.skip_trivia();
+          if self.peek(0) == Some(b':') {
+            self.pos += 1;
+            self.scan_container_declaration_value(is_container_name);
+          }
+        }
+        _ => {
+          declaration_start = false;
+          self.pos += 1;
+        }
+      }
+    }
+  }
+
+  /// A container name, or a `:global(...)`/`:local(...)` wrapped one.
+  fn scan_container_name(&mut self, is_name: impl Fn(&str, Option<u8>) -> bool) {
+    if let Some(kind) = self.read_wrapper_open() {
+      self.skip_trivia();
+      if let Some((name, start, end)) = self.read_ident()
+        && kind == Paren::Local
+      {
+        self.push_local(name, start, end);
+      }
+      self.skip_trivia();
+      if self.peek(0) == Some(b')') {
+        self.push_wrapper(self.pos, self.pos + 1);
+        self.pos += 1;
+      }
+      return;
+    }
+    let Some(end) = self.ident_end(self.pos) else {
+      return;
+    };
+    let start = self.pos;
+    let name = &self.source[start..end];
+    if is_name(name, self.bytes.get(end).copied()) {
+      self.push_local(name, start, end);
+    }
+    self.pos = end;
+  }
+
+  /// `@container <name>? <query>, ...`
+  fn scan_container_prelude(&mut self) {
+    loop {
+      self.skip_trivia();
+      self.scan_container_name(|name, next| {
+        // `style(...)` and other functions are part of the query
+        next != Some(b'(')
+          && !CONTAINER_QUERY_KEYWORDS
+            .iter()
+            .any(|keyword| name.eq_ignore_ascii_case(keyword))
+      });
+      if self.skip_to_prelude_separator() != Some(b',') {
+        return;
+      }
+      self.pos += 1;
+    }
+  }
+
+  /// `container-name: <name>+` and `container: <name>+ [/ <type>]?`
+  fn scan_container_declaration_value(&mut self, is_container_name: bool) {
+    loop {
+      self.skip_trivia();
+      match self.peek(0) {
+        None | Some(b';' | b'}' | b'!') => return,
+        Some(b'/') if !is_container_name => return,
+        Some(b':' | b'-' | b'_' | b'\\') | Some(b'a'..=b'z' | b'A'..=b'Z' | 0x80..) => {
+          let pos = self.pos;
+          self.scan_container_name(|name, next| {
+            next != Some(b'(')
+              && !name.eq_ignore_ascii_case("none")
+              && !name.eq_ignore_ascii_case("inherit")
+              && !name.eq_ignore_ascii_case("initial")
+              && !name.eq_ignore_ascii_case("unset")
+              && !name.eq_ignore_ascii_case("revert")
+              && !name.eq_ignore_ascii_case("revert-layer")
+          });
+          if self.pos == pos {
+            self.pos += 1;
+          }
+        }
+        Some(_) => {
+          // var(...) and other values can't be rewritten
+          self.skip_to_prelude_separator();
+          return;
+        }
+      }
+    }
+  }
+
+  /// `@layer <name>, ...` where a name is dot separated identifiers.
+  fn scan_layer_prelude(&mut self) {
+    loop {
+      self.skip_trivia();
+      if let Some(kind) = self.read_wrapper_open() {
+        self.skip_trivia();
+        self.scan_layer_name(kind == Paren::Local);
+        self.skip_trivia();
+        if self.peek(0) == Some(b')') {
+          self.push_wrapper(self.pos, self.pos + 1);
+          self.pos += 1;
+        }
+      } else {
+        self.scan_layer_name(true);
+      }
+      self.skip_trivia();
+      if self.peek(0) != Some(b',') {
+        return;
+      }
+      self.pos += 1;
+    }
+  }
+
+  fn scan_layer_name(&mut self, is_local: bool) {
+    while let Some((name, start, end)) = self.read_ident() {
+      if is_local {
+        self.push_local(name, start, end);
+      }
+      if self.peek(0) != Some(b'.') {
+        return;
+      }
+      self.pos += 1;
+    }
+  }
+
+  /// `@scope (<root>)? [to (<limit>)]?`, classes and ids of the selectors are locals.
+  fn scan_scope_prelude(&mut self) {
+    let mut parens = vec![];
+    while let Some(b) = self.peek(0) {
+      if self.skip_comment() || self.skip_string() {
+        continue;
+      }
+      match b {
+        b'{' | b';' | b'}' => return,
+        b'(' => {
+          parens.push(Paren::Plain);
+          self.pos += 1;
+        }
+        b')' => {
+          if matches!(parens.pop(), Some(Paren::Global | Paren::Local)) {
+            self.push_wrapper(self.pos, self.pos + 1);
+          }
+          self.pos += 1;
+        }
+        b':' => {
+          if let Some(kind) = self.read_wrapper_open() {
+            parens.push(kind);
+          } else {
+            self.pos += 1;
+          }
+        }
+        b'.' | b'#' => {
+          self.pos += 1;
+          if let Some((name, start, end)) = self.read_ident()
+            && !parens.contains(&Paren::Global)
+          {
+            self.push_local(name, start, end);
+          }
+        }
+        b'\\' => self.pos = (self.pos + 2).min(self.bytes.len()),
+        _ => self.pos += 1,
+      }
+    }
+  }
+
+  /// Moves to the next `,`, `{` or `;` outside of parentheses and returns it without
+  /// consuming it.
+  fn skip_to_prelude_separator(&mut self) -> Option<u8> {
+    let mut depth = 0usize;
+    while let Some(b) = self.peek(0) {
+      if self.skip_comment() || self.skip_string() {
+        continue;
+      }
+      match b {
+        b'(' => depth += 1,
+        b')' => depth = depth.saturating_sub(1),
+        b',' | b'{' | b';' | b'}' if depth == 0 => return Some(b),
+        b'\\' => self.pos += 1,
+        _ => {}
+      }
+      self.pos = (self.pos + 1).min(self.bytes.len());
+    }
+    None
+  }
+}
@@ -1,3 +1,5 @@
+mod at_rule_locals;
+
 use std::{
   borrow::Cow,
   sync::{Arc, LazyLock},
@@ -10,6 +12,7 @@ use rspack_cacheable::{
   cacheable, cacheable_dyn,
   with::{AsCacheable, AsMap, AsOption, AsVec},
 };
+use rspack_collections::{IdentifierMap, IdentifierSet};
 use rspack_core::{
   BoxDependencyTemplate, BoxModuleDependency, BuildMetaDefaultObject, BuildMetaExportsType,
   ChunkGraph, Compilation, ConstDependency, CssExportsConvention, Dependency, DependencyId,
@@ -26,11 +29,12 @@ use rspack_hash::{RspackHash, RspackHashDigest};
 use rspack_util::{atom::Atom, ext::DynHash};
 use rustc_hash::{FxHashMap, FxHashSet};
 
+use self::at_rule_locals::collect_at_rule_locals;
 use crate::{
   dependency::{
-    CssComposeDependency, CssExportDependency, CssImportDependency, CssLayer,
-    CssLocalIdentDependency, CssMedia, CssSelfReferenceLocalIdentDependency,
-    CssSelfReferenceLocalIdentReplacement, CssSupports, CssUrlDependency,
+    CssComposeDependency, CssExportDependency, CssImportCondition, CssImportConditions,
+    CssImportDependency, CssLayer, CssLocalIdentDependency, CssSelfReferenceLocalIdentDependency,
+    CssSelfReferenceLocalIdentReplacement, CssUrlDependency,
   },
   utils::{
     LocalIdentOptions, css_modules_exports_to_concatenate_module_string,
@@ -39,7 +43,7 @@ use crate::{
   },
 };
 
-static REGEX_IS_MODULES: LazyLock<Regex> =
+pub(crate) static REGEX_IS_MODULES: LazyLock<Regex> =
   LazyLock::new(|| Regex::new(r"\.module(s)?\.[^.]+$").expect("Invalid regex"));
 
 static REGEX_IS_COMMENTS: LazyLock<Regex> =
@@ -64,6 +68,70 @@ pub struct CssExport {
 
 pub type CssExports = IndexMap<String, IndexSet<CssExport>>;
 
+/// Collects the `@import` condition chains of a module by walking up its importers, and returns
+/// whether the chains are complete.
+///
+/// The chains of the modules are cached in `cache`, so a module imported through several paths is
+/// only walked once. The chains cut short by a circular import depend on where the walk starts,
+/// they are not cached.
+fn collect_import_conditions(
+  module_graph: &ModuleGraph,
+  module: ModuleIdentifier,
+  visiting: &mut IdentifierSet,
+  cache: &mut IdentifierMap<Vec<Vec<CssImportCondition>>>,
+) -> (Vec<Vec<CssImportCondition>>, bool) {
+  if let Some(chains) = cache.get(&module) {
+    return (chains.clone(), true);
+  }
+  if !visiting.insert(module) {
+    // circular imports add nothing
+    return (vec![], false);
+  }
+  let mut complete = true;
+  let mut chains: Vec<Vec<CssImportCondition>> = vec![];
+  for conn in module_graph.get_incoming_connections(&module) {
+    let Some(dep) = module_graph.dependency_by_id(&conn.dependency_id) else {
+      continue;
+    };
+    if conn.original_module_identifier == Some(module) {
+      continue;
+    }
+    let Some(css_import_dep) = dep.downcast_ref::<CssImportDependency>() else {
+      // imported by javascript or `composes`
+      chains.push(vec![]);
+      continue;
+    };
+    let parent_chains = match conn.original_module_identifier {
+      Some(origin) => {
+        let (parent_chains, parent_complete) =
+          collect_import_conditions(module_graph, origin, visiting, cache);
+        complete &= parent_complete;
+        parent_chains
+      }
+      None => vec![vec![]],
+    };
+    let condition = css_import_dep.condition();
+    for mut chain in parent_chains {
+      chain.extend(condition.clone());
+      if !chains.contains(&chain) {
+        chains.push(chain);
+      }
+    }
+  }
+  visiting.remove(&module);
+
+  if chains.is_empty() || chains.iter().any(|chain| chain.is_empty()) {
+    // an unconditional import covers the media and supports conditions of the other imports,
+    // but layers still change the cascade
+    chains.retain(|chain| chain.iter().any(|condition| condition.layer.is_some()));
+    chains.insert(0, vec![]);
+  }
+  if complete {
+    cache.insert(module, chains.clone());
+  }
+  (chains, complete)
+}
+
 fn update_css_exports(exports: &mut CssExports, name: String, css_export: CssExport) -> bool {
   if let Some(existing) = exports.get_mut(&name) {
     existing.insert(css_export)
@@ -83,6 +151,7 @@ pub struct CssParserAndGenerator {
   pub named_exports: bool,
   pub es_module: bool,
   pub url: bool,
+  pub emit_dts: bool,
   #[cacheable(with=AsOption<AsMap<AsCacheable, AsVec>>)]
   pub exports: Option<CssExports>,
   pub local_names: Option<FxHashMap<String, String>>,
@@ -183,8 +252,23 @@ impl ParserAndGenerator for CssParserAndGenerator {
     let mut presentational_dependencies: Vec<BoxDependencyTemplate> = vec![];
     let mut code_generation_dependencies: Vec<BoxModuleDependency> = vec![];
 
+    let is_local = matches!(mode, css_module_lexer::Mode::Local);
+    // start of the ranges rewritten by the lexer, the at-rule locals found later skip them
+    let mut lexer_rewritten = FxHashSet::default();
+
     let (deps, warnings) = css_module_lexer::collect_dependencies(&source_code, mode);
     for dependency in deps {
+      match &dependency {
+        css_module_lexer::Dependency::Replace { range, .. }
+        | css_module_lexer::Dependency::LocalKeyframesDecl { range, .. } => {
+          lexer_rewritten.insert(range.start);
+        }
+        css_module_lexer::Dependency::LocalClass { range, .. }
+        | css_module_lexer::Dependency::LocalId { range, .. } => {
+          lexer_rewritten.insert(range.start + 1);
+        }
+        _ => {}
+      }
       match dependency {
         css_module_lexer::Dependency::Url {
           request,
@@ -477,6 +561,63 @@ impl ParserAndGenerator for CssParserAndGenerator {
         _ => {}
       }
     }
+    if is_local {
+      let at_rule_locals = collect_at_rule_locals(&source_code);
+      for (start, end) in at_rule_locals.wrappers {
+        if lexer_rewritten.contains(&start) {
+          continue;
+        }
+        presentational_dependencies.push(Box::new(ConstDependency::new(
+          (start, end).into(),
+          "".into(),
+          None,
+        )));
+      }
+      for local in at_rule_locals.locals {
+        if lexer_rewritten.contains(&local.start) {
+          continue;
+        }
+        let name = unescape(local.name);
+        let local_ident = LocalIdentOptions::new(
+          resource_data,
+          self
+            .local_ident_name
+            .as_ref()
+            .expect("should have local_ident_name for module_type css/auto or css/module"),
+          compiler_options,
+        )
+        .get_local_ident(&name)
+        .await?;
+        let exports = self.exports.get_or_insert_default();
+        let convention = self
+          .convention
+          .as_ref()
+          .expect("should have local_ident_name for module_type css/auto or css/module");
+        let convention_names = export_locals_convention(&name, convention);
+        for convention_name in convention_names.iter() {
+          update_css_exports(
+            exports,
+            convention_name.to_owned(),
+            CssExport {
+              ident: local_ident.clone(),
+              from: None,
+              id: None,
+              orig_name: name.clone().into_owned(),
+            },
+          );
+        }
+
+        let local_names = self.local_names.get_or_insert_default();
+        local_names.insert(name.into_owned(), local_ident.clone());
+
+        dependencies.push(Box::new(CssLocalIdentDependency::new(
+          local_ident,
+          convention_names,
+          local.start,
+          local.end,
+        )));
+      }
+    }
     for warning in warnings {
       let range = warning.range();
       let error = css_parsing_traceable_error(
@@ -556,32 +697,14 @@ impl ParserAndGenerator for CssParserAndGenerator {
           }
         });
 
-        for conn in module_graph.get_incoming_connections(&module.identifier()) {
-          let Some(dep) = module_graph.dependency_by_id(&conn.dependency_id) else {
-            continue;
-          };
-
-          if matches!(dep.dependency_type(), DependencyType::CssImport) {
-            let Some(css_import_dep) = dep.downcast_ref::<CssImportDependency>() else {
-              panic!(
-                "dependency with type DependencyType::CssImport should only be CssImportDependency"
-              );
-            };
-
-            if let Some(media) = css_import_dep.media() {
-              let media = CssMedia(media.to_string());
-              context.data.insert(media);
-            }
-
-            if let Some(supports) = css_import_dep.supports() {
-              let supports = CssSupports(supports.to_string());
-              context.data.insert(supports);
-            }
-
-            if let Some(layer) = css_import_dep.layer() {
-              context.data.insert(layer.clone());
-            }
-          }
+        let (conditions, _) = collect_import_conditions(
+          &module_graph,
+          module.identifier(),
+          &mut IdentifierSet::default(),
+          &mut IdentifierMap::default(),
+        );
+        if conditions.iter().any(|chain| !chain.is_empty()) {
+          context.data.insert(CssImportConditions(conditions));
         }
 
         if let Some(dependencies) = module.get_presentational_dependencies() {
@@ -9,19 +9,21 @@ use std::{
 use atomic_refcell::AtomicRefCell;
 use rspack_collections::{DatabaseItem, ItemUkey};
 use rspack_core::{
-  AssetInfo, Chunk, ChunkGraph, ChunkKind, ChunkLoading, ChunkLoadingType, ChunkUkey, Compilation,
-  CompilationContentHash, CompilationId, CompilationParams, CompilationRenderManifest,
-  CompilationRuntimeRequirementInTree, CompilerCompilation, DependencyType, ManifestAssetType,
-  Module, ModuleGraph, ModuleType, ParserAndGenerator, PathData, Plugin, PublicPath,
-  RenderManifestEntry, RuntimeGlobals, RuntimeModuleExt, SelfModuleFactory, SourceType,
-  get_css_chunk_filename_template,
+  AssetInfo, AsyncModulesArtifact, Chunk, ChunkGraph, ChunkKind, ChunkLoading, ChunkLoadingType,
+  ChunkUkey, Compilation, CompilationContentHash, CompilationFinishModules, CompilationId,
+  CompilationParams, CompilationRenderManifest, CompilationRuntimeRequirementInTree,
+  CompilerCompilation, DependencyType, ManifestAssetType, Module, ModuleGraph, ModuleType,
+  ParserAndGenerator, PathData, Plugin, PublicPath, RenderManifestEntry, RuntimeGlobals,
+  RuntimeModuleExt, SelfModuleFactory, SourceType, get_css_chunk_filename_template,
   rspack_sources::{
     BoxSource, CachedSource, ConcatSource, RawStringSource, ReplaceSource, Source, SourceExt,
   },
 };
 use rspack_error::{Diagnostic, Result, ToStringResultToRspackResultExt};
+use rspack_fs::WritableFileSystem;
 use rspack_hash::RspackHash;
 use rspack_hook::plugin_hook;
+use rspack_paths::Utf8PathBuf;
 use rspack_plugin_runtime::is_enabled_for_chunk;
 use rspack_util::fx_hash::FxDashMap;
 use rustc_hash::{FxHashMap as HashMap, FxHashSet as HashSet};
@@ -29,13 +31,16 @@ use rustc_hash::{FxHashMap as HashMap, FxHashSet as HashSet};
 use crate::{
   CssPlugin,
   dependency::{
-    CssImportDependencyTemplate, CssLayer, CssLocalIdentDependencyTemplate, CssMedia,
-    CssSelfReferenceLocalIdentDependencyTemplate, CssSupports, CssUrlDependencyTemplate,
+    CssImportCondition, CssImportConditions, CssImportDependencyTemplate, CssLayer,
+    CssLocalIdentDependencyTemplate, CssSelfReferenceLocalIdentDependencyTemplate,
+    CssUrlDependencyTemplate,
   },
-  parser_and_generator::{CodeGenerationDataUnusedLocalIdent, CssParserAndGenerator},
-  plugin::{CssModulesPluginHooks, CssModulesRenderSource, CssPluginInner},
+  parser_and_generator::{
+    CodeGenerationDataUnusedLocalIdent, CssParserAndGenerator, REGEX_IS_MODULES,
+  },
+  plugin::{CssModulesPluginHooks, CssModulesRenderSource, CssOrderConflictsMode, CssPluginInner},
   runtime::CssLoadingRuntimeModule,
-  utils::AUTO_PUBLIC_PATH_PLACEHOLDER,
+  utils::{AUTO_PUBLIC_PATH_PLACEHOLDER, css_modules_exports_to_dts},
 };
 
 /// Safety with [atomic_refcell::AtomicRefCell]:
@@ -130,20 +135,34 @@ impl CssPlugin {
           .module_by_identifier(&conflict.selected_module)
           .expect("should have module");
 
-        let mut diagnostic = Diagnostic::warn(
-          "Conflicting order".into(),
-          format!(
-            "chunk {}\nConflicting order between {} and {}",
-            chunk.name().unwrap_or(
-              chunk
-                .id(&compilation.chunk_ids_artifact)
-                .expect("should have chunk id")
-                .as_str()
+        let chunk_name = chunk.name().unwrap_or(
+          chunk
+            .id(&compilation.chunk_ids_artifact)
+            .expect("should have chunk id")
+            .as_str(),
+        );
+        let mut diagnostic = match self.options.order_conflicts {
+          CssOrderConflictsMode::Warning => Diagnostic::warn(
+            "Conflicting order".into(),
+            format!(
+              "chunk {chunk_name}\nConflicting order between {} and {}",
+              failed_module.readable_identifier(&compilation.options.context),
+              selected_module.readable_identifier(&compilation.options.context)
             ),
-            failed_module.readable_identifier(&compilation.options.context),
-            selected_module.readable_identifier(&compilation.options.context)
           ),
-        );
+          CssOrderConflictsMode::Error => Diagnostic::error(
+            "Conflicting order".into(),
+            format!(
+              "chunk {chunk_name}\nConflicting order between {} and {}",
+              failed_module
+                .name_for_condition()
+                .unwrap_or_else(|| failed_module.identifier().as_str().into()),
+              selected_module
+                .name_for_condition()
+                .unwrap_or_else(|| selected_module.identifier().as_str().into())
+            ),
+          ),
+        };
         diagnostic.file = Some(output_path.to_owned().into());
         diagnostic.chunk = Some(chunk.ukey().as_u32());
         diagnostic
@@ -195,40 +214,14 @@ impl CssPlugin {
             |(compilation, chunk, debug_info, data, cur_source, hooks)| async move {
               let mut post_module_container = {
                 let mut container_source = ConcatSource::default();
-
-                let mut num_close_bracket = 0;
-
-                // TODO: use PrefixSource to create indent
-                if let Some(media) = data.get::<CssMedia>() {
-                  num_close_bracket += 1;
-                  container_source.add(RawStringSource::from(format!("@media {media}{{\n")));
-                }
-
-                if let Some(supports) = data.get::<CssSupports>() {
-                  num_close_bracket += 1;
-                  container_source.add(RawStringSource::from(format!(
-                    "@supports ({supports}) {{\n"
-                  )));
-                }
-
-                if let Some(layer) = data.get::<CssLayer>() {
-                  num_close_bracket += 1;
-                  container_source.add(RawStringSource::from(format!(
-                    "@layer{} {{\n",
-                    if let CssLayer::Named(layer) = &layer {
-                      Cow::Owned(format!(" {layer}"))
-                    } else {
-                      Cow::Borrowed("")
+                match data.get::<CssImportConditions>() {
+                  Some(CssImportConditions(chains)) => {
+                    for chain in chains {
+                      wrap_with_import_conditions(&mut container_source, chain, cur_source);
                     }
-                  )));
-                }
-
-                container_source.add(cur_source.clone());
-
-                for _ in 0..num_close_bracket {
-                  container_source.add(RawStringSource::from_static("\n}"));
+                  }
+                  None => wrap_with_import_conditions(&mut container_source, &[], cur_source),
                 }
-                container_source.add(RawStringSource::from_static("\n"));
                 CssModulesRenderSource {
                   source: container_source.boxed(),
                 }
@@ -265,6 +258,50 @@ impl CssPlugin {
   }
 }
 
+/// Nests the source in `@media`, `@supports` and `@layer` blocks, the conditions of one
+/// `@import` are nested as `@import url layer() supports() media` is specified.
+fn wrap_with_import_conditions(
+  container_source: &mut ConcatSource,
+  chain: &[CssImportCondition],
+  source: &BoxSource,
+) {
+  let mut num_close_bracket = 0;
+
+  // TODO: use PrefixSource to create indent
+  for condition in chain {
+    if let Some(media) = &condition.media {
+      num_close_bracket += 1;
+      container_source.add(RawStringSource::from(format!("@media {media}{{\n")));
+    }
+
+    if let Some(supports) = &condition.supports {
+      num_close_bracket += 1;
+      container_source.add(RawStringSource::from(format!(
+        "@supports ({supports}) {{\n"
+      )));
+    }
+
+    if let Some(layer) = &condition.layer {
+      num_close_bracket += 1;
+      container_source.add(RawStringSource::from(format!(
+        "@layer{} {{\n",
+        if let CssLayer::Named(layer) = &layer {
+          Cow::Owned(format!(" {layer}"))
+        } else {
+          Cow::Borrowed("")
+        }
+      )));
+    }
+  }
+
+  container_source.add(source.clone());
+
+  for _ in 0..num_close_bracket {
+    container_source.add(RawStringSource::from_static("\n}"));
+  }
+  container_source.add(RawStringSource::from_static("\n"));
+}
+
 #[plugin_hook(CompilerCompilation for CssPlugin)]
 async fn compilation(
   &self,
@@ -303,6 +340,64 @@ async fn compilation(
   Ok(())
 }
 
+#[plugin_hook(CompilationFinishModules for CssPlugin)]
+async fn finish_modules(
+  &self,
+  compilation: &mut Compilation,
+  _async_modules_artifact: &mut AsyncModulesArtifact,
+) -> Result<()> {
+  let module_graph = compilation.get_module_graph();
+  let declarations = module_graph
+    .modules()
+    .values()
+    .filter_map(|module| {
+      let module = module.as_normal_module()?;
+      let parser_and_generator = module
+        .parser_and_generator()
+        .downcast_ref::<CssParserAndGenerator>()?;
+      if !parser_and_generator.emit_dts {
+        return None;
+      }
+      let path = module.resource_resolved_data().path()?;
+      if matches!(module.module_type(), ModuleType::CssAuto)
+        && !REGEX_IS_MODULES.is_match(path.as_str())
+      {
+        return None;
+      }
+      Some((
+        Utf8PathBuf::from(format!("{path}.d.ts")),
+        css_modules_exports_to_dts(
+          parser_and_generator.exports.as_ref(),
+          parser_and_generator.named_exports,
+        ),
+      ))
+    })
+    .collect::<Vec<_>>();
+
+  // the declarations live next to the sources rather than in the output directory, so they are
+  // written to the real file system instead of the output file system, which may be in memory
+  let fs = &compilation.intermediate_filesystem;
+  for (path, dts) in declarations {
+    if self
+      .emitted_dts
+      .get(&path)
+      .is_some_and(|emitted| *emitted == dts)
+    {
+      continue;
+    }
+    // don't touch the files which are up to date, as type checkers may watch them
+    let up_to_date = fs
+      .read_file(&path)
+      .await
+      .is_ok_and(|content| content == dts.as_bytes());
+    if !up_to_date {
+      fs.write(&path, dts.as_bytes()).await?;
+    }
+    self.emitted_dts.insert(path, dts);
+  }
+  Ok(())
+}
+
 #[plugin_hook(CompilationRuntimeRequirementInTree for CssPlugin)]
 async fn runtime_requirements_in_tree(
   &self,
@@ -480,6 +575,10 @@ impl Plugin for CssPlugin {
 
   fn apply(&self, ctx: &mut rspack_core::ApplyContext<'_>) -> Result<()> {
     ctx.compiler_hooks.compilation.tap(compilation::new(self));
+    ctx
+      .compilation_hooks
+      .finish_modules
+      .tap(finish_modules::new(self));
     ctx
       .compilation_hooks
       .runtime_requirement_in_tree
@@ -512,6 +611,7 @@ impl Plugin for CssPlugin {
           es_module: g.es_module.expect("should have es_module"),
           hot: false,
           url: p.url.expect("should have url"),
+          emit_dts: false,
         }) as Box<dyn ParserAndGenerator>
       }),
     );
@@ -541,6 +641,7 @@ impl Plugin for CssPlugin {
           es_module: g.es_module.expect("should have es_module"),
           hot: false,
           url: p.url.expect("should have url"),
+          emit_dts: g.emit_dts.unwrap_or_default(),
         }) as Box<dyn ParserAndGenerator>
       }),
     );
@@ -570,6 +671,7 @@ impl Plugin for CssPlugin {
           es_module: g.es_module.expect("should have es_module"),
           hot: false,
           url: p.url.expect("should have url"),
+          emit_dts: g.emit_dts.unwrap_or_default(),
         }) as Box<dyn ParserAndGenerator>
       }),
     );
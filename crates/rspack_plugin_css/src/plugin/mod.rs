@@ -6,11 +6,47 @@ use std::cmp::{self, Reverse};
 pub use drive::*;
 use rspack_collections::{DatabaseItem, IdentifierSet};
 use rspack_core::{Chunk, ChunkUkey, Compilation, Module, ModuleIdentifier};
+use rspack_error::{Result, error};
 use rspack_hook::plugin;
+use rspack_paths::Utf8PathBuf;
+use rspack_util::fx_hash::FxDashMap;
+
+/// How conflicting orders of CSS modules between chunks are reported.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub enum CssOrderConflictsMode {
+  #[default]
+  Warning,
+  /// Fail the build, reporting the full path of each conflicting module.
+  Error,
+}
+
+impl TryFrom<&str> for CssOrderConflictsMode {
+  type Error = rspack_error::Error;
+
+  fn try_from(value: &str) -> Result<Self> {
+    match value {
+      "warning" => Ok(Self::Warning),
+      "error" => Ok(Self::Error),
+      _ => Err(error!(
+        "Unsupported css order conflicts mode \"{value}\", expected \"warning\" or \"error\""
+      )),
+    }
+  }
+}
+
+#[derive(Debug, Default)]
+pub struct CssPluginOptions {
+  pub order_conflicts: CssOrderConflictsMode,
+}
 
 #[plugin]
 #[derive(Debug, Default)]
-pub struct CssPlugin;
+pub struct CssPlugin {
+  options: CssPluginOptions,
+  /// Content of the `.d.ts` files written for CSS modules, so unchanged files are not
+  /// rewritten on rebuilds.
+  emitted_dts: FxDashMap<Utf8PathBuf, String>,
+}
 
 #[derive(Debug)]
 pub struct CssOrderConflicts {
@@ -20,6 +56,10 @@ pub struct CssOrderConflicts {
 }
 
 impl CssPlugin {
+  pub fn new(options: CssPluginOptions) -> Self {
+    Self::new_inner(options, Default::default())
+  }
+
   pub(crate) fn get_ordered_chunk_css_modules<'a>(
     chunk: &Chunk,
     compilation: &Compilation,
@@ -21,7 +21,7 @@ use rspack_hash::RspackHash;
 use rspack_util::{identifier::make_paths_relative, itoa, json_stringify};
 use rustc_hash::FxHashSet as HashSet;
 
-use crate::parser_and_generator::CssExport;
+use crate::parser_and_generator::{CssExport, CssExports};
 
 pub const AUTO_PUBLIC_PATH_PLACEHOLDER: &str = "__RSPACK_PLUGIN_CSS_AUTO_PUBLIC_PATH__";
 pub static LEADING_DIGIT_REGEX: LazyLock<Regex> =
@@ -340,6 +340,38 @@ pub fn css_modules_exports_to_concatenate_module_string<'a>(
   Ok(())
 }
 
+/// Renders the TypeScript declaration of a CSS module, the export names have the exports
+/// convention applied already.
+pub fn css_modules_exports_to_dts(exports: Option<&CssExports>, named_exports: bool) -> String {
+  let mut dts = String::from("// This file is generated by rspack, do not edit it.\n");
+  let names = exports.into_iter().flat_map(|exports| exports.keys());
+  if named_exports {
+    let mut aliases = String::new();
+    for (i, name) in names.enumerate() {
+      if to_identifier(name) == name.as_str() && !RESERVED_IDENTIFIER.contains(name.as_str()) {
+        writeln!(dts, "export declare const {name}: string;").expect("should write");
+      } else {
+        // names which are not identifiers can only be imported as a namespace or by string name
+        writeln!(dts, "declare const __css_export_{i}: string;").expect("should write");
+        writeln!(
+          aliases,
+          "export {{ __css_export_{i} as {} }};",
+          json_stringify(name)
+        )
+        .expect("should write");
+      }
+    }
+    dts += &aliases;
+  } else {
+    dts += "declare const styles: {\n";
+    for name in names {
+      writeln!(dts, "  readonly {}: string;", json_stringify(name)).expect("should write");
+    }
+    dts += "};\nexport default styles;\n";
+  }
+  dts
+}
+
 static STRING_MULTILINE: LazyLock<Regex> =
   LazyLock::new(|| Regex::new(r"\\[\n\r\f]").expect("Invalid RegExp"));
 
@@ -5,6 +5,8 @@ mod mapped_assets_cache;
 mod module_filename_helpers;
 mod source_map_dev_tool_module_options_plugin;
 mod source_map_dev_tool_plugin;
+mod source_map_validation;
+mod source_map_validation_plugin;
 
 use std::sync::Arc;
 
@@ -15,6 +17,7 @@ use rspack_core::ModuleIdentifier;
 use rspack_error::Result;
 pub use source_map_dev_tool_module_options_plugin::*;
 pub use source_map_dev_tool_plugin::*;
+pub use source_map_validation_plugin::*;
 
 pub type ModuleFilenameTemplateFn =
   Arc<dyn Fn(ModuleFilenameTemplateFnCtx) -> BoxFuture<'static, Result<String>> + Sync + Send>;
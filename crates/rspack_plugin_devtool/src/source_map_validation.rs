@@ -0,0 +1,253 @@
+use serde::Deserialize;
+
+/// The subset of a source map v3 payload that is needed for validation.
+///
+/// Mappings are kept as the raw VLQ string, so that malformed segments can be reported
+/// instead of being rejected by a full source map parser.
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub(crate) struct RawSourceMap {
+  #[serde(default)]
+  pub(crate) sources: Vec<Option<String>>,
+  #[serde(default)]
+  pub(crate) sources_content: Option<Vec<Option<String>>>,
+  #[serde(default)]
+  pub(crate) names: Vec<String>,
+  #[serde(default)]
+  pub(crate) mappings: String,
+}
+
+impl RawSourceMap {
+  pub(crate) fn from_json(json: &str) -> Result<Self, String> {
+    serde_json::from_str(json).map_err(|e| format!("failed to parse source map: {e}"))
+  }
+}
+
+fn base64_vlq_digit(c: char) -> Option<i64> {
+  let digit = match c {
+    'A'..='Z' => c as u32 - 'A' as u32,
+    'a'..='z' => c as u32 - 'a' as u32 + 26,
+    '0'..='9' => c as u32 - '0' as u32 + 52,
+    '+' => 62,
+    '/' => 63,
+    _ => return None,
+  };
+  Some(digit as i64)
+}
+
+fn decode_vlq_segment(segment: &str) -> Result<Vec<i64>, String> {
+  let mut values = Vec::with_capacity(5);
+  let mut value = 0i64;
+  let mut shift = 0u32;
+  let mut continued = false;
+  for c in segment.chars() {
+    let Some(digit) = base64_vlq_digit(c) else {
+      return Err(format!("invalid base64 VLQ character {c:?}"));
+    };
+    if shift > 30 {
+      return Err("VLQ value exceeds 32 bits".to_string());
+    }
+    value += (digit & 0b11111) << shift;
+    continued = digit & 0b100000 != 0;
+    if continued {
+      shift += 5;
+    } else {
+      let negative = value & 1 == 1;
+      value >>= 1;
+      values.push(if negative { -value } else { value });
+      value = 0;
+      shift = 0;
+    }
+  }
+  if continued {
+    return Err("unterminated VLQ value".to_string());
+  }
+  Ok(values)
+}
+
+fn line_lengths(code: &str) -> Vec<usize> {
+  // source map columns are counted in UTF-16 code units
+  code
+    .split('\n')
+    .map(|line| line.encode_utf16().count())
+    .collect()
+}
+
+/// Checks that every segment of `map` is valid VLQ, that source and name indices are in
+/// range, and that generated and original positions point to existing lines and columns.
+///
+/// Original positions can only be checked for sources whose content is embedded in the map.
+/// Lines are reported 1-based and columns 0-based, as in browser devtools.
+pub(crate) fn validate_source_map(map: &RawSourceMap, generated_code: &str) -> Vec<String> {
+  let generated_lines = line_lengths(generated_code);
+  let original_lines = map
+    .sources_content
+    .as_ref()
+    .map(|contents| {
+      contents
+        .iter()
+        .map(|content| content.as_deref().map(line_lengths))
+        .collect::<Vec<_>>()
+    })
+    .unwrap_or_default();
+
+  let mut problems = vec![];
+  let mut source_index = 0i64;
+  let mut original_line = 0i64;
+  let mut original_column = 0i64;
+  let mut name_index = 0i64;
+
+  for (line_index, line) in map.mappings.split(';').enumerate() {
+    let generated_line = line_index + 1;
+    let mut generated_column = 0i64;
+    for segment in line.split(',').filter(|segment| !segment.is_empty()) {
+      let fields = match decode_vlq_segment(segment) {
+        Ok(fields) => fields,
+        Err(e) => {
+          problems.push(format!(
+            "generated {generated_line}:{generated_column}: segment {segment:?}: {e}"
+          ));
+          continue;
+        }
+      };
+      if !matches!(fields.len(), 1 | 4 | 5) {
+        problems.push(format!(
+          "generated {generated_line}:{generated_column}: segment {segment:?} has {} fields, expected 1, 4 or 5",
+          fields.len()
+        ));
+        continue;
+      }
+
+      generated_column += fields[0];
+      if generated_column < 0 {
+        problems.push(format!(
+          "generated {generated_line}: negative generated column {generated_column}"
+        ));
+      } else if let Some(&length) = generated_lines.get(line_index) {
+        if generated_column as usize > length {
+          problems.push(format!(
+            "generated {generated_line}:{generated_column}: column is out of range, the generated line has {length} columns"
+          ));
+        }
+      } else {
+        problems.push(format!(
+          "generated {generated_line}:{generated_column}: line is out of range, the asset has {} lines",
+          generated_lines.len()
+        ));
+      }
+
+      if fields.len() < 4 {
+        continue;
+      }
+      source_index += fields[1];
+      original_line += fields[2];
+      original_column += fields[3];
+      let location = format!("generated {generated_line}:{generated_column}");
+      if source_index < 0 || source_index as usize >= map.sources.len() {
+        problems.push(format!(
+          "{location}: source index {source_index} is out of range, the map has {} sources",
+          map.sources.len()
+        ));
+      } else if original_line < 0 || original_column < 0 {
+        problems.push(format!(
+          "{location}: negative original position {}:{original_column}",
+          original_line + 1
+        ));
+      } else if let Some(Some(lines)) = original_lines.get(source_index as usize) {
+        let source = map.sources[source_index as usize]
+          .as_deref()
+          .unwrap_or_default();
+        match lines.get(original_line as usize) {
+          Some(&length) if original_column as usize > length => problems.push(format!(
+            "{location}: original {source} {}:{original_column} is out of range, the original line has {length} columns",
+            original_line + 1
+          )),
+          Some(_) => {}
+          None => problems.push(format!(
+            "{location}: original {source} {}:{original_column} is out of range, the original source has {} lines",
+            original_line + 1,
+            lines.len()
+          )),
+        }
+      }
+
+      if fields.len() == 5 {
+        name_index += fields[4];
+        if name_index < 0 || name_index as usize >= map.names.len() {
+          problems.push(format!(
+            "{location}: name index {name_index} is out of range, the map has {} names",
+            map.names.len()
+          ));
+        }
+      }
+    }
+  }
+
+  problems
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  fn map(mappings: &str) -> RawSourceMap {
+    RawSourceMap {
+      sources: vec![Some("webpack:///./src/index.js".to_string())],
+      sources_content: Some(vec![Some("const a = 1;\nconsole.log(a);".to_string())]),
+      names: vec!["a".to_string()],
+      mappings: mappings.to_string(),
+    }
+  }
+
+  #[test]
+  fn valid_mappings() {
+    let problems = validate_source_map(&map("AAAA,MAAMA;AACA"), "var a = 1;\nconsole.log(a);");
+    assert!(problems.is_empty(), "{problems:?}");
+  }
+
+  #[test]
+  fn invalid_vlq() {
+    let problems = validate_source_map(&map("AA!A"), "var a = 1;");
+    assert_eq!(problems.len(), 1);
+    assert!(problems[0].contains("invalid base64 VLQ character '!'"));
+
+    let problems = validate_source_map(&map("AAAg"), "var a = 1;");
+    assert_eq!(problems.len(), 1);
+    assert!(problems[0].contains("unterminated VLQ value"));
+
+    let problems = validate_source_map(&map("AA"), "var a = 1;");
+    assert_eq!(problems.len(), 1);
+    assert!(problems[0].contains("has 2 fields"));
+  }
+
+  #[test]
+  fn out_of_range_indices() {
+    // source index 1
+    let problems = validate_source_map(&map("ACAA"), "var a = 1;");
+    assert_eq!(problems.len(), 1);
+    assert!(problems[0].contains("source index 1 is out of range"));
+
+    // name index 1
+    let problems = validate_source_map(&map("AAAAC"), "var a = 1;");
+    assert_eq!(problems.len(), 1);
+    assert!(problems[0].contains("name index 1 is out of range"));
+  }
+
+  #[test]
+  fn out_of_range_positions() {
+    // generated line 2 does not exist
+    let problems = validate_source_map(&map(";AAAA"), "var a = 1;");
+    assert_eq!(problems.len(), 1);
+    assert!(problems[0].contains("line is out of range"));
+
+    // generated column 32
+    let problems = validate_source_map(&map("gCAAA"), "var a = 1;");
+    assert_eq!(problems.len(), 1);
+    assert!(problems[0].contains("column is out of range"));
+
+    // original line 3 does not exist
+    let problems = validate_source_map(&map("AAEA"), "var a = 1;");
+    assert_eq!(problems.len(), 1);
+    assert!(problems[0].contains("the original source has 2 lines"));
+  }
+}
@@ -0,0 +1,264 @@
+use std::sync::LazyLock;
+
+use derive_more::Debug;
+use rayon::prelude::*;
+use regex::Regex;
+use rspack_collections::IdentifierMap;
+use rspack_core::{
+  AssetInfo, Compilation, CompilationAsset, CompilationProcessAssets, Logger, Plugin,
+  rspack_sources::{BoxSource, MapOptions, ObjectPool, RawStringSource, Source, SourceExt},
+};
+use rspack_error::{Diagnostic, Result};
+use rspack_hook::{plugin, plugin_hook};
+use rspack_util::{asset_condition::AssetConditions, base64};
+use serde_json::{Value, json};
+
+use crate::source_map_validation::{RawSourceMap, validate_source_map};
+
+static INLINE_SOURCE_MAP_REGEXP: LazyLock<Regex> = LazyLock::new(|| {
+  Regex::new(
+    r"[#@] sourceMappingURL=data:application/json(?:;charset=[^;,]+)?;base64,([A-Za-z0-9+/=]+)",
+  )
+  .expect("failed to compile INLINE_SOURCE_MAP_REGEXP")
+});
+
+const MAX_REPORTED_PROBLEMS: usize = 10;
+
+#[derive(Debug, Default)]
+pub struct SourceMapValidationPluginOptions {
+  // Only validate the source maps of assets matching these conditions.
+  pub test: Option<AssetConditions>,
+  // Dump the chain of source maps (build, code generation and emitted assets) of the modules whose readable identifier matches these conditions.
+  pub dump_modules: Option<AssetConditions>,
+}
+
+/// Validates the source maps of the emitted assets, after every plugin that may have
+/// transformed them, and reports one warning per asset with invalid mappings.
+#[plugin]
+#[derive(Debug)]
+pub struct SourceMapValidationPlugin {
+  options: SourceMapValidationPluginOptions,
+}
+
+impl SourceMapValidationPlugin {
+  pub fn new(options: SourceMapValidationPluginOptions) -> Self {
+    Self::new_inner(options)
+  }
+}
+
+fn source_map_of(
+  compilation: &Compilation,
+  asset: &CompilationAsset,
+  code: &str,
+) -> Option<String> {
+  if let Some(source_map_filename) = &asset.info.related.source_map {
+    return compilation
+      .assets()
+      .get(source_map_filename)
+      .and_then(|asset| asset.get_source())
+      .map(|source| source.source().into_string_lossy().into_owned());
+  }
+  let captures = INLINE_SOURCE_MAP_REGEXP.captures_iter(code).last()?;
+  let decoded = base64::decode_to_vec(captures.get(1)?.as_str()).ok()?;
+  String::from_utf8(decoded).ok()
+}
+
+fn map_to_json(source: &BoxSource, object_pool: &ObjectPool) -> Value {
+  source
+    .map(object_pool, &MapOptions::new(true))
+    .and_then(|map| map.to_json().ok())
+    .and_then(|json| serde_json::from_str(&json).ok())
+    .unwrap_or(Value::Null)
+}
+
+fn dump_filename(readable_identifier: &str) -> String {
+  let name = readable_identifier
+    .chars()
+    .map(|c| match c {
+      'a'..='z' | 'A'..='Z' | '0'..='9' | '.' | '-' | '_' => c,
+      _ => '_',
+    })
+    .collect::<String>();
+  format!("source-map-chain/{name}.json")
+}
+
+/// Collects every source map a module went through: the one produced by the loaders, the ones
+/// produced by code generation for each runtime, and the final maps of the assets it ends up in.
+///
+/// The inner modules of a concatenated module are not in any chunk, so the code generation
+/// results and the assets of the concatenated module that contains them are used instead.
+fn dump_module_source_map_chain(
+  compilation: &Compilation,
+  conditions: &AssetConditions,
+) -> Vec<(String, String)> {
+  let module_graph = compilation.get_module_graph();
+  let object_pool = ObjectPool::default();
+  let mut dumps = vec![];
+
+  let mut concatenated_modules = IdentifierMap::default();
+  for (identifier, module) in module_graph.modules() {
+    if let Some(concatenated_module) = module.as_concatenated_module() {
+      for inner_module in concatenated_module.get_modules() {
+        concatenated_modules.insert(inner_module.id, identifier);
+      }
+    }
+  }
+
+  for (identifier, module) in module_graph.modules() {
+    let readable_identifier = module.readable_identifier(&compilation.options.context);
+    if !conditions.try_match(&readable_identifier) {
+      continue;
+    }
+
+    let mut chain = vec![];
+    if let Some(source) = module.source() {
+      chain.push(json!({ "stage": "build", "map": map_to_json(source, &object_pool) }));
+    }
+
+    let concatenated_module = concatenated_modules.get(&identifier).copied();
+    let chunk_module = concatenated_module.unwrap_or(identifier);
+    let mut files = vec![];
+    for chunk_ukey in compilation.chunk_graph.get_module_chunks(chunk_module) {
+      let chunk = compilation.chunk_by_ukey.expect_get(chunk_ukey);
+      let code_generation_result = compilation
+        .code_generation_results
+        .get(&chunk_module, Some(chunk.runtime()));
+      for (source_type, source) in code_generation_result.inner() {
+        chain.push(json!({
+          "stage": "codeGeneration",
+          "runtime": chunk.runtime().as_str(),
+          "sourceType": source_type.to_string(),
+          "map": map_to_json(source, &object_pool),
+        }));
+      }
+      files.extend(chunk.files().iter().cloned());
+    }
+
+    files.sort();
+    files.dedup();
+    for file in files {
+      let Some(asset) = compilation.assets().get(&file) else {
+        continue;
+      };
+      let Some(source) = asset.get_source() else {
+        continue;
+      };
+      let code = source.source().into_string_lossy();
+      let map = source_map_of(compilation, asset, &code)
+        .and_then(|json| serde_json::from_str::<Value>(&json).ok())
+        .unwrap_or(Value::Null);
+      chain.push(json!({ "stage": "asset", "file": file, "map": map }));
+    }
+
+    let mut dump = json!({ "module": readable_identifier, "chain": chain });
+    if let Some(concatenated_module) = concatenated_module
+      .and_then(|concatenated_module| module_graph.module_by_identifier(&concatenated_module))
+    {
+      dump["concatenatedModule"] = json!(
+        concatenated_module
+          .readable_identifier(&compilation.options.context)
+          .as_ref()
+      );
+    }
+    dumps.push((
+      dump_filename(&readable_identifier),
+      serde_json::to_string_pretty(&dump).expect("should serialize source map chain"),
+    ));
+  }
+
+  dumps
+}
+
+#[plugin_hook(CompilationProcessAssets for SourceMapValidationPlugin, stage = Compilation::PROCESS_ASSETS_STAGE_REPORT)]
+async fn process_assets(&self, compilation: &mut Compilation) -> Result<()> {
+  let logger = compilation.get_logger("rspack.SourceMapValidationPlugin");
+
+  let start = logger.time("validate source maps");
+  let to_validate = compilation
+    .assets()
+    .iter()
+    .filter(|(filename, asset)| {
+      !asset.info.development.unwrap_or(false)
+        && self
+          .options
+          .test
+          .as_ref()
+          .is_none_or(|test| test.try_match(filename))
+    })
+    .filter_map(|(filename, asset)| {
+      let code = asset
+        .get_source()?
+        .source()
+        .into_string_lossy()
+        .into_owned();
+      let source_map = source_map_of(compilation, asset, &code)?;
+      Some((filename, code, source_map))
+    })
+    .collect::<Vec<_>>();
+
+  let mut diagnostics = to_validate
+    .into_par_iter()
+    .filter_map(|(filename, code, source_map)| {
+      let problems = match RawSourceMap::from_json(&source_map) {
+        Ok(map) => validate_source_map(&map, &code),
+        Err(e) => vec![e],
+      };
+      if problems.is_empty() {
+        return None;
+      }
+      let mut message = format!(
+        "Source map of asset '{filename}' has {} invalid mapping(s):",
+        problems.len()
+      );
+      for problem in problems.iter().take(MAX_REPORTED_PROBLEMS) {
+        message.push_str("\n  ");
+        message.push_str(problem);
+      }
+      if problems.len() > MAX_REPORTED_PROBLEMS {
+        message.push_str(&format!(
+          "\n  ... and {} more",
+          problems.len() - MAX_REPORTED_PROBLEMS
+        ));
+      }
+      let mut diagnostic = Diagnostic::warn("SourceMapValidationWarning".to_string(), message);
+      diagnostic.file = Some(filename.into());
+      Some((filename.clone(), diagnostic))
+    })
+    .collect::<Vec<_>>();
+  diagnostics.sort_by(|a, b| a.0.cmp(&b.0));
+  logger.time_end(start);
+
+  let dumps = self
+    .options
+    .dump_modules
+    .as_ref()
+    .map(|conditions| dump_module_source_map_chain(compilation, conditions))
+    .unwrap_or_default();
+
+  compilation.extend_diagnostics(diagnostics.into_iter().map(|(_, diagnostic)| diagnostic));
+  for (filename, content) in dumps {
+    compilation.emit_asset(
+      filename,
+      CompilationAsset::new(
+        Some(RawStringSource::from(content).boxed()),
+        AssetInfo::default().with_development(Some(true)),
+      ),
+    );
+  }
+
+  Ok(())
+}
+
+impl Plugin for SourceMapValidationPlugin {
+  fn name(&self) -> &'static str {
+    "rspack.SourceMapValidationPlugin"
+  }
+
+  fn apply(&self, ctx: &mut rspack_core::ApplyContext<'_>) -> Result<()> {
+    ctx
+      .compilation_hooks
+      .process_assets
+      .tap(process_assets::new(self));
+    Ok(())
+  }
+}
@@ -15,8 +15,8 @@ impl ModuleFactory for DllModuleFactory {
       .downcast_ref::<DllEntryDependency>()
       .expect("unreachable");
 
-    Ok(ModuleFactoryResult {
-      module: Some(DllModule::new(dll_entry_dependency).boxed()),
-    })
+    Ok(ModuleFactoryResult::new_with_module(
+      DllModule::new(dll_entry_dependency).boxed(),
+    ))
   }
 }
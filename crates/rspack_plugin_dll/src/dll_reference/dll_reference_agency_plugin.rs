@@ -67,7 +67,9 @@ impl Plugin for DllReferenceAgencyPlugin {
 
     let external = ExternalItem::Object(external_item_object);
 
-    ExternalsPlugin::new(source_type.unwrap_or("var".into()), vec![external], false).apply(ctx)?;
+    let external_type = source_type.as_deref().unwrap_or("var").parse()?;
+
+    ExternalsPlugin::new(external_type, vec![external], false).apply(ctx)?;
 
     DelegatedPlugin::new(DelegatedPluginOptions {
       source: source.clone(),
@@ -302,6 +302,12 @@ pub struct ChunkLinkContext {
   */
   pub imports: IdentifierIndexMap<FxHashMap<Atom, Atom>>,
 
+  /**
+  the local names that hold the required exports of other chunks, only used by
+  the commonjs format
+  */
+  pub chunk_namespaces: FxHashMap<ChunkUkey, Atom>,
+
   /**
   raw import statements
    */
@@ -360,6 +366,7 @@ impl ChunkLinkContext {
       exports: Default::default(),
       re_exports: Default::default(),
       imports: Default::default(),
+      chunk_namespaces: Default::default(),
       required: Default::default(),
       needed_namespace_objects: Default::default(),
       namespace_object_sources: Default::default(),
@@ -7,7 +7,7 @@ use rspack_core::{
 };
 use rspack_plugin_javascript::dependency::ImportDependency;
 
-use crate::EsmLibraryPlugin;
+use crate::{EsmLibraryPlugin, LibraryFormat};
 
 pub static NAMESPACE_SYMBOL: &str = "mod";
 
@@ -112,7 +112,15 @@ fn then_expr(
 }
 
 #[derive(Debug, Default)]
-pub struct DynamicImportDependencyTemplate;
+pub struct DynamicImportDependencyTemplate {
+  format: LibraryFormat,
+}
+
+impl DynamicImportDependencyTemplate {
+  pub fn new(format: LibraryFormat) -> Self {
+    Self { format }
+  }
+}
 
 impl DependencyTemplate for DynamicImportDependencyTemplate {
   fn render(
@@ -182,14 +190,17 @@ impl DependencyTemplate for DynamicImportDependencyTemplate {
     let import_promise = if already_in_chunk {
       Cow::Borrowed("Promise.resolve()")
     } else {
-      Cow::Owned(format!(
-        "import(\"__RSPACK_ESM_CHUNK_{}\")",
-        ChunkGraph::get_chunk_id(
-          &code_generatable_context.compilation.chunk_ids_artifact,
-          &ref_chunk
-        )
-        .expect("should have id")
-      ))
+      let ref_chunk_id = ChunkGraph::get_chunk_id(
+        &code_generatable_context.compilation.chunk_ids_artifact,
+        &ref_chunk,
+      )
+      .expect("should have id");
+      match self.format {
+        LibraryFormat::Esm => Cow::Owned(format!("import(\"__RSPACK_ESM_CHUNK_{ref_chunk_id}\")")),
+        LibraryFormat::CommonJs => Cow::Owned(format!(
+          "Promise.resolve().then(function() {{ return require(\"__RSPACK_ESM_CHUNK_{ref_chunk_id}\"); }})"
+        )),
+      }
     };
 
     let Some(concatenation_scope) = &mut code_generatable_context.concatenation_scope else {
@@ -8,4 +8,4 @@ mod preserve_modules;
 mod render;
 mod runtime;
 
-pub use plugin::EsmLibraryPlugin;
+pub use plugin::{EsmLibraryPlugin, LibraryFormat};
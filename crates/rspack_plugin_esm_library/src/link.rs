@@ -10,14 +10,14 @@ use rspack_core::{
   BuildMetaDefaultObject, BuildMetaExportsType, ChunkGraph, ChunkInitFragments, ChunkUkey,
   CodeGenerationPublicPathAutoReplace, Compilation, ConcatenatedModuleIdent, DependencyType,
   ExportMode, ExportModeNormalReexport, ExportProvided, ExportsInfoGetter, ExportsType,
-  ExternalModule, FindTargetResult, GetUsedNameParam, IdentCollector, InitFragmentKey,
-  InitFragmentStage, MaybeDynamicTargetExportInfoHashKey, ModuleGraph, ModuleGraphCacheArtifact,
-  ModuleIdentifier, ModuleInfo, NAMESPACE_OBJECT_EXPORT, NormalInitFragment, NormalReexportItem,
-  PathData, PrefetchExportsInfoMode, RuntimeGlobals, SourceType, URLStaticMode, UsageState,
-  UsedName, UsedNameItem, escape_name, find_new_name, get_cached_readable_identifier,
-  get_js_chunk_filename_template, get_target, property_access, property_name,
-  reserved_names::RESERVED_NAMES, rspack_sources::ReplaceSource, split_readable_identifier,
-  to_normal_comment,
+  ExternalModule, ExternalType, FindTargetResult, GetUsedNameParam, IdentCollector,
+  InitFragmentKey, InitFragmentStage, MaybeDynamicTargetExportInfoHashKey, ModuleGraph,
+  ModuleGraphCacheArtifact, ModuleIdentifier, ModuleInfo, NAMESPACE_OBJECT_EXPORT,
+  NormalInitFragment, NormalReexportItem, PathData, PrefetchExportsInfoMode, RuntimeGlobals,
+  SourceType, URLStaticMode, UsageState, UsedName, UsedNameItem, escape_name, find_new_name,
+  get_cached_readable_identifier, get_js_chunk_filename_template, get_target, property_access,
+  property_name, reserved_names::RESERVED_NAMES, rspack_sources::ReplaceSource,
+  split_readable_identifier, to_normal_comment,
 };
 use rspack_error::{Diagnostic, Result};
 use rspack_javascript_compiler::ast::Ast;
@@ -39,7 +39,7 @@ use swc_core::{
 };
 
 use crate::{
-  EsmLibraryPlugin,
+  EsmLibraryPlugin, LibraryFormat,
   chunk_link::{ChunkLinkContext, ExternalInterop, Ref, SymbolRef},
 };
 
@@ -1258,8 +1258,8 @@ impl EsmLibraryPlugin {
 
         if let Some(external_module) = ref_box_module.as_external_module()
           && matches!(
-            external_module.get_external_type().as_str(),
-            "module-import" | "module"
+            external_module.get_external_type(),
+            ExternalType::ModuleImport | ExternalType::Module
           )
         {
           Self::re_export_from_external_module(external_module, current_chunk, &re_exports, link);
@@ -1475,8 +1475,8 @@ impl EsmLibraryPlugin {
                   .expect("should have module")
                   .as_external_module()
                 && matches!(
-                  external_module.get_external_type().as_str(),
-                  "module-import" | "module"
+                  external_module.get_external_type(),
+                  ExternalType::ModuleImport | ExternalType::Module
                 )
               {
                 // handle external module
@@ -2022,6 +2022,7 @@ impl EsmLibraryPlugin {
 
       let mut refs = inline_refs;
       let all_used_names = &mut chunk_link.used_names;
+      let chunk_namespaces = &mut chunk_link.chunk_namespaces;
 
       for ((symbol, m), mut all_refs) in ref_by_symbol {
         let ref_chunk = Self::get_module_chunk(m, compilation);
@@ -2034,7 +2035,33 @@ impl EsmLibraryPlugin {
           chunk_imports.entry(m).or_default();
         }
 
-        if needs_import_chunk && !from_external {
+        if needs_import_chunk && !from_external && self.format == LibraryFormat::CommonJs {
+          // keep reading the symbol through the required exports, so that the
+          // binding stays live and circular requires see the final value
+          let namespace = chunk_namespaces
+            .entry(ref_chunk)
+            .or_insert_with(|| {
+              let namespace = find_new_name("chunk", all_used_names, &vec![]);
+              all_used_names.insert(namespace.clone());
+              namespace
+            })
+            .clone();
+
+          // ref_chunk should ensure exporting the symbol
+          let exported = Self::add_chunk_export(
+            ref_chunk,
+            symbol.clone(),
+            symbol.clone(),
+            &mut exports,
+            false,
+          )
+          .expect("no strict export should always success");
+
+          for (_, cur_ref) in &mut all_refs {
+            cur_ref.symbol = namespace.clone();
+            cur_ref.ids.insert(0, exported.clone());
+          }
+        } else if needs_import_chunk && !from_external {
           let readable_identifier = get_cached_readable_identifier(
             &m,
             &module_graph,
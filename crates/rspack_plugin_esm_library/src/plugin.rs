@@ -34,11 +34,38 @@ use crate::{
 };
 
 pub static RSPACK_ESM_RUNTIME_CHUNK: &str = "RSPACK_ESM_RUNTIME";
+// mark the chunks rendered in commonjs format, as they are not javascript modules
+pub static RSPACK_CJS_CHUNK: &str = "RSPACK_CJS_CHUNK";
+
+/// The module format of the rendered chunks.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub enum LibraryFormat {
+  /// `import` and `export` statements.
+  #[default]
+  Esm,
+  /// `require()` calls and `exports` assignments, with the `__esModule` flag.
+  CommonJs,
+}
+
+impl TryFrom<&str> for LibraryFormat {
+  type Error = rspack_error::Error;
+
+  fn try_from(value: &str) -> Result<Self> {
+    match value {
+      "esm" => Ok(Self::Esm),
+      "commonjs" => Ok(Self::CommonJs),
+      _ => Err(rspack_error::error!(
+        "Unsupported library format `{value}`, expected `esm` or `commonjs`"
+      )),
+    }
+  }
+}
 
 #[plugin]
 #[derive(Debug, Default)]
 pub struct EsmLibraryPlugin {
   pub(crate) preserve_modules: Option<PathBuf>,
+  pub(crate) format: LibraryFormat,
   // module instance will hold this map till compile done, we can't mutate it,
   // normal concatenateModule just read the info from it
   // the Arc here is to for module_codegen API, which needs to render module in parallel
@@ -51,9 +78,10 @@ pub struct EsmLibraryPlugin {
 }
 
 impl EsmLibraryPlugin {
-  pub fn new(preserve_modules: Option<PathBuf>) -> Self {
+  pub fn new(preserve_modules: Option<PathBuf>, format: LibraryFormat) -> Self {
     Self::new_inner(
       preserve_modules,
+      format,
       Default::default(),
       Default::default(),
       Default::default(),
@@ -77,7 +105,7 @@ async fn compilation(
 
   compilation.set_dependency_template(
     ImportDependencyTemplate::template_type(),
-    Arc::new(DynamicImportDependencyTemplate::default()),
+    Arc::new(DynamicImportDependencyTemplate::new(self.format)),
   );
   Ok(())
 }
@@ -381,7 +409,9 @@ async fn process_assets(&self, compilation: &mut Compilation) -> Result<()> {
   let mut removed = vec![];
 
   for (asset_name, asset) in compilation.assets() {
-    if asset.get_info().javascript_module.unwrap_or_default() {
+    if asset.get_info().javascript_module.unwrap_or_default()
+      || asset.get_info().extras.contains_key(RSPACK_CJS_CHUNK)
+    {
       let Some(source) = asset.get_source() else {
         continue;
       };
@@ -6,7 +6,8 @@ use rspack_core::{
   AssetInfo, Chunk, ChunkGraph, ChunkRenderContext, ChunkUkey, CodeGenerationDataFilename,
   Compilation, ConcatenatedModuleInfo, DependencyId, InitFragment, ModuleIdentifier, PathData,
   PathInfo, RuntimeGlobals, RuntimeVariable, SourceType, get_js_chunk_filename_template,
-  get_module_directives, get_module_hashbang, get_undo_path, render_init_fragments,
+  get_module_directives, get_module_hashbang, get_undo_path, property_access, property_name,
+  render_init_fragments,
   rspack_sources::{ConcatSource, RawStringSource, ReplaceSource, Source, SourceExt},
 };
 use rspack_error::Result;
@@ -25,7 +26,7 @@ use swc_core::common::sync::Lazy;
 
 use crate::{
   chunk_link::{ChunkLinkContext, ReExportFrom, Ref},
-  plugin::RSPACK_ESM_RUNTIME_CHUNK,
+  plugin::{LibraryFormat, RSPACK_CJS_CHUNK, RSPACK_ESM_RUNTIME_CHUNK},
   runtime::RegisterModuleRuntime,
 };
 
@@ -128,6 +129,20 @@ impl EsmLibraryPlugin {
       break; // Only process the first entry module with hashbang/directives
     }
 
+    if self.format == LibraryFormat::CommonJs
+      && (!chunk_link.hoisted_modules.is_empty() || !chunk_link.decl_modules.is_empty())
+    {
+      // esm is always strict, keep it after the hashbang and among the directives,
+      // the chunks without modules are not flagged, so empty runtime chunks can be removed
+      chunk_init_fragments.push(Box::new(rspack_core::NormalInitFragment::new(
+        "\"use strict\";\n".to_string(),
+        rspack_core::InitFragmentStage::StageConstants,
+        i32::MIN + 1,
+        rspack_core::InitFragmentKey::unique(),
+        None,
+      )));
+    }
+
     let mut replace_auto_public_path = false;
     let mut replace_static_url = false;
 
@@ -141,7 +156,14 @@ impl EsmLibraryPlugin {
       &compilation.chunk_group_by_ukey,
     );
 
-    asset_info.set_javascript_module(true);
+    match self.format {
+      LibraryFormat::Esm => asset_info.set_javascript_module(true),
+      LibraryFormat::CommonJs => {
+        asset_info
+          .extras
+          .insert(RSPACK_CJS_CHUNK.into(), "true".into());
+      }
+    }
 
     let output_path = compilation
       .get_path_with_info(
@@ -220,7 +242,8 @@ impl EsmLibraryPlugin {
     // render cross module links
     let mut runtime_source = ConcatSource::default();
     let mut render_source = ConcatSource::default();
-    let mut export_specifiers: FxIndexSet<Cow<str>> = Default::default();
+    // (local, exported)
+    let mut export_specifiers: FxIndexSet<(Cow<str>, Cow<str>)> = Default::default();
     let mut export_default = None;
     let mut imported_chunks = UkeyIndexMap::<ChunkUkey, FxHashMap<Atom, Atom>>::default();
     let mut runtime_requirements =
@@ -257,11 +280,12 @@ impl EsmLibraryPlugin {
         .any(|m| m.contains(EXPORT_REQUIRE_RUNTIME_MODULE_ID))
         && tree_runtime_requirements.contains(RuntimeGlobals::REQUIRE)
       {
-        export_specifiers.insert(Cow::Owned(
+        let require_name: Cow<str> = Cow::Owned(
           compilation
             .runtime_template
             .render_runtime_globals(&RuntimeGlobals::REQUIRE),
-        ));
+        );
+        export_specifiers.insert((require_name.clone(), require_name));
       }
     }
 
@@ -377,9 +401,52 @@ impl EsmLibraryPlugin {
       }
     }
 
+    let mut exports = chunk_link.exports().iter().collect::<Vec<_>>();
+    exports.sort_by(|a, b| a.0.cmp(b.0));
+
+    for (raw_symbol, exports) in exports {
+      let mut exports = exports.iter().collect::<Vec<_>>();
+      exports.sort_unstable();
+      for export_name in exports {
+        let is_default = export_name.as_str() == "default";
+
+        if is_default {
+          if export_default.is_none() {
+            export_default = Some(raw_symbol);
+          } else {
+            // multiple export default
+            export_specifiers.insert((Cow::Borrowed(raw_symbol), Cow::Borrowed(raw_symbol)));
+          }
+        } else {
+          export_specifiers.insert((Cow::Borrowed(raw_symbol), Cow::Borrowed(export_name)));
+        }
+      }
+    }
+
     // render imports and exports to other chunks
     let mut final_source = ConcatSource::default();
 
+    if self.format == LibraryFormat::CommonJs {
+      if !export_specifiers.is_empty()
+        || !chunk_link.exports().is_empty()
+        || !chunk_link.re_exports().is_empty()
+        || !chunk_link.raw_star_exports.is_empty()
+      {
+        // mark before requiring other chunks, so circular requires see the flag as well
+        final_source.add(RawStringSource::from_static(
+          "Object.defineProperty(exports, \"__esModule\", { value: true });\n",
+        ));
+      }
+
+      // the getters are lazy, so define them before requiring other chunks, then a circular
+      // require sees the exports of this chunk, the same as `__webpack_require__.d`
+      render_cjs_local_exports(
+        &mut final_source,
+        std::mem::take(&mut export_specifiers),
+        export_default.take(),
+      );
+    }
+
     for required_module in already_required {
       runtime_requirements.insert(RuntimeGlobals::REQUIRE);
       let target_chunk = Self::get_module_chunk(required_module, compilation);
@@ -391,19 +458,35 @@ impl EsmLibraryPlugin {
     if !runtime_requirements.is_empty() {
       let runtime_chunk = Self::get_runtime_chunk(*chunk_ukey, compilation);
       if &runtime_chunk != chunk_ukey && runtime_requirements.contains(RuntimeGlobals::REQUIRE) {
-        final_source.add(RawStringSource::from(format!(
-          "import {{ {} }} from \"__RSPACK_ESM_CHUNK_{}\";\n",
-          compilation
-            .runtime_template
-            .render_runtime_globals(&RuntimeGlobals::REQUIRE),
+        let require_name = compilation
+          .runtime_template
+          .render_runtime_globals(&RuntimeGlobals::REQUIRE);
+        let runtime_chunk_id =
           ChunkGraph::get_chunk_id(&compilation.chunk_ids_artifact, &runtime_chunk)
-            .expect("should have id for chunk")
-            .as_str()
-        )));
+            .expect("should have id for chunk");
+        final_source.add(RawStringSource::from(match self.format {
+          LibraryFormat::Esm => {
+            format!("import {{ {require_name} }} from \"__RSPACK_ESM_CHUNK_{runtime_chunk_id}\";\n")
+          }
+          LibraryFormat::CommonJs => format!(
+            "var {require_name} = require(\"__RSPACK_ESM_CHUNK_{runtime_chunk_id}\"){};\n",
+            property_access([&require_name], 0)
+          ),
+        }));
       }
     }
 
     for ((source, attr), symbols) in &chunk_link.raw_import_stmts {
+      if self.format == LibraryFormat::CommonJs {
+        // import attributes are not supported by require()
+        final_source.add(RawStringSource::from(render_cjs_import(
+          &serde_json::to_string(source).expect("should have source"),
+          symbols.default_import.as_deref(),
+          symbols.atoms.iter(),
+        )?));
+        continue;
+      }
+
       let source_str = format!(
         "{}{}",
         serde_json::to_string(source).expect("should have source"),
@@ -462,7 +545,9 @@ impl EsmLibraryPlugin {
     }
 
     for (chunk, imported) in &imported_chunks {
+      let namespace = chunk_link.chunk_namespaces.get(chunk);
       if imported.is_empty()
+        && namespace.is_none()
         && chunk_link
           .re_exports()
           .contains_key(&ReExportFrom::Chunk(*chunk))
@@ -470,6 +555,17 @@ impl EsmLibraryPlugin {
         continue;
       }
 
+      if self.format == LibraryFormat::CommonJs {
+        let chunk_id = ChunkGraph::get_chunk_id(&compilation.chunk_ids_artifact, chunk)
+          .expect("should have chunk id");
+        final_source.add(RawStringSource::from(if let Some(namespace) = namespace {
+          format!("var {namespace} = require(\"__RSPACK_ESM_CHUNK_{chunk_id}\");\n")
+        } else {
+          format!("require(\"__RSPACK_ESM_CHUNK_{chunk_id}\");\n")
+        }));
+        continue;
+      }
+
       final_source.add(RawStringSource::from(format!(
         "import {}\"__RSPACK_ESM_CHUNK_{}\";\n",
         if imported.is_empty() {
@@ -512,100 +608,86 @@ impl EsmLibraryPlugin {
       Arc::new(render_source),
     ]);
 
-    let mut exports = chunk_link.exports().iter().collect::<Vec<_>>();
-    exports.sort_by(|a, b| a.0.cmp(b.0));
-
-    for (raw_symbol, exports) in exports {
-      let mut exports = exports.iter().collect::<Vec<_>>();
-      exports.sort_unstable();
-      for export_name in exports {
-        let is_default = export_name.as_str() == "default";
+    if self.format == LibraryFormat::CommonJs {
+      Self::render_cjs_exports(compilation, chunk_link, &mut final_source);
+    } else {
+      if !export_specifiers.is_empty() {
+        final_source.add(RawStringSource::from(format!(
+          "export {{ {} }};\n",
+          export_specifiers
+            .into_iter()
+            .map(|(local, exported)| {
+              if local == exported {
+                local.to_string()
+              } else {
+                format!("{local} as {exported}")
+              }
+            })
+            .collect::<Vec<_>>()
+            .join(", ")
+        )));
+      }
 
-        if is_default {
-          if export_default.is_none() {
-            export_default = Some(raw_symbol);
+      // render star exports
+      for (source, export_names) in &chunk_link.raw_star_exports {
+        for name in export_names {
+          if name == "*" {
+            final_source.add(RawStringSource::from(format!(
+              "export * from {};\n",
+              serde_json::to_string(source).expect("should have correct request")
+            )));
           } else {
-            // multiple export default
-            export_specifiers.insert(Cow::Borrowed(raw_symbol));
+            final_source.add(RawStringSource::from(format!(
+              "export * as {name} from {};\n",
+              serde_json::to_string(source).expect("should have correct request")
+            )));
           }
-        } else if raw_symbol == export_name {
-          export_specifiers.insert(Cow::Borrowed(raw_symbol));
-        } else {
-          export_specifiers.insert(Cow::Owned(format!("{raw_symbol} as {export_name}")));
-        }
-      }
-    }
-
-    if !export_specifiers.is_empty() {
-      final_source.add(RawStringSource::from(format!(
-        "export {{ {} }};\n",
-        export_specifiers
-          .into_iter()
-          .map(|s| s.to_string())
-          .collect::<Vec<_>>()
-          .join(", ")
-      )));
-    }
-
-    // render star exports
-    for (source, export_names) in &chunk_link.raw_star_exports {
-      for name in export_names {
-        if name == "*" {
-          final_source.add(RawStringSource::from(format!(
-            "export * from {};\n",
-            serde_json::to_string(source).expect("should have correct request")
-          )));
-        } else {
-          final_source.add(RawStringSource::from(format!(
-            "export * as {name} from {};\n",
-            serde_json::to_string(source).expect("should have correct request")
-          )));
         }
       }
-    }
 
-    // render re-exports
-    for (re_export_from, export_symbols) in chunk_link.re_exports() {
-      let mut export_symbols = export_symbols.iter().collect::<Vec<_>>();
-      export_symbols.sort_by(|a, b| a.0.cmp(b.0));
+      // render re-exports
+      for (re_export_from, export_symbols) in chunk_link.re_exports() {
+        let mut export_symbols = export_symbols.iter().collect::<Vec<_>>();
+        export_symbols.sort_by(|a, b| a.0.cmp(b.0));
 
-      final_source.add(RawStringSource::from(format!(
-        "export {{ {} }} from \"{}\";\n",
-        export_symbols
-          .iter()
-          .flat_map(|(imported, exports)| {
-            let mut vec = exports.iter().collect::<Vec<_>>();
-            vec.sort_unstable();
-            vec.into_iter().map(move |export_name| {
-              if *imported == export_name {
-                Cow::Borrowed(imported.as_str())
-              } else {
-                Cow::Owned(format!("{imported} as {export_name}"))
-              }
+        final_source.add(RawStringSource::from(format!(
+          "export {{ {} }} from \"{}\";\n",
+          export_symbols
+            .iter()
+            .flat_map(|(imported, exports)| {
+              let mut vec = exports.iter().collect::<Vec<_>>();
+              vec.sort_unstable();
+              vec.into_iter().map(move |export_name| {
+                if *imported == export_name {
+                  Cow::Borrowed(imported.as_str())
+                } else {
+                  Cow::Owned(format!("{imported} as {export_name}"))
+                }
+              })
             })
-          })
-          .collect::<Vec<_>>()
-          .join(", "),
-        match re_export_from {
-          crate::chunk_link::ReExportFrom::Chunk(chunk_ukey) => {
-            Cow::Owned(format!(
-              "__RSPACK_ESM_CHUNK_{}",
-              ChunkGraph::get_chunk_id(&compilation.chunk_ids_artifact, chunk_ukey)
-                .expect("should have chunk id")
-                .as_str()
-            ))
+            .collect::<Vec<_>>()
+            .join(", "),
+          match re_export_from {
+            crate::chunk_link::ReExportFrom::Chunk(chunk_ukey) => {
+              Cow::Owned(format!(
+                "__RSPACK_ESM_CHUNK_{}",
+                ChunkGraph::get_chunk_id(&compilation.chunk_ids_artifact, chunk_ukey)
+                  .expect("should have chunk id")
+                  .as_str()
+              ))
+            }
+            crate::chunk_link::ReExportFrom::Request(request) => {
+              Cow::Borrowed(request)
+            }
           }
-          crate::chunk_link::ReExportFrom::Request(request) => {
-            Cow::Borrowed(request)
-          }
-        }
-      )));
-    }
+        )));
+      }
 
-    if let Some(default_export) = export_default {
-      final_source.add(RawStringSource::from(format!(
-        "export default {default_export};\n",
-      )));
+      if let Some(default_export) = export_default {
+        final_source.add(RawStringSource::from(format!(
+          "export default {default_export};\n",
+        )));
+      }
     }
 
     let final_source = if replace_auto_public_path {
@@ -834,6 +916,60 @@ impl EsmLibraryPlugin {
     Ok(source)
   }
 
+  fn render_cjs_exports(
+    compilation: &Compilation,
+    chunk_link: &ChunkLinkContext,
+    final_source: &mut ConcatSource,
+  ) {
+    // render star exports, the same as `export *` the default export is excluded
+    for (source, export_names) in &chunk_link.raw_star_exports {
+      let request = serde_json::to_string(source).expect("should have correct request");
+      for name in export_names {
+        if name == "*" {
+          final_source.add(RawStringSource::from(format!(
+            "(function(m) {{ Object.keys(m).forEach(function(k) {{ if (k !== \"default\" && k !== \"__esModule\" && !Object.prototype.hasOwnProperty.call(exports, k)) Object.defineProperty(exports, k, {{ enumerable: true, get: function() {{ return m[k]; }} }}); }}); }})(require({request}));\n"
+          )));
+        } else {
+          final_source.add(RawStringSource::from(format!(
+            "Object.defineProperty(exports, {}, {{ enumerable: true, value: require({request}) }});\n",
+            serde_json::to_string(name.as_str()).expect("should have correct export name")
+          )));
+        }
+      }
+    }
+
+    // render re-exports, read from the required exports lazily to keep the bindings live
+    for (re_export_from, export_symbols) in chunk_link.re_exports() {
+      let mut export_symbols = export_symbols.iter().collect::<Vec<_>>();
+      export_symbols.sort_by(|a, b| a.0.cmp(b.0));
+
+      let request = match re_export_from {
+        ReExportFrom::Chunk(chunk_ukey) => format!(
+          "\"__RSPACK_ESM_CHUNK_{}\"",
+          ChunkGraph::get_chunk_id(&compilation.chunk_ids_artifact, chunk_ukey)
+            .expect("should have chunk id")
+        ),
+        ReExportFrom::Request(request) => {
+          serde_json::to_string(request).expect("should have correct request")
+        }
+      };
+      let mut re_exports = String::new();
+      for (imported, exports) in export_symbols {
+        let mut exports = exports.iter().collect::<Vec<_>>();
+        exports.sort_unstable();
+        for export_name in exports {
+          re_exports.push_str(&render_cjs_export(
+            export_name,
+            &format!("m{}", property_access([imported], 0)),
+          ));
+        }
+      }
+      final_source.add(RawStringSource::from(format!(
+        "(function(m) {{\n{re_exports}}})(require({request}));\n"
+      )));
+    }
+  }
+
   pub fn render_external_required(
     root: ModuleIdentifier,
     compilation: &Compilation,
@@ -856,3 +992,63 @@ impl EsmLibraryPlugin {
     source
   }
 }
+
+/// Render the exports of the local symbols as getters of `exports`.
+fn render_cjs_local_exports(
+  final_source: &mut ConcatSource,
+  export_specifiers: FxIndexSet<(Cow<'_, str>, Cow<'_, str>)>,
+  export_default: Option<&Atom>,
+) {
+  for (local, exported) in &export_specifiers {
+    final_source.add(RawStringSource::from(render_cjs_export(exported, local)));
+  }
+  if let Some(default_export) = export_default {
+    final_source.add(RawStringSource::from(render_cjs_export(
+      "default",
+      default_export,
+    )));
+  }
+}
+
+/// `Object.defineProperty(exports, "name", { enumerable: true, get: function() { return value; } });`
+fn render_cjs_export(exported: &str, value: &str) -> String {
+  format!(
+    "Object.defineProperty(exports, {}, {{ enumerable: true, get: function() {{ return {value}; }} }});\n",
+    serde_json::to_string(exported).expect("should have correct export name")
+  )
+}
+
+/// Render an import declaration of an external as `require()`, the default import reads
+/// `default` of the modules that flag `__esModule`, and the whole `module.exports` of the
+/// others. The imported locals are renamed identifiers in the module code, so they are
+/// destructured here, other chunks are read through their namespace instead.
+fn render_cjs_import<'a>(
+  request: &str,
+  default_import: Option<&str>,
+  imports: impl Iterator<Item = (&'a Atom, &'a Atom)>,
+) -> Result<String> {
+  let mut properties = vec![];
+  for (imported, local) in imports {
+    if imported == local {
+      properties.push(imported.to_string());
+    } else {
+      properties.push(format!("{}: {local}", property_name(imported)?));
+    }
+  }
+
+  let mut import_str = String::new();
+  if let Some(default_import) = default_import {
+    import_str.push_str(&format!(
+      "var {default_import} = require({request});\n{default_import} = {default_import} && {default_import}.__esModule ? {default_import}[\"default\"] : {default_import};\n"
+    ));
+  }
+  if !properties.is_empty() {
+    import_str.push_str(&format!(
+      "var {{ {} }} = require({request});\n",
+      properties.join(", ")
+    ));
+  } else if default_import.is_none() {
+    import_str.push_str(&format!("require({request});\n"));
+  }
+  Ok(import_str)
+}
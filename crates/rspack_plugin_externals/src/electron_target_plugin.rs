@@ -1,4 +1,4 @@
-use rspack_core::{BoxPlugin, ExternalItem, PluginExt};
+use rspack_core::{BoxPlugin, ExternalItem, ExternalType, PluginExt};
 
 use crate::ExternalsPlugin;
 
@@ -27,7 +27,7 @@ impl From<String> for ElectronTargetContext {
 pub fn electron_target_plugin(context: ElectronTargetContext, plugins: &mut Vec<BoxPlugin>) {
   plugins.push(
     ExternalsPlugin::new(
-      "node-commonjs".to_string(),
+      ExternalType::NodeCommonjs,
       [
         "clipboard",
         "crash-reporter",
@@ -48,7 +48,7 @@ pub fn electron_target_plugin(context: ElectronTargetContext, plugins: &mut Vec<
   match context {
     ElectronTargetContext::Main => plugins.push(
       ExternalsPlugin::new(
-        "node-commonjs".to_string(),
+        ExternalType::NodeCommonjs,
         [
           "app",
           "auto-updater",
@@ -75,7 +75,7 @@ pub fn electron_target_plugin(context: ElectronTargetContext, plugins: &mut Vec<
     ),
     ElectronTargetContext::Preload | ElectronTargetContext::Renderer => plugins.push(
       ExternalsPlugin::new(
-        "node-commonjs".to_string(),
+        ExternalType::NodeCommonjs,
         ["desktop-capturer", "ipc-renderer", "remote", "web-frame"]
           .into_iter()
           .map(|i| ExternalItem::String(i.to_string()))
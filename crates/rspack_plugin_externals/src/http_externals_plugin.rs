@@ -1,5 +1,6 @@
 use rspack_core::{
-  BoxPlugin, ExternalItem, ExternalItemFnCtx, ExternalItemFnResult, ExternalItemValue, PluginExt,
+  BoxPlugin, ExternalItem, ExternalItemFnCtx, ExternalItemFnResult, ExternalItemValue,
+  ExternalType, PluginExt,
 };
 
 use crate::ExternalsPlugin;
@@ -7,14 +8,14 @@ use crate::ExternalsPlugin;
 pub fn http_externals_rspack_plugin(css: bool, web_async: bool) -> BoxPlugin {
   if web_async {
     ExternalsPlugin::new(
-      "import".to_owned(),
+      ExternalType::Import,
       vec![http_external_item_web_async(css)],
       false,
     )
     .boxed()
   } else {
     ExternalsPlugin::new(
-      "module".to_owned(),
+      ExternalType::Module,
       vec![http_external_item_web(css)],
       false,
     )
@@ -28,26 +29,26 @@ fn http_external_item_web(css: bool) -> ExternalItem {
       if ctx.dependency_type == "url" {
         if is_external_http_request(&ctx.request) {
           return Ok(ExternalItemFnResult {
-            external_type: Some("asset".to_owned()),
+            external_type: Some(ExternalType::Asset),
             result: Some(ExternalItemValue::String(ctx.request)),
           });
         }
       } else if css && ctx.dependency_type == "css-import" {
         if is_external_http_request(&ctx.request) {
           return Ok(ExternalItemFnResult {
-            external_type: Some("css-import".to_owned()),
+            external_type: Some(ExternalType::CssImport),
             result: Some(ExternalItemValue::String(ctx.request)),
           });
         }
       } else if is_external_http_std_request(&ctx.request) {
         if css && is_external_css_request(&ctx.request) {
           return Ok(ExternalItemFnResult {
-            external_type: Some("css-import".to_owned()),
+            external_type: Some(ExternalType::CssImport),
             result: Some(ExternalItemValue::String(ctx.request)),
           });
         } else {
           return Ok(ExternalItemFnResult {
-            external_type: Some("module".to_owned()),
+            external_type: Some(ExternalType::Module),
             result: Some(ExternalItemValue::String(ctx.request)),
           });
         }
@@ -66,26 +67,26 @@ fn http_external_item_web_async(css: bool) -> ExternalItem {
       if ctx.dependency_type == "url" {
         if is_external_http_request(&ctx.request) {
           return Ok(ExternalItemFnResult {
-            external_type: Some("asset".to_owned()),
+            external_type: Some(ExternalType::Asset),
             result: Some(ExternalItemValue::String(ctx.request)),
           });
         }
       } else if css && ctx.dependency_type == "css-import" {
         if is_external_http_request(&ctx.request) {
           return Ok(ExternalItemFnResult {
-            external_type: Some("css-import".to_owned()),
+            external_type: Some(ExternalType::CssImport),
             result: Some(ExternalItemValue::String(ctx.request)),
           });
         }
       } else if is_external_http_std_request(&ctx.request) {
         if css && is_external_css_request(&ctx.request) {
           return Ok(ExternalItemFnResult {
-            external_type: Some("css-import".to_owned()),
+            external_type: Some(ExternalType::CssImport),
             result: Some(ExternalItemValue::String(ctx.request)),
           });
         } else {
           return Ok(ExternalItemFnResult {
-            external_type: Some("import".to_owned()),
+            external_type: Some(ExternalType::Import),
             result: Some(ExternalItemValue::String(ctx.request)),
           });
         }
@@ -1,9 +1,11 @@
 mod electron_target_plugin;
 mod http_externals_plugin;
 mod node_target_plugin;
+mod package_json_externals_plugin;
 mod plugin;
 
 pub use electron_target_plugin::{ElectronTargetContext, electron_target_plugin};
 pub use http_externals_plugin::http_externals_rspack_plugin;
 pub use node_target_plugin::node_target_plugin;
+pub use package_json_externals_plugin::PackageJsonExternalsRspackPlugin;
 pub use plugin::ExternalsPlugin;
@@ -1,11 +1,11 @@
-use rspack_core::{BoxPlugin, ExternalItem, PluginExt};
+use rspack_core::{BoxPlugin, ExternalItem, ExternalType, PluginExt};
 use rspack_regex::RspackRegex;
 
 use crate::ExternalsPlugin;
 
 pub fn node_target_plugin() -> BoxPlugin {
   ExternalsPlugin::new(
-    "node-commonjs".to_string(),
+    ExternalType::NodeCommonjs,
     vec![
       ExternalItem::from("assert".to_string()),
       ExternalItem::from("assert/strict".to_string()),
@@ -0,0 +1,107 @@
+use std::sync::LazyLock;
+
+use regex::Regex;
+use rspack_core::{
+  ApplyContext, CompilerOptions, DependencyCategory, ExternalItem, ExternalItemFnCtx,
+  ExternalItemFnResult, ExternalItemValue, ExternalType, Plugin, ResolveOptionsWithDependencyType,
+  ResolveResult,
+};
+use rspack_error::Result;
+use rspack_hook::plugin;
+use rspack_paths::Utf8PathBuf;
+
+use crate::ExternalsPlugin;
+
+static PACKAGE_NAME: LazyLock<Regex> =
+  LazyLock::new(|| Regex::new(r"^((?:@[^\\/]+[\\/])?[^\\/]+)").expect("Invalid regex"));
+
+/// Treat `dependencies` and `peerDependencies` of the `package.json` nearest to the context,
+/// and their deep imports like `pkg/sub/path`, as externals.
+///
+/// The external type is chosen from the output library format.
+#[plugin]
+#[derive(Debug)]
+pub struct PackageJsonExternalsRspackPlugin {}
+
+impl Default for PackageJsonExternalsRspackPlugin {
+  fn default() -> Self {
+    Self::new_inner()
+  }
+}
+
+fn external_type_of_library(options: &CompilerOptions) -> Result<ExternalType> {
+  match &options.output.library {
+    Some(library) => match library.library_type.as_str() {
+      "module" | "modern-module" => Ok(ExternalType::ModuleImport),
+      library_type => library_type.parse(),
+    },
+    None if options.output.module => Ok(ExternalType::ModuleImport),
+    None => Ok(ExternalType::Commonjs),
+  }
+}
+
+/// Returns the package name of a bare request, e.g. `@scope/pkg` of `@scope/pkg/sub/path`.
+fn package_name(request: &str) -> Option<&str> {
+  if request.starts_with(['.', '/', '\\', '!', '#']) {
+    return None;
+  }
+  let name = PACKAGE_NAME.captures(request)?.get(1)?.as_str();
+  // protocols like `node:` or `data:`, and windows absolute paths
+  (!name.contains(':')).then_some(name)
+}
+
+fn package_json_external_item(context: Utf8PathBuf) -> ExternalItem {
+  ExternalItem::Fn(Box::new(move |ctx: ExternalItemFnCtx| {
+    let context = context.clone();
+    Box::pin(async move {
+      let unmatched = ExternalItemFnResult {
+        external_type: None,
+        result: None,
+      };
+      let Some(name) = package_name(&ctx.request) else {
+        return Ok(unmatched);
+      };
+      let resolver = ctx.resolver_factory.get(ResolveOptionsWithDependencyType {
+        resolve_options: None,
+        resolve_to_context: true,
+        dependency_category: DependencyCategory::Unknown,
+      });
+      let Ok(ResolveResult::Resource(resource)) =
+        resolver.resolve(context.as_std_path(), ".").await
+      else {
+        return Ok(unmatched);
+      };
+      let Some(description_data) = resource.description_data else {
+        return Ok(unmatched);
+      };
+      let json = description_data.json();
+      let is_dependency = ["dependencies", "peerDependencies"]
+        .into_iter()
+        .any(|field| json.get(field).and_then(|deps| deps.get(name)).is_some());
+      if !is_dependency {
+        return Ok(unmatched);
+      }
+      Ok(ExternalItemFnResult {
+        external_type: None,
+        result: Some(ExternalItemValue::Bool(true)),
+      })
+    })
+  }))
+}
+
+impl Plugin for PackageJsonExternalsRspackPlugin {
+  fn name(&self) -> &'static str {
+    "rspack.PackageJsonExternalsRspackPlugin"
+  }
+
+  fn apply(&self, ctx: &mut ApplyContext<'_>) -> Result<()> {
+    let external_type = external_type_of_library(ctx.compiler_options)?;
+    let context = ctx.compiler_options.context.as_path().to_path_buf();
+    ExternalsPlugin::new(
+      external_type,
+      vec![package_json_external_item(context)],
+      false,
+    )
+    .apply(ctx)
+  }
+}
@@ -10,7 +10,7 @@ use rspack_core::{
   ExternalTypeEnum, ModuleDependency, ModuleExt, ModuleFactoryCreateData,
   NormalModuleFactoryFactorize, Plugin, ResolveOptionsWithDependencyType, SourceType,
 };
-use rspack_error::Result;
+use rspack_error::{Diagnostic, Result};
 use rspack_hook::{plugin, plugin_hook};
 use rspack_plugin_javascript::dependency::{ESMImportSideEffectDependency, ImportDependency};
 
@@ -33,17 +33,19 @@ impl ExternalsPlugin {
   fn handle_external(
     &self,
     config: &ExternalItemValue,
-    r#type: Option<String>,
+    r#type: Option<ExternalType>,
     dependency: &dyn ModuleDependency,
+    diagnostics: &mut Vec<Diagnostic>,
   ) -> Option<ExternalModule> {
     let (external_module_config, external_module_type) = match config {
       ExternalItemValue::String(config) => {
-        let (external_type, config) =
-          if let Some((external_type, new_config)) = parse_external_type_from_str(config) {
-            (external_type, new_config)
-          } else {
-            (self.r#type.clone(), config.to_owned())
-          };
+        let (external_type, config) = if let Some((external_type, new_config)) =
+          parse_external_type_from_str(config, diagnostics)
+        {
+          (external_type, new_config)
+        } else {
+          (self.r#type, config.to_owned())
+        };
         (
           ExternalRequest::Single(ExternalRequestValue::new(config, None)),
           external_type,
@@ -52,12 +54,13 @@ impl ExternalsPlugin {
       ExternalItemValue::Array(arr) => {
         let mut iter = arr.iter().peekable();
         let primary = iter.next()?;
-        let (external_type, primary) =
-          if let Some((external_type, new_primary)) = parse_external_type_from_str(primary) {
-            (external_type, new_primary)
-          } else {
-            (self.r#type.clone(), primary.to_owned())
-          };
+        let (external_type, primary) = if let Some((external_type, new_primary)) =
+          parse_external_type_from_str(primary, diagnostics)
+        {
+          (external_type, new_primary)
+        } else {
+          (self.r#type, primary.to_owned())
+        };
         let rest = iter.peek().is_some().then(|| iter.cloned().collect());
         (
           ExternalRequest::Single(ExternalRequestValue::new(primary, rest)),
@@ -71,7 +74,7 @@ impl ExternalsPlugin {
               dependency.request().to_string(),
               None,
             )),
-            self.r#type.clone(),
+            self.r#type,
           )
         } else {
           return None;
@@ -92,15 +95,28 @@ impl ExternalsPlugin {
             })
             .collect(),
         ),
-        self.r#type.clone(),
+        self.r#type,
       ),
     };
 
-    fn parse_external_type_from_str(v: &str) -> Option<(ExternalType, String)> {
+    fn parse_external_type_from_str(
+      v: &str,
+      diagnostics: &mut Vec<Diagnostic>,
+    ) -> Option<(ExternalType, String)> {
       if UNSPECIFIED_EXTERNAL_TYPE_REGEXP.is_match(v)
         && let Some((t, c)) = v.split_once(' ')
       {
-        return Some((t.to_owned(), c.to_owned()));
+        // align with webpack, which renders the externals of unknown types like `var` ones
+        let external_type = t.parse().unwrap_or_else(|_| {
+          diagnostics.push(Diagnostic::warn(
+            "UnknownExternalTypeWarning".into(),
+            format!(
+              "Unknown external type \"{t}\" in \"{v}\", \"{c}\" is treated as a \"var\" external"
+            ),
+          ));
+          ExternalType::Var
+        });
+        return Some((external_type, c.to_owned()));
       }
       None
     }
@@ -127,7 +143,7 @@ impl ExternalsPlugin {
       source_type: None,
     };
 
-    if r#type.as_ref().is_some_and(|t| t == "asset")
+    if r#type == Some(ExternalType::Asset)
       && matches!(dependency.dependency_type(), DependencyType::CssUrl)
     {
       dependency_meta.source_type = Some(SourceType::CssUrl);
@@ -155,7 +171,7 @@ async fn factorize(&self, data: &mut ModuleFactoryCreateData) -> Result<Option<B
         let request = dependency.request();
 
         if let Some(value) = eh.get(request) {
-          let maybe_module = self.handle_external(value, None, dependency);
+          let maybe_module = self.handle_external(value, None, dependency, &mut data.diagnostics);
           return Ok(maybe_module.map(|i| i.boxed()));
         }
       }
@@ -166,6 +182,7 @@ async fn factorize(&self, data: &mut ModuleFactoryCreateData) -> Result<Option<B
             &ExternalItemValue::String(request.to_string()),
             None,
             dependency,
+            &mut data.diagnostics,
           );
           return Ok(maybe_module.map(|i| i.boxed()));
         }
@@ -177,6 +194,7 @@ async fn factorize(&self, data: &mut ModuleFactoryCreateData) -> Result<Option<B
             &ExternalItemValue::String(request.to_string()),
             None,
             dependency,
+            &mut data.diagnostics,
           );
           return Ok(maybe_module.map(|i| i.boxed()));
         }
@@ -211,7 +229,8 @@ async fn factorize(&self, data: &mut ModuleFactoryCreateData) -> Result<Option<B
         })
         .await?;
         if let Some(r) = result.result {
-          let maybe_module = self.handle_external(&r, result.external_type, dependency);
+          let maybe_module =
+            self.handle_external(&r, result.external_type, dependency, &mut data.diagnostics);
           return Ok(maybe_module.map(|i| i.boxed()));
         }
       }
@@ -0,0 +1,370 @@
+mod runtime;
+
+use std::{
+  hash::Hash,
+  sync::{Arc, RwLock},
+};
+
+use cow_utils::CowUtils;
+use rspack_core::{
+  AssetInfo, ChunkLoading, ChunkLoadingType, ChunkUkey, Compilation, CompilationAsset,
+  CompilationProcessAssets, CompilationRuntimeRequirementInTree, CompilationSeal, Filename,
+  PathData, Plugin, RuntimeGlobals,
+  rspack_sources::{RawStringSource, SourceExt},
+};
+use rspack_error::{Diagnostic, Result, ToStringResultToRspackResultExt, error};
+use rspack_hash::RspackHash;
+use rspack_hook::{plugin, plugin_hook};
+use rustc_hash::{FxHashMap, FxHashSet};
+
+use crate::runtime::{I18nLoadingRuntimeModule, I18nMessagesLoading};
+
+const LOCALE_PLACEHOLDER: &str = "[locale]";
+
+/// The message catalog of a locale.
+#[derive(Debug)]
+pub enum I18nCatalog {
+  /// The messages keyed by message id.
+  Messages(FxHashMap<String, String>),
+  /// The path of a JSON file containing the messages, relative to the context of the compiler.
+  File(String),
+}
+
+#[derive(Debug)]
+pub struct I18nLocale {
+  pub name: String,
+  pub catalog: I18nCatalog,
+}
+
+/// The messages of a locale, read from its catalog at the beginning of each seal.
+#[derive(Debug)]
+struct LoadedLocale {
+  name: String,
+  messages: FxHashMap<String, String>,
+}
+
+#[derive(Debug)]
+pub struct I18nRspackPluginOptions {
+  /// Sorted by locale name, so the output is deterministic.
+  pub locales: Vec<I18nLocale>,
+  /// The filename template of the messages assets, supports `[locale]` besides the chunk placeholders.
+  pub filename: String,
+  /// The expression evaluated at runtime to get the locale to load.
+  pub locale_expression: String,
+  /// The property of the global object which the messages are registered to.
+  pub global_name: String,
+}
+
+/// The messages of a chunk in a locale.
+#[derive(Debug)]
+struct MessagesAsset {
+  locale: String,
+  filename: String,
+  source: String,
+  info: AssetInfo,
+  missing: Vec<String>,
+}
+
+/// Emits the messages used by each chunk as a script per locale, which is loaded alongside the chunk.
+///
+/// The used message ids are collected by `rspackExperiments.collectI18nMessages` of `builtin:swc-loader`.
+#[plugin]
+#[derive(Debug)]
+pub struct I18nRspackPlugin {
+  options: I18nRspackPluginOptions,
+  locales: RwLock<Arc<Vec<LoadedLocale>>>,
+}
+
+impl I18nRspackPlugin {
+  pub fn new(options: I18nRspackPluginOptions) -> Self {
+    Self::new_inner(options, Default::default())
+  }
+
+  fn loaded_locales(&self) -> Arc<Vec<LoadedLocale>> {
+    self
+      .locales
+      .read()
+      .expect("should lock i18n locales")
+      .clone()
+  }
+
+  fn chunk_message_ids(&self, compilation: &Compilation, chunk_ukey: &ChunkUkey) -> Vec<String> {
+    let module_graph = compilation.get_module_graph();
+    let mut ids = compilation
+      .chunk_graph
+      .get_chunk_modules(chunk_ukey, &module_graph)
+      .into_iter()
+      .filter_map(|module| module.build_info().i18n_message_ids.as_ref())
+      .flatten()
+      .cloned()
+      .collect::<Vec<_>>();
+    ids.sort_unstable();
+    ids.dedup();
+    ids
+  }
+
+  fn render_messages(&self, compilation: &Compilation, locale: &str, messages: String) -> String {
+    let registry = format!(
+      "{}[{}]",
+      compilation.options.output.global_object,
+      serde_json::to_string(&self.options.global_name).expect("should serialize string")
+    );
+    let locale = serde_json::to_string(locale).expect("should serialize string");
+    format!(
+      "(function (registry) {{\n  registry[{locale}] = Object.assign(registry[{locale}] || {{}}, {messages});\n}})({registry} = {registry} || {{}});\n"
+    )
+  }
+
+  async fn messages_assets(
+    &self,
+    compilation: &Compilation,
+    chunk_ukey: &ChunkUkey,
+  ) -> Result<Vec<MessagesAsset>> {
+    let ids = self.chunk_message_ids(compilation, chunk_ukey);
+    if ids.is_empty() {
+      return Ok(vec![]);
+    }
+    let chunk = compilation.chunk_by_ukey.expect_get(chunk_ukey);
+    let locales = self.loaded_locales();
+    let mut assets = Vec::with_capacity(locales.len());
+    for locale in locales.iter() {
+      let mut messages = serde_json::Map::new();
+      let mut missing = vec![];
+      for id in &ids {
+        match locale.messages.get(id) {
+          Some(message) => {
+            messages.insert(id.clone(), serde_json::Value::String(message.clone()));
+          }
+          None => missing.push(id.clone()),
+        }
+      }
+      let source = self.render_messages(
+        compilation,
+        &locale.name,
+        serde_json::Value::Object(messages).to_string(),
+      );
+
+      let mut hasher = RspackHash::from(&compilation.options.output);
+      source.hash(&mut hasher);
+      let content_hash = hasher.digest(&compilation.options.output.hash_digest);
+      let filename = Filename::from(
+        self
+          .options
+          .filename
+          .cow_replace(LOCALE_PLACEHOLDER, &locale.name)
+          .into_owned(),
+      );
+      let (filename, info) = compilation
+        .get_asset_path_with_info(
+          &filename,
+          PathData::default()
+            .chunk_id_optional(
+              chunk
+                .id(&compilation.chunk_ids_artifact)
+                .map(|id| id.as_str()),
+            )
+            .chunk_name_optional(chunk.name_for_filename_template(&compilation.chunk_ids_artifact))
+            .content_hash(content_hash.rendered(compilation.options.output.hash_digest_length)),
+        )
+        .await?;
+      assets.push(MessagesAsset {
+        locale: locale.name.clone(),
+        filename,
+        source,
+        info,
+        missing,
+      });
+    }
+    Ok(assets)
+  }
+}
+
+#[plugin_hook(CompilationSeal for I18nRspackPlugin)]
+async fn seal(&self, compilation: &mut Compilation) -> Result<()> {
+  let mut locales = Vec::with_capacity(self.options.locales.len());
+  for locale in &self.options.locales {
+    let messages = match &locale.catalog {
+      I18nCatalog::Messages(messages) => messages.clone(),
+      I18nCatalog::File(file) => {
+        let path = compilation.options.context.as_path().join(file);
+        compilation.file_dependencies.insert(path.as_path().into());
+        let content = compilation
+          .input_filesystem
+          .read(&path)
+          .await
+          .map_err(|e| {
+            error!(
+              "Failed to read the catalog of locale \"{}\": {e}",
+              locale.name
+            )
+          })?;
+        serde_json::from_slice(&content).to_rspack_result_with_message(|e| {
+          format!(
+            "Failed to parse the catalog of locale \"{}\" at {path}: {e}",
+            locale.name
+          )
+        })?
+      }
+    };
+    locales.push(LoadedLocale {
+      name: locale.name.clone(),
+      messages,
+    });
+  }
+  *self.locales.write().expect("should lock i18n locales") = Arc::new(locales);
+  Ok(())
+}
+
+/// The way messages scripts are loaded depends on how the chunks are loaded.
+fn messages_loading(
+  compilation: &Compilation,
+  chunk_ukey: &ChunkUkey,
+) -> Option<I18nMessagesLoading> {
+  let chunk_loading = compilation
+    .chunk_by_ukey
+    .get(chunk_ukey)
+    .and_then(|chunk| chunk.get_entry_options(&compilation.chunk_group_by_ukey))
+    .and_then(|options| options.chunk_loading.as_ref())
+    .unwrap_or(&compilation.options.output.chunk_loading);
+  match chunk_loading {
+    ChunkLoading::Enable(ChunkLoadingType::Jsonp | ChunkLoadingType::Custom(_)) => {
+      Some(I18nMessagesLoading::Script)
+    }
+    ChunkLoading::Enable(ChunkLoadingType::ImportScripts) => {
+      Some(I18nMessagesLoading::ImportScripts)
+    }
+    ChunkLoading::Enable(ChunkLoadingType::Import) => Some(I18nMessagesLoading::Import),
+    ChunkLoading::Enable(ChunkLoadingType::Require | ChunkLoadingType::AsyncNode) => {
+      Some(I18nMessagesLoading::Require)
+    }
+    ChunkLoading::Disable => None,
+  }
+}
+
+#[plugin_hook(CompilationRuntimeRequirementInTree for I18nRspackPlugin)]
+async fn runtime_requirements_in_tree(
+  &self,
+  compilation: &mut Compilation,
+  chunk_ukey: &ChunkUkey,
+  _all_runtime_requirements: &RuntimeGlobals,
+  runtime_requirements: &RuntimeGlobals,
+  runtime_requirements_mut: &mut RuntimeGlobals,
+) -> Result<Option<()>> {
+  if !runtime_requirements.contains(RuntimeGlobals::ENSURE_CHUNK_HANDLERS) {
+    return Ok(None);
+  }
+  let Some(loading) = messages_loading(compilation, chunk_ukey) else {
+    return Ok(None);
+  };
+
+  let chunk = compilation.chunk_by_ukey.expect_get(chunk_ukey);
+  let mut chunk_files = vec![];
+  for async_chunk in chunk.get_all_async_chunks(&compilation.chunk_group_by_ukey) {
+    let assets = self.messages_assets(compilation, &async_chunk).await?;
+    if assets.is_empty() {
+      continue;
+    }
+    let Some(chunk_id) = compilation
+      .chunk_by_ukey
+      .expect_get(&async_chunk)
+      .id(&compilation.chunk_ids_artifact)
+    else {
+      continue;
+    };
+    chunk_files.push((
+      chunk_id.as_str().to_string(),
+      assets
+        .into_iter()
+        .map(|asset| (asset.locale, asset.filename))
+        .collect::<Vec<_>>(),
+    ));
+  }
+  if chunk_files.is_empty() {
+    return Ok(None);
+  }
+
+  match loading {
+    I18nMessagesLoading::Script => {
+      runtime_requirements_mut.insert(RuntimeGlobals::PUBLIC_PATH);
+      runtime_requirements_mut.insert(RuntimeGlobals::LOAD_SCRIPT);
+    }
+    I18nMessagesLoading::ImportScripts | I18nMessagesLoading::Import => {
+      runtime_requirements_mut.insert(RuntimeGlobals::PUBLIC_PATH);
+    }
+    I18nMessagesLoading::Require => {}
+  }
+  compilation.add_runtime_module(
+    chunk_ukey,
+    Box::new(I18nLoadingRuntimeModule::new(
+      &compilation.runtime_template,
+      chunk_files,
+      self.options.locale_expression.clone(),
+      loading,
+    )),
+  )?;
+
+  Ok(None)
+}
+
+#[plugin_hook(CompilationProcessAssets for I18nRspackPlugin, stage = Compilation::PROCESS_ASSETS_STAGE_ADDITIONAL)]
+async fn process_assets(&self, compilation: &mut Compilation) -> Result<()> {
+  let mut chunks = compilation
+    .chunk_by_ukey
+    .keys()
+    .copied()
+    .collect::<Vec<_>>();
+  chunks.sort_unstable();
+
+  let mut reported = FxHashSet::default();
+  let mut diagnostics = vec![];
+  let mut emitted = vec![];
+  for chunk_ukey in chunks {
+    for asset in self.messages_assets(compilation, &chunk_ukey).await? {
+      for id in asset.missing {
+        if reported.insert((asset.locale.clone(), id.clone())) {
+          diagnostics.push(Diagnostic::warn(
+            "I18nRspackPlugin".to_string(),
+            format!(
+              "Message \"{id}\" is missing in the catalog of locale \"{}\"",
+              asset.locale
+            ),
+          ));
+        }
+      }
+      emitted.push((chunk_ukey, asset.filename, asset.source, asset.info));
+    }
+  }
+
+  for (chunk_ukey, filename, source, info) in emitted {
+    compilation
+      .chunk_by_ukey
+      .expect_get_mut(&chunk_ukey)
+      .add_auxiliary_file(filename.clone());
+    compilation.emit_asset(
+      filename,
+      CompilationAsset::new(Some(RawStringSource::from(source).boxed()), info),
+    );
+  }
+  compilation.extend_diagnostics(diagnostics);
+
+  Ok(())
+}
+
+impl Plugin for I18nRspackPlugin {
+  fn name(&self) -> &'static str {
+    "rspack.I18nRspackPlugin"
+  }
+
+  fn apply(&self, ctx: &mut rspack_core::ApplyContext<'_>) -> Result<()> {
+    ctx.compilation_hooks.seal.tap(seal::new(self));
+    ctx
+      .compilation_hooks
+      .runtime_requirement_in_tree
+      .tap(runtime_requirements_in_tree::new(self));
+    ctx
+      .compilation_hooks
+      .process_assets
+      .tap(process_assets::new(self));
+    Ok(())
+  }
+}
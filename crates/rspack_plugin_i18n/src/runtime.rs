@@ -0,0 +1,147 @@
+use rspack_collections::Identifier;
+use rspack_core::{
+  ChunkUkey, Compilation, RuntimeGlobals, RuntimeModule, RuntimeModuleStage, RuntimeTemplate,
+  impl_runtime_module,
+};
+use rspack_error::Result;
+use rspack_plugin_runtime::get_output_dir;
+
+/// How the messages scripts are loaded, follows the chunk loading of the runtime chunk.
+#[derive(Debug, Clone, Copy)]
+pub(crate) enum I18nMessagesLoading {
+  /// `<script>` tags, for `jsonp` chunk loading.
+  Script,
+  ImportScripts,
+  Import,
+  /// `require()` relative to the output directory, for `require` and `async-node` chunk loading.
+  Require,
+}
+
+#[impl_runtime_module]
+#[derive(Debug)]
+pub(crate) struct I18nLoadingRuntimeModule {
+  id: Identifier,
+  chunk: Option<ChunkUkey>,
+  /// The messages files of each async chunk, as `(chunk id, [(locale, filename)])`.
+  chunk_files: Vec<(String, Vec<(String, String)>)>,
+  locale_expression: String,
+  loading: I18nMessagesLoading,
+}
+
+impl I18nLoadingRuntimeModule {
+  pub(crate) fn new(
+    runtime_template: &RuntimeTemplate,
+    chunk_files: Vec<(String, Vec<(String, String)>)>,
+    locale_expression: String,
+    loading: I18nMessagesLoading,
+  ) -> Self {
+    Self::with_default(
+      Identifier::from(format!(
+        "{}i18n_messages_loading",
+        runtime_template.runtime_module_prefix()
+      )),
+      None,
+      chunk_files,
+      locale_expression,
+      loading,
+    )
+  }
+
+  async fn render_load(&self, compilation: &Compilation) -> Result<String> {
+    let runtime_template = &compilation.runtime_template;
+    let public_path = runtime_template.render_runtime_globals(&RuntimeGlobals::PUBLIC_PATH);
+    Ok(match self.loading {
+      I18nMessagesLoading::Script => {
+        let load_script = runtime_template.render_runtime_globals(&RuntimeGlobals::LOAD_SCRIPT);
+        format!(
+          r#"new Promise(function (resolve, reject) {{
+      var url = {public_path} + file;
+      {load_script}(url, function (event) {{
+        if (event && event.type === "load") return resolve();
+        delete installedI18nMessages[file];
+        var error = new Error("Loading i18n messages " + file + " of chunk " + chunkId + " failed.\n(" + url + ")");
+        error.name = "ChunkLoadError";
+        error.type = event && event.type;
+        error.request = url;
+        reject(error);
+      }}, "i18n-messages-" + file, chunkId);
+    }})"#
+        )
+      }
+      I18nMessagesLoading::ImportScripts => {
+        format!("(importScripts({public_path} + file), Promise.resolve())")
+      }
+      I18nMessagesLoading::Import => format!(
+        r#"import({public_path} + file).catch(function (error) {{
+      delete installedI18nMessages[file];
+      throw error;
+    }})"#
+      ),
+      I18nMessagesLoading::Require => {
+        let chunk = compilation
+          .chunk_by_ukey
+          .expect_get(&self.chunk.expect("The chunk should be attached."));
+        let output_dir = serde_json::to_string(&get_output_dir(chunk, compilation, true).await?)
+          .expect("should serialize string");
+        format!("(require({output_dir} + file), Promise.resolve())")
+      }
+    })
+  }
+}
+
+#[async_trait::async_trait]
+impl RuntimeModule for I18nLoadingRuntimeModule {
+  fn name(&self) -> Identifier {
+    self.id
+  }
+
+  fn attach(&mut self, chunk: ChunkUkey) {
+    self.chunk = Some(chunk);
+  }
+
+  fn stage(&self) -> RuntimeModuleStage {
+    RuntimeModuleStage::Attach
+  }
+
+  async fn generate(&self, compilation: &Compilation) -> Result<String> {
+    let runtime_template = &compilation.runtime_template;
+    let chunk_files = serde_json::Value::Object(
+      self
+        .chunk_files
+        .iter()
+        .map(|(chunk_id, files)| {
+          (
+            chunk_id.clone(),
+            serde_json::Value::Object(
+              files
+                .iter()
+                .map(|(locale, filename)| (locale.clone(), filename.clone().into()))
+                .collect(),
+            ),
+          )
+        })
+        .collect(),
+    );
+    let ensure_chunk_handlers =
+      runtime_template.render_runtime_globals(&RuntimeGlobals::ENSURE_CHUNK_HANDLERS);
+    let load = self.render_load(compilation).await?;
+    let locale_expression = &self.locale_expression;
+
+    Ok(format!(
+      r#"
+var i18nMessagesFiles = {chunk_files};
+var installedI18nMessages = {{}};
+{ensure_chunk_handlers}.i18n = function (chunkId, promises) {{
+  var files = i18nMessagesFiles[chunkId];
+  if (!files) return;
+  var file = files[{locale_expression}];
+  if (!file) return;
+  if (!installedI18nMessages[file]) {{
+    installedI18nMessages[file] = {load};
+  }}
+  promises.push(installedI18nMessages[file]);
+}};
+"#
+    ))
+  }
+}
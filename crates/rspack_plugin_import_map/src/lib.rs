@@ -0,0 +1,246 @@
+use std::collections::BTreeMap;
+
+use cow_utils::CowUtils;
+use rspack_core::{
+  AssetInfo, Compilation, CompilationAsset, CompilationId, CompilationParams,
+  CompilationProcessAssets, CompilerThisCompilation, Diagnostic, ExternalRequest, ExternalType,
+  Plugin, PublicPath,
+  rspack_sources::{RawStringSource, SourceExt},
+};
+use rspack_error::{Result, ToStringResultToRspackResultExt};
+use rspack_hook::{plugin, plugin_hook};
+use rspack_plugin_html::{
+  AlterAssetTagGroupsData, HtmlPluginAlterAssetTagGroups, HtmlRspackPlugin,
+  tag::{HtmlPluginAttribute, HtmlPluginTag},
+};
+use rspack_util::fx_hash::FxDashMap;
+
+const PLUGIN_NAME: &str = "rspack.ImportMapRspackPlugin";
+
+#[derive(Debug)]
+pub struct ImportMapRspackPluginOptions {
+  /// The filename of the emitted import map, the import map is not emitted when not set.
+  pub filename: Option<String>,
+  /// The entries added to the import map as-is, they take precedence over the generated ones.
+  pub imports: BTreeMap<String, String>,
+  /// The url template of `module` and `import` externals, `[request]` is replaced by the request.
+  pub external_url: Option<String>,
+  /// Whether to map the JavaScript files of chunks by the chunk name or id.
+  pub chunks: bool,
+  /// The prefix of the specifiers of chunks.
+  pub chunk_prefix: String,
+  /// The public path of the urls of chunks, uses `output.publicPath` when not set.
+  pub public_path: Option<String>,
+  /// Whether to inline the import map into the html files of `HtmlRspackPlugin`.
+  pub inject: bool,
+}
+
+#[plugin]
+#[derive(Debug)]
+pub struct ImportMapRspackPlugin {
+  options: ImportMapRspackPluginOptions,
+  /// The serialized import map of the latest compilation, read by `HtmlRspackPlugin` hooks.
+  import_maps: FxDashMap<CompilationId, String>,
+}
+
+impl ImportMapRspackPlugin {
+  pub fn new(options: ImportMapRspackPluginOptions) -> Self {
+    Self::new_inner(options, Default::default())
+  }
+
+  async fn get_public_path(&self, compilation: &Compilation) -> String {
+    let public_path = match &self.options.public_path {
+      Some(public_path) => PublicPath::ensure_ends_with_slash(public_path.clone()),
+      None => {
+        let filename = self.options.filename.as_deref().unwrap_or_default();
+        compilation
+          .options
+          .output
+          .public_path
+          .render(compilation, filename)
+          .await
+      }
+    };
+    // relative urls must start with `./` in import maps, otherwise they are treated as bare specifiers
+    if public_path.is_empty() {
+      "./".to_string()
+    } else {
+      public_path
+    }
+  }
+
+  fn get_external_url(&self, request: &str) -> Option<String> {
+    self
+      .options
+      .external_url
+      .as_ref()
+      .map(|template| template.cow_replace("[request]", request).into_owned())
+  }
+}
+
+#[plugin_hook(CompilerThisCompilation for ImportMapRspackPlugin)]
+async fn this_compilation(
+  &self,
+  compilation: &mut Compilation,
+  _params: &mut CompilationParams,
+) -> Result<()> {
+  let hooks = HtmlRspackPlugin::get_compilation_hooks_mut(compilation.id());
+  let mut hooks = hooks.borrow_mut();
+  hooks
+    .alter_asset_tag_groups
+    .tap(alter_asset_tag_groups::new(self));
+  Ok(())
+}
+
+#[plugin_hook(CompilationProcessAssets for ImportMapRspackPlugin, stage = Compilation::PROCESS_ASSETS_STAGE_ADDITIONS)]
+async fn process_assets(&self, compilation: &mut Compilation) -> Result<()> {
+  let mut imports = BTreeMap::new();
+
+  if self.options.chunks {
+    let public_path = self.get_public_path(compilation).await;
+    for chunk in compilation.chunk_by_ukey.values() {
+      let Some(name) = chunk.name().or_else(|| {
+        chunk
+          .id(&compilation.chunk_ids_artifact)
+          .map(|id| id.as_str())
+      }) else {
+        continue;
+      };
+      let mut files = chunk
+        .files()
+        .iter()
+        .filter(|file| is_javascript_file(file))
+        .collect::<Vec<_>>();
+      // a chunk with multiple JavaScript files can't be mapped by a single specifier
+      if files.len() != 1 {
+        continue;
+      }
+      let file = files.remove(0);
+      imports.insert(
+        format!("{}{name}", self.options.chunk_prefix),
+        format!("{public_path}{file}"),
+      );
+    }
+  }
+
+  let mut missing_externals = vec![];
+  let module_graph = compilation.get_module_graph();
+  for module in module_graph.modules().values() {
+    let Some(external_module) = module.as_external_module() else {
+      continue;
+    };
+    let external_type = external_module.get_external_type();
+    if !matches!(
+      external_type,
+      ExternalType::Module | ExternalType::Import | ExternalType::ModuleImport
+    ) {
+      continue;
+    }
+    let request = match &external_module.request {
+      ExternalRequest::Single(request) => Some(request),
+      ExternalRequest::Map(map) => map.get(external_type.as_str()),
+    };
+    let Some(request) = request.map(|request| request.primary()) else {
+      continue;
+    };
+    if !is_bare_specifier(request) || self.options.imports.contains_key(request) {
+      continue;
+    }
+    match self.get_external_url(request) {
+      Some(url) => {
+        imports.insert(request.to_string(), url);
+      }
+      None => missing_externals.push(request.to_string()),
+    }
+  }
+
+  for request in missing_externals {
+    compilation.push_diagnostic(Diagnostic::warn(
+      "ImportMapRspackPlugin".to_string(),
+      format!(
+        "The external \"{request}\" is not mapped in the import map. Add it to the \"imports\" option or set the \"externalUrl\" option."
+      ),
+    ));
+  }
+
+  imports.extend(self.options.imports.clone());
+  let import_map = serde_json::json!({ "imports": imports });
+
+  if let Some(filename) = &self.options.filename {
+    let content = serde_json::to_string_pretty(&import_map).to_rspack_result()?;
+    compilation.emit_asset(
+      filename.clone(),
+      CompilationAsset::new(
+        Some(RawStringSource::from(content).boxed()),
+        AssetInfo::default(),
+      ),
+    );
+  }
+
+  if self.options.inject {
+    let content = serde_json::to_string(&import_map).to_rspack_result()?;
+    // the import maps of the previous compilations are not read anymore
+    self.import_maps.clear();
+    self.import_maps.insert(compilation.id(), content);
+  }
+
+  Ok(())
+}
+
+#[plugin_hook(HtmlPluginAlterAssetTagGroups for ImportMapRspackPlugin)]
+async fn alter_asset_tag_groups(
+  &self,
+  mut data: AlterAssetTagGroupsData,
+) -> Result<AlterAssetTagGroupsData> {
+  let Some(import_map) = self.import_maps.get(&data.compilation_id) else {
+    return Ok(data);
+  };
+  // the import map must be placed before any module scripts
+  data.head_tags.insert(
+    0,
+    HtmlPluginTag {
+      tag_name: "script".to_string(),
+      attributes: vec![HtmlPluginAttribute {
+        attr_name: "type".to_string(),
+        attr_value: Some("importmap".to_string()),
+      }],
+      inner_html: Some(import_map.cow_replace("</", "<\\/").into_owned()),
+      ..Default::default()
+    },
+  );
+  Ok(data)
+}
+
+fn is_javascript_file(file: &str) -> bool {
+  let file = file.split_once('?').map_or(file, |(path, _)| path);
+  file.ends_with(".js") || file.ends_with(".mjs") || file.ends_with(".cjs")
+}
+
+/// Urls and relative or absolute paths are resolved by browsers without import maps.
+fn is_bare_specifier(request: &str) -> bool {
+  !(request.starts_with('/')
+    || request.starts_with("./")
+    || request.starts_with("../")
+    || request.contains("://"))
+}
+
+impl Plugin for ImportMapRspackPlugin {
+  fn name(&self) -> &'static str {
+    PLUGIN_NAME
+  }
+
+  fn apply(&self, ctx: &mut rspack_core::ApplyContext<'_>) -> Result<()> {
+    ctx
+      .compilation_hooks
+      .process_assets
+      .tap(process_assets::new(self));
+
+    if self.options.inject {
+      ctx
+        .compiler_hooks
+        .this_compilation
+        .tap(this_compilation::new(self));
+    }
+    Ok(())
+  }
+}
@@ -2,10 +2,11 @@ use std::{borrow::Cow, sync::Arc};
 
 use rspack_cacheable::{cacheable, cacheable_dyn, with::Skip};
 use rspack_core::{
-  AsyncDependenciesBlockIdentifier, BuildMetaExportsType, COLLECTED_TYPESCRIPT_INFO_PARSE_META_KEY,
-  ChunkGraph, CollectedTypeScriptInfo, Compilation, DependenciesBlock, DependencyId,
-  DependencyRange, GenerateContext, Module, ModuleGraph, ModuleType, ParseContext, ParseResult,
-  ParserAndGenerator, SideEffectsBailoutItem, SourceType, TemplateContext, TemplateReplaceSource,
+  AsyncDependenciesBlockIdentifier, BuildMetaExportsType, COLLECTED_I18N_MESSAGES_PARSE_META_KEY,
+  COLLECTED_TYPESCRIPT_INFO_PARSE_META_KEY, ChunkGraph, CollectedTypeScriptInfo, Compilation,
+  DependenciesBlock, DependencyId, DependencyRange, GenerateContext, Module, ModuleGraph,
+  ModuleType, ParseContext, ParseResult, ParserAndGenerator, SideEffectsBailoutItem, SourceType,
+  TemplateContext, TemplateReplaceSource,
   diagnostics::map_box_diagnostics_to_module_parse_diagnostics,
   remove_bom, render_init_fragments,
   rspack_sources::{BoxSource, ReplaceSource, Source, SourceExt},
@@ -150,6 +151,13 @@ impl ParserAndGenerator for JavaScriptParserAndGenerator {
       build_info.collected_typescript_info = Some(*collected_ts_info);
     }
 
+    if let Some(i18n_message_ids) = parse_meta.remove(COLLECTED_I18N_MESSAGES_PARSE_META_KEY)
+      && let Ok(i18n_message_ids) =
+        (i18n_message_ids as Box<dyn std::any::Any>).downcast::<Vec<String>>()
+    {
+      build_info.i18n_message_ids = Some(*i18n_message_ids);
+    }
+
     let default_with_diagnostics = |source: Arc<dyn Source>, diagnostics: Vec<Diagnostic>| {
       Ok(
         ParseResult {
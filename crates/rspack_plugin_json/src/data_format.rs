@@ -0,0 +1,229 @@
+use json::{JsonValue, number::Number, object::Object};
+use rspack_cacheable::cacheable;
+use rspack_error::{Error, error};
+use yaml_rust2::{Yaml, YamlLoader};
+
+/// The format of a data module, all formats are converted to json data,
+/// so they share the exports and tree shaking of json modules.
+#[cacheable]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum DataFormat {
+  Json,
+  Yaml,
+  Toml,
+}
+
+// the error label covers one character at `offset`, or the last character if `offset` is the end of the source
+fn error_at(source: &str, offset: usize, title: &str, message: String) -> Error {
+  let offset = if offset >= source.len() {
+    source
+      .char_indices()
+      .next_back()
+      .map_or(0, |(index, _)| index)
+  } else {
+    (0..=offset)
+      .rev()
+      .find(|index| source.is_char_boundary(*index))
+      .unwrap_or(0)
+  };
+  let end = source[offset..]
+    .chars()
+    .next()
+    .map_or(offset, |ch| offset + ch.len_utf8());
+  Error::from_string(
+    Some(source.to_string()),
+    offset,
+    end,
+    title.to_string(),
+    message,
+  )
+}
+
+fn number(value: f64) -> JsonValue {
+  JsonValue::Number(Number::from(value))
+}
+
+fn yaml_key(key: Yaml) -> String {
+  match key {
+    Yaml::Real(value) | Yaml::String(value) => value,
+    Yaml::Integer(value) => value.to_string(),
+    Yaml::Boolean(value) => value.to_string(),
+    Yaml::Null => "null".to_string(),
+    // complex keys are stringified the same way as javascript object keys
+    _ => "[object Object]".to_string(),
+  }
+}
+
+// the value of a merge key `<<` is a mapping or a sequence of mappings
+fn is_yaml_merge_value(value: &Yaml) -> bool {
+  match value {
+    Yaml::Hash(_) => true,
+    Yaml::Array(items) => items.iter().all(|item| matches!(item, Yaml::Hash(_))),
+    _ => false,
+  }
+}
+
+fn yaml_to_json(value: Yaml) -> JsonValue {
+  match value {
+    Yaml::Real(ref real) => value
+      .as_f64()
+      .map_or_else(|| JsonValue::String(real.clone()), number),
+    Yaml::Integer(value) => JsonValue::Number(Number::from(value)),
+    Yaml::String(value) => JsonValue::String(value),
+    Yaml::Boolean(value) => JsonValue::Boolean(value),
+    Yaml::Array(items) => JsonValue::Array(items.into_iter().map(yaml_to_json).collect()),
+    Yaml::Hash(hash) => {
+      let mut object = Object::with_capacity(hash.len());
+      let mut merged = vec![];
+      for (key, value) in hash {
+        if matches!(&key, Yaml::String(key) if key == "<<") && is_yaml_merge_value(&value) {
+          match value {
+            Yaml::Array(items) => merged.extend(items),
+            value => merged.push(value),
+          }
+          continue;
+        }
+        object.insert(&yaml_key(key), yaml_to_json(value));
+      }
+      // keys of the mapping itself override the merged ones, and earlier merged mappings
+      // override later ones, see https://yaml.org/type/merge.html
+      for value in merged {
+        if let JsonValue::Object(source) = yaml_to_json(value) {
+          for (key, value) in source.iter() {
+            if object.get(key).is_none() {
+              object.insert(key, value.clone());
+            }
+          }
+        }
+      }
+      JsonValue::Object(object)
+    }
+    // aliases are resolved by the loader
+    Yaml::Alias(_) | Yaml::Null | Yaml::BadValue => JsonValue::Null,
+  }
+}
+
+/// Parses a single yaml document, `offset` is the byte offset of `source` in the module source.
+pub fn parse_yaml(source: &str, module_source: &str, offset: usize) -> Result<JsonValue, Error> {
+  let mut documents = YamlLoader::load_from_str(source).map_err(|e| {
+    // the marker index is a char index
+    let marker = e.marker();
+    let byte_offset = source
+      .char_indices()
+      .nth(marker.index())
+      .map_or(source.len(), |(index, _)| index);
+    error_at(
+      module_source,
+      offset + byte_offset,
+      "YAML parse error",
+      e.info().to_string(),
+    )
+  })?;
+  if documents.len() > 1 {
+    return Err(error!(
+      "YAML parse error: expected a single document, but found {}",
+      documents.len()
+    ));
+  }
+  Ok(documents.pop().map_or(JsonValue::Null, yaml_to_json))
+}
+
+fn toml_to_json(value: toml::Value) -> JsonValue {
+  match value {
+    toml::Value::String(value) => JsonValue::String(value),
+    toml::Value::Integer(value) => JsonValue::Number(Number::from(value)),
+    toml::Value::Float(value) => number(value),
+    toml::Value::Boolean(value) => JsonValue::Boolean(value),
+    toml::Value::Datetime(value) => JsonValue::String(value.to_string()),
+    toml::Value::Array(items) => JsonValue::Array(items.into_iter().map(toml_to_json).collect()),
+    toml::Value::Table(table) => toml_table_to_json(table),
+  }
+}
+
+fn toml_table_to_json(table: toml::Table) -> JsonValue {
+  let mut object = Object::with_capacity(table.len());
+  for (key, value) in table {
+    object.insert(&key, toml_to_json(value));
+  }
+  JsonValue::Object(object)
+}
+
+/// Parses a toml document, `offset` is the byte offset of `source` in the module source.
+pub fn parse_toml(source: &str, module_source: &str, offset: usize) -> Result<JsonValue, Error> {
+  source
+    .parse::<toml::Table>()
+    .map(toml_table_to_json)
+    .map_err(|e| {
+      let start = e.span().map_or(source.len(), |span| span.start);
+      error_at(
+        module_source,
+        offset + start,
+        "TOML parse error",
+        e.message().to_string(),
+      )
+    })
+}
+
+#[cfg(test)]
+mod test {
+  use super::*;
+
+  #[test]
+  fn should_convert_yaml_to_json() {
+    let source = "name: app\nport: 8080\nratio: 0.5\ntags:\n  - a\n  - b\nbase: &base\n  debug: true\n  level: 1\nprod:\n  <<: *base\n  level: 2\n";
+    let data = parse_yaml(source, source, 0).expect("should parse yaml");
+    assert_eq!(
+      data.dump(),
+      r#"{"name":"app","port":8080,"ratio":0.5,"tags":["a","b"],"base":{"debug":true,"level":1},"prod":{"level":2,"debug":true}}"#
+    );
+  }
+
+  #[test]
+  fn should_merge_yaml_mappings_in_order() {
+    let source =
+      "a: &a\n  x: 1\n  y: 1\nb: &b\n  y: 2\n  z: 2\nc:\n  <<: [*a, *b]\n  z: 3\nd:\n  <<: 1\n";
+    let data = parse_yaml(source, source, 0).expect("should parse yaml");
+    assert_eq!(data["c"].dump(), r#"{"z":3,"x":1,"y":1}"#);
+    // merge keys whose value is not a mapping are kept as plain keys
+    assert_eq!(data["d"].dump(), r#"{"<<":1}"#);
+  }
+
+  #[test]
+  fn should_report_yaml_error_offset() {
+    let source = "a: 1\nb: [1, 2\n";
+    let err = parse_yaml(source, source, 0).expect_err("should fail");
+    let label = &err.labels.as_ref().expect("should have label")[0];
+    assert!(label.offset >= "a: 1\n".len());
+  }
+
+  #[test]
+  fn should_report_error_at_the_end_of_non_ascii_source() {
+    let source = "a: 1\nb: é";
+    let err = error_at(source, source.len(), "YAML parse error", String::new());
+    let label = &err.labels.as_ref().expect("should have label")[0];
+    assert_eq!(label.offset, source.len() - 'é'.len_utf8());
+    assert_eq!(label.len, 'é'.len_utf8());
+
+    let source = "title = \"应用";
+    let err = parse_toml(source, source, 0).expect_err("should fail");
+    let label = &err.labels.as_ref().expect("should have label")[0];
+    assert!(source.is_char_boundary(label.offset));
+  }
+
+  #[test]
+  fn should_convert_toml_to_json() {
+    let source = "title = \"app\"\n[server]\nport = 8080\nhosts = [\"a\", \"b\"]\n";
+    let data = parse_toml(source, source, 0).expect("should parse toml");
+    assert_eq!(data["title"], "app");
+    assert_eq!(data["server"]["port"], 8080);
+    assert_eq!(data["server"]["hosts"][0], "a");
+  }
+
+  #[test]
+  fn should_report_toml_error_offset() {
+    let source = "a = 1\nb = = 2\n";
+    let err = parse_toml(source, source, 0).expect_err("should fail");
+    let label = &err.labels.as_ref().expect("should have label")[0];
+    assert_eq!(label.offset, "a = 1\nb = ".len());
+  }
+}
@@ -22,8 +22,12 @@ use rspack_core::{
 use rspack_error::{Error, IntoTWithDiagnosticArray, Result, TWithDiagnosticArray, error};
 use rspack_util::itoa;
 
-use crate::json_exports_dependency::JsonExportsDependency;
+use crate::{
+  data_format::{DataFormat, parse_toml, parse_yaml},
+  json_exports_dependency::JsonExportsDependency,
+};
 
+mod data_format;
 mod json_exports_dependency;
 mod utils;
 
@@ -32,6 +36,50 @@ mod utils;
 struct JsonParserAndGenerator {
   pub exports_depth: u32,
   pub json_parse: bool,
+  pub format: DataFormat,
+}
+
+impl JsonParserAndGenerator {
+  fn parse_json(json_source: &str, source: &str, need_strip_bom: bool) -> Result<JsonValue> {
+    json::parse(json_source).map_err(|e| {
+      match e {
+        UnexpectedCharacter { ch, line, column } => {
+          let rope = ropey::Rope::from_str(source);
+          let line_offset = rope.try_line_to_byte(line - 1).expect("TODO:");
+          let start_offset = source[line_offset..]
+            .chars()
+            .take(column)
+            .fold(line_offset, |acc, cur| acc + cur.len_utf8());
+          let start_offset = if need_strip_bom {
+            start_offset + 1
+          } else {
+            start_offset
+          };
+          Error::from_string(
+            Some(source.to_string()),
+            // one character offset
+            start_offset,
+            start_offset + 1,
+            "JSON parse error".to_string(),
+            format!("Unexpected character {ch}"),
+          )
+        }
+        ExceededDepthLimit | WrongType(_) | FailedUtf8Parsing => error!("{}", e),
+        UnexpectedEndOfJson => {
+          // End offset of json file
+          let length = source.len();
+          let offset = if length > 0 { length - 1 } else { length };
+          Error::from_string(
+            Some(source.to_string()),
+            offset,
+            offset,
+            "JSON parse error".to_string(),
+            format!("{e}"),
+          )
+        }
+      }
+    })
+  }
 }
 
 #[cacheable_dyn]
@@ -80,45 +128,23 @@ impl ParserAndGenerator for JsonParserAndGenerator {
       None
     };
 
-    let parse_result = json::parse(parse_result_str.as_deref().unwrap_or(strip_bom_source))
-      .map_err(|e| {
-        match e {
-          UnexpectedCharacter { ch, line, column } => {
-            let rope = ropey::Rope::from_str(&source);
-            let line_offset = rope.try_line_to_byte(line - 1).expect("TODO:");
-            let start_offset = source[line_offset..]
-              .chars()
-              .take(column)
-              .fold(line_offset, |acc, cur| acc + cur.len_utf8());
-            let start_offset = if need_strip_bom {
-              start_offset + 1
-            } else {
-              start_offset
-            };
-            Error::from_string(
-              Some(source.into_owned()),
-              // one character offset
-              start_offset,
-              start_offset + 1,
-              "JSON parse error".to_string(),
-              format!("Unexpected character {ch}"),
-            )
-          }
-          ExceededDepthLimit | WrongType(_) | FailedUtf8Parsing => error!("{}", e),
-          UnexpectedEndOfJson => {
-            // End offset of json file
-            let length = source.len();
-            let offset = if length > 0 { length - 1 } else { length };
-            Error::from_string(
-              Some(source.into_owned()),
-              offset,
-              offset,
-              "JSON parse error".to_string(),
-              format!("{e}"),
-            )
-          }
-        }
-      });
+    let parse_result = match self.format {
+      DataFormat::Json => Self::parse_json(
+        parse_result_str.as_deref().unwrap_or(strip_bom_source),
+        &source,
+        need_strip_bom,
+      ),
+      DataFormat::Yaml => parse_yaml(
+        strip_bom_source,
+        &source,
+        source.len() - strip_bom_source.len(),
+      ),
+      DataFormat::Toml => parse_toml(
+        strip_bom_source,
+        &source,
+        source.len() - strip_bom_source.len(),
+      ),
+    };
 
     let data = match parse_result {
       Ok(data) => data,
@@ -271,6 +297,37 @@ impl Plugin for JsonPlugin {
         Box::new(JsonParserAndGenerator {
           exports_depth: p.exports_depth.expect("should have exports_depth"),
           json_parse: g.json_parse.expect("should have json_parse"),
+          format: DataFormat::Json,
+        })
+      }),
+    );
+
+    ctx.register_parser_and_generator_builder(
+      rspack_core::ModuleType::Yaml,
+      Box::new(|p, _| {
+        let p = p
+          .and_then(|p| p.get_yaml())
+          .expect("should have YamlParserOptions");
+
+        Box::new(JsonParserAndGenerator {
+          exports_depth: p.exports_depth.expect("should have exports_depth"),
+          json_parse: true,
+          format: DataFormat::Yaml,
+        })
+      }),
+    );
+
+    ctx.register_parser_and_generator_builder(
+      rspack_core::ModuleType::Toml,
+      Box::new(|p, _| {
+        let p = p
+          .and_then(|p| p.get_toml())
+          .expect("should have TomlParserOptions");
+
+        Box::new(JsonParserAndGenerator {
+          exports_depth: p.exports_depth.expect("should have exports_depth"),
+          json_parse: true,
+          format: DataFormat::Toml,
         })
       }),
     );
@@ -196,6 +196,7 @@ impl Module for LazyCompilationProxyModule {
       dependencies,
       blocks,
       optimization_bailouts: vec![],
+      profile: None,
     })
   }
 
@@ -2,8 +2,8 @@ use std::hash::Hash;
 
 use rspack_core::{
   ChunkUkey, Compilation, CompilationAdditionalChunkRuntimeRequirements, CompilationParams,
-  CompilerCompilation, ExternalModule, Filename, LibraryName, LibraryNonUmdObject, LibraryOptions,
-  LibraryType, PathData, Plugin, RuntimeGlobals, SourceType,
+  CompilerCompilation, ExternalModule, ExternalType, Filename, LibraryName, LibraryNonUmdObject,
+  LibraryOptions, LibraryType, PathData, Plugin, RuntimeGlobals, SourceType,
   rspack_sources::{ConcatSource, RawStringSource, SourceExt},
 };
 use rspack_error::{Result, error_bail};
@@ -107,8 +107,11 @@ async fn render(
         .module_by_identifier(identifier)
         .and_then(|module| module.as_external_module())
         .and_then(|m| {
-          let ty = m.get_external_type();
-          (ty == "amd" || ty == "amd-require").then_some(m)
+          matches!(
+            m.get_external_type(),
+            ExternalType::Amd | ExternalType::AmdRequire
+          )
+          .then_some(m)
         })
     })
     .collect::<Vec<&ExternalModule>>();
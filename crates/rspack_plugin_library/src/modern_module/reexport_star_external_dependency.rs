@@ -114,7 +114,7 @@ impl DependencyTemplate for ModernModuleReexportStarExternalDependencyTemplate {
 
     let request = match &dep.target_request {
       ExternalRequest::Single(request) => Some(request),
-      ExternalRequest::Map(map) => map.get(&dep.external_type),
+      ExternalRequest::Map(map) => map.get(dep.external_type.as_str()),
     };
 
     if let Some(request) = request {
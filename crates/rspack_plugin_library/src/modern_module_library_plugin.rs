@@ -5,8 +5,8 @@ use rspack_core::{
   BoxDependency, ChunkUkey, CodeGenerationExportsFinalNames, Compilation,
   CompilationOptimizeChunkModules, CompilationParams, CompilerCompilation, CompilerFinishMake,
   ConcatenatedModule, ConcatenatedModuleExportsDefinitions, DependencyId, ExportsType,
-  LibraryOptions, ModuleGraph, ModuleIdentifier, Plugin, PrefetchExportsInfoMode, RuntimeSpec,
-  RuntimeVariable, UsedNameItem,
+  ExternalType, LibraryOptions, ModuleGraph, ModuleIdentifier, Plugin, PrefetchExportsInfoMode,
+  RuntimeSpec, RuntimeVariable, UsedNameItem,
   rspack_sources::{ConcatSource, RawStringSource, SourceExt},
   to_identifier,
 };
@@ -47,7 +47,10 @@ impl ModernModuleLibraryPlugin {
   ) -> bool {
     if let Some(m) = mg.get_module_by_dependency_id(&dep.id)
       && let Some(m) = m.as_external_module()
-      && (m.get_external_type() == "module" || m.get_external_type() == "module-import")
+      && matches!(
+        m.get_external_type(),
+        ExternalType::Module | ExternalType::ModuleImport
+      )
     {
       // Star reexport will meet the condition.
       return dep.name.is_none() && dep.other_star_exports.is_some();
@@ -220,7 +223,7 @@ impl ModernModuleLibraryPlugin {
                 *dep_id,
                 reexport_dep.request.as_str().into(),
                 external_module.request.clone(),
-                external_module.external_type.clone(),
+                external_module.external_type,
               );
 
               deps_to_replace.push(Box::new(new_dep));
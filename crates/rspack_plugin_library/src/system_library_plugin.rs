@@ -2,8 +2,8 @@ use std::hash::Hash;
 
 use rspack_core::{
   ChunkUkey, Compilation, CompilationAdditionalChunkRuntimeRequirements, CompilationParams,
-  CompilerCompilation, ExternalModule, ExternalRequest, Filename, LibraryName, LibraryNonUmdObject,
-  LibraryOptions, PathData, Plugin, RuntimeGlobals,
+  CompilerCompilation, ExternalModule, ExternalRequest, ExternalType, Filename, LibraryName,
+  LibraryNonUmdObject, LibraryOptions, PathData, Plugin, RuntimeGlobals,
   rspack_sources::{ConcatSource, RawStringSource, SourceExt},
 };
 use rspack_error::{Result, ToStringResultToRspackResultExt, error_bail};
@@ -119,7 +119,7 @@ async fn render(
       module_graph
         .module_by_identifier(identifier)
         .and_then(|module| module.as_external_module())
-        .and_then(|m| (m.get_external_type() == "system").then_some(m))
+        .and_then(|m| (m.get_external_type() == ExternalType::System).then_some(m))
     })
     .collect::<Vec<&ExternalModule>>();
   let external_deps_array = modules
@@ -2,9 +2,10 @@ use std::{borrow::Cow, hash::Hash};
 
 use rspack_core::{
   Chunk, ChunkUkey, Compilation, CompilationAdditionalChunkRuntimeRequirements, CompilationParams,
-  CompilerCompilation, ExternalModule, ExternalRequest, Filename, LibraryAuxiliaryComment,
-  LibraryCustomUmdObject, LibraryName, LibraryNonUmdObject, LibraryOptions, LibraryType,
-  ModuleGraph, ModuleGraphCacheArtifact, PathData, Plugin, RuntimeGlobals, SourceType,
+  CompilerCompilation, ExternalModule, ExternalRequest, ExternalType, Filename,
+  LibraryAuxiliaryComment, LibraryCustomUmdObject, LibraryName, LibraryNonUmdObject,
+  LibraryOptions, LibraryType, ModuleGraph, ModuleGraphCacheArtifact, PathData, Plugin,
+  RuntimeGlobals, SourceType,
   rspack_sources::{ConcatSource, RawStringSource, SourceExt},
 };
 use rspack_error::{Result, ToStringResultToRspackResultExt, error};
@@ -118,8 +119,11 @@ async fn render(
         .module_by_identifier(identifier)
         .and_then(|module| module.as_external_module())
         .and_then(|m| {
-          let ty = m.get_external_type();
-          (ty == "umd" || ty == "umd2").then_some(m)
+          matches!(
+            m.get_external_type(),
+            ExternalType::Umd | ExternalType::Umd2
+          )
+          .then_some(m)
         })
     })
     .collect::<Vec<&ExternalModule>>();
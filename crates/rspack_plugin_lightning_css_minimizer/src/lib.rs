@@ -12,8 +12,10 @@ use lightningcss::{
 };
 use rayon::prelude::*;
 use regex::Regex;
+use rspack_cacheable::{cacheable, from_bytes, to_bytes};
 use rspack_core::{
   ChunkUkey, Compilation, CompilationChunkHash, CompilationProcessAssets, Plugin,
+  cache::persistent::ScopedCache,
   diagnostics::MinifyError,
   rspack_sources::{
     MapOptions, ObjectPool, RawStringSource, SourceExt, SourceMap, SourceMapSource,
@@ -21,11 +23,14 @@ use rspack_core::{
   },
 };
 use rspack_error::{Diagnostic, Result, ToStringResultToRspackResultExt};
-use rspack_hash::RspackHash;
+use rspack_hash::{HashDigest, HashFunction, RspackHash};
 use rspack_hook::{plugin, plugin_hook};
 use rspack_util::asset_condition::AssetConditions;
 use thread_local::ThreadLocal;
 
+const PLUGIN_NAME: &str = "rspack.LightningCssMinimizerRspackPlugin";
+const CACHE_SCOPE: &str = "lightningcss_minimizer";
+
 static CSS_ASSET_REGEXP: LazyLock<Regex> =
   LazyLock::new(|| Regex::new(r"\.css(\?.*)?$").expect("Invalid RegExp"));
 
@@ -98,10 +103,18 @@ impl Hash for MinimizerOptions {
   }
 }
 
+/// The minified output of an asset, saved in persistent cache.
+#[cacheable]
+struct MinifiedAsset {
+  code: String,
+  source_map: Option<String>,
+}
+
 #[plugin]
 #[derive(Debug)]
 pub struct LightningCssMinimizerRspackPlugin {
   options: PluginOptions,
+  cache: ScopedCache,
 }
 
 pub fn match_object(obj: &PluginOptions, str: &str) -> bool {
@@ -125,7 +138,7 @@ pub fn match_object(obj: &PluginOptions, str: &str) -> bool {
 
 impl LightningCssMinimizerRspackPlugin {
   pub fn new(options: PluginOptions) -> Self {
-    Self::new_inner(options)
+    Self::new_inner(options, ScopedCache::new(CACHE_SCOPE))
   }
 }
 
@@ -146,6 +159,12 @@ async fn process_assets(&self, compilation: &mut Compilation) -> Result<()> {
   let minimizer_options = &self.options.minimizer_options;
   let all_warnings: RwLock<Vec<Diagnostic>> = Default::default();
 
+  let storage = compilation.persistent_cache_storage.clone();
+  if let Some(storage) = &storage {
+    self.cache.recovery(storage).await?;
+  }
+  let storage = storage.as_ref();
+
   let tls: ThreadLocal<ObjectPool> = ThreadLocal::new();
   compilation
     .assets_mut()
@@ -173,124 +192,160 @@ async fn process_assets(&self, compilation: &mut Compilation) -> Result<()> {
         let object_pool = tls.get_or(ObjectPool::default);
         let input_source_map = original_source.map(object_pool, &MapOptions::default());
 
-        let mut parser_flags = ParserFlags::empty();
-        parser_flags.set(
-          ParserFlags::CUSTOM_MEDIA,
-          matches!(&minimizer_options.draft, Some(draft) if draft.custom_media),
-        );
-        parser_flags.set(
-          ParserFlags::DEEP_SELECTOR_COMBINATOR,
-          matches!(&minimizer_options.non_standard, Some(non_standard) if non_standard.deep_selector_combinator),
-        );
-
-        let mut source_map = input_source_map
+        let mut unused_symbols = HashSet::from_iter(minimizer_options.unused_symbols.clone());
+        if self.options.remove_unused_local_idents
+          && let Some(css_unused_idents) = original.info.css_unused_idents.take()
+        {
+          unused_symbols.extend(css_unused_idents);
+        }
+
+        let cache_key = storage.map(|_| {
+          let mut sorted_unused_symbols = unused_symbols.iter().collect::<Vec<_>>();
+          sorted_unused_symbols.sort();
+          let mut hasher = RspackHash::new(&HashFunction::MD4);
+          PLUGIN_NAME.hash(&mut hasher);
+          options.hash(&mut hasher);
+          filename.hash(&mut hasher);
+          input_source_map.is_some().hash(&mut hasher);
+          sorted_unused_symbols.hash(&mut hasher);
+          input.hash(&mut hasher);
+          hasher.digest(&HashDigest::Hex).encoded().as_bytes().to_vec()
+        });
+        let cached = cache_key
           .as_ref()
-          .map(|input_source_map| -> Result<_> {
-            let mut sm =
-              parcel_sourcemap::SourceMap::new(input_source_map.source_root().unwrap_or("/"));
-            sm.add_source(filename);
-            sm.set_source_content(0, &input).to_rspack_result()?;
-            Ok(sm)
-          })
-          .transpose()?;
-        let result = {
-          let warnings: Arc<RwLock<Vec<_>>> = Default::default();
-          let mut stylesheet = StyleSheet::parse(
-            &input,
-            ParserOptions {
-              filename: filename.to_string(),
-              css_modules: None,
-              source_index: 0,
-              error_recovery: minimizer_options.error_recovery,
-              warnings: Some(warnings.clone()),
-              flags: parser_flags,
-            },
-          )
-          .to_rspack_result()?;
-
-          let targets = Targets {
-            browsers: minimizer_options.targets,
-            include: minimizer_options
-              .include
-              .as_ref()
-              .map(|include| Features::from_bits_truncate(*include))
-              .unwrap_or(Features::empty()),
-            exclude: minimizer_options
-              .exclude
-              .as_ref()
-              .map(|exclude| Features::from_bits_truncate(*exclude))
-              .unwrap_or(Features::empty()),
-          };
-          let mut unused_symbols = HashSet::from_iter(minimizer_options.unused_symbols.clone());
-          if self.options.remove_unused_local_idents
-            && let Some(css_unused_idents) = original.info.css_unused_idents.take()
-          {
-            unused_symbols.extend(css_unused_idents);
-          }
-          stylesheet
-            .minify(MinifyOptions {
-              targets,
-              unused_symbols,
+          .and_then(|key| self.cache.get(key))
+          .and_then(|value| from_bytes::<MinifiedAsset, ()>(&value, &()).ok());
+
+        let (code, source_map) = if let Some(minified) = cached {
+          (minified.code, minified.source_map)
+        } else {
+          let mut parser_flags = ParserFlags::empty();
+          parser_flags.set(
+            ParserFlags::CUSTOM_MEDIA,
+            matches!(&minimizer_options.draft, Some(draft) if draft.custom_media),
+          );
+          parser_flags.set(
+            ParserFlags::DEEP_SELECTOR_COMBINATOR,
+            matches!(&minimizer_options.non_standard, Some(non_standard) if non_standard.deep_selector_combinator),
+          );
+
+          let mut source_map = input_source_map
+            .as_ref()
+            .map(|input_source_map| -> Result<_> {
+              let mut sm =
+                parcel_sourcemap::SourceMap::new(input_source_map.source_root().unwrap_or("/"));
+              sm.add_source(filename);
+              sm.set_source_content(0, &input).to_rspack_result()?;
+              Ok(sm)
             })
+            .transpose()?;
+          let result = {
+            let warnings: Arc<RwLock<Vec<_>>> = Default::default();
+            let mut stylesheet = StyleSheet::parse(
+              &input,
+              ParserOptions {
+                filename: filename.to_string(),
+                css_modules: None,
+                source_index: 0,
+                error_recovery: minimizer_options.error_recovery,
+                warnings: Some(warnings.clone()),
+                flags: parser_flags,
+              },
+            )
             .to_rspack_result()?;
-          // FIXME: Disable the warnings for now, cause it cause too much positive-negative warnings,
-          // enable when we have a better way to handle it. let warnings = warnings.read().expect("should lock");
-          // all_warnings.write().expect("should lock").extend(
-          //   warnings.iter().map(|e| {
-          //     if let Some(loc) = &e.loc {
-          //       let rope = ropey::Rope::from_str(&input);
-          //       let start = rope.line_to_byte(loc.line as usize) + loc.column as usize - 1;
-          //       let end = start;
-          //       Diagnostic::from(Box::new(Error::from_file(
-          //         input.clone(),
-          //         start,
-          //         end,
-          //         "LightningCSS minimize warning".to_string(),
-          //         e.to_string(),
-          //       )
-          //       .with_severity(Severity::Warning)))
-          //     } else {
-          //       Diagnostic::warn("LightningCSS minimize warning".to_string(), e.to_string())
-          //     }
-          //   }),
-          // );
-          stylesheet
-            .to_css(PrinterOptions {
-              minify: true,
-              source_map: source_map.as_mut(),
-              project_root: None,
-              targets,
-              analyze_dependencies: None,
-              pseudo_classes: minimizer_options.pseudo_classes
-              .as_ref()
-              .map(|pseudo_classes| lightningcss::stylesheet::PseudoClasses {
-                hover: pseudo_classes.hover.as_deref(),
-                active: pseudo_classes.active.as_deref(),
-                focus: pseudo_classes.focus.as_deref(),
-                focus_visible: pseudo_classes.focus_visible.as_deref(),
-                focus_within: pseudo_classes.focus_within.as_deref(),
-              }),
-            })
-            .to_rspack_result()?
+
+            let targets = Targets {
+              browsers: minimizer_options.targets,
+              include: minimizer_options
+                .include
+                .as_ref()
+                .map(|include| Features::from_bits_truncate(*include))
+                .unwrap_or(Features::empty()),
+              exclude: minimizer_options
+                .exclude
+                .as_ref()
+                .map(|exclude| Features::from_bits_truncate(*exclude))
+                .unwrap_or(Features::empty()),
+            };
+            stylesheet
+              .minify(MinifyOptions {
+                targets,
+                unused_symbols,
+              })
+              .to_rspack_result()?;
+            // FIXME: Disable the warnings for now, cause it cause too much positive-negative warnings,
+            // enable when we have a better way to handle it. let warnings = warnings.read().expect("should lock");
+            // all_warnings.write().expect("should lock").extend(
+            //   warnings.iter().map(|e| {
+            //     if let Some(loc) = &e.loc {
+            //       let rope = ropey::Rope::from_str(&input);
+            //       let start = rope.line_to_byte(loc.line as usize) + loc.column as usize - 1;
+            //       let end = start;
+            //       Diagnostic::from(Box::new(Error::from_file(
+            //         input.clone(),
+            //         start,
+            //         end,
+            //         "LightningCSS minimize warning".to_string(),
+            //         e.to_string(),
+            //       )
+            //       .with_severity(Severity::Warning)))
+            //     } else {
+            //       Diagnostic::warn("LightningCSS minimize warning".to_string(), e.to_string())
+            //     }
+            //   }),
+            // );
+            stylesheet
+              .to_css(PrinterOptions {
+                minify: true,
+                source_map: source_map.as_mut(),
+                project_root: None,
+                targets,
+                analyze_dependencies: None,
+                pseudo_classes: minimizer_options.pseudo_classes
+                .as_ref()
+                .map(|pseudo_classes| lightningcss::stylesheet::PseudoClasses {
+                  hover: pseudo_classes.hover.as_deref(),
+                  active: pseudo_classes.active.as_deref(),
+                  focus: pseudo_classes.focus.as_deref(),
+                  focus_visible: pseudo_classes.focus_visible.as_deref(),
+                  focus_within: pseudo_classes.focus_within.as_deref(),
+                }),
+              })
+              .to_rspack_result()?
+          };
+
+          let source_map = source_map
+            .as_mut()
+            .map(|source_map| source_map.to_json(None))
+            .transpose()
+            .to_rspack_result()?;
+          if let (Some(storage), Some(cache_key)) = (storage, cache_key) {
+            let minified = MinifiedAsset {
+              code: result.code.clone(),
+              source_map: source_map.clone(),
+            };
+            self.cache.set(
+              storage,
+              cache_key,
+              to_bytes(&minified, &()).expect("should serialize minified asset"),
+            );
+          }
+          (result.code, source_map)
         };
 
-        let minimized_source = if let Some(mut source_map) = source_map {
+        let minimized_source = if let Some(source_map) = source_map {
           SourceMapSource::new(SourceMapSourceOptions {
-            value: result.code,
+            value: code,
             name: filename,
-            source_map: SourceMap::from_json(
-              &source_map
-                .to_json(None)
-                .to_rspack_result()?,
-            )
-            .expect("should be able to generate source-map"),
+            source_map: SourceMap::from_json(&source_map)
+              .expect("should be able to generate source-map"),
             original_source: Some(Arc::from(input)),
             inner_source_map: input_source_map,
             remove_original_source: true,
           })
           .boxed()
         } else {
-          RawStringSource::from(result.code).boxed()
+          RawStringSource::from(code).boxed()
         };
 
         original.set_source(Some(minimized_source));
@@ -300,13 +355,16 @@ async fn process_assets(&self, compilation: &mut Compilation) -> Result<()> {
     }).map_err(MinifyError)?;
 
   compilation.extend_diagnostics(all_warnings.into_inner().expect("should lock"));
+  if let Some(storage) = storage {
+    self.cache.remove_unused(storage);
+  }
 
   Ok(())
 }
 
 impl Plugin for LightningCssMinimizerRspackPlugin {
   fn name(&self) -> &'static str {
-    "rspack.LightningCssMinimizerRspackPlugin"
+    PLUGIN_NAME
   }
 
   fn apply(&self, ctx: &mut rspack_core::ApplyContext<'_>) -> Result<()> {
@@ -138,6 +138,7 @@ impl Module for FallbackModule {
       dependencies,
       blocks: Vec::new(),
       optimization_bailouts: vec![],
+      profile: None,
     })
   }
 
@@ -181,6 +181,7 @@ impl Module for RemoteModule {
       dependencies,
       blocks: Vec::new(),
       optimization_bailouts: vec![],
+      profile: None,
     })
   }
 
@@ -0,0 +1,121 @@
+use rspack_javascript_compiler::{
+  JavaScriptCompiler,
+  ast::Ast,
+  stringify::{PrintOptions, SourceMapConfig},
+};
+use rspack_paths::Utf8Path;
+use rspack_plugin_javascript::visitors::swc_visitor::resolver;
+use swc_core::{
+  base::config::IsModule,
+  common::{FileName, comments::Comments, sync::Lrc},
+  ecma::{
+    ast::{EsVersion, Program},
+    parser::{Syntax, TsSyntax},
+    visit::VisitMut,
+  },
+};
+use swc_node_comments::SwcComments;
+use swc_typescript::fast_dts::{FastDts, FastDtsOptions};
+
+/// The declarations of a module, `.d.ts` files are used as is.
+#[derive(Debug, Clone)]
+pub(crate) struct DtsModule {
+  pub(crate) ast: Ast,
+  /// The problems that make the declarations incomplete, e.g. a missing return type.
+  pub(crate) issues: Vec<String>,
+}
+
+pub(crate) fn is_declaration_file(path: &Utf8Path) -> bool {
+  let name = path.file_name().unwrap_or_default();
+  name.ends_with(".d.ts") || name.ends_with(".d.mts") || name.ends_with(".d.cts")
+}
+
+pub(crate) fn is_typescript_file(path: &Utf8Path) -> bool {
+  matches!(path.extension(), Some("ts" | "tsx" | "mts" | "cts"))
+}
+
+struct FastDtsPass {
+  fast_dts: FastDts,
+  issues: Vec<String>,
+}
+
+impl VisitMut for FastDtsPass {
+  fn visit_mut_program(&mut self, program: &mut Program) {
+    self.issues = self
+      .fast_dts
+      .transform(program)
+      .into_iter()
+      .map(|issue| issue.message.to_string())
+      .collect();
+  }
+}
+
+/// Generates the declarations of a TypeScript module with isolated declarations,
+/// which only relies on the explicit type annotations of the module itself.
+pub(crate) fn isolated_declarations(path: &Utf8Path, source: String) -> Result<DtsModule, String> {
+  let is_dts = is_declaration_file(path);
+  let syntax = Syntax::Typescript(TsSyntax {
+    tsx: path.extension() == Some("tsx"),
+    dts: is_dts,
+    ..Default::default()
+  });
+  let comments = SwcComments::default();
+  let mut ast = JavaScriptCompiler::new()
+    .parse(
+      FileName::Real(path.as_std_path().to_path_buf()),
+      source,
+      EsVersion::EsNext,
+      syntax,
+      IsModule::Bool(true),
+      Some(comments),
+    )
+    .map_err(|_| format!("Can't parse {path}"))?;
+  if is_dts {
+    return Ok(DtsModule {
+      ast,
+      issues: vec![],
+    });
+  }
+
+  let issues = ast.transform(|program, context| {
+    program.visit_mut_with(&mut resolver(
+      context.unresolved_mark,
+      context.top_level_mark,
+      true,
+    ));
+    let mut pass = FastDtsPass {
+      fast_dts: FastDts::new(
+        Lrc::new(FileName::Real(path.as_std_path().to_path_buf())),
+        context.unresolved_mark,
+        FastDtsOptions::default(),
+      ),
+      issues: vec![],
+    };
+    program.visit_mut_with(&mut pass);
+    pass.issues
+  });
+  Ok(DtsModule { ast, issues })
+}
+
+pub(crate) fn print_declarations(ast: &Ast) -> Result<String, String> {
+  ast.visit(|program, context| {
+    JavaScriptCompiler::new()
+      .print(
+        program.get_inner_program(),
+        PrintOptions {
+          source_len: 0,
+          source_map: context.source_map.clone(),
+          target: EsVersion::EsNext,
+          source_map_config: SourceMapConfig::default(),
+          input_source_map: None,
+          minify: false,
+          comments: program.comments.as_ref().map(|c| c as &dyn Comments),
+          preamble: "",
+          ascii_only: false,
+          inline_script: false,
+        },
+      )
+      .map(|output| output.code)
+      .map_err(|err| err.to_string())
+  })
+}
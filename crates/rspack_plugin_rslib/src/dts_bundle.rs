@@ -0,0 +1,990 @@
+use std::{
+  collections::VecDeque,
+  sync::{Arc, Mutex},
+};
+
+use rspack_core::{Resolve, ResolveContext, ResolveResult, Resolver};
+use rspack_paths::{Utf8Path, Utf8PathBuf};
+use rspack_util::fx_hash::FxIndexSet;
+use rustc_hash::{FxHashMap, FxHashSet};
+use swc_core::{
+  common::DUMMY_SP,
+  ecma::{
+    ast::{
+      ClassDecl, Decl, DefaultDecl, ExportSpecifier, FnDecl, Ident, ImportSpecifier, ModuleDecl,
+      ModuleExportName, ModuleItem, Stmt, TsEnumMember, TsGetterSignature, TsImportType,
+      TsMethodSignature, TsModuleName, TsPropertySignature, TsSetterSignature,
+    },
+    atoms::Atom,
+    visit::{VisitMut, VisitMutWith},
+  },
+};
+
+use crate::dts::{DtsModule, is_typescript_file, isolated_declarations, print_declarations};
+
+// the name given to an anonymous `export default` declaration
+const DEFAULT_EXPORT_NAME: &str = "_default";
+
+// re-export chains longer than this are treated as unresolved
+const MAX_RESOLVE_DEPTH: usize = 32;
+
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+enum Imported {
+  Named(Atom),
+  Namespace,
+}
+
+#[derive(Debug, Clone)]
+struct ImportBinding {
+  local: Atom,
+  source: String,
+  imported: Imported,
+}
+
+#[derive(Debug, Clone)]
+enum ExportEntry {
+  Local {
+    exported: Atom,
+    local: Atom,
+  },
+  Reexport {
+    exported: Atom,
+    source: String,
+    imported: Imported,
+  },
+}
+
+impl ExportEntry {
+  fn exported(&self) -> &Atom {
+    match self {
+      ExportEntry::Local { exported, .. } | ExportEntry::Reexport { exported, .. } => exported,
+    }
+  }
+}
+
+/// The top-level bindings of a declaration module.
+#[derive(Debug, Default, Clone)]
+struct ModuleInfo {
+  declared: Vec<Atom>,
+  imports: Vec<ImportBinding>,
+  exports: Vec<ExportEntry>,
+  star_exports: Vec<String>,
+}
+
+fn export_name(name: &ModuleExportName) -> Atom {
+  match name {
+    ModuleExportName::Ident(ident) => ident.sym.clone(),
+    ModuleExportName::Str(str) => str.value.to_atom_lossy().into_owned(),
+  }
+}
+
+fn decl_name(decl: &Decl) -> Vec<Atom> {
+  match decl {
+    Decl::Class(class) => vec![class.ident.sym.clone()],
+    Decl::Fn(func) => vec![func.ident.sym.clone()],
+    Decl::Var(var) => var
+      .decls
+      .iter()
+      .filter_map(|decl| decl.name.as_ident().map(|ident| ident.sym.clone()))
+      .collect(),
+    Decl::TsInterface(interface) => vec![interface.id.sym.clone()],
+    Decl::TsTypeAlias(alias) => vec![alias.id.sym.clone()],
+    Decl::TsEnum(ts_enum) => vec![ts_enum.id.sym.clone()],
+    Decl::TsModule(module) => match &module.id {
+      TsModuleName::Ident(ident) if !module.global => vec![ident.sym.clone()],
+      _ => vec![],
+    },
+    Decl::Using(_) => vec![],
+  }
+}
+
+fn collect_module_info(items: &[ModuleItem]) -> ModuleInfo {
+  let mut info = ModuleInfo::default();
+  for item in items {
+    match item {
+      ModuleItem::Stmt(Stmt::Decl(decl)) => info.declared.extend(decl_name(decl)),
+      ModuleItem::Stmt(_) => {}
+      ModuleItem::ModuleDecl(ModuleDecl::Import(import)) => {
+        let source = import.src.value.to_string_lossy().to_string();
+        for specifier in &import.specifiers {
+          let (local, imported) = match specifier {
+            ImportSpecifier::Named(named) => (
+              named.local.sym.clone(),
+              Imported::Named(
+                named
+                  .imported
+                  .as_ref()
+                  .map_or_else(|| named.local.sym.clone(), export_name),
+              ),
+            ),
+            ImportSpecifier::Default(default) => {
+              (default.local.sym.clone(), Imported::Named("default".into()))
+            }
+            ImportSpecifier::Namespace(namespace) => {
+              (namespace.local.sym.clone(), Imported::Namespace)
+            }
+          };
+          info.imports.push(ImportBinding {
+            local,
+            source: source.clone(),
+            imported,
+          });
+        }
+      }
+      ModuleItem::ModuleDecl(ModuleDecl::ExportDecl(export)) => {
+        for name in decl_name(&export.decl) {
+          info.declared.push(name.clone());
+          info.exports.push(ExportEntry::Local {
+            exported: name.clone(),
+            local: name,
+          });
+        }
+      }
+      ModuleItem::ModuleDecl(ModuleDecl::ExportDefaultDecl(export)) => {
+        let ident = match &export.decl {
+          DefaultDecl::Class(class) => class.ident.as_ref(),
+          DefaultDecl::Fn(func) => func.ident.as_ref(),
+          DefaultDecl::TsInterfaceDecl(interface) => Some(&interface.id),
+        };
+        let local = ident.map_or_else(|| DEFAULT_EXPORT_NAME.into(), |ident| ident.sym.clone());
+        info.declared.push(local.clone());
+        info.exports.push(ExportEntry::Local {
+          exported: "default".into(),
+          local,
+        });
+      }
+      ModuleItem::ModuleDecl(ModuleDecl::ExportDefaultExpr(export)) => {
+        if let Some(ident) = export.expr.as_ident() {
+          info.exports.push(ExportEntry::Local {
+            exported: "default".into(),
+            local: ident.sym.clone(),
+          });
+        }
+      }
+      ModuleItem::ModuleDecl(ModuleDecl::ExportNamed(named)) => {
+        let source = named
+          .src
+          .as_ref()
+          .map(|src| src.value.to_string_lossy().to_string());
+        for specifier in &named.specifiers {
+          match (specifier, &source) {
+            (ExportSpecifier::Named(spec), None) => {
+              let local = export_name(&spec.orig);
+              info.exports.push(ExportEntry::Local {
+                exported: spec
+                  .exported
+                  .as_ref()
+                  .map_or_else(|| local.clone(), export_name),
+                local,
+              });
+            }
+            (ExportSpecifier::Named(spec), Some(source)) => {
+              let imported = export_name(&spec.orig);
+              info.exports.push(ExportEntry::Reexport {
+                exported: spec
+                  .exported
+                  .as_ref()
+                  .map_or_else(|| imported.clone(), export_name),
+                source: source.clone(),
+                imported: Imported::Named(imported),
+              });
+            }
+            (ExportSpecifier::Namespace(spec), Some(source)) => {
+              info.exports.push(ExportEntry::Reexport {
+                exported: export_name(&spec.name),
+                source: source.clone(),
+                imported: Imported::Namespace,
+              });
+            }
+            (ExportSpecifier::Default(spec), Some(source)) => {
+              info.exports.push(ExportEntry::Reexport {
+                exported: spec.exported.sym.clone(),
+                source: source.clone(),
+                imported: Imported::Named("default".into()),
+              });
+            }
+            _ => {}
+          }
+        }
+      }
+      ModuleItem::ModuleDecl(ModuleDecl::ExportAll(all)) => {
+        info
+          .star_exports
+          .push(all.src.value.to_string_lossy().to_string());
+      }
+      ModuleItem::ModuleDecl(_) => {}
+    }
+  }
+  info
+}
+
+/// Turns the module into a part of the bundle: the imports and exports are removed,
+/// as they are linked by the bundle, and the top-level bindings are renamed.
+struct BundleModuleTransform<'a> {
+  renames: &'a FxHashMap<Atom, Atom>,
+}
+
+impl VisitMut for BundleModuleTransform<'_> {
+  fn visit_mut_module_items(&mut self, items: &mut Vec<ModuleItem>) {
+    let mut result = Vec::with_capacity(items.len());
+    for item in std::mem::take(items) {
+      let item = match item {
+        ModuleItem::ModuleDecl(ModuleDecl::ExportDecl(export)) => {
+          ModuleItem::Stmt(Stmt::Decl(export.decl))
+        }
+        ModuleItem::ModuleDecl(ModuleDecl::ExportDefaultDecl(export)) => {
+          let decl = match export.decl {
+            DefaultDecl::Class(class) => Decl::Class(ClassDecl {
+              ident: class
+                .ident
+                .unwrap_or_else(|| Ident::new_no_ctxt(DEFAULT_EXPORT_NAME.into(), DUMMY_SP)),
+              declare: true,
+              class: class.class,
+            }),
+            DefaultDecl::Fn(func) => Decl::Fn(FnDecl {
+              ident: func
+                .ident
+                .unwrap_or_else(|| Ident::new_no_ctxt(DEFAULT_EXPORT_NAME.into(), DUMMY_SP)),
+              declare: true,
+              function: func.function,
+            }),
+            DefaultDecl::TsInterfaceDecl(interface) => Decl::TsInterface(interface),
+          };
+          ModuleItem::Stmt(Stmt::Decl(decl))
+        }
+        ModuleItem::ModuleDecl(_) => continue,
+        item => item,
+      };
+      result.push(item);
+    }
+    *items = result;
+    items.visit_mut_children_with(self);
+  }
+
+  fn visit_mut_ident(&mut self, ident: &mut Ident) {
+    if let Some(name) = self.renames.get(&ident.sym) {
+      ident.sym = name.clone();
+    }
+  }
+
+  // the keys of members are not bindings
+
+  fn visit_mut_ts_property_signature(&mut self, node: &mut TsPropertySignature) {
+    if node.computed {
+      node.key.visit_mut_with(self);
+    }
+    node.type_ann.visit_mut_with(self);
+  }
+
+  fn visit_mut_ts_method_signature(&mut self, node: &mut TsMethodSignature) {
+    if node.computed {
+      node.key.visit_mut_with(self);
+    }
+    node.params.visit_mut_with(self);
+    node.type_ann.visit_mut_with(self);
+    node.type_params.visit_mut_with(self);
+  }
+
+  fn visit_mut_ts_getter_signature(&mut self, node: &mut TsGetterSignature) {
+    if node.computed {
+      node.key.visit_mut_with(self);
+    }
+    node.type_ann.visit_mut_with(self);
+  }
+
+  fn visit_mut_ts_setter_signature(&mut self, node: &mut TsSetterSignature) {
+    if node.computed {
+      node.key.visit_mut_with(self);
+    }
+    node.param.visit_mut_with(self);
+  }
+
+  fn visit_mut_ts_enum_member(&mut self, node: &mut TsEnumMember) {
+    node.init.visit_mut_with(self);
+  }
+
+  // `import('pkg').Foo` refers to the exports of another module
+  fn visit_mut_ts_import_type(&mut self, node: &mut TsImportType) {
+    node.type_args.visit_mut_with(self);
+  }
+}
+
+struct BundleModule {
+  path: Utf8PathBuf,
+  dts: DtsModule,
+  info: ModuleInfo,
+  /// The requests of the module, resolved to the files of the bundle.
+  requests: FxHashMap<String, Utf8PathBuf>,
+  renames: FxHashMap<Atom, Atom>,
+}
+
+#[derive(Debug)]
+struct ExternalImport {
+  source: String,
+  imported: Imported,
+  local: Atom,
+}
+
+/// The result of rolling up the declarations of an entry.
+#[derive(Debug, Default)]
+pub(crate) struct DtsBundle {
+  pub(crate) code: String,
+  pub(crate) files: Vec<Utf8PathBuf>,
+  pub(crate) missing_files: Vec<Utf8PathBuf>,
+  pub(crate) warnings: Vec<String>,
+}
+
+#[derive(Debug)]
+struct CachedModule {
+  source: String,
+  dts: DtsModule,
+  info: ModuleInfo,
+}
+
+/// The declarations of the modules, reused while the sources of them are unchanged.
+#[derive(Debug, Default)]
+pub(crate) struct DtsModuleCache {
+  modules: Mutex<FxHashMap<Utf8PathBuf, Arc<CachedModule>>>,
+}
+
+impl DtsModuleCache {
+  fn get_or_create(&self, path: &Utf8Path, source: String) -> Result<Arc<CachedModule>, String> {
+    if let Some(module) = self
+      .modules
+      .lock()
+      .expect("should lock dts module cache")
+      .get(path)
+      && module.source == source
+    {
+      return Ok(module.clone());
+    }
+    let dts = isolated_declarations(path, source.clone())?;
+    let info = dts.ast.visit(|program, _| {
+      program
+        .get_inner_program()
+        .as_module()
+        .map(|module| collect_module_info(&module.body))
+        .unwrap_or_default()
+    });
+    let module = Arc::new(CachedModule { source, dts, info });
+    self
+      .modules
+      .lock()
+      .expect("should lock dts module cache")
+      .insert(path.to_path_buf(), module.clone());
+    Ok(module)
+  }
+
+  /// Removes the modules which are not part of any bundle.
+  pub(crate) fn retain(&self, files: &FxHashSet<Utf8PathBuf>) {
+    self
+      .modules
+      .lock()
+      .expect("should lock dts module cache")
+      .retain(|path, _| files.contains(path));
+  }
+}
+
+/// The resolve options of the requests of declarations, which prefer the TypeScript sources and
+/// the `types` of packages.
+pub(crate) fn declaration_resolve_options() -> Resolve {
+  let strings = |values: &[&str]| values.iter().map(|value| value.to_string()).collect();
+  Resolve {
+    extensions: Some(strings(&[".ts", ".tsx", ".mts", ".cts", ".d.ts"])),
+    // `./foo.js` is written for `./foo.ts` in ESM TypeScript
+    extension_alias: Some(vec![
+      (".js".into(), strings(&[".ts", ".tsx", ".d.ts", ".js"])),
+      (".jsx".into(), strings(&[".tsx", ".d.ts", ".jsx"])),
+      (".mjs".into(), strings(&[".mts", ".d.mts", ".mjs"])),
+      (".cjs".into(), strings(&[".cts", ".d.cts", ".cjs"])),
+    ]),
+    condition_names: Some(strings(&["types", "import", "require", "default"])),
+    main_fields: Some(strings(&["types", "typings", "module", "main"])),
+    ..Default::default()
+  }
+}
+
+/// Rolls up the declarations of an entry and the modules it imports into a single file. The
+/// requests resolved to the TypeScript files of the project, including the ones mapped by the
+/// `paths` of tsconfig and the subpaths of the package itself, are bundled, and the imports of
+/// other packages are kept as is.
+pub(crate) async fn bundle_declarations(
+  resolver: &Resolver,
+  cache: &DtsModuleCache,
+  entry: &Utf8Path,
+) -> Result<DtsBundle, String> {
+  let mut bundler = DtsBundler {
+    resolver,
+    cache,
+    modules: vec![],
+    module_by_path: FxHashMap::default(),
+    externals: vec![],
+    external_by_key: FxHashMap::default(),
+    namespaces: vec![],
+    namespace_by_module: FxHashMap::default(),
+    used_names: FxHashSet::default(),
+    missing_files: vec![],
+    warnings: vec![],
+  };
+  bundler.load(entry.to_path_buf()).await?;
+  bundler.rename();
+  let code = bundler.render()?;
+  Ok(DtsBundle {
+    code,
+    files: bundler.modules.iter().map(|m| m.path.clone()).collect(),
+    missing_files: bundler.missing_files,
+    warnings: bundler.warnings,
+  })
+}
+
+struct DtsBundler<'a> {
+  resolver: &'a Resolver,
+  cache: &'a DtsModuleCache,
+  /// The entry is the first one.
+  modules: Vec<BundleModule>,
+  module_by_path: FxHashMap<Utf8PathBuf, usize>,
+  externals: Vec<ExternalImport>,
+  external_by_key: FxHashMap<(String, Imported), Atom>,
+  /// The namespaces declared for the namespace imports of the modules of the bundle.
+  namespaces: Vec<(usize, Atom)>,
+  namespace_by_module: FxHashMap<usize, Atom>,
+  used_names: FxHashSet<Atom>,
+  missing_files: Vec<Utf8PathBuf>,
+  warnings: Vec<String>,
+}
+
+impl DtsBundler<'_> {
+  async fn resolve_request(&mut self, from: &Utf8Path, request: &str) -> Option<Utf8PathBuf> {
+    let mut resolve_context = ResolveContext::default();
+    let result = self
+      .resolver
+      .resolve_with_context(
+        from.parent().unwrap_or(from).as_std_path(),
+        request,
+        &mut resolve_context,
+      )
+      .await;
+    self.missing_files.extend(
+      resolve_context
+        .missing_dependencies
+        .into_iter()
+        .filter_map(|path| Utf8PathBuf::from_path_buf(path).ok()),
+    );
+    let Ok(ResolveResult::Resource(resource)) = result else {
+      return None;
+    };
+    // the declarations of other packages are imported from them
+    if resource
+      .path
+      .components()
+      .any(|component| component.as_str() == "node_modules")
+    {
+      return None;
+    }
+    is_typescript_file(&resource.path).then_some(resource.path)
+  }
+
+  async fn load(&mut self, entry: Utf8PathBuf) -> Result<(), String> {
+    let mut queue = VecDeque::from([entry]);
+    while let Some(path) = queue.pop_front() {
+      if self.module_by_path.contains_key(&path) {
+        continue;
+      }
+      let source = self
+        .resolver
+        .inner_fs()
+        .read_to_string(&path)
+        .await
+        .map_err(|err| format!("Can't read {path}: {err}"))?;
+      let module = self.cache.get_or_create(&path, source)?;
+      for issue in &module.dts.issues {
+        self.warnings.push(format!("{path}: {issue}"));
+      }
+      let info = module.info.clone();
+
+      let sources = info
+        .imports
+        .iter()
+        .map(|import| &import.source)
+        .chain(info.exports.iter().filter_map(|export| match export {
+          ExportEntry::Reexport { source, .. } => Some(source),
+          ExportEntry::Local { .. } => None,
+        }))
+        .chain(info.star_exports.iter())
+        .cloned()
+        .collect::<FxIndexSet<_>>();
+      let mut requests = FxHashMap::default();
+      for source in sources {
+        match self.resolve_request(&path, &source).await {
+          Some(resolved) => {
+            queue.push_back(resolved.clone());
+            requests.insert(source, resolved);
+          }
+          None if source.starts_with('.') => self.warnings.push(format!(
+            "{path}: Can't resolve the declarations of \"{source}\""
+          )),
+          None => {}
+        }
+      }
+
+      self.module_by_path.insert(path.clone(), self.modules.len());
+      self.modules.push(BundleModule {
+        path,
+        dts: module.dts.clone(),
+        info,
+        requests,
+        renames: FxHashMap::default(),
+      });
+    }
+    Ok(())
+  }
+
+  /// The module of the bundle which the request of a module refers to,
+  /// `None` for the requests of other packages.
+  fn target(&self, module: usize, source: &str) -> Option<usize> {
+    self.modules[module]
+      .requests
+      .get(source)
+      .and_then(|path| self.module_by_path.get(path))
+      .copied()
+  }
+
+  fn is_external(&self, module: usize, source: &str) -> bool {
+    !self.modules[module].requests.contains_key(source)
+  }
+
+  fn fresh_name(&mut self, name: &Atom) -> Atom {
+    let mut fresh = name.clone();
+    let mut index = 1;
+    while self.used_names.contains(&fresh) {
+      fresh = format!("{name}_{index}").into();
+      index += 1;
+    }
+    self.used_names.insert(fresh.clone());
+    fresh
+  }
+
+  fn external_binding(&mut self, source: &str, imported: &Imported, preferred: &Atom) -> Atom {
+    let key = (source.to_string(), imported.clone());
+    if let Some(local) = self.external_by_key.get(&key) {
+      return local.clone();
+    }
+    let local = self.fresh_name(preferred);
+    self.externals.push(ExternalImport {
+      source: source.to_string(),
+      imported: imported.clone(),
+      local: local.clone(),
+    });
+    self.external_by_key.insert(key, local.clone());
+    local
+  }
+
+  /// Gives each top-level binding of the bundle a unique name, the entry keeps its names first.
+  fn rename(&mut self) {
+    for index in 0..self.modules.len() {
+      let mut renames = FxHashMap::default();
+      let declared = std::mem::take(&mut self.modules[index].info.declared);
+      let mut seen = FxHashSet::default();
+      for name in &declared {
+        // declaration merging, e.g. an interface and a namespace of the same name
+        if !seen.insert(name.clone()) {
+          continue;
+        }
+        let fresh = self.fresh_name(name);
+        if &fresh != name {
+          renames.insert(name.clone(), fresh);
+        }
+      }
+      self.modules[index].info.declared = declared;
+
+      let imports = std::mem::take(&mut self.modules[index].info.imports);
+      for import in &imports {
+        if self.is_external(index, &import.source) {
+          let local = self.external_binding(&import.source, &import.imported, &import.local);
+          if local != import.local {
+            renames.insert(import.local.clone(), local);
+          }
+        }
+      }
+      self.modules[index].info.imports = imports;
+
+      let exports = std::mem::take(&mut self.modules[index].info.exports);
+      for export in &exports {
+        if let ExportEntry::Reexport {
+          exported,
+          source,
+          imported,
+        } = export
+          && self.is_external(index, source)
+        {
+          let preferred = match imported {
+            Imported::Named(name) if name != "default" => name,
+            _ => exported,
+          };
+          self.external_binding(source, imported, preferred);
+        }
+      }
+      self.modules[index].info.exports = exports;
+      self.modules[index].renames = renames;
+    }
+
+    // the namespace imports of the modules of the bundle are declared as namespaces
+    for index in 0..self.modules.len() {
+      let info = &self.modules[index].info;
+      let namespaces = info
+        .imports
+        .iter()
+        .filter(|import| import.imported == Imported::Namespace)
+        .map(|import| (&import.source, &import.local))
+        .chain(info.exports.iter().filter_map(|export| match export {
+          ExportEntry::Reexport {
+            exported,
+            source,
+            imported: Imported::Namespace,
+          } => Some((source, exported)),
+          _ => None,
+        }))
+        .filter_map(|(source, preferred)| Some((self.target(index, source)?, preferred.clone())))
+        .collect::<Vec<_>>();
+      for (target, preferred) in namespaces {
+        if !self.namespace_by_module.contains_key(&target) {
+          let name = self.fresh_name(&preferred);
+          self.namespaces.push((target, name.clone()));
+          self.namespace_by_module.insert(target, name);
+        }
+      }
+    }
+
+    // links the imports of the bundle to the bindings of the imported modules
+    let mut warnings = vec![];
+    for index in 0..self.modules.len() {
+      let mut renames = FxHashMap::default();
+      for import in &self.modules[index].info.imports {
+        let Some(target) = self.target(index, &import.source) else {
+          continue;
+        };
+        match self.resolve_imported(target, &import.imported, 0) {
+          Some(name) => {
+            if name != import.local {
+              renames.insert(import.local.clone(), name);
+            }
+          }
+          None => warnings.push(format!(
+            "{}: Can't find the declaration of \"{}\" imported from \"{}\"",
+            self.modules[index].path, import.local, import.source
+          )),
+        }
+      }
+      self.modules[index].renames.extend(renames);
+    }
+    self.warnings.extend(warnings);
+  }
+
+  fn resolve_imported(&self, module: usize, imported: &Imported, depth: usize) -> Option<Atom> {
+    match imported {
+      Imported::Named(name) => self.resolve_export(module, name, depth),
+      Imported::Namespace => self.namespace_by_module.get(&module).cloned(),
+    }
+  }
+
+  fn resolve_local(&self, module: usize, local: &Atom, depth: usize) -> Option<Atom> {
+    let info = &self.modules[module].info;
+    if let Some(import) = info.imports.iter().find(|import| &import.local == local) {
+      return match self.target(module, &import.source) {
+        Some(target) => self.resolve_imported(target, &import.imported, depth + 1),
+        None => self
+          .external_by_key
+          .get(&(import.source.clone(), import.imported.clone()))
+          .cloned(),
+      };
+    }
+    Some(
+      self.modules[module]
+        .renames
+        .get(local)
+        .cloned()
+        .unwrap_or_else(|| local.clone()),
+    )
+  }
+
+  fn resolve_export(&self, module: usize, name: &Atom, depth: usize) -> Option<Atom> {
+    if depth > MAX_RESOLVE_DEPTH {
+      return None;
+    }
+    let info = &self.modules[module].info;
+    if let Some(export) = info.exports.iter().find(|export| export.exported() == name) {
+      return match export {
+        ExportEntry::Local { local, .. } => self.resolve_local(module, local, depth + 1),
+        ExportEntry::Reexport {
+          source, imported, ..
+        } => match self.target(module, source) {
+          Some(target) => self.resolve_imported(target, imported, depth + 1),
+          None => self
+            .external_by_key
+            .get(&(source.clone(), imported.clone()))
+            .cloned(),
+        },
+      };
+    }
+    // `export *` never re-exports `default`
+    if name == "default" {
+      return None;
+    }
+    info.star_exports.iter().find_map(|source| {
+      self
+        .target(module, source)
+        .and_then(|target| self.resolve_export(target, name, depth + 1))
+    })
+  }
+
+  /// The names exported by a module, and the packages it re-exports all of.
+  fn export_names(
+    &self,
+    module: usize,
+    visited: &mut FxHashSet<usize>,
+    names: &mut Vec<Atom>,
+    external_stars: &mut Vec<String>,
+  ) {
+    if !visited.insert(module) {
+      return;
+    }
+    let is_entry = visited.len() == 1;
+    let info = &self.modules[module].info;
+    for export in &info.exports {
+      let exported = export.exported();
+      if (is_entry || exported != "default") && !names.contains(exported) {
+        names.push(exported.clone());
+      }
+    }
+    for source in &info.star_exports {
+      match self.target(module, source) {
+        Some(target) => self.export_names(target, visited, names, external_stars),
+        None => {
+          if !external_stars.contains(source) {
+            external_stars.push(source.clone());
+          }
+        }
+      }
+    }
+  }
+
+  /// The specifiers exporting the exports of a module from the bundle, and the packages it
+  /// re-exports all of.
+  fn export_specifiers(&mut self, module: usize) -> (Vec<String>, Vec<String>) {
+    let mut names = vec![];
+    let mut external_stars = vec![];
+    self.export_names(
+      module,
+      &mut FxHashSet::default(),
+      &mut names,
+      &mut external_stars,
+    );
+    let mut specifiers = vec![];
+    for name in names {
+      match self.resolve_export(module, &name, 0) {
+        Some(local) if local == name => specifiers.push(name.to_string()),
+        Some(local) => specifiers.push(format!("{local} as {name}")),
+        None => self.warnings.push(format!(
+          "{}: Can't find the declaration of the export \"{name}\"",
+          self.modules[module].path
+        )),
+      }
+    }
+    (specifiers, external_stars)
+  }
+
+  fn render(&mut self) -> Result<String, String> {
+    let (specifiers, external_stars) = self.export_specifiers(0);
+    let mut namespaces = vec![];
+    for (module, name) in self.namespaces.clone() {
+      let (specifiers, external_stars) = self.export_specifiers(module);
+      if !external_stars.is_empty() {
+        self.warnings.push(format!(
+          "{}: The namespace \"{name}\" doesn't include the exports of {}",
+          self.modules[module].path,
+          external_stars
+            .iter()
+            .map(|source| format!("\"{source}\""))
+            .collect::<Vec<_>>()
+            .join(", ")
+        ));
+      }
+      namespaces.push(format!(
+        "declare namespace {name} {{\n  export {{ {} }};\n}}\n",
+        specifiers.join(", ")
+      ));
+    }
+
+    let mut code = String::new();
+    for external in &self.externals {
+      let source = serde_json::to_string(&external.source).expect("should serialize string");
+      let local = &external.local;
+      code += &match &external.imported {
+        Imported::Named(name) if name == "default" => format!("import {local} from {source};\n"),
+        Imported::Named(name) if name == local => format!("import {{ {local} }} from {source};\n"),
+        Imported::Named(name) => format!("import {{ {name} as {local} }} from {source};\n"),
+        Imported::Namespace => format!("import * as {local} from {source};\n"),
+      };
+    }
+
+    // the dependencies come first, so the entry is at the end of the bundle
+    for module in self.modules.iter_mut().rev() {
+      let renames = &module.renames;
+      module.dts.ast.transform(|program, _| {
+        program.visit_mut_with(&mut BundleModuleTransform { renames });
+      });
+      let declarations = print_declarations(&module.dts.ast)?;
+      if !declarations.trim().is_empty() {
+        if !code.is_empty() {
+          code.push('\n');
+        }
+        code += &declarations;
+      }
+    }
+
+    for namespace in namespaces {
+      if !code.is_empty() {
+        code.push('\n');
+      }
+      code += &namespace;
+    }
+
+    if !code.is_empty() {
+      code.push('\n');
+    }
+    if !specifiers.is_empty() || external_stars.is_empty() {
+      code += &format!("export {{ {} }};\n", specifiers.join(", "));
+    }
+    for source in external_stars {
+      code += &format!(
+        "export * from {};\n",
+        serde_json::to_string(&source).expect("should serialize string")
+      );
+    }
+    Ok(code)
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use rspack_fs::{MemoryFileSystem, WritableFileSystem};
+
+  use super::*;
+
+  async fn bundle(files: &[(&str, &str)]) -> DtsBundle {
+    let fs = Arc::new(MemoryFileSystem::default());
+    for (path, content) in files {
+      let path = Utf8Path::new(path);
+      fs.create_dir_all(path.parent().expect("should have parent"))
+        .await
+        .expect("should create dir");
+      fs.write(path, content.as_bytes())
+        .await
+        .expect("should write file");
+    }
+    let resolver = Resolver::new(declaration_resolve_options(), fs);
+    bundle_declarations(
+      &resolver,
+      &DtsModuleCache::default(),
+      Utf8Path::new("/src/index.ts"),
+    )
+    .await
+    .expect("should bundle declarations")
+  }
+
+  #[tokio::test]
+  async fn should_bundle_reexports() {
+    let bundle = bundle(&[
+      (
+        "/src/index.ts",
+        r#"import type { Ext } from "pkg";
+export { foo } from "./foo.js";
+export type { Baz as Qux } from "./baz";
+export * from "./bar";
+export interface Local { ext: Ext }"#,
+      ),
+      (
+        "/src/foo.ts",
+        "export function foo(): string { return ''; }",
+      ),
+      ("/src/bar.ts", "export const bar: number = 1;"),
+      ("/src/baz.ts", "export interface Baz { a: string }"),
+    ])
+    .await;
+    assert!(bundle.warnings.is_empty(), "{:?}", bundle.warnings);
+    assert!(bundle.code.starts_with("import { Ext } from \"pkg\";\n"));
+    assert!(bundle.code.contains("declare function foo(): string;"));
+    assert!(bundle.code.contains("declare const bar: number;"));
+    assert!(bundle.code.contains("interface Baz {"));
+    assert!(
+      bundle
+        .code
+        .ends_with("export { foo, Baz as Qux, Local, bar };\n")
+    );
+    assert_eq!(bundle.files.len(), 4);
+  }
+
+  #[tokio::test]
+  async fn should_declare_namespaces() {
+    let bundle = bundle(&[
+      (
+        "/src/index.ts",
+        r#"import * as utils from "./utils";
+export { utils };
+export * as helpers from "./helpers";"#,
+      ),
+      ("/src/utils.ts", "export const a: number = 1;"),
+      ("/src/helpers.ts", "export function b(): void {}"),
+    ])
+    .await;
+    assert!(bundle.warnings.is_empty(), "{:?}", bundle.warnings);
+    assert!(
+      bundle
+        .code
+        .contains("declare namespace utils {\n  export { a };\n}\n")
+    );
+    assert!(
+      bundle
+        .code
+        .contains("declare namespace helpers {\n  export { b };\n}\n")
+    );
+    assert!(bundle.code.ends_with("export { utils, helpers };\n"));
+  }
+
+  #[tokio::test]
+  async fn should_rename_colliding_names() {
+    let bundle = bundle(&[
+      (
+        "/src/index.ts",
+        r#"import type { Options as AOptions } from "./a";
+export { Options as BOptions } from "./b";
+export interface Options { a: AOptions }"#,
+      ),
+      ("/src/a.ts", "export interface Options { a: string }"),
+      ("/src/b.ts", "export interface Options { b: number }"),
+    ])
+    .await;
+    assert!(bundle.warnings.is_empty(), "{:?}", bundle.warnings);
+    // the entry keeps its names
+    assert!(
+      bundle
+        .code
+        .contains("interface Options {\n    a: Options_1;\n}")
+    );
+    assert!(
+      bundle
+        .code
+        .contains("interface Options_1 {\n    a: string;\n}")
+    );
+    assert!(
+      bundle
+        .code
+        .contains("interface Options_2 {\n    b: number;\n}")
+    );
+    assert!(
+      bundle
+        .code
+        .ends_with("export { Options_2 as BOptions, Options };\n")
+    );
+  }
+}
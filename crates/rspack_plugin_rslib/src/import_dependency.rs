@@ -131,7 +131,7 @@ impl DependencyTemplate for RslibDependencyTemplate {
 
     let request_and_external_type = match &dep.target_request {
       ExternalRequest::Single(request) => (Some(request), &dep.external_type),
-      ExternalRequest::Map(map) => (map.get(&dep.external_type), &dep.external_type),
+      ExternalRequest::Map(map) => (map.get(dep.external_type.as_str()), &dep.external_type),
     };
 
     if let Some(request_and_external_type) = request_and_external_type.0 {
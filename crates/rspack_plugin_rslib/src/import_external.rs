@@ -37,7 +37,7 @@ pub fn replace_import_dependencies_for_external_modules(
                 *block_dep.id(),
                 import_dependency.request().into(),
                 external_module.request.clone(),
-                external_module.external_type.clone(),
+                external_module.external_type,
                 import_dependency.range,
                 import_dependency.get_attributes().cloned(),
                 import_dependency.comments.clone(),
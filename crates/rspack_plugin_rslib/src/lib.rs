@@ -1,4 +1,6 @@
 mod asset;
+mod dts;
+mod dts_bundle;
 mod hashbang_parser_plugin;
 mod import_dependency;
 mod import_external;
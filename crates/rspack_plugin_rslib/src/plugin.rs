@@ -4,30 +4,45 @@ use std::{
 };
 
 use rspack_core::{
-  AssetEmittedInfo, ChunkUkey, Compilation, CompilationParams, CompilerAssetEmitted,
-  CompilerCompilation, CompilerFinishMake, ModuleType, NormalModuleFactoryParser,
-  ParserAndGenerator, ParserOptions, Plugin, get_module_directives, get_module_hashbang,
+  AssetEmittedInfo, ChunkUkey, Compilation, CompilationAsset, CompilationParams,
+  CompilationProcessAssets, CompilerAssetEmitted, CompilerCompilation, CompilerFinishMake,
+  ConcatenatedModule, DependencyCategory, Filename, ModuleType, NormalModuleFactoryParser,
+  ParserAndGenerator, ParserOptions, PathData, Plugin, ResolveOptionsWithDependencyType,
+  get_module_directives, get_module_hashbang,
   rspack_sources::{ConcatSource, RawStringSource, Source, SourceExt},
 };
-use rspack_error::Result;
+use rspack_error::{Diagnostic, Result};
 use rspack_hook::{plugin, plugin_hook};
+use rspack_paths::Utf8PathBuf;
 use rspack_plugin_asset::AssetParserAndGenerator;
 use rspack_plugin_javascript::{
   BoxJavascriptParserPlugin, JavascriptModulesRender, JsPlugin, RenderSource,
   parser_and_generator::JavaScriptParserAndGenerator,
 };
+use rustc_hash::FxHashSet;
 
 use crate::{
-  asset::RslibAssetParserAndGenerator, hashbang_parser_plugin::HashbangParserPlugin,
+  asset::RslibAssetParserAndGenerator,
+  dts::is_typescript_file,
+  dts_bundle::{DtsModuleCache, bundle_declarations, declaration_resolve_options},
+  hashbang_parser_plugin::HashbangParserPlugin,
   import_dependency::RslibDependencyTemplate,
   import_external::replace_import_dependencies_for_external_modules,
-  parser_plugin::RslibParserPlugin, react_directives_parser_plugin::ReactDirectivesParserPlugin,
+  parser_plugin::RslibParserPlugin,
+  react_directives_parser_plugin::ReactDirectivesParserPlugin,
 };
 
 #[derive(Debug)]
 pub struct RslibPluginOptions {
   pub intercept_api_plugin: bool,
   pub force_node_shims: bool,
+  pub dts: Option<RslibDtsOptions>,
+}
+
+#[derive(Debug)]
+pub struct RslibDtsOptions {
+  /// The filename template of the declaration bundle of each entry.
+  pub filename: String,
 }
 
 #[derive(Debug)]
@@ -41,11 +56,12 @@ pub struct ProgressPluginStateInfo {
 #[derive(Debug)]
 pub struct RslibPlugin {
   options: RslibPluginOptions,
+  dts_module_cache: DtsModuleCache,
 }
 
 impl RslibPlugin {
   pub fn new(options: RslibPluginOptions) -> Self {
-    Self::new_inner(options)
+    Self::new_inner(options, Default::default())
   }
 }
 
@@ -191,6 +207,105 @@ async fn asset_emitted(
   Ok(())
 }
 
+#[plugin_hook(CompilationProcessAssets for RslibPlugin, stage = Compilation::PROCESS_ASSETS_STAGE_ADDITIONAL)]
+async fn process_assets(&self, compilation: &mut Compilation) -> Result<()> {
+  let Some(dts) = &self.options.dts else {
+    return Ok(());
+  };
+
+  let mut entries: Vec<(ChunkUkey, Utf8PathBuf)> = vec![];
+  {
+    let module_graph = compilation.get_module_graph();
+    for (name, entrypoint_ukey) in compilation.entrypoints.iter() {
+      let entrypoint = compilation.chunk_group_by_ukey.expect_get(entrypoint_ukey);
+      // the declarations are rolled up from the first TypeScript module of the entry
+      let entry_path = compilation.entries[name]
+        .dependencies
+        .iter()
+        .filter_map(|dep_id| module_graph.module_identifier_by_dependency_id(dep_id))
+        .filter_map(|module_id| {
+          let module = module_graph.module_by_identifier(module_id)?;
+          let module = match module.as_ref().downcast_ref::<ConcatenatedModule>() {
+            Some(concatenated_module) => {
+              module_graph.module_by_identifier(&concatenated_module.get_root())?
+            }
+            None => module,
+          };
+          let path = module.as_normal_module()?.resource_resolved_data().path()?;
+          is_typescript_file(path).then(|| path.to_path_buf())
+        })
+        .next();
+      if let Some(entry_path) = entry_path {
+        entries.push((entrypoint.get_entrypoint_chunk(), entry_path));
+      }
+    }
+  }
+
+  let resolver = compilation
+    .resolver_factory
+    .get(ResolveOptionsWithDependencyType {
+      resolve_options: Some(Box::new(declaration_resolve_options())),
+      resolve_to_context: false,
+      dependency_category: DependencyCategory::Esm,
+    });
+  let mut diagnostics = vec![];
+  let mut files = FxHashSet::default();
+  for (chunk_ukey, entry_path) in entries {
+    let bundle = match bundle_declarations(&resolver, &self.dts_module_cache, &entry_path).await {
+      Ok(bundle) => bundle,
+      Err(reason) => {
+        diagnostics.push(Diagnostic::warn(
+          "RslibPlugin".to_string(),
+          format!("Failed to emit the declarations of {entry_path}.\nReason: {reason}"),
+        ));
+        continue;
+      }
+    };
+    diagnostics.extend(
+      bundle
+        .warnings
+        .into_iter()
+        .map(|warning| Diagnostic::warn("RslibPlugin".to_string(), warning)),
+    );
+
+    let chunk = compilation.chunk_by_ukey.expect_get(&chunk_ukey);
+    let (filename, info) = compilation
+      .get_asset_path_with_info(
+        &Filename::from(dts.filename.clone()),
+        PathData::default()
+          .chunk_id_optional(
+            chunk
+              .id(&compilation.chunk_ids_artifact)
+              .map(|id| id.as_str()),
+          )
+          .chunk_name_optional(chunk.name_for_filename_template(&compilation.chunk_ids_artifact)),
+      )
+      .await?;
+    compilation
+      .file_dependencies
+      .extend(bundle.files.iter().map(|path| path.as_path().into()));
+    compilation.missing_dependencies.extend(
+      bundle
+        .missing_files
+        .iter()
+        .map(|path| path.as_path().into()),
+    );
+    files.extend(bundle.files);
+    compilation
+      .chunk_by_ukey
+      .expect_get_mut(&chunk_ukey)
+      .add_auxiliary_file(filename.clone());
+    compilation.emit_asset(
+      filename,
+      CompilationAsset::new(Some(RawStringSource::from(bundle.code).boxed()), info),
+    );
+  }
+  compilation.extend_diagnostics(diagnostics);
+  self.dts_module_cache.retain(&files);
+
+  Ok(())
+}
+
 impl Plugin for RslibPlugin {
   fn name(&self) -> &'static str {
     "rslib"
@@ -204,6 +319,10 @@ impl Plugin for RslibPlugin {
       .tap(nmf_parser::new(self));
 
     ctx.compiler_hooks.finish_make.tap(finish_make::new(self));
+    ctx
+      .compilation_hooks
+      .process_assets
+      .tap(process_assets::new(self));
     ctx
       .compiler_hooks
       .asset_emitted
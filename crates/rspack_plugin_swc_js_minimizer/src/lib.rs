@@ -9,17 +9,19 @@ use cow_utils::CowUtils;
 use once_cell::sync::OnceCell;
 use rayon::prelude::*;
 use regex::Regex;
+use rspack_cacheable::{cacheable, from_bytes, to_bytes};
 use rspack_core::{
   AssetInfo, ChunkUkey, Compilation, CompilationAsset, CompilationParams, CompilationProcessAssets,
   CompilerCompilation, Plugin,
+  cache::persistent::ScopedCache,
   diagnostics::MinifyError,
   rspack_sources::{
-    ConcatSource, MapOptions, ObjectPool, RawStringSource, Source, SourceExt, SourceMapSource,
-    SourceMapSourceOptions,
+    ConcatSource, MapOptions, ObjectPool, RawStringSource, Source, SourceExt, SourceMap,
+    SourceMapSource, SourceMapSourceOptions,
   },
 };
-use rspack_error::{Diagnostic, Result};
-use rspack_hash::RspackHash;
+use rspack_error::{Diagnostic, Result, error};
+use rspack_hash::{HashDigest, HashFunction, RspackHash};
 use rspack_hook::{plugin, plugin_hook};
 use rspack_javascript_compiler::JavaScriptCompiler;
 use rspack_plugin_javascript::{ExtractedCommentsInfo, JavascriptModulesChunkHash, JsPlugin};
@@ -36,6 +38,7 @@ pub use swc_ecma_minifier::option::{
 use thread_local::ThreadLocal;
 
 const PLUGIN_NAME: &str = "rspack.SwcJsMinimizerRspackPlugin";
+const CACHE_SCOPE: &str = "swc_js_minimizer";
 
 static JAVASCRIPT_ASSET_REGEXP: LazyLock<Regex> =
   LazyLock::new(|| Regex::new(r"\.[cm]?js(\?.*)?$").expect("Invalid RegExp"));
@@ -68,6 +71,11 @@ pub struct MinimizerOptions {
 
 impl std::hash::Hash for MinimizerOptions {
   fn hash<H: std::hash::Hasher>(&self, state: &mut H) {
+    serde_json::to_string(&self.ecma)
+      .expect("Should be able to serialize")
+      .hash(state);
+    self.minify.hash(state);
+    self.module.hash(state);
     self
       .__format_cache
       .get_or_init(|| serde_json::to_string(&self.format).expect("Should be able to serialize"))
@@ -120,15 +128,24 @@ struct NormalizedExtractComments<'a> {
   banner: Option<String>,
 }
 
+/// The minified output of an asset, saved in persistent cache.
+#[cacheable]
+struct MinifiedAsset {
+  code: String,
+  source_map: Option<String>,
+  extracted_comments: Option<String>,
+}
+
 #[plugin]
 #[derive(Debug)]
 pub struct SwcJsMinimizerRspackPlugin {
   options: PluginOptions,
+  cache: ScopedCache,
 }
 
 impl SwcJsMinimizerRspackPlugin {
   pub fn new(options: PluginOptions) -> Self {
-    Self::new_inner(options)
+    Self::new_inner(options, ScopedCache::new(CACHE_SCOPE))
   }
 }
 
@@ -174,6 +191,12 @@ async fn process_assets(&self, compilation: &mut Compilation) -> Result<()> {
     });
   let enter_span = tracing::Span::current();
 
+  let storage = compilation.persistent_cache_storage.clone();
+  if let Some(storage) = &storage {
+    self.cache.recovery(storage).await?;
+  }
+  let storage = storage.as_ref();
+
   let tls: ThreadLocal<ObjectPool> = ThreadLocal::new();
   compilation
     .assets_mut()
@@ -298,27 +321,88 @@ async fn process_assets(&self, compilation: &mut Compilation) -> Result<()> {
           }
         };
 
-        let output = match javascript_compiler.minify(
-          swc_core::common::FileName::Custom(filename.to_string()),
-          input,
-          js_minify_options,
-          Some(comments_op),
-        ) {
-            Ok(r) => r,
-            Err(e) => {
-              let errors = e.into_inner().into_iter().map(|err| {
-                let mut d = Diagnostic::from(MinifyError(err));
-                d.file = Some(filename.into());
-                d
-              }).collect::<Vec<_>>();
-              tx.send(errors)?;
-              return Ok(())
-            },
+        let cache_key = storage.map(|_| {
+          let mut hasher = RspackHash::new(&HashFunction::MD4);
+          PLUGIN_NAME.hash(&mut hasher);
+          options.hash(&mut hasher);
+          filename.hash(&mut hasher);
+          is_module.hash(&mut hasher);
+          input_source_map.is_some().hash(&mut hasher);
+          input.hash(&mut hasher);
+          hasher.digest(&HashDigest::Hex).encoded().as_bytes().to_vec()
+        });
+        let cached = cache_key
+          .as_ref()
+          .and_then(|key| self.cache.get(key))
+          .and_then(|value| from_bytes::<MinifiedAsset, ()>(&value, &()).ok());
+
+        let (code, source_map) = if let Some(minified) = cached {
+          if let Some(comments) = minified.extracted_comments
+            && let Some(extract_comments) = &extract_comments_option
+          {
+            all_extracted_comments
+              .lock()
+              .expect("all_extract_comments lock failed")
+              .insert(
+                filename.to_string(),
+                ExtractedCommentsInfo {
+                  source: RawStringSource::from(comments).boxed(),
+                  comments_file_name: extract_comments.filename.to_string(),
+                },
+              );
+          }
+          let source_map = minified
+            .source_map
+            .map(|source_map| SourceMap::from_json(&source_map))
+            .transpose()
+            .map_err(|e| error!(e.to_string()))?;
+          (minified.code, source_map)
+        } else {
+          let output = match javascript_compiler.minify(
+            swc_core::common::FileName::Custom(filename.to_string()),
+            input,
+            js_minify_options,
+            Some(comments_op),
+          ) {
+              Ok(r) => r,
+              Err(e) => {
+                let errors = e.into_inner().into_iter().map(|err| {
+                  let mut d = Diagnostic::from(MinifyError(err));
+                  d.file = Some(filename.into());
+                  d
+                }).collect::<Vec<_>>();
+                tx.send(errors)?;
+                return Ok(())
+              },
+          };
+
+          if let (Some(storage), Some(cache_key)) = (storage, cache_key) {
+            let minified = MinifiedAsset {
+              code: output.code.clone(),
+              source_map: output
+                .map
+                .as_ref()
+                .map(|source_map| source_map.to_json())
+                .transpose()
+                .map_err(|e| error!(e.to_string()))?,
+              extracted_comments: all_extracted_comments
+                .lock()
+                .expect("all_extract_comments lock failed")
+                .get(filename)
+                .map(|comments| comments.source.source().into_string_lossy().into_owned()),
+            };
+            self.cache.set(
+              storage,
+              cache_key,
+              to_bytes(&minified, &()).expect("should serialize minified asset"),
+            );
+          }
+          (output.code, output.map)
         };
 
-        let source = if let Some(source_map) = output.map {
+        let source = if let Some(source_map) = source_map {
           SourceMapSource::new(SourceMapSourceOptions {
-            value: output.code,
+            value: code,
             name: filename,
             source_map,
             original_source: None,
@@ -327,7 +411,7 @@ async fn process_assets(&self, compilation: &mut Compilation) -> Result<()> {
           })
           .boxed()
         } else {
-          RawStringSource::from(output.code).boxed()
+          RawStringSource::from(code).boxed()
         };
         let source = if let Some(Some(banner)) = extract_comments_option.map(|option| option.banner)
           && all_extracted_comments
@@ -350,6 +434,9 @@ async fn process_assets(&self, compilation: &mut Compilation) -> Result<()> {
       Ok(())
   })?;
   compilation.extend_diagnostics(rx.into_iter().flatten().collect::<Vec<_>>());
+  if let Some(storage) = storage {
+    self.cache.remove_unused(storage);
+  }
 
   // write all extracted comments to assets
   all_extracted_comments
@@ -0,0 +1,244 @@
+use std::{
+  fs::File,
+  io::{BufWriter, Write},
+  sync::{
+    Arc, Mutex,
+    atomic::{AtomicU64, Ordering},
+  },
+  time::Instant,
+};
+
+use serde_json::{Map, Value, json};
+use tracing::{
+  Event, Subscriber,
+  field::{Field, Visit},
+  span,
+};
+use tracing_subscriber::{Layer, layer::Context, registry::LookupSpan};
+
+use crate::{TraceEvent, Tracer, tracer::Layered};
+
+static JAVASCRIPT_CATEGORY: &str = "javascript";
+
+static NEXT_TID: AtomicU64 = AtomicU64::new(1);
+
+thread_local! {
+  // chrome trace viewer needs a numeric thread id, `ThreadId::as_u64` is unstable
+  static TID: u64 = NEXT_TID.fetch_add(1, Ordering::Relaxed);
+}
+
+/// Writes spans and events in the [Chrome Trace Event Format](https://docs.google.com/document/d/1CvAClvFfyA5R-PhYUmn5OOQtYMH4h6I0nSsKchNAySU),
+/// the output can be loaded by `chrome://tracing` without perfetto tooling.
+#[derive(Default)]
+pub struct ChromeTracer {
+  writer: Option<Arc<ChromeWriter>>,
+}
+
+struct ChromeWriter {
+  start: Instant,
+  pid: u32,
+  file: Mutex<Option<BufWriter<File>>>,
+}
+
+impl ChromeWriter {
+  // elapsed microseconds since the tracer is set up
+  fn now(&self) -> u64 {
+    self.start.elapsed().as_micros() as u64
+  }
+
+  fn write_event(&self, event: Value) {
+    let mut file = self.file.lock().expect("failed to lock chrome trace file");
+    // the file has been finished by teardown
+    let Some(file) = file.as_mut() else {
+      return;
+    };
+    let _ = file.write_all(b",\n");
+    let _ = serde_json::to_writer(file, &event);
+  }
+
+  fn finish(&self) {
+    let mut file = self.file.lock().expect("failed to lock chrome trace file");
+    if let Some(mut file) = file.take() {
+      let _ = file.write_all(b"\n]}\n");
+      let _ = file.flush();
+    }
+  }
+}
+
+struct ArgsVisitor<'a>(&'a mut Map<String, Value>);
+
+impl Visit for ArgsVisitor<'_> {
+  fn record_f64(&mut self, field: &Field, value: f64) {
+    self.0.insert(field.name().to_string(), json!(value));
+  }
+
+  fn record_i64(&mut self, field: &Field, value: i64) {
+    self.0.insert(field.name().to_string(), json!(value));
+  }
+
+  fn record_u64(&mut self, field: &Field, value: u64) {
+    self.0.insert(field.name().to_string(), json!(value));
+  }
+
+  fn record_bool(&mut self, field: &Field, value: bool) {
+    self.0.insert(field.name().to_string(), json!(value));
+  }
+
+  fn record_str(&mut self, field: &Field, value: &str) {
+    self.0.insert(field.name().to_string(), json!(value));
+  }
+
+  fn record_debug(&mut self, field: &Field, value: &dyn std::fmt::Debug) {
+    self
+      .0
+      .insert(field.name().to_string(), json!(format!("{value:?}")));
+  }
+}
+
+// per span state stored in the span extensions until the span is closed
+struct SpanData {
+  ts: u64,
+  tid: u64,
+  args: Map<String, Value>,
+}
+
+struct ChromeLayer {
+  writer: Arc<ChromeWriter>,
+}
+
+impl<S> Layer<S> for ChromeLayer
+where
+  S: Subscriber + for<'a> LookupSpan<'a>,
+{
+  fn on_new_span(&self, attrs: &span::Attributes<'_>, id: &span::Id, ctx: Context<'_, S>) {
+    let Some(span) = ctx.span(id) else {
+      return;
+    };
+    let mut args = Map::new();
+    attrs.record(&mut ArgsVisitor(&mut args));
+    span.extensions_mut().insert(SpanData {
+      ts: self.writer.now(),
+      tid: TID.with(|tid| *tid),
+      args,
+    });
+  }
+
+  fn on_record(&self, id: &span::Id, values: &span::Record<'_>, ctx: Context<'_, S>) {
+    let Some(span) = ctx.span(id) else {
+      return;
+    };
+    if let Some(data) = span.extensions_mut().get_mut::<SpanData>() {
+      values.record(&mut ArgsVisitor(&mut data.args));
+    }
+  }
+
+  fn on_event(&self, event: &Event<'_>, _ctx: Context<'_, S>) {
+    let mut args = Map::new();
+    event.record(&mut ArgsVisitor(&mut args));
+    let metadata = event.metadata();
+    self.writer.write_event(json!({
+      "name": metadata.name(),
+      "cat": metadata.target(),
+      "ph": "i",
+      "s": "t",
+      "ts": self.writer.now(),
+      "pid": self.writer.pid,
+      "tid": TID.with(|tid| *tid),
+      "args": args,
+    }));
+  }
+
+  fn on_close(&self, id: span::Id, ctx: Context<'_, S>) {
+    let Some(span) = ctx.span(&id) else {
+      return;
+    };
+    let Some(data) = span.extensions_mut().remove::<SpanData>() else {
+      return;
+    };
+    let metadata = span.metadata();
+    self.writer.write_event(json!({
+      "name": metadata.name(),
+      "cat": metadata.target(),
+      "ph": "X",
+      "ts": data.ts,
+      "dur": self.writer.now().saturating_sub(data.ts),
+      "pid": self.writer.pid,
+      "tid": data.tid,
+      "args": data.args,
+    }));
+  }
+}
+
+impl Tracer for ChromeTracer {
+  fn setup(&mut self, output: &str) -> Option<Layered> {
+    let mut file = BufWriter::new(
+      File::create(output)
+        .unwrap_or_else(|e| panic!("failed to create trace file: {output} due to {e}")),
+    );
+    let pid = std::process::id();
+    // write the process name as the first event, so the following events can always be prefixed with a comma
+    let _ = file.write_all(b"{\"traceEvents\":[\n");
+    let _ = serde_json::to_writer(
+      &mut file,
+      &json!({
+        "name": "process_name",
+        "ph": "M",
+        "pid": pid,
+        "args": { "name": "Rspack" },
+      }),
+    );
+    let writer = Arc::new(ChromeWriter {
+      start: Instant::now(),
+      pid,
+      file: Mutex::new(Some(file)),
+    });
+    self.writer = Some(writer.clone());
+    Some(Box::new(ChromeLayer { writer }))
+  }
+
+  fn sync_trace(&mut self, events: Vec<TraceEvent>) {
+    let Some(writer) = &self.writer else {
+      return;
+    };
+    for event in events {
+      let ph = match event.ph.as_str() {
+        "b" | "e" => event.ph.as_str(),
+        "P" | "X" => "i",
+        // drop not supported events
+        _ => continue,
+      };
+      // the values are always json string
+      let args = event
+        .args
+        .unwrap_or_default()
+        .into_iter()
+        .map(|(key, value)| {
+          let value = serde_json::from_str(&value).unwrap_or(Value::String(value));
+          (key, value)
+        })
+        .collect::<Map<_, _>>();
+      let categories = event
+        .categories
+        .map(|categories| categories.join(","))
+        .unwrap_or_else(|| JAVASCRIPT_CATEGORY.to_string());
+      writer.write_event(json!({
+        "name": event.name,
+        "cat": categories,
+        "ph": ph,
+        "s": "t",
+        "id": event.uuid,
+        // javascript timestamps are in nanoseconds
+        "ts": event.ts / 1000,
+        "pid": writer.pid,
+        "tid": 0,
+        "args": args,
+      }));
+    }
+  }
+
+  fn teardown(&mut self) {
+    if let Some(writer) = self.writer.take() {
+      writer.finish();
+    }
+  }
+}
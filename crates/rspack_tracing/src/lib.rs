@@ -1,9 +1,11 @@
+mod chrome;
 mod perfetto;
 mod stdout;
 mod tracer;
 
 use std::{fs, io, path::PathBuf};
 
+pub use chrome::ChromeTracer;
 pub use perfetto::PerfettoTracer;
 pub use stdout::StdoutTracer;
 pub use tracer::{TraceEvent, Tracer};
@@ -10,3 +10,8 @@ pub static TRACING_BENCH_PRESET: LazyLock<String> =
   LazyLock::new(|| format!("off,{TRACING_BENCH_TARGET}=info"));
 
 pub static TRACING_BENCH_TARGET: &str = "rspack_compilation_main";
+
+// opt-in fine-grained spans, e.g. one span per module build, loader invocation, chunk render and asset emission
+pub static TRACING_DETAIL_TARGET: &str = "rspack_detail";
+pub static TRACING_DETAIL_PRESET: LazyLock<String> =
+  LazyLock::new(|| format!("info,{TRACING_DETAIL_TARGET}=trace"));
@@ -84,6 +84,20 @@ pub struct FsWatcherOptions {
   pub aggregate_timeout: Option<u32>,
 }
 
+/// A handle to report file system events that the disk watcher can't see to a [`FsWatcher`],
+/// e.g. the changes of in-memory files.
+///
+/// Paths that don't exist on disk should be watched as missing, otherwise they are reported as
+/// removed when the watcher starts.
+#[derive(Clone)]
+pub struct FsEventTrigger(Arc<Trigger>);
+
+impl FsEventTrigger {
+  pub fn trigger_event(&self, path: &ArcPath, kind: FsEventKind) {
+    self.0.on_event(path, kind);
+  }
+}
+
 pub struct FsWatcher {
   path_manager: Arc<PathManager>,
   disk_watcher: DiskWatcher,
@@ -164,6 +178,12 @@ impl FsWatcher {
     }
   }
 
+  /// Returns a handle to trigger events from outside of the watcher, which can be moved to
+  /// another thread. Returns `None` if the watcher is closed.
+  pub fn event_trigger(&self) -> Option<FsEventTrigger> {
+    self.trigger.clone().map(FsEventTrigger)
+  }
+
   /// Pauses the file system watcher, stopping the execution of the event loop.
   pub fn pause(&self) -> Result<()> {
     self.executor.pause();
@@ -0,0 +1,235 @@
+use rustc_hash::{FxHashMap as HashMap, FxHashSet as HashSet};
+use swc_core::{
+  atoms::Wtf8Atom,
+  ecma::{
+    ast::{
+      Decl, ExportSpecifier, ImportSpecifier, Module, ModuleDecl, ModuleExportName, ModuleItem,
+      ObjectPatProp, Pat, TsModuleName,
+    },
+    atoms::Atom,
+  },
+};
+
+/// How a name exported by a barrel file is imported from the module declaring it.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum BarrelImport {
+  /// `export { default as Button } from './button'`
+  Default,
+  /// `export { Button } from './button'`
+  Named(Atom),
+  /// `export * as icons from './icons'`
+  Namespace,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct BarrelExport {
+  /// The request of the module declaring the export.
+  pub source: String,
+  pub imported: BarrelImport,
+}
+
+/// The re-export table of a barrel file, keyed by the exported name.
+pub type BarrelExports = HashMap<Atom, BarrelExport>;
+
+/// The exports of a single module, as written in its source.
+#[derive(Debug, Default)]
+pub struct ModuleReexports {
+  /// Names re-exported from another module, `source` is the request written in the module.
+  pub reexports: BarrelExports,
+  /// Requests of `export * from '...'`.
+  pub star_sources: Vec<String>,
+  /// Names declared and exported by the module itself.
+  pub locals: HashSet<Atom>,
+}
+
+fn export_name(name: &ModuleExportName) -> Atom {
+  match name {
+    ModuleExportName::Ident(ident) => ident.sym.clone(),
+    ModuleExportName::Str(str) => str.value.to_atom_lossy().into_owned(),
+  }
+}
+
+fn request(value: &Wtf8Atom) -> String {
+  value.to_string_lossy().to_string()
+}
+
+/// Collects the names bound by a declaration pattern, e.g. `export const { a, b: [c] } = ...`.
+fn collect_pat_names(pat: &Pat, names: &mut HashSet<Atom>) {
+  match pat {
+    Pat::Ident(ident) => {
+      names.insert(ident.sym.clone());
+    }
+    Pat::Array(array) => {
+      for elem in array.elems.iter().flatten() {
+        collect_pat_names(elem, names);
+      }
+    }
+    Pat::Object(object) => {
+      for prop in &object.props {
+        match prop {
+          ObjectPatProp::KeyValue(key_value) => collect_pat_names(&key_value.value, names),
+          ObjectPatProp::Assign(assign) => {
+            names.insert(assign.key.sym.clone());
+          }
+          ObjectPatProp::Rest(rest) => collect_pat_names(&rest.arg, names),
+        }
+      }
+    }
+    Pat::Rest(rest) => collect_pat_names(&rest.arg, names),
+    Pat::Assign(assign) => collect_pat_names(&assign.left, names),
+    Pat::Invalid(_) | Pat::Expr(_) => {}
+  }
+}
+
+fn barrel_import(name: Atom) -> BarrelImport {
+  if name == "default" {
+    BarrelImport::Default
+  } else {
+    BarrelImport::Named(name)
+  }
+}
+
+/// Collects the exports of a module, which is used to derive the re-export table of a barrel file.
+///
+/// Both `export { Button } from './button'` and `import { Button } from './button'; export { Button }`
+/// are treated as re-exports.
+pub fn collect_reexports(module: &Module) -> ModuleReexports {
+  let mut imports: HashMap<Atom, BarrelExport> = HashMap::default();
+  for item in &module.body {
+    let ModuleItem::ModuleDecl(ModuleDecl::Import(import)) = item else {
+      continue;
+    };
+    if import.type_only {
+      continue;
+    }
+    let source = request(&import.src.value);
+    for specifier in &import.specifiers {
+      let (local, imported) = match specifier {
+        ImportSpecifier::Named(named) => {
+          if named.is_type_only {
+            continue;
+          }
+          let imported = named
+            .imported
+            .as_ref()
+            .map_or_else(|| named.local.sym.clone(), export_name);
+          (named.local.sym.clone(), barrel_import(imported))
+        }
+        ImportSpecifier::Default(default) => (default.local.sym.clone(), BarrelImport::Default),
+        ImportSpecifier::Namespace(namespace) => {
+          (namespace.local.sym.clone(), BarrelImport::Namespace)
+        }
+      };
+      imports.insert(
+        local,
+        BarrelExport {
+          source: source.clone(),
+          imported,
+        },
+      );
+    }
+  }
+
+  let mut result = ModuleReexports::default();
+  for item in &module.body {
+    let ModuleItem::ModuleDecl(decl) = item else {
+      continue;
+    };
+    match decl {
+      ModuleDecl::ExportNamed(named) => {
+        if named.type_only {
+          continue;
+        }
+        for specifier in &named.specifiers {
+          match specifier {
+            ExportSpecifier::Named(spec) => {
+              if spec.is_type_only {
+                continue;
+              }
+              let orig = export_name(&spec.orig);
+              let exported = spec
+                .exported
+                .as_ref()
+                .map_or_else(|| orig.clone(), export_name);
+              let export = if let Some(src) = &named.src {
+                Some(BarrelExport {
+                  source: request(&src.value),
+                  imported: barrel_import(orig),
+                })
+              } else {
+                imports.get(&orig).cloned()
+              };
+              match export {
+                Some(export) => {
+                  result.reexports.insert(exported, export);
+                }
+                None => {
+                  result.locals.insert(exported);
+                }
+              }
+            }
+            ExportSpecifier::Namespace(spec) => {
+              if let Some(src) = &named.src {
+                result.reexports.insert(
+                  export_name(&spec.name),
+                  BarrelExport {
+                    source: request(&src.value),
+                    imported: BarrelImport::Namespace,
+                  },
+                );
+              }
+            }
+            ExportSpecifier::Default(spec) => {
+              if let Some(src) = &named.src {
+                result.reexports.insert(
+                  spec.exported.sym.clone(),
+                  BarrelExport {
+                    source: request(&src.value),
+                    imported: BarrelImport::Default,
+                  },
+                );
+              }
+            }
+          }
+        }
+      }
+      ModuleDecl::ExportAll(all) => {
+        if !all.type_only {
+          result.star_sources.push(request(&all.src.value));
+        }
+      }
+      ModuleDecl::ExportDecl(export) => match &export.decl {
+        Decl::Class(class) => {
+          result.locals.insert(class.ident.sym.clone());
+        }
+        Decl::Fn(func) => {
+          result.locals.insert(func.ident.sym.clone());
+        }
+        Decl::Var(var) => {
+          for decl in &var.decls {
+            collect_pat_names(&decl.name, &mut result.locals);
+          }
+        }
+        Decl::Using(using) => {
+          for decl in &using.decls {
+            collect_pat_names(&decl.name, &mut result.locals);
+          }
+        }
+        Decl::TsEnum(ts_enum) => {
+          result.locals.insert(ts_enum.id.sym.clone());
+        }
+        Decl::TsModule(ts_module) => {
+          if let TsModuleName::Ident(ident) = &ts_module.id {
+            result.locals.insert(ident.sym.clone());
+          }
+        }
+        Decl::TsInterface(_) | Decl::TsTypeAlias(_) => {}
+      },
+      ModuleDecl::ExportDefaultDecl(_) | ModuleDecl::ExportDefaultExpr(_) => {
+        result.locals.insert("default".into());
+      }
+      _ => {}
+    }
+  }
+  result
+}
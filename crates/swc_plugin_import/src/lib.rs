@@ -1,10 +1,11 @@
 #![allow(clippy::unwrap_used)]
 
+mod barrel;
 mod legacy_case;
 mod template;
 mod visit;
 
-use std::fmt::Debug;
+use std::{fmt::Debug, sync::Arc};
 
 use cow_utils::CowUtils;
 use heck::{ToKebabCase, ToLowerCamelCase, ToSnakeCase};
@@ -16,13 +17,17 @@ use swc_core::{
   ecma::{
     ast::{
       Ident, ImportDecl, ImportDefaultSpecifier, ImportNamedSpecifier, ImportPhase,
-      ImportSpecifier, Module, ModuleDecl, ModuleExportName, ModuleItem, Str,
+      ImportSpecifier, ImportStarAsSpecifier, Module, ModuleDecl, ModuleExportName, ModuleItem,
+      Str,
     },
     atoms::Atom,
     visit::{VisitMut, VisitWith, visit_mut_pass},
   },
 };
 
+pub use crate::barrel::{
+  BarrelExport, BarrelExports, BarrelImport, ModuleReexports, collect_reexports,
+};
 use crate::{
   legacy_case::{identifier_to_legacy_kebab_case, identifier_to_legacy_snake_case},
   template::{Template, TemplateEngine},
@@ -66,6 +71,7 @@ pub struct RawImportOptions {
   pub transform_to_default_import: Option<bool>,
   pub ignore_es_component: Option<Vec<String>>,
   pub ignore_style_component: Option<Vec<String>>,
+  pub barrel: Option<bool>,
 }
 
 impl From<RawImportOptions> for ImportOptions {
@@ -80,6 +86,7 @@ impl From<RawImportOptions> for ImportOptions {
       transform_to_default_import,
       ignore_es_component,
       ignore_style_component,
+      barrel,
     } = plugin_import;
 
     Self {
@@ -92,6 +99,7 @@ impl From<RawImportOptions> for ImportOptions {
       transform_to_default_import,
       ignore_es_component,
       ignore_style_component,
+      barrel,
     }
   }
 }
@@ -146,6 +154,16 @@ pub struct ImportOptions {
 
   pub ignore_es_component: Option<Vec<String>>,
   pub ignore_style_component: Option<Vec<String>>,
+
+  /// Derive the import paths from the re-export table of the library's barrel file,
+  /// instead of `libraryDirectory` and `customName`.
+  pub barrel: Option<bool>,
+}
+
+impl ImportOptions {
+  pub fn is_barrel(&self) -> bool {
+    self.barrel.unwrap_or(false)
+  }
 }
 
 const CUSTOM_JS: &str = "CUSTOM_JS_NAME";
@@ -157,6 +175,17 @@ const CUSTOM_STYLE_NAME: &str = "CUSTOM_STYLE_NAME";
 /// Panics in sometimes if [swc_core::common::errors::HANDLER] is not provided.
 pub fn plugin_import(
   config: &Vec<ImportOptions>,
+) -> swc_core::ecma::visit::VisitMutPass<ImportPlugin<'_>> {
+  plugin_import_with_barrels(config, HashMap::default())
+}
+
+/// Same as [plugin_import], with the re-export tables of the libraries that enable `barrel`,
+/// keyed by the library name.
+///
+/// Imports of a barrel library without a table are kept as is.
+pub fn plugin_import_with_barrels(
+  config: &Vec<ImportOptions>,
+  barrels: HashMap<String, Arc<BarrelExports>>,
 ) -> swc_core::ecma::visit::VisitMutPass<ImportPlugin<'_>> {
   let mut renderer = TemplateEngine::new();
 
@@ -229,7 +258,11 @@ pub fn plugin_import(
     }
   });
 
-  visit_mut_pass(ImportPlugin { config, renderer })
+  visit_mut_pass(ImportPlugin {
+    config,
+    renderer,
+    barrels,
+  })
 }
 
 #[derive(Debug)]
@@ -244,6 +277,7 @@ struct EsSpec {
 pub struct ImportPlugin<'a> {
   pub config: &'a Vec<ImportOptions>,
   pub renderer: TemplateEngine<'a>,
+  pub barrels: HashMap<String, Arc<BarrelExports>>,
 }
 
 impl ImportPlugin<'_> {
@@ -391,6 +425,7 @@ impl VisitMut for ImportPlugin<'_> {
 
     let mut specifiers_css = vec![];
     let mut specifiers_es = vec![];
+    let mut specifiers_barrel = vec![];
     let mut specifiers_rm_es = HashSet::default();
 
     let config = &self.config;
@@ -403,6 +438,15 @@ impl VisitMut for ImportPlugin<'_> {
           .iter()
           .find(|&c| c.library_name == source.to_string_lossy())
         {
+          let barrel = if child_config.is_barrel() {
+            match self.barrels.get(&child_config.library_name) {
+              Some(barrel) => Some(barrel.clone()),
+              // the re-export table is unavailable, keep the import as is
+              None => continue,
+            }
+          } else {
+            None
+          };
           let mut rm_specifier = HashSet::default();
 
           for (specifier_idx, specifier) in var.specifiers.iter().enumerate() {
@@ -418,7 +462,19 @@ impl VisitMut for ImportPlugin<'_> {
 
                 let mark = s.local.ctxt.as_u32();
 
-                if ident_referenced(&s.local) {
+                if let Some(barrel) = &barrel {
+                  if !ident_referenced(&s.local) && !type_ident_referenced(&s.local) {
+                    rm_specifier.insert(specifier_idx);
+                  } else if ident_referenced(&s.local)
+                    && let Some(export) = barrel.get(&Atom::from(ident.as_str()))
+                  {
+                    specifiers_barrel.push((
+                      export.source.clone(),
+                      barrel_specifier(&export.imported, s.local.clone()),
+                    ));
+                    rm_specifier.insert(specifier_idx);
+                  }
+                } else if ident_referenced(&s.local) {
                   let use_default_import = child_config.transform_to_default_import.unwrap_or(true);
 
                   let (import_es_source, import_css_source) =
@@ -524,6 +580,22 @@ impl VisitMut for ImportPlugin<'_> {
       body.insert(0, dec);
     }
 
+    for (source, specifier) in specifiers_barrel {
+      let dec = ModuleItem::ModuleDecl(ModuleDecl::Import(ImportDecl {
+        span: DUMMY_SP,
+        specifiers: vec![specifier],
+        src: Box::new(Str {
+          span: DUMMY_SP,
+          value: Wtf8Atom::from(source),
+          raw: None,
+        }),
+        type_only: false,
+        with: Default::default(),
+        phase: ImportPhase::default(),
+      }));
+      body.insert(0, dec);
+    }
+
     for css_source in specifiers_css {
       let dec = ModuleItem::ModuleDecl(ModuleDecl::Import(ImportDecl {
         span: DUMMY_SP,
@@ -542,6 +614,32 @@ impl VisitMut for ImportPlugin<'_> {
   }
 }
 
+fn barrel_specifier(imported: &BarrelImport, local: Ident) -> ImportSpecifier {
+  match imported {
+    BarrelImport::Default => ImportSpecifier::Default(ImportDefaultSpecifier {
+      span: DUMMY_SP,
+      local,
+    }),
+    BarrelImport::Named(name) => ImportSpecifier::Named(ImportNamedSpecifier {
+      span: DUMMY_SP,
+      imported: (name != &local.sym).then(|| {
+        ModuleExportName::Ident(Ident {
+          span: DUMMY_SP,
+          ctxt: Default::default(),
+          sym: name.clone(),
+          optional: false,
+        })
+      }),
+      local,
+      is_type_only: false,
+    }),
+    BarrelImport::Namespace => ImportSpecifier::Namespace(ImportStarAsSpecifier {
+      span: DUMMY_SP,
+      local,
+    }),
+  }
+}
+
 fn render_context(s: String) -> HashMap<&'static str, String> {
   let mut ctx = HashMap::default();
   ctx.insert("member", s);
@@ -1,6 +1,7 @@
 /**
  * `RSPACK_PROFILE=ALL` // all trace events
  * `RSPACK_PROFILE=OVERVIEW` // overview trace events
+ * `RSPACK_PROFILE=DETAIL` // overview trace events and spans of each module build, loader, chunk render and asset emission
  * `RSPACK_PROFILE=warn,tokio::net=info` // trace filter from  https://docs.rs/tracing-subscriber/latest/tracing_subscriber/filter/struct.EnvFilter.html#example-syntax
  */
 import fs from "node:fs";
@@ -16,7 +17,11 @@ export async function applyProfile(
 ) {
 	const { asyncExitHook } = await import("exit-hook");
 
-	if (traceLayer !== "logger" && traceLayer !== "perfetto") {
+	if (
+		traceLayer !== "logger" &&
+		traceLayer !== "perfetto" &&
+		traceLayer !== "chrome"
+	) {
 		throw new Error(`unsupported trace layer: ${traceLayer}`);
 	}
 	const timestamp = Date.now();
@@ -28,12 +33,18 @@ export async function applyProfile(
 			defaultOutputDir,
 			"rspack.pftrace"
 		);
+		const defaultRustTraceChromeOutput = path.resolve(
+			defaultOutputDir,
+			"trace.json"
+		);
 		const defaultRustTraceLoggerOutput = "stdout";
 
 		const defaultTraceOutput =
 			traceLayer === "perfetto"
 				? defaultRustTracePerfettoOutput
-				: defaultRustTraceLoggerOutput;
+				: traceLayer === "chrome"
+					? defaultRustTraceChromeOutput
+					: defaultRustTraceLoggerOutput;
 
 		traceOutput = defaultTraceOutput;
 	} else if (traceOutput !== "stdout" && traceOutput !== "stderr") {
@@ -108,4 +108,35 @@ describe("profile", () => {
 		expect(exitCode).toBe(0);
 		expect(stdout.includes("rspack_core::compiler")).toBe(true);
 	});
+
+	it("should write chrome trace with detail spans when RSPACK_TRACE_LAYER=chrome", async () => {
+		const { exitCode } = await run(
+			__dirname,
+			[],
+			{},
+			{
+				RSPACK_PROFILE: "DETAIL",
+				RSPACK_TRACE_LAYER: "chrome"
+			}
+		);
+		expect(exitCode).toBe(0);
+		const dirname = findDefaultOutputDirname();
+		const tracePath = resolve(dirname, "./trace.json");
+		expect(fs.existsSync(tracePath)).toBeTruthy();
+		const { traceEvents } = JSON.parse(fs.readFileSync(tracePath, "utf-8"));
+		const moduleBuild = traceEvents.find(
+			(event: any) => event.name === "module:build"
+		);
+		expect(moduleBuild.ph).toBe("X");
+		expect(moduleBuild.args.module).toContain("index.js");
+		expect(
+			traceEvents.some((event: any) => event.name === "chunk:render")
+		).toBe(true);
+		expect(
+			traceEvents.some(
+				(event: any) =>
+					event.name === "asset:emit" && event.args.asset === "main.js"
+			)
+		).toBe(true);
+	});
 });
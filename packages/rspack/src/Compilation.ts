@@ -758,6 +758,14 @@ BREAKING CHANGE: Asset processing hooks in Compilation has been merged into a si
 		return this.#inner.getAssetPathWithInfo(filename, pathData);
 	}
 
+	/**
+	 * Get the estimated bytes retained by this compilation, broken down by artifact,
+	 * by module type and by memory cache generation.
+	 */
+	getMemoryReport(): binding.JsMemoryReport {
+		return this.#inner.getMemoryReport();
+	}
+
 	getLogger(name: string | (() => string)) {
 		if (!name) {
 			throw new TypeError("Compilation.getLogger(name) called without a name");
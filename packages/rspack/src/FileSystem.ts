@@ -223,6 +223,10 @@ class ThreadsafeOutputNodeFS implements ThreadsafeNodeFS {
 			};
 		});
 		this.chmod = memoizeFn(() => util.promisify(fs.chmod.bind(fs)));
+		if (fs.rename) {
+			const rename = fs.rename.bind(fs);
+			this.rename = memoizeFn(() => util.promisify(rename));
+		}
 	}
 
 	static __to_binding(fs?: OutputFileSystem) {
@@ -8,7 +8,10 @@
  * https://github.com/webpack/webpack/blob/main/LICENSE
  */
 
-import type { KnownCreateStatsOptionsContext } from "./Compilation";
+import type {
+	KnownCreateStatsOptionsContext,
+	NormalizedStatsOptions
+} from "./Compilation";
 import type { MultiStatsOptions, StatsPresets } from "./config";
 import type { Stats } from "./Stats";
 import type { StatsCompilation } from "./stats/statsFactoryUtils";
@@ -142,6 +145,11 @@ export default class MultiStats {
 			forToString: true
 		});
 
+		const report = this.#diagnosticsReport(childOptions.children);
+		if (report !== undefined) {
+			return report;
+		}
+
 		const results = this.stats.map((stat, idx) => {
 			const str = stat.toString(childOptions.children[idx]);
 			const compilationName = stat.compilation.name;
@@ -159,6 +167,56 @@ export default class MultiStats {
 		});
 		return results.filter(Boolean).join("\n\n");
 	}
+
+	/**
+	 * Merges the machine readable diagnostics reports of the children into one report,
+	 * as concatenated reports are neither valid JSON nor valid SARIF.
+	 */
+	#diagnosticsReport(children: NormalizedStatsOptions[]): string | undefined {
+		const formats = new Set(children.map(o => o.diagnosticsFormat ?? "text"));
+		if (!formats.has("json") && !formats.has("sarif")) {
+			return undefined;
+		}
+		if (formats.size > 1) {
+			const got = [...formats].map(format => `"${format}"`).join(", ");
+			throw new Error(
+				`All compilers must use the same "stats.diagnosticsFormat" to output a merged report, but got ${got}.`
+			);
+		}
+
+		const reports = this.stats.map((stat, idx) => ({
+			name: stat.compilation.name,
+			report: JSON.parse(stat.toString(children[idx]))
+		}));
+		if (formats.has("json")) {
+			return JSON.stringify(
+				reports.flatMap(({ name, report }) =>
+					name
+						? report.map((diagnostic: object) => ({
+								...diagnostic,
+								compilerPath: name
+							}))
+						: report
+				),
+				null,
+				2
+			);
+		}
+		// a SARIF log has a run per compiler, identified by the compiler name
+		const [{ report: log }] = reports;
+		return JSON.stringify(
+			{
+				...log,
+				runs: reports.flatMap(({ name, report }) =>
+					report.runs.map((run: object) =>
+						name ? { ...run, automationDetails: { id: name } } : run
+					)
+				)
+			},
+			null,
+			2
+		);
+	}
 }
 
 export { MultiStats };
@@ -115,6 +115,12 @@ export class Stats {
 		const options = this.compilation.createStatsOptions(opts, {
 			forToString: true
 		});
+		if (
+			options.diagnosticsFormat === "json" ||
+			options.diagnosticsFormat === "sarif"
+		) {
+			return this.#inner.diagnosticsReport(options.diagnosticsFormat);
+		}
 		const statsFactory = this.compilation.createStatsFactory(options);
 
 		const statsPrinter = this.compilation.createStatsPrinter(options);
@@ -0,0 +1,13 @@
+export type CollectI18nMessagesOptions = {
+	/**
+	 * The names of the functions whose first argument is a message id, both
+	 * `t("id")` and member calls like `i18n.t("id")` are collected.
+	 * @default ["t"]
+	 */
+	functions?: string[];
+	/**
+	 * The names of the JSX components whose `id` attribute is a message id.
+	 * @default ["FormattedMessage"]
+	 */
+	components?: string[];
+};
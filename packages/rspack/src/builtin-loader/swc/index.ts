@@ -1,3 +1,4 @@
+export type { CollectI18nMessagesOptions } from "./collectI18nMessages";
 export type { CollectTypeScriptInfoOptions } from "./collectTypeScriptInfo";
 export { resolveCollectTypeScriptInfo } from "./collectTypeScriptInfo";
 export type { PluginImportOptions } from "./pluginImport";
@@ -15,6 +15,7 @@ type RawPluginImportConfig = {
 	transformToDefaultImport?: boolean;
 	ignoreEsComponent?: string[];
 	ignoreStyleComponent?: string[];
+	barrel?: boolean;
 };
 
 type PluginImportConfig = {
@@ -28,6 +29,7 @@ type PluginImportConfig = {
 	transformToDefaultImport?: boolean;
 	ignoreEsComponent?: string[];
 	ignoreStyleComponent?: string[];
+	barrel?: boolean;
 };
 
 type PluginImportOptions = PluginImportConfig[];
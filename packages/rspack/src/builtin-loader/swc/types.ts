@@ -9,6 +9,7 @@ import type {
 	TransformConfig,
 	TsParserConfig
 } from "@swc/types";
+import type { CollectI18nMessagesOptions } from "./collectI18nMessages";
 import type { CollectTypeScriptInfoOptions } from "./collectTypeScriptInfo";
 import type { PluginImportOptions } from "./pluginImport";
 export type SwcLoaderEnvConfig = EnvConfig;
@@ -31,6 +32,11 @@ export type SwcLoaderOptions = Config & {
 		 * providing better TypeScript development experience and smaller output bundle size.
 		 */
 		collectTypeScriptInfo?: CollectTypeScriptInfoOptions;
+		/**
+		 * Collects the static message ids used by i18n functions and components,
+		 * so `I18nRspackPlugin` can emit only the messages used by each chunk.
+		 */
+		collectI18nMessages?: CollectI18nMessagesOptions;
 	};
 };
 
@@ -0,0 +1,59 @@
+import {
+	BuiltinPluginName,
+	type RawCompressionAlgorithmOptions,
+	type RawCompressionRspackPluginOptions
+} from "@rspack/binding";
+
+import type { AssetConditions } from "../util/assetCondition";
+import { create } from "./base";
+
+export type CompressionAlgorithm = "gzip" | "brotli" | "zstd";
+
+export type CompressionRspackPluginOptions = {
+	/**
+	 * The algorithms used to compress the assets, each of them emits a sibling
+	 * asset with the `.gz`, `.br` or `.zst` extension.
+	 * The level defaults to 9 for gzip, 11 for brotli and 3 for zstd.
+	 * @default ["gzip"]
+	 */
+	algorithms?: (
+		| CompressionAlgorithm
+		| { algorithm: CompressionAlgorithm; level?: number }
+	)[];
+	test?: AssetConditions;
+	include?: AssetConditions;
+	exclude?: AssetConditions;
+	/**
+	 * Only assets bigger than this size in bytes are compressed.
+	 * @default 0
+	 */
+	threshold?: number;
+	/**
+	 * Only the compressed assets whose ratio to the original size is below this
+	 * value are emitted.
+	 * @default 0.8
+	 */
+	minRatio?: number;
+};
+
+export const CompressionRspackPlugin = create(
+	BuiltinPluginName.CompressionRspackPlugin,
+	(
+		options: CompressionRspackPluginOptions = {}
+	): RawCompressionRspackPluginOptions => {
+		const algorithms = (options.algorithms ?? ["gzip"]).map(
+			(algorithm): RawCompressionAlgorithmOptions =>
+				typeof algorithm === "string" ? { algorithm } : algorithm
+		);
+
+		return {
+			algorithms,
+			test: options.test,
+			include: options.include,
+			exclude: options.exclude,
+			threshold: options.threshold ?? 0,
+			minRatio: options.minRatio ?? 0.8
+		};
+	},
+	"compilation"
+);
@@ -1,9 +1,22 @@
-import { BuiltinPluginName } from "@rspack/binding";
+import {
+	BuiltinPluginName,
+	type RawCssModulesPluginOptions
+} from "@rspack/binding";
 
 import { create } from "./base";
 
+export type CssModulesPluginOptions = {
+	/**
+	 * How to report conflicting orders of CSS modules between chunks,
+	 * "error" fails the build and reports the full path of each conflicting module.
+	 * @default "warning"
+	 */
+	orderConflicts?: "warning" | "error";
+};
+
 export const CssModulesPlugin = create(
 	BuiltinPluginName.CssModulesPlugin,
-	() => {},
+	(options: CssModulesPluginOptions = {}): RawCssModulesPluginOptions =>
+		options,
 	"compilation"
 );
@@ -5,22 +5,67 @@ import type { RspackOptionsNormalized } from "../config";
 import type { Logger } from "../logging/Logger";
 import { RemoveDuplicateModulesPlugin } from "./RemoveDuplicateModulesPlugin";
 
-function applyLimits(options: RspackOptionsNormalized, logger: Logger) {
+type LibraryFormat = "esm" | "commonjs";
+
+// library types that the output files can be rendered as
+const LIBRARY_TYPE_FORMATS: Record<string, LibraryFormat> = {
+	module: "esm",
+	"modern-module": "esm",
+	commonjs: "commonjs",
+	commonjs2: "commonjs",
+	"commonjs-module": "commonjs",
+	"commonjs-static": "commonjs"
+};
+
+function resolveFormat(
+	options: RspackOptionsNormalized,
+	format: LibraryFormat | undefined
+): LibraryFormat {
+	const libraryType = options.output.library?.type;
+	if (libraryType === undefined) {
+		return format ?? "esm";
+	}
+	const libraryFormat = LIBRARY_TYPE_FORMATS[libraryType];
+	if (!libraryFormat) {
+		const supported = Object.keys(LIBRARY_TYPE_FORMATS)
+			.map(type => `\`"${type}"\``)
+			.join(", ");
+		throw new rspack.WebpackError(
+			`Conflicted config for ${EsmLibraryPlugin.PLUGIN_NAME}: \`output.library.type\` \`"${libraryType}"\` is not supported, expected one of ${supported}`
+		);
+	}
+	if (format && format !== libraryFormat) {
+		throw new rspack.WebpackError(
+			`Conflicted config for ${EsmLibraryPlugin.PLUGIN_NAME}: \`format\` is \`"${format}"\`, but \`output.library.type\` is \`"${libraryType}"\``
+		);
+	}
+	return libraryFormat;
+}
+
+function applyLimits(
+	options: RspackOptionsNormalized,
+	logger: Logger,
+	format: LibraryFormat
+) {
 	// concatenateModules is not supported in ESM library mode, it has its own scope hoist algorithm
 	options.optimization.concatenateModules = false;
 
 	// chunk rendering is handled by EsmLibraryPlugin
 	options.output.chunkFormat = false;
 
-	if (options.output.chunkLoading && options.output.chunkLoading !== "import") {
+	const chunkLoading = format === "commonjs" ? "require" : "import";
+	if (
+		options.output.chunkLoading &&
+		options.output.chunkLoading !== chunkLoading
+	) {
 		logger.warn(
-			`\`output.chunkLoading\` should be \`"import"\` or \`false\`, but got ${options.output.chunkLoading}, changed it to \`"import"\``
+			`\`output.chunkLoading\` should be \`"${chunkLoading}"\` or \`false\`, but got ${options.output.chunkLoading}, changed it to \`"${chunkLoading}"\``
 		);
-		options.output.chunkLoading = "import";
+		options.output.chunkLoading = chunkLoading;
 	}
 
 	if (options.output.chunkLoading === undefined) {
-		options.output.chunkLoading = "import";
+		options.output.chunkLoading = chunkLoading;
 	}
 
 	if (options.output.library) {
@@ -45,9 +90,12 @@ function applyLimits(options: RspackOptionsNormalized, logger: Logger) {
 
 export class EsmLibraryPlugin {
 	static PLUGIN_NAME = "EsmLibraryPlugin";
-	options?: { preserveModules?: string };
+	options?: { preserveModules?: string; format?: "esm" | "commonjs" };
 
-	constructor(options?: { preserveModules?: string }) {
+	constructor(options?: {
+		preserveModules?: string;
+		format?: "esm" | "commonjs";
+	}) {
 		this.options = options;
 	}
 
@@ -55,11 +103,12 @@ export class EsmLibraryPlugin {
 		const logger = compiler.getInfrastructureLogger(
 			EsmLibraryPlugin.PLUGIN_NAME
 		);
-		applyLimits(compiler.options, logger);
+		const format = resolveFormat(compiler.options, this.options?.format);
+		applyLimits(compiler.options, logger, format);
 		new RemoveDuplicateModulesPlugin().apply(compiler);
 
 		let err;
-		if ((err = checkConfig(compiler.options))) {
+		if ((err = checkConfig(compiler.options, format))) {
 			throw new rspack.WebpackError(
 				`Conflicted config for ${EsmLibraryPlugin.PLUGIN_NAME}: ${err}`
 			);
@@ -68,13 +117,17 @@ export class EsmLibraryPlugin {
 		compiler.__internal__registerBuiltinPlugin({
 			name: BuiltinPluginName.EsmLibraryPlugin,
 			options: {
-				preserveModules: this.options?.preserveModules
+				preserveModules: this.options?.preserveModules,
+				format
 			}
 		});
 	}
 }
 
-function checkConfig(config: RspackOptionsNormalized): string | undefined {
+function checkConfig(
+	config: RspackOptionsNormalized,
+	format: LibraryFormat
+): string | undefined {
 	if (config.optimization.concatenateModules) {
 		return "You should disable `config.optimization.concatenateModules`";
 	}
@@ -82,4 +135,8 @@ function checkConfig(config: RspackOptionsNormalized): string | undefined {
 	if (config.output.chunkFormat !== false) {
 		return "You should disable default chunkFormat by `config.output.chunkFormat = false`";
 	}
+
+	if (format === "commonjs" && config.output.module) {
+		return "You should disable `config.output.module` for the commonjs format";
+	}
 }
@@ -0,0 +1,54 @@
+import {
+	type BuiltinPlugin,
+	BuiltinPluginName,
+	type RawI18nRspackPluginOptions
+} from "@rspack/binding";
+import { createBuiltinPlugin, RspackBuiltinPlugin } from "./base";
+
+export type I18nRspackPluginOptions = {
+	/**
+	 * The message catalogs keyed by locale. A catalog is either an object of
+	 * message ids to messages, or the path of a JSON file containing one,
+	 * resolved from the `context` of the compiler.
+	 */
+	locales: Record<string, string | Record<string, string>>;
+	/**
+	 * The filename of the messages assets, `[locale]` is replaced with the
+	 * locale, `[name]`, `[id]` and `[contenthash]` are supported as well.
+	 * @default "[name].[locale].messages.js"
+	 */
+	filename?: string;
+	/**
+	 * The expression evaluated at runtime to get the locale of the messages to
+	 * load with async chunks.
+	 * @default "document.documentElement.lang"
+	 */
+	localeExpression?: string;
+	/**
+	 * The property of the global object which the messages are registered to.
+	 * @default "__RSPACK_I18N_MESSAGES__"
+	 */
+	globalName?: string;
+};
+
+export class I18nRspackPlugin extends RspackBuiltinPlugin {
+	name = BuiltinPluginName.I18nRspackPlugin;
+	_options: I18nRspackPluginOptions;
+
+	constructor(options: I18nRspackPluginOptions) {
+		super();
+		this._options = options;
+	}
+
+	raw(): BuiltinPlugin {
+		const rawOptions: RawI18nRspackPluginOptions = {
+			locales: this._options.locales,
+			filename: this._options.filename ?? "[name].[locale].messages.js",
+			localeExpression:
+				this._options.localeExpression ?? "document.documentElement.lang",
+			globalName: this._options.globalName ?? "__RSPACK_I18N_MESSAGES__"
+		};
+
+		return createBuiltinPlugin(this.name, rawOptions);
+	}
+}
@@ -0,0 +1,66 @@
+import {
+	BuiltinPluginName,
+	type RawImportMapRspackPluginOptions
+} from "@rspack/binding";
+
+import { create } from "./base";
+
+export type ImportMapRspackPluginOptions = {
+	/**
+	 * The filename of the emitted import map, set to `false` to not emit it.
+	 * @default "importmap.json"
+	 */
+	filename?: string | false;
+	/**
+	 * The entries added to the import map as-is, they take precedence over the
+	 * generated entries of chunks and externals.
+	 */
+	imports?: Record<string, string>;
+	/**
+	 * The URL of `module` and `import` externals, `[request]` is replaced by the
+	 * request of the external, e.g. `"https://esm.sh/[request]"`.
+	 */
+	externalUrl?: string;
+	/**
+	 * Whether to map the JavaScript file of each chunk by the chunk name, or the
+	 * chunk id if the chunk has no name.
+	 * @default true
+	 */
+	chunks?: boolean;
+	/**
+	 * The prefix of the specifiers of chunks. The default prefix can't start a package name,
+	 * so the specifiers of chunks never shadow packages.
+	 * @default "_chunks/"
+	 */
+	chunkPrefix?: string;
+	/**
+	 * The public path of the URLs of chunks, defaults to `output.publicPath`.
+	 */
+	publicPath?: string;
+	/**
+	 * Whether to inline the import map into the HTML files generated by `HtmlRspackPlugin`.
+	 * @default false
+	 */
+	inject?: boolean;
+};
+
+export const ImportMapRspackPlugin = create(
+	BuiltinPluginName.ImportMapRspackPlugin,
+	(
+		options: ImportMapRspackPluginOptions = {}
+	): RawImportMapRspackPluginOptions => {
+		return {
+			filename:
+				options.filename === false
+					? undefined
+					: (options.filename ?? "importmap.json"),
+			imports: options.imports ?? {},
+			externalUrl: options.externalUrl,
+			chunks: options.chunks ?? true,
+			chunkPrefix: options.chunkPrefix ?? "_chunks/",
+			publicPath: options.publicPath,
+			inject: options.inject ?? false
+		};
+	},
+	"compilation"
+);
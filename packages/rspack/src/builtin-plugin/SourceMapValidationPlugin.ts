@@ -0,0 +1,30 @@
+import {
+	BuiltinPluginName,
+	type RawSourceMapValidationPluginOptions
+} from "@rspack/binding";
+
+import type { AssetConditions } from "../util/assetCondition";
+import { create } from "./base";
+
+export type SourceMapValidationPluginOptions = {
+	/**
+	 * Only validate the source maps of the assets matching these conditions.
+	 */
+	test?: AssetConditions;
+	/**
+	 * Emit the chain of source maps of the modules whose readable identifier
+	 * matches these conditions as `source-map-chain/[name].json` assets.
+	 */
+	dumpModules?: AssetConditions;
+};
+
+export const SourceMapValidationPlugin = create(
+	BuiltinPluginName.SourceMapValidationPlugin,
+	(
+		options: SourceMapValidationPluginOptions = {}
+	): RawSourceMapValidationPluginOptions => ({
+		test: options.test,
+		dumpModules: options.dumpModules
+	}),
+	"compilation"
+);
@@ -8,6 +8,7 @@ export { createNativePlugin, RspackBuiltinPlugin } from "./base";
 export * from "./ChunkPrefetchPreloadPlugin";
 export * from "./CircularDependencyRspackPlugin";
 export * from "./CommonJsChunkFormatPlugin";
+export * from "./CompressionRspackPlugin";
 export * from "./ContextReplacementPlugin";
 export * from "./CopyRspackPlugin";
 export * from "./CssChunkingPlugin";
@@ -38,7 +39,9 @@ export * from "./HotModuleReplacementPlugin";
 export * from "./HttpExternalsRspackPlugin";
 export * from "./HttpUriPlugin";
 export * from "./html-plugin/index";
+export * from "./I18nRspackPlugin";
 export * from "./IgnorePlugin";
+export * from "./ImportMapRspackPlugin";
 export * from "./InferAsyncModulesPlugin";
 export * from "./InlineExportsPlugin";
 export * from "./JavascriptModulesPlugin";
@@ -62,6 +65,7 @@ export * from "./NodeTargetPlugin";
 export * from "./NoEmitOnErrorsPlugin";
 export * from "./NormalModuleReplacementPlugin";
 export * from "./OccurrenceChunkIdsPlugin";
+export * from "./PackageJsonExternalsRspackPlugin";
 export * from "./ProgressPlugin";
 export * from "./ProvidePlugin";
 export * from "./RealContentHashPlugin";
@@ -75,6 +79,7 @@ export * from "./RuntimePlugin";
 export * from "./SideEffectsFlagPlugin";
 export * from "./SizeLimitsPlugin";
 export * from "./SourceMapDevToolPlugin";
+export * from "./SourceMapValidationPlugin";
 export * from "./SplitChunksPlugin";
 export * from "./SubresourceIntegrityPlugin";
 export * from "./SwcJsMinimizerPlugin";
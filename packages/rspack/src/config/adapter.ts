@@ -546,6 +546,24 @@ function getRawParserOptions(
 		};
 	}
 
+	if (type === "yaml") {
+		return {
+			type: "yaml",
+			yaml: {
+				exportsDepth: parser.exportsDepth
+			}
+		};
+	}
+
+	if (type === "toml") {
+		return {
+			type: "toml",
+			toml: {
+				exportsDepth: parser.exportsDepth
+			}
+		};
+	}
+
 	// FIXME: shouldn't depend on module type, for example: `rules: [{ test: /\.css/, generator: {..} }]` will error
 	throw new Error(`unreachable: unknown module type: ${type}`);
 }
@@ -616,8 +634,14 @@ function getRawAssetParserDataUrl(
 			}
 		};
 	}
+	if (typeof dataUrlCondition === "function") {
+		return {
+			type: "function",
+			function: (source, context) => dataUrlCondition(source, context)
+		};
+	}
 	throw new Error(
-		`unreachable: AssetParserDataUrl type should be one of "options", but got ${dataUrlCondition}`
+		`unreachable: AssetParserDataUrl type should be one of "options", "function", but got ${dataUrlCondition}`
 	);
 }
 
@@ -737,7 +761,8 @@ function getRawAssetResourceGeneratorOptions(
 		outputPath: options.outputPath,
 		publicPath: options.publicPath,
 		importMode: options.importMode,
-		binary: options.binary
+		binary: options.binary,
+		imageOptimization: options.imageOptimization
 	};
 }
 
@@ -775,7 +800,8 @@ function getRawCssAutoOrModuleGeneratorOptions(
 		localIdentName: options.localIdentName,
 		exportsConvention: options.exportsConvention,
 		exportsOnly: options.exportsOnly,
-		esModule: options.esModule
+		esModule: options.esModule,
+		emitDts: options.emitDts
 	};
 }
 
@@ -808,6 +834,7 @@ function getRawNode(node: Node): RawOptions["node"] {
 function getRawStats(stats: StatsValue): RawOptions["stats"] {
 	const statsOptions = normalizeStatsPreset(stats);
 	return {
-		colors: Boolean(statsOptions.colors)
+		colors: Boolean(statsOptions.colors),
+		diagnosticsFormat: statsOptions.diagnosticsFormat
 	};
 }
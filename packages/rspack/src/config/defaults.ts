@@ -391,6 +391,22 @@ const applyModuleDefaults = (
 		mode === "development" ? 1 : Number.MAX_SAFE_INTEGER
 	);
 
+	F(module.parser, "yaml", () => ({}));
+	assertNotNill(module.parser.yaml);
+	D(
+		module.parser.yaml,
+		"exportsDepth",
+		mode === "development" ? 1 : Number.MAX_SAFE_INTEGER
+	);
+
+	F(module.parser, "toml", () => ({}));
+	assertNotNill(module.parser.toml);
+	D(
+		module.parser.toml,
+		"exportsDepth",
+		mode === "development" ? 1 : Number.MAX_SAFE_INTEGER
+	);
+
 	F(module.generator, "json", () => ({}));
 	assertNotNill(module.generator.json);
 	applyJsonGeneratorOptionsDefaults(module.generator.json);
@@ -724,6 +740,7 @@ const applyOutputDefaults = (
 	D(output, "assetModuleFilename", "[hash][ext][query]");
 	D(output, "webassemblyModuleFilename", "[hash].module.wasm");
 	D(output, "compareBeforeEmit", true);
+	D(output, "atomicEmit", false);
 	F(output, "path", () => path.join(process.cwd(), "dist"));
 	// IGNORE(output.pathinfo): Rspack disabled pathinfo by default
 	F(output, "pathinfo", () => false);
@@ -235,7 +235,8 @@ export const getNormalizedRspackOptions = (
 				chunkLoadTimeout: output.chunkLoadTimeout,
 				charset: output.charset,
 				environment: cloneObject(output.environment),
-				compareBeforeEmit: output.compareBeforeEmit
+				compareBeforeEmit: output.compareBeforeEmit,
+				atomicEmit: output.atomicEmit
 			};
 		}),
 		resolve: nestedConfig(config.resolve, resolve => ({
@@ -617,6 +618,7 @@ export interface OutputNormalized {
 	charset?: boolean;
 	chunkLoadTimeout?: number;
 	compareBeforeEmit?: boolean;
+	atomicEmit?: boolean;
 }
 
 export interface ModuleOptionsNormalized {
@@ -660,6 +662,7 @@ export interface ExperimentsNormalized {
 	outputModule?: boolean;
 	topLevelAwait?: boolean;
 	css?: boolean;
+	cssOrderConflicts?: "warning" | "error";
 	/**
 	 * @deprecated This option is deprecated, layers is enabled since v1.6.0
 	 */
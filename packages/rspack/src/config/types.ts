@@ -290,7 +290,16 @@ export type EnabledLibraryTypes = string[];
 /** Whether delete all files in the output directory. */
 export type Clean =
 	| boolean
-	| { keep?: string | RegExp | ((path: string) => boolean) };
+	| {
+			keep?: string | RegExp | ((path: string) => boolean);
+			/**
+			 * Record the content hashes of emitted files in a manifest in the output directory,
+			 * unchanged files are not written again and the files no longer emitted are removed.
+			 * If a string, it's used as the name of the manifest.
+			 * @default false
+			 */
+			manifest?: boolean | string;
+	  };
 
 /** Output JavaScript files as module type. */
 export type OutputModule = boolean;
@@ -706,6 +715,13 @@ export type Output = {
 	 * Check if to be emitted file already exists and have the same content before writing to output filesystem.
 	 */
 	compareBeforeEmit?: boolean;
+
+	/**
+	 * Emit assets into a staging directory next to the output directory, and replace the output directory
+	 * with it after all assets are emitted, so the output directory never contains a partial build.
+	 * @default false
+	 */
+	atomicEmit?: boolean;
 };
 
 //#endregion
@@ -1003,9 +1019,27 @@ export type AssetParserDataUrlOptions = {
 };
 
 /**
- * Options object for DataUrl condition.
+ * Function for DataUrl condition, returns `true` to inline the asset.
+ * The module is still building when the function is called, so only its identifier, type and layer are provided.
+ * */
+export type AssetParserDataUrlFunction = (
+	source: Buffer,
+	context: {
+		filename: string;
+		module: {
+			identifier: string;
+			type: string;
+			layer?: string;
+		};
+	}
+) => boolean;
+
+/**
+ * Options object or function for DataUrl condition.
  * */
-export type AssetParserDataUrl = AssetParserDataUrlOptions;
+export type AssetParserDataUrl =
+	| AssetParserDataUrlOptions
+	| AssetParserDataUrlFunction;
 
 /** Options object for `asset` modules. */
 export type AssetParserOptions = {
@@ -1013,7 +1047,7 @@ export type AssetParserOptions = {
 	 * It be used only for Asset Module scenarios.
 	 * @default { maxSize: 8096 }
 	 * */
-	dataUrlCondition?: AssetParserDataUrlOptions;
+	dataUrlCondition?: AssetParserDataUrl;
 };
 
 export type CssParserNamedExports = boolean;
@@ -1203,6 +1237,20 @@ export type JsonParserOptions = {
 	parse?: (source: string) => any;
 };
 
+export type YamlParserOptions = {
+	/**
+	 * The depth of yaml dependency flagged as `exportInfo`.
+	 */
+	exportsDepth?: number;
+};
+
+export type TomlParserOptions = {
+	/**
+	 * The depth of toml dependency flagged as `exportInfo`.
+	 */
+	exportsDepth?: number;
+};
+
 /** Configure all parsers' options in one place with module.parser. */
 export type ParserOptionsByModuleTypeKnown = {
 	/** Parser options for `asset` modules. */
@@ -1231,6 +1279,12 @@ export type ParserOptionsByModuleTypeKnown = {
 
 	/** Parser options for `json` modules. */
 	json?: JsonParserOptions;
+
+	/** Parser options for `yaml` modules. */
+	yaml?: YamlParserOptions;
+
+	/** Parser options for `toml` modules. */
+	toml?: TomlParserOptions;
 };
 
 /** Configure all parsers' options in one place with module.parser. */
@@ -1244,7 +1298,12 @@ export type ParserOptionsByModuleType =
 	| ParserOptionsByModuleTypeUnknown;
 
 export type AssetGeneratorDataUrlOptions = {
-	encoding?: false | "base64";
+	/**
+	 * - `"base64"`: encode the content with base64.
+	 * - `"mini-svg"`: only escape the characters required by data URLs, which is usually smaller than base64 for SVG images.
+	 * - `false`: encode the content with URL encoding.
+	 */
+	encoding?: false | "base64" | "mini-svg";
 	mimetype?: string;
 };
 
@@ -1310,6 +1369,13 @@ export type AssetResourceGeneratorOptions = {
 	 * Whether or not this asset module should be considered binary. This can be set to 'false' to treat this asset module as text.
 	 */
 	binary?: boolean;
+
+	/**
+	 * Losslessly optimize PNG, JPEG, GIF and SVG images, and generate WebP or AVIF variants
+	 * with the `format`, `width`, `height` and `quality` query parameters, e.g. `./image.png?format=webp&width=640`.
+	 * @default false
+	 */
+	imageOptimization?: boolean;
 };
 
 /** Generator options for asset modules. */
@@ -1329,6 +1395,8 @@ export type CssGeneratorLocalIdentName = string;
 
 export type CssGeneratorEsModule = boolean;
 
+export type CssGeneratorEmitDts = boolean;
+
 /** Generator options for css modules. */
 export type CssGeneratorOptions = {
 	/**
@@ -1360,6 +1428,12 @@ export type CssAutoGeneratorOptions = {
 
 	/** This configuration is available for improved ESM-CJS interoperability purposes. */
 	esModule?: CssGeneratorEsModule;
+
+	/**
+	 * Write a TypeScript declaration file next to each CSS module, e.g. `style.module.css.d.ts`
+	 * @default false
+	 * */
+	emitDts?: CssGeneratorEmitDts;
 };
 
 /** Generator options for css/module modules. */
@@ -1647,6 +1721,9 @@ export type ExternalsPresets = {
 
 	/** Treat `NW.js` legacy `nw.gui` module as external and load it via `require()` when used. */
 	nwjs?: boolean;
+
+	/** Treat `dependencies` and `peerDependencies` of the nearest `package.json`, including deep imports like `pkg/sub/path`, as external and load them with the type of `output.library`. */
+	dependencies?: boolean;
 };
 
 //#endregion
@@ -1897,6 +1974,13 @@ export type StatsOptions = {
 	 * @default false
 	 */
 	colors?: boolean | StatsColorOptions;
+	/**
+	 * The format of the errors and warnings output by `stats.toString()`.
+	 * `"json"` and `"sarif"` output only the diagnostics in a machine readable form,
+	 * including the code, severity, file, line and column range, module trace and help text.
+	 * @default "text"
+	 */
+	diagnosticsFormat?: "text" | "json" | "sarif";
 	/**
 	 * Enables or disables the display of the hash.
 	 * @default true
@@ -2794,6 +2878,12 @@ export type Experiments = {
 	 * - `module.generator["css/module"]`
 	 */
 	css?: boolean;
+	/**
+	 * How to report conflicting orders of CSS modules between chunks when `experiments.css` is enabled,
+	 * "error" fails the build and reports the full path of each conflicting module.
+	 * @default "warning"
+	 */
+	cssOrderConflicts?: "warning" | "error";
 	/**
 	 * Enable module layers feature.
 	 * @deprecated This option is deprecated, layers is enabled since v1.6.0
@@ -316,29 +316,37 @@ export type {
 ///// Rspack Postfixed Internal Plugins /////
 export type {
 	CircularDependencyRspackPluginOptions,
+	CompressionRspackPluginOptions,
 	CopyRspackPluginOptions,
 	CssExtractRspackLoaderOptions,
 	CssExtractRspackPluginOptions,
 	EvalDevToolModulePluginOptions,
 	HtmlRspackPluginOptions,
+	I18nRspackPluginOptions,
+	ImportMapRspackPluginOptions,
 	LightningCssMinimizerRspackPluginOptions,
 	RsdoctorPluginData,
 	RsdoctorPluginHooks,
 	SourceMapDevToolPluginOptions,
+	SourceMapValidationPluginOptions,
 	SubresourceIntegrityPluginOptions,
 	SwcJsMinimizerRspackPluginOptions
 } from "./builtin-plugin";
 export {
 	CircularDependencyRspackPlugin,
+	CompressionRspackPlugin,
 	ContextReplacementPlugin,
 	CopyRspackPlugin,
 	CssExtractRspackPlugin,
 	EvalDevToolModulePlugin,
 	EvalSourceMapDevToolPlugin,
 	HtmlRspackPlugin,
+	I18nRspackPlugin,
+	ImportMapRspackPlugin,
 	LightningCssMinimizerRspackPlugin,
 	NormalModuleReplacementPlugin,
 	SourceMapDevToolPlugin,
+	SourceMapValidationPlugin,
 	SwcJsMinimizerRspackPlugin
 } from "./builtin-plugin";
 
@@ -363,7 +371,7 @@ interface Experiments {
 	globalTrace: {
 		register: (
 			filter: string,
-			layer: "logger" | "perfetto",
+			layer: "logger" | "perfetto" | "chrome",
 			output: string
 		) => Promise<void>;
 		cleanup: () => Promise<void>;
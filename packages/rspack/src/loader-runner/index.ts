@@ -976,6 +976,7 @@ export async function runLoaders(
 		const pitch = loaderState === JsLoaderState.Pitching;
 		const loaderName = extractLoaderName(currentLoaderObject!.request);
 		let result: any;
+		const start = performance.now();
 		JavaScriptTracer.startAsync({
 			name: loaderName,
 			trackName: loaderName,
@@ -1005,6 +1006,14 @@ export async function runLoaders(
 				convertArgs(args, !!currentLoaderObject?.raw);
 			result = (await runSyncOrAsync(fn, loaderContext, args)) || [];
 		}
+		// durations are reported to Rust side in microseconds
+		const duration = Math.round((performance.now() - start) * 1000);
+		const loaderItem = currentLoaderObject!.loaderItem;
+		if (pitch) {
+			loaderItem.pitchDuration = (loaderItem.pitchDuration ?? 0) + duration;
+		} else {
+			loaderItem.normalDuration = (loaderItem.normalDuration ?? 0) + duration;
+		}
 		JavaScriptTracer.endAsync({
 			name: loaderName,
 			trackName: loaderName,
@@ -57,6 +57,7 @@ import {
 	NodeTargetPlugin,
 	NoEmitOnErrorsPlugin,
 	OccurrenceChunkIdsPlugin,
+	PackageJsonExternalsRspackPlugin,
 	RealContentHashPlugin,
 	RemoveEmptyChunksPlugin,
 	RuntimeChunkPlugin,
@@ -135,6 +136,9 @@ export class RspackOptionsApply {
 				!!options.externalsPresets.webAsync
 			).apply(compiler);
 		}
+		if (options.externalsPresets.dependencies) {
+			new PackageJsonExternalsRspackPlugin().apply(compiler);
+		}
 
 		new ChunkPrefetchPreloadPlugin().apply(compiler);
 
@@ -233,7 +237,9 @@ export class RspackOptionsApply {
 			new AsyncWebAssemblyModulesPlugin().apply(compiler);
 		}
 		if (options.experiments.css) {
-			new CssModulesPlugin().apply(compiler);
+			new CssModulesPlugin({
+				orderConflicts: options.experiments.cssOrderConflicts
+			}).apply(compiler);
 		}
 
 		new EntryOptionPlugin().apply(compiler);
@@ -858,8 +858,12 @@ const SIMPLE_EXTRACTORS: SimpleExtractors = {
 		env: (object, _compilation, _context, { _env }) => {
 			object.env = _env;
 		},
-		timings: (object, compilation) => {
+		timings: (object, compilation, context: KnownStatsFactoryContext) => {
 			object.time = compilation.endTime! - compilation.startTime!;
+			const { profile } = context.getStatsCompilation(compilation);
+			if (profile) {
+				object.profile = profile;
+			}
 		},
 		builtAt: (object, compilation) => {
 			object.builtAt = compilation.endTime;
@@ -1342,12 +1346,16 @@ const SIMPLE_EXTRACTORS: SimpleExtractors = {
 	},
 	profile: {
 		_: (object, profile) => {
-			const factory = profile.factory;
-			const building = profile.building;
+			const { factory, building, codeGeneration, hashing } = profile;
 			const statsProfile: StatsProfile = {
-				total: factory + building,
-				resolving: factory,
-				building
+				total: factory + building + codeGeneration + hashing,
+				factory,
+				resolving: profile.resolving,
+				building,
+				loaders: profile.loaders,
+				parsing: profile.parsing,
+				codeGeneration,
+				hashing
 			};
 			Object.assign(object, statsProfile);
 		}
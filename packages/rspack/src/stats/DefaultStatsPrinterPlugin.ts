@@ -14,7 +14,11 @@ import { compareIds } from "../util/comparators";
 import { formatSize } from "../util/SizeFormatHelpers";
 import { SHARED_ITEM_NAMES } from "./DefaultStatsFactoryPlugin";
 import type { StatsPrinter, StatsPrinterContext } from "./StatsPrinter";
-import type { StatsChunkGroup, StatsCompilation } from "./statsFactoryUtils";
+import type {
+	StatsChunkGroup,
+	StatsCompilation,
+	StatsLoaderProfile
+} from "./statsFactoryUtils";
 
 const DATA_URI_CONTENT_LENGTH = 16;
 
@@ -483,6 +487,8 @@ const SIMPLE_PRINTERS: Record<
 			: undefined,
 
 	"module.profile.total": (value, { formatTime }) => formatTime(value),
+	"module.profile.factory": (value, { formatTime }) =>
+		`factory: ${formatTime(value)}`,
 	"module.profile.resolving": (value, { formatTime }) =>
 		`resolving: ${formatTime(value)}`,
 	"module.profile.restoring": (value, { formatTime }) =>
@@ -491,6 +497,17 @@ const SIMPLE_PRINTERS: Record<
 		`integration: ${formatTime(value)}`,
 	"module.profile.building": (value, { formatTime }) =>
 		`building: ${formatTime(value)}`,
+	"module.profile.loaders": (loaders: StatsLoaderProfile[], { formatTime }) => {
+		if (!loaders.length) return undefined;
+		const time = loaders.reduce((sum, l) => sum + l.pitch + l.normal, 0);
+		return `loaders: ${formatTime(time)}`;
+	},
+	"module.profile.parsing": (value, { formatTime }) =>
+		`parsing: ${formatTime(value)}`,
+	"module.profile.codeGeneration": (value, { formatTime }) =>
+		`code generation: ${formatTime(value)}`,
+	"module.profile.hashing": (value, { formatTime }) =>
+		`hashing: ${formatTime(value)}`,
 	"module.profile.storing": (value, { formatTime }) =>
 		`storing: ${formatTime(value)}`,
 	"module.profile.additionalResolving": (value, { formatTime }) =>
@@ -838,10 +855,15 @@ const PREFERRED_ORDERS: Record<string, string[]> = {
 	"module.profile": [
 		"total",
 		"separator!",
+		"factory",
 		"resolving",
 		"restoring",
 		"integration",
 		"building",
+		"loaders",
+		"parsing",
+		"codeGeneration",
+		"hashing",
 		"storing",
 		"additionalResolving",
 		"additionalIntegration"
@@ -140,8 +140,36 @@ export type StatsProfile = KnownStatsProfile & Record<string, any>;
 
 export type KnownStatsProfile = {
 	total: number;
+	factory: number;
 	resolving: number;
 	building: number;
+	loaders: StatsLoaderProfile[];
+	parsing: number;
+	codeGeneration: number;
+	hashing: number;
+};
+
+export type StatsLoaderProfile = {
+	loader: string;
+	pitch: number;
+	normal: number;
+};
+
+/**
+ * Module timings summed up by loader and by package, only available when `profile` is enabled.
+ */
+export type StatsProfileSummary = {
+	loaders: (StatsLoaderProfile & { modules: number })[];
+	packages: {
+		name: string;
+		modules: number;
+		resolving: number;
+		building: number;
+		loaders: number;
+		parsing: number;
+		codeGeneration: number;
+		hashing: number;
+	}[];
 };
 
 export type StatsModule = KnownStatsModule & Record<string, any>;
@@ -287,6 +315,7 @@ export type KnownStatsCompilation = {
 	filteredModules?: number;
 	children?: StatsCompilation[];
 	logging?: Record<string, StatsLogging>;
+	profile?: StatsProfileSummary;
 
 	// TODO: not aligned with webpack
 	// env?: any;
@@ -149,6 +149,11 @@ export interface OutputFileSystem {
 		arg1: number,
 		arg2: (arg0?: NodeJS.ErrnoException | null) => void
 	) => void;
+	rename?: (
+		arg0: string,
+		arg1: string,
+		arg2: (arg0?: null | NodeJS.ErrnoException) => void
+	) => void;
 	join?: (arg0: string, arg1: string) => string;
 	relative?: (arg0: string, arg1: string) => string;
 	dirname?: (arg0: string) => string;
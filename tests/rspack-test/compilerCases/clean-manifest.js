@@ -0,0 +1,65 @@
+const path = require("path");
+const fs = require("fs");
+const { rimrafSync } = require("rimraf");
+
+let first_asset_mtime;
+
+/** @type {import('@rspack/test-tools').TCompilerCaseConfig} */
+module.exports = {
+  description: "should skip unchanged files and remove stale files with clean manifest",
+  options(context) {
+    return {
+      output: {
+        path: context.getDist(),
+        filename: "main.js",
+        compareBeforeEmit: false,
+        clean: {
+          manifest: true
+        }
+      },
+      context: context.getSource(),
+      entry: "./d"
+    };
+  },
+  compiler(context, compiler) {
+    compiler.outputFileSystem = fs;
+  },
+  async build(context, compiler) {
+    rimrafSync(context.getDist());
+    // a file left before the first build is cleaned by `output.clean`
+    fs.mkdirSync(context.getDist("nested"), { recursive: true });
+    fs.writeFileSync(context.getDist("nested/leftover.js"), "");
+    await new Promise((resolve, reject) => {
+      compiler.run(err => {
+        if (err) return reject(err);
+        first_asset_mtime = fs.statSync(context.getDist("main.js")).mtime;
+
+        // a file emitted by the previous build and a file not emitted by rspack
+        const manifestPath = context.getDist(".rspack-manifest.json");
+        const manifest = JSON.parse(fs.readFileSync(manifestPath, "utf-8"));
+        manifest.files["stale.js"] = "0000";
+        fs.writeFileSync(manifestPath, JSON.stringify(manifest));
+        fs.writeFileSync(context.getDist("stale.js"), "");
+        fs.writeFileSync(context.getDist("untracked.js"), "");
+
+        setTimeout(() => {
+          compiler.run(err => {
+            if (err) return reject(err);
+            resolve();
+          });
+        }, 100);
+      });
+    });
+  },
+  async check({ context }) {
+    const manifest = JSON.parse(
+      fs.readFileSync(context.getDist(".rspack-manifest.json"), "utf-8")
+    );
+    expect(Object.keys(manifest.files)).toEqual(["main.js"]);
+    expect(fs.statSync(context.getDist("main.js")).mtime).toEqual(first_asset_mtime);
+    expect(fs.existsSync(context.getDist("stale.js"))).toBe(false);
+    expect(fs.existsSync(context.getDist("untracked.js"))).toBe(true);
+    expect(fs.existsSync(context.getDist("nested/leftover.js"))).toBe(false);
+    expect(fs.existsSync(path.join(context.getDist(), ".rspack-manifest.json.tmp"))).toBe(false);
+  }
+};
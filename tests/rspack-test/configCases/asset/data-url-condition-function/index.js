@@ -0,0 +1,13 @@
+import SVG from "./logo.svg";
+import PNG from "../_images/file.png";
+
+it("should inline the asset if `dataUrlCondition` returns true", () => {
+	expect(SVG.startsWith("data:image/svg+xml,")).toBe(true);
+	expect(PNG).toMatch(/\.png$/);
+});
+
+it("should encode svg with the `mini-svg` encoding", () => {
+	expect(SVG).toBe(
+		"data:image/svg+xml,%3csvg xmlns='http://www.w3.org/2000/svg' width='16' height='16'%3e %3crect width='16' height='16' fill='%23ff0000' /%3e %3c/svg%3e"
+	);
+});
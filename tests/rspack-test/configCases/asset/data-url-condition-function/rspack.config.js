@@ -0,0 +1,29 @@
+/** @type {import("@rspack/core").Configuration} */
+module.exports = {
+	context: __dirname,
+	module: {
+		rules: [
+			{
+				test: /\.(png|svg)$/,
+				type: "asset"
+			},
+			{
+				test: /\.svg$/,
+				generator: {
+					dataUrl: {
+						encoding: "mini-svg"
+					}
+				}
+			}
+		],
+		parser: {
+			asset: {
+				dataUrlCondition: (source, { filename, module }) =>
+					Buffer.isBuffer(source) &&
+					module.type === "asset" &&
+					module.identifier.includes(filename) &&
+					filename.endsWith(".svg")
+			}
+		}
+	}
+};
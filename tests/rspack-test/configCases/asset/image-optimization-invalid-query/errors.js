@@ -0,0 +1 @@
+module.exports = [[/Module build failed/, /Invalid image width "auto"/]];
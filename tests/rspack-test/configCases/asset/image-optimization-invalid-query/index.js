@@ -0,0 +1,5 @@
+it("should report an invalid image query as a build error of the module", () => {
+	expect(() => require("../_images/file.png?width=auto")).toThrow(
+		/Invalid image width/
+	);
+});
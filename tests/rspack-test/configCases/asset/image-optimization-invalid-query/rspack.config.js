@@ -0,0 +1,16 @@
+/** @type {import("@rspack/core").Configuration} */
+module.exports = {
+	module: {
+		generator: {
+			"asset/resource": {
+				imageOptimization: true
+			}
+		},
+		rules: [
+			{
+				test: /\.png$/,
+				type: "asset/resource"
+			}
+		]
+	}
+};
@@ -0,0 +1,20 @@
+import fs from "fs";
+import path from "path";
+import logo from "./logo.svg";
+import webp from "../_images/file.png?format=webp&width=8";
+
+it("should minify svg images", () => {
+	expect(logo).toMatch(/logo\.svg$/);
+	const original = fs.readFileSync(path.resolve(CONTEXT, "logo.svg"), "utf-8");
+	const optimized = fs.readFileSync(path.resolve(__dirname, "logo.svg"), "utf-8");
+	expect(optimized).not.toContain("<!--");
+	expect(optimized).toContain("<rect");
+	expect(optimized.length).toBeLessThan(original.length);
+});
+
+it("should convert images to the requested format", () => {
+	expect(webp).toMatch(/file\.webp$/);
+	const content = fs.readFileSync(path.resolve(__dirname, "file.webp"));
+	expect(content.toString("ascii", 0, 4)).toBe("RIFF");
+	expect(content.toString("ascii", 8, 12)).toBe("WEBP");
+});
@@ -0,0 +1,26 @@
+const { DefinePlugin } = require("@rspack/core");
+
+/** @type {import("@rspack/core").Configuration} */
+module.exports = {
+	module: {
+		generator: {
+			"asset/resource": {
+				imageOptimization: true
+			}
+		},
+		rules: [
+			{
+				test: /\.(png|svg)$/,
+				type: "asset/resource"
+			}
+		]
+	},
+	output: {
+		assetModuleFilename: "[name][ext]"
+	},
+	plugins: [
+		new DefinePlugin({
+			CONTEXT: JSON.stringify(__dirname)
+		})
+	]
+};
@@ -0,0 +1,35 @@
+import {
+	Button,
+	Input as TextInput,
+	ArrowIcon,
+	primary,
+	secondary,
+	palette
+} from "ui-lib";
+import { Button as ExportsButton } from "exports-lib";
+
+it("should import from the submodules re-exported by the barrel file", () => {
+	expect(Button).toBe("Button");
+	expect(TextInput).toBe("Input");
+	expect(ArrowIcon).toBe("ArrowIcon");
+});
+
+it("should import the destructured exports from the submodules", () => {
+	expect(primary).toBe("primary");
+	expect(secondary).toBe("secondary");
+});
+
+it("should import from the packages re-exported by the barrel file", () => {
+	expect(palette).toBe("palette");
+});
+
+it("should import from the subpaths exposed by the exports of the package", () => {
+	expect(ExportsButton).toBe("ExportsButton");
+	expect(globalThis.__exportsLibBarrelLoaded).toBeUndefined();
+});
+
+it("should keep the imports of the submodules not exposed by the exports of the package", async () => {
+	const { Internal } = await import("./internal");
+	expect(Internal).toBe("Internal");
+	expect(globalThis.__exportsLibBarrelLoaded).toBe(true);
+});
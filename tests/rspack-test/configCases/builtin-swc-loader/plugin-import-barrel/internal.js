@@ -0,0 +1,3 @@
+import { Internal } from "exports-lib";
+
+export { Internal };
@@ -0,0 +1,26 @@
+/** @type {import("@rspack/core").Configuration} */
+module.exports = {
+	module: {
+		rules: [
+			{
+				test: /\.js$/,
+				exclude: /node_modules/,
+				loader: "builtin:swc-loader",
+				options: {
+					rspackExperiments: {
+						import: [
+							{
+								libraryName: "ui-lib",
+								barrel: true
+							},
+							{
+								libraryName: "exports-lib",
+								barrel: true
+							}
+						]
+					}
+				}
+			}
+		]
+	}
+};
@@ -0,0 +1,5 @@
+import data from "./data.json";
+
+it("should report retained memory of the compilation", () => {
+	expect(data.name).toBe("memory-report");
+});
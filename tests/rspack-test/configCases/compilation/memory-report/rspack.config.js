@@ -0,0 +1,45 @@
+const PLUGIN_NAME = "plugin";
+
+class Plugin {
+	/**
+	 * @param {import("@rspack/core").Compiler} compiler
+	 */
+	apply(compiler) {
+		compiler.hooks.done.tap(PLUGIN_NAME, stats => {
+			const report = stats.compilation.getMemoryReport();
+			expect(report.artifacts.map(artifact => artifact.name)).toEqual([
+				"moduleGraph",
+				"codeGenerationResults",
+				"runtimeModules",
+				"chunkRenderArtifact",
+				"chunkRenderCacheArtifact",
+				"assets"
+			]);
+			const moduleGraph = report.artifacts.find(
+				artifact => artifact.name === "moduleGraph"
+			);
+			expect(moduleGraph.count).toBe(2);
+			expect(moduleGraph.size).toBeGreaterThan(0);
+			expect(report.moduleTypes.map(type => type.name).sort()).toEqual([
+				"javascript/auto",
+				"json"
+			]);
+			expect(
+				report.moduleTypes.reduce((size, type) => size + type.size, 0)
+			).toBe(moduleGraph.size);
+			const assets = report.artifacts.find(
+				artifact => artifact.name === "assets"
+			);
+			expect(assets.count).toBe(1);
+			expect(assets.size).toBeGreaterThan(0);
+			for (const generation of report.cacheGenerations) {
+				expect(generation.cache).toBe("chunkRenderCacheArtifact");
+			}
+		});
+	}
+}
+
+/**@type {import("@rspack/core").Configuration}*/
+module.exports = {
+	plugins: [new Plugin()]
+};
@@ -0,0 +1,17 @@
+require("./a.css");
+const fs = __non_webpack_require__("fs");
+const path = __non_webpack_require__("path");
+
+it("should nest the conditions of every import in the chain", async () => {
+	const css = await fs.promises.readFile(
+		path.resolve(__dirname, "bundle0.css"),
+		"utf-8"
+	);
+
+	expect(css).toMatch(
+		/@media\s+screen\s*\{\s*@supports \(display: grid\) \{\s*@layer base \{\s*\.b \{/
+	);
+	expect(css).toMatch(
+		/@media\s+screen\s*\{\s*@supports \(display: grid\) \{\s*@layer base \{\s*@media\s+print\s*\{\s*\.c \{/
+	);
+});
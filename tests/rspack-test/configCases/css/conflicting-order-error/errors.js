@@ -0,0 +1,5 @@
+module.exports = [
+	[
+		/Conflicting order between .*[\\/]conflicting-order-error[\\/]b\.css and .*[\\/]conflicting-order-error[\\/]c\.css/
+	]
+];
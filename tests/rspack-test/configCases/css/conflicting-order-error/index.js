@@ -0,0 +1,9 @@
+it("should still emit the chunks when the order conflicts are errors", async () => {
+	__non_webpack_require__("./lazy4_js.bundle0.js");
+	await Promise.all([
+		import("./lazy1.css"),
+		import("./lazy2.css"),
+		import("./lazy3.css"),
+		import("./lazy4.js")
+	]);
+});
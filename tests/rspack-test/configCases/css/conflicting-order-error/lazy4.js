@@ -0,0 +1,2 @@
+import "./e.css";
+import "./a.css";
@@ -0,0 +1,26 @@
+/** @type {import("@rspack/core").Configuration} */
+module.exports = {
+	target: "web",
+	mode: "development",
+	experiments: {
+		css: true,
+		cssOrderConflicts: "error"
+	},
+	optimization: {
+		splitChunks: {
+			cacheGroups: {
+				css: {
+					type: "css/auto",
+					enforce: true,
+					name: "css"
+				}
+			}
+		}
+	},
+	externalsPresets: {
+		node: true
+	},
+	node: {
+		__dirname: false
+	}
+};
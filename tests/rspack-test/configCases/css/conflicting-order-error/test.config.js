@@ -0,0 +1,5 @@
+module.exports = {
+	findBundle: function (i, options) {
+		return ["css.bundle0.js", "lazy4_js.bundle0.js", "bundle0.js"];
+	}
+};
@@ -0,0 +1,29 @@
+import * as styles from "./index.module.css";
+
+const fs = __non_webpack_require__("fs");
+const path = __non_webpack_require__("path");
+
+it("should export container names, scope selectors and layer names", () => {
+	expect(styles.sidebar).toBe("m_sidebar");
+	expect(styles.main).toBe("m_main");
+	expect(styles.content).toBe("m_content");
+	expect(styles.base).toBe("m_base");
+	expect(styles.components).toBe("m_components");
+	expect(styles.buttons).toBe("m_buttons");
+	expect(styles.app).toBeUndefined();
+	expect(styles.external).toBeUndefined();
+	expect(styles.reset).toBeUndefined();
+});
+
+it("should rewrite locals of at-rules", () => {
+	const css = fs.readFileSync(path.resolve(__dirname, "bundle0.css"), "utf-8");
+	expect(css).toContain("container-name: m_sidebar");
+	expect(css).toContain("container: m_main / inline-size");
+	expect(css).toContain("container-name: app");
+	expect(css).toContain("@container m_sidebar (min-width: 400px)");
+	expect(css).toContain("@container app (min-width: 400px)");
+	expect(css).toContain("@scope (.m_card) to (.m_content)");
+	expect(css).toContain("@scope (.external)");
+	expect(css).toContain("@layer m_base, m_components.m_buttons");
+	expect(css).toContain("@layer reset");
+});
@@ -0,0 +1,5 @@
+module.exports = {
+	findBundle() {
+		return ["bundle0.css", "bundle0.js"];
+	}
+};
@@ -0,0 +1,26 @@
+import * as styles from "./style.module.css";
+import legacy from "./legacy.module.css";
+import "./plain.css";
+
+const fs = __non_webpack_require__("fs");
+const path = __non_webpack_require__("path");
+
+it("should emit declarations of named exports", () => {
+	expect(styles.fooBar).toBeDefined();
+	const dts = fs.readFileSync(path.resolve(__TEST_PATH__, "configCases/css/css-modules-dts/style.module.css.d.ts"), "utf-8");
+	expect(dts).toContain("export declare const foo: string;");
+	expect(dts).toContain("export declare const fooBar: string;");
+	expect(dts).toMatch(/export \{ __css_export_\d+ as "foo-bar" \};/);
+});
+
+it("should emit declarations of the default export", () => {
+	expect(legacy.title).toBeDefined();
+	const dts = fs.readFileSync(path.resolve(__TEST_PATH__, "configCases/css/css-modules-dts/legacy.module.css.d.ts"), "utf-8");
+	expect(dts).toContain('readonly "title": string;');
+	expect(dts).toContain('readonly "sub-title": string;');
+	expect(dts).toContain("export default styles;");
+});
+
+it("should not emit declarations of css files", () => {
+	expect(fs.existsSync(path.resolve(__TEST_PATH__, "configCases/css/css-modules-dts/plain.css.d.ts"))).toBe(false);
+});
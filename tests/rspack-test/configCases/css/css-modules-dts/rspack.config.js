@@ -0,0 +1,31 @@
+/** @type {import("@rspack/core").Configuration} */
+module.exports = {
+	target: "web",
+	node: {
+		__dirname: false,
+		__filename: false
+	},
+	module: {
+		generator: {
+			"css/auto": {
+				exportsConvention: "camel-case",
+				emitDts: true
+			}
+		},
+		rules: [
+			{
+				test: /legacy\.module\.css$/,
+				type: "css/module",
+				parser: {
+					namedExports: false
+				},
+				generator: {
+					emitDts: true
+				}
+			}
+		]
+	},
+	experiments: {
+		css: true
+	}
+};
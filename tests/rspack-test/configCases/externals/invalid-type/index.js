@@ -0,0 +1,3 @@
+it("should render externals of an unknown type like var externals", () => {
+	expect(require("typo")).toBe("typo");
+});
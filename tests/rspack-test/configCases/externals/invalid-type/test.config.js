@@ -0,0 +1,5 @@
+module.exports = {
+	moduleScope(scope) {
+		scope.typo = "typo";
+	}
+};
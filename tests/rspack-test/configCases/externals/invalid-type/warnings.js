@@ -0,0 +1,3 @@
+module.exports = [
+	[/Unknown external type "comonjs" in "comonjs typo", "typo" is treated as a "var" external/]
+];
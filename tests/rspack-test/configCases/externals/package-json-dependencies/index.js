@@ -0,0 +1,19 @@
+const fs = require("fs");
+
+function load() {
+	return [require("dep"), require("dep/sub/path"), require("@scope/peer")];
+}
+
+it("should externalize dependencies and peer dependencies of package.json", () => {
+	expect(typeof load).toBe("function");
+	const content = fs.readFileSync(__filename, "utf-8");
+	for (const request of ["dep", "dep/sub/path", "@scope/peer"]) {
+		expect(content).toContain(
+			`module.exports = require(${JSON.stringify(request)});`
+		);
+	}
+});
+
+it("should bundle other packages", () => {
+	expect(require("bundled")).toBe("bundled");
+});
@@ -0,0 +1,11 @@
+/** @type {import("@rspack/core").Configuration} */
+module.exports = {
+	output: {
+		library: {
+			type: "commonjs2"
+		}
+	},
+	externalsPresets: {
+		dependencies: true
+	}
+};
@@ -0,0 +1,50 @@
+module.exports = [
+	"0: Excluded assets are never compressed by the plugin.",
+	"1: Excluded assets are never compressed by the plugin.",
+	"2: Excluded assets are never compressed by the plugin.",
+	"3: Excluded assets are never compressed by the plugin.",
+	"4: Excluded assets are never compressed by the plugin.",
+	"5: Excluded assets are never compressed by the plugin.",
+	"6: Excluded assets are never compressed by the plugin.",
+	"7: Excluded assets are never compressed by the plugin.",
+	"8: Excluded assets are never compressed by the plugin.",
+	"9: Excluded assets are never compressed by the plugin.",
+	"10: Excluded assets are never compressed by the plugin.",
+	"11: Excluded assets are never compressed by the plugin.",
+	"12: Excluded assets are never compressed by the plugin.",
+	"13: Excluded assets are never compressed by the plugin.",
+	"14: Excluded assets are never compressed by the plugin.",
+	"15: Excluded assets are never compressed by the plugin.",
+	"16: Excluded assets are never compressed by the plugin.",
+	"17: Excluded assets are never compressed by the plugin.",
+	"18: Excluded assets are never compressed by the plugin.",
+	"19: Excluded assets are never compressed by the plugin.",
+	"20: Excluded assets are never compressed by the plugin.",
+	"21: Excluded assets are never compressed by the plugin.",
+	"22: Excluded assets are never compressed by the plugin.",
+	"23: Excluded assets are never compressed by the plugin.",
+	"24: Excluded assets are never compressed by the plugin.",
+	"25: Excluded assets are never compressed by the plugin.",
+	"26: Excluded assets are never compressed by the plugin.",
+	"27: Excluded assets are never compressed by the plugin.",
+	"28: Excluded assets are never compressed by the plugin.",
+	"29: Excluded assets are never compressed by the plugin.",
+	"30: Excluded assets are never compressed by the plugin.",
+	"31: Excluded assets are never compressed by the plugin.",
+	"32: Excluded assets are never compressed by the plugin.",
+	"33: Excluded assets are never compressed by the plugin.",
+	"34: Excluded assets are never compressed by the plugin.",
+	"35: Excluded assets are never compressed by the plugin.",
+	"36: Excluded assets are never compressed by the plugin.",
+	"37: Excluded assets are never compressed by the plugin.",
+	"38: Excluded assets are never compressed by the plugin.",
+	"39: Excluded assets are never compressed by the plugin.",
+	"40: Excluded assets are never compressed by the plugin.",
+	"41: Excluded assets are never compressed by the plugin.",
+	"42: Excluded assets are never compressed by the plugin.",
+	"43: Excluded assets are never compressed by the plugin.",
+	"44: Excluded assets are never compressed by the plugin.",
+	"45: Excluded assets are never compressed by the plugin.",
+	"46: Excluded assets are never compressed by the plugin.",
+	"47: Excluded assets are never compressed by the plugin."
+];
@@ -0,0 +1,27 @@
+const fs = require("fs");
+const path = require("path");
+const zlib = require("zlib");
+
+const readAsset = filename => fs.readFileSync(path.join(__dirname, filename));
+
+it("should emit the compressed assets of each algorithm", () => {
+	const content = readAsset("large.js");
+	expect(zlib.gunzipSync(readAsset("large.js.gz"))).toEqual(content);
+	expect(zlib.brotliDecompressSync(readAsset("large.js.br"))).toEqual(content);
+	const zstd = readAsset("large.js.zst");
+	if (zlib.zstdDecompressSync) {
+		expect(zlib.zstdDecompressSync(zstd)).toEqual(content);
+	} else {
+		// the zstd frame magic number
+		expect(zstd.readUInt32LE(0)).toBe(0xfd2fb528);
+	}
+});
+
+it("should not compress the excluded assets and the assets below the threshold", () => {
+	expect(fs.existsSync(path.join(__dirname, "excluded.js.gz"))).toBe(false);
+	expect(fs.existsSync(path.join(__dirname, "small.js.gz"))).toBe(false);
+});
+
+it("should insert the extension before the query of the filename", () => {
+	expect(zlib.gunzipSync(readAsset("query.js.gz"))).toEqual(readAsset("query.js"));
+});
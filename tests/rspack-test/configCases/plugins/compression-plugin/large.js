@@ -0,0 +1,50 @@
+module.exports = [
+	"0: Rspack compresses the emitted assets natively with gzip, brotli and zstd.",
+	"1: Rspack compresses the emitted assets natively with gzip, brotli and zstd.",
+	"2: Rspack compresses the emitted assets natively with gzip, brotli and zstd.",
+	"3: Rspack compresses the emitted assets natively with gzip, brotli and zstd.",
+	"4: Rspack compresses the emitted assets natively with gzip, brotli and zstd.",
+	"5: Rspack compresses the emitted assets natively with gzip, brotli and zstd.",
+	"6: Rspack compresses the emitted assets natively with gzip, brotli and zstd.",
+	"7: Rspack compresses the emitted assets natively with gzip, brotli and zstd.",
+	"8: Rspack compresses the emitted assets natively with gzip, brotli and zstd.",
+	"9: Rspack compresses the emitted assets natively with gzip, brotli and zstd.",
+	"10: Rspack compresses the emitted assets natively with gzip, brotli and zstd.",
+	"11: Rspack compresses the emitted assets natively with gzip, brotli and zstd.",
+	"12: Rspack compresses the emitted assets natively with gzip, brotli and zstd.",
+	"13: Rspack compresses the emitted assets natively with gzip, brotli and zstd.",
+	"14: Rspack compresses the emitted assets natively with gzip, brotli and zstd.",
+	"15: Rspack compresses the emitted assets natively with gzip, brotli and zstd.",
+	"16: Rspack compresses the emitted assets natively with gzip, brotli and zstd.",
+	"17: Rspack compresses the emitted assets natively with gzip, brotli and zstd.",
+	"18: Rspack compresses the emitted assets natively with gzip, brotli and zstd.",
+	"19: Rspack compresses the emitted assets natively with gzip, brotli and zstd.",
+	"20: Rspack compresses the emitted assets natively with gzip, brotli and zstd.",
+	"21: Rspack compresses the emitted assets natively with gzip, brotli and zstd.",
+	"22: Rspack compresses the emitted assets natively with gzip, brotli and zstd.",
+	"23: Rspack compresses the emitted assets natively with gzip, brotli and zstd.",
+	"24: Rspack compresses the emitted assets natively with gzip, brotli and zstd.",
+	"25: Rspack compresses the emitted assets natively with gzip, brotli and zstd.",
+	"26: Rspack compresses the emitted assets natively with gzip, brotli and zstd.",
+	"27: Rspack compresses the emitted assets natively with gzip, brotli and zstd.",
+	"28: Rspack compresses the emitted assets natively with gzip, brotli and zstd.",
+	"29: Rspack compresses the emitted assets natively with gzip, brotli and zstd.",
+	"30: Rspack compresses the emitted assets natively with gzip, brotli and zstd.",
+	"31: Rspack compresses the emitted assets natively with gzip, brotli and zstd.",
+	"32: Rspack compresses the emitted assets natively with gzip, brotli and zstd.",
+	"33: Rspack compresses the emitted assets natively with gzip, brotli and zstd.",
+	"34: Rspack compresses the emitted assets natively with gzip, brotli and zstd.",
+	"35: Rspack compresses the emitted assets natively with gzip, brotli and zstd.",
+	"36: Rspack compresses the emitted assets natively with gzip, brotli and zstd.",
+	"37: Rspack compresses the emitted assets natively with gzip, brotli and zstd.",
+	"38: Rspack compresses the emitted assets natively with gzip, brotli and zstd.",
+	"39: Rspack compresses the emitted assets natively with gzip, brotli and zstd.",
+	"40: Rspack compresses the emitted assets natively with gzip, brotli and zstd.",
+	"41: Rspack compresses the emitted assets natively with gzip, brotli and zstd.",
+	"42: Rspack compresses the emitted assets natively with gzip, brotli and zstd.",
+	"43: Rspack compresses the emitted assets natively with gzip, brotli and zstd.",
+	"44: Rspack compresses the emitted assets natively with gzip, brotli and zstd.",
+	"45: Rspack compresses the emitted assets natively with gzip, brotli and zstd.",
+	"46: Rspack compresses the emitted assets natively with gzip, brotli and zstd.",
+	"47: Rspack compresses the emitted assets natively with gzip, brotli and zstd."
+];
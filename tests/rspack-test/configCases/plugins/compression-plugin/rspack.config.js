@@ -0,0 +1,57 @@
+const { CompressionRspackPlugin } = require("@rspack/core");
+
+class Plugin {
+	/**
+	 * @param {import('@rspack/core').Compiler} compiler
+	 */
+	apply(compiler) {
+		const { RawSource } = compiler.webpack.sources;
+		compiler.hooks.thisCompilation.tap("Test", compilation => {
+			compilation.hooks.processAssets.tap(
+				{
+					name: "Test",
+					stage: compiler.webpack.Compilation.PROCESS_ASSETS_STAGE_ADDITIONAL
+				},
+				() => {
+					compilation.emitAsset(
+						"query.js?v=1",
+						new RawSource(`// ${"query ".repeat(256)}`)
+					);
+				}
+			);
+		});
+		compiler.hooks.afterEmit.tap("Test", compilation => {
+			const { related } = compilation.getAsset("large.js").info;
+			expect(related.gzip).toBe("large.js.gz");
+			expect(related.brotli).toBe("large.js.br");
+			expect(related.zstd).toBe("large.js.zst");
+			expect(compilation.getAsset("small.js").info.related.gzip).toBeFalsy();
+			// the extension is inserted before the query
+			expect(compilation.getAsset("query.js?v=1").info.related.gzip).toBe(
+				"query.js.gz?v=1"
+			);
+		});
+	}
+}
+
+/** @type {import("@rspack/core").Configuration} */
+module.exports = {
+	entry: {
+		main: "./index",
+		large: "./large",
+		excluded: "./excluded",
+		small: "./small"
+	},
+	output: {
+		filename: "[name].js"
+	},
+	plugins: [
+		new CompressionRspackPlugin({
+			algorithms: ["gzip", "brotli", { algorithm: "zstd", level: 19 }],
+			test: /\.js(\?.*)?$/,
+			exclude: /excluded\.js/,
+			threshold: 1024
+		}),
+		new Plugin()
+	]
+};
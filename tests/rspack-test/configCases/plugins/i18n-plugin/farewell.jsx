@@ -0,0 +1 @@
+export const Farewell = () => <FormattedMessage id="farewell" />;
@@ -0,0 +1,3 @@
+const t = id => id;
+
+export const greeting = t("greeting");
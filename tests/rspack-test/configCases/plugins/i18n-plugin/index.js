@@ -0,0 +1,33 @@
+import { greeting } from "./greeting";
+
+const fs = require("fs");
+const path = require("path");
+
+const loadFarewell = () => import(/* webpackChunkName: "farewell" */ "./farewell");
+
+const readAsset = filename =>
+	fs.readFileSync(path.join(__dirname, filename), "utf-8");
+
+it("should emit the messages used by each chunk per locale", () => {
+	expect(greeting).toBe("greeting");
+
+	const mainEn = readAsset("main.en.messages.js");
+	expect(mainEn).toContain('{"greeting":"Hello"}');
+	expect(readAsset("main.zh.messages.js")).toContain('{"greeting":"你好"}');
+
+	const farewellEn = readAsset("farewell.en.messages.js");
+	expect(farewellEn).toContain('{"farewell":"Goodbye"}');
+	expect(farewellEn).not.toContain("greeting");
+	expect(readAsset("farewell.zh.messages.js")).toContain(", {});");
+	expect(mainEn).not.toContain("unused");
+});
+
+it("should register the ensure chunk handler of the messages", () => {
+	expect(typeof __webpack_require__.f.i18n).toBe("function");
+});
+
+it("should load the messages of the async chunk with the chunk", async () => {
+	const { Farewell } = await loadFarewell();
+	expect(typeof Farewell).toBe("function");
+	expect(self.__RSPACK_I18N_MESSAGES__.en.farewell).toBe("Goodbye");
+});
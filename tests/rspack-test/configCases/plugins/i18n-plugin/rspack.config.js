@@ -0,0 +1,44 @@
+const { I18nRspackPlugin } = require("@rspack/core");
+
+/** @type {import("@rspack/core").Configuration} */
+module.exports = {
+	resolve: {
+		extensions: ["...", ".jsx"]
+	},
+	module: {
+		rules: [
+			{
+				test: /\.jsx?$/,
+				exclude: /node_modules/,
+				use: [
+					{
+						loader: "builtin:swc-loader",
+						options: {
+							jsc: {
+								parser: {
+									syntax: "ecmascript",
+									jsx: true
+								}
+							},
+							rspackExperiments: {
+								collectI18nMessages: {}
+							}
+						}
+					}
+				]
+			}
+		]
+	},
+	plugins: [
+		new I18nRspackPlugin({
+			locales: {
+				en: "./locales/en.json",
+				zh: {
+					greeting: "你好",
+					unused: "未使用"
+				}
+			},
+			localeExpression: '"en"'
+		})
+	]
+};
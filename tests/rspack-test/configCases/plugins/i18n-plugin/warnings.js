@@ -0,0 +1 @@
+module.exports = [[/Message "farewell" is missing in the catalog of locale "zh"/]];
@@ -0,0 +1,24 @@
+const fs = require("fs");
+const path = require("path");
+
+const readHtml = filename =>
+	fs.readFileSync(path.join(__dirname, filename), "utf-8");
+
+it("should inline the import map into each html file", () => {
+	for (const filename of ["a.html", "b.html"]) {
+		const html = readHtml(filename);
+		const matched = html.match(/<script type="importmap">(.*?)<\/script>/);
+		expect(matched).toBeTruthy();
+		expect(JSON.parse(matched[1])).toEqual({
+			imports: {
+				"_chunks/main": "/main.js"
+			}
+		});
+		// the import map is placed before the scripts of chunks
+		expect(matched.index).toBeLessThan(html.indexOf('src="/main.js"'));
+	}
+});
+
+it("should not emit the import map file", () => {
+	expect(fs.existsSync(path.join(__dirname, "importmap.json"))).toBe(false);
+});
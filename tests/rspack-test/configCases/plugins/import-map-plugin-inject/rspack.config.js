@@ -0,0 +1,24 @@
+const { HtmlRspackPlugin, ImportMapRspackPlugin } = require("@rspack/core");
+
+/** @type {import("@rspack/core").Configuration} */
+module.exports = {
+	entry: {
+		main: "./index"
+	},
+	output: {
+		filename: "[name].js",
+		publicPath: "/"
+	},
+	plugins: [
+		new ImportMapRspackPlugin({
+			filename: false,
+			inject: true
+		}),
+		new HtmlRspackPlugin({
+			filename: "a.html"
+		}),
+		new HtmlRspackPlugin({
+			filename: "b.html"
+		})
+	]
+};
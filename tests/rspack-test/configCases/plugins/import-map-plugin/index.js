@@ -0,0 +1,20 @@
+const fs = require("fs");
+const path = require("path");
+
+const readImportMap = () =>
+	JSON.parse(fs.readFileSync(path.join(__dirname, "importmap.json"), "utf-8"));
+
+it("should map the chunks to their hashed filenames", () => {
+	const { imports } = readImportMap();
+	expect(imports["_chunks/main"]).toBe("/main.js");
+	expect(imports["_chunks/lib"]).toMatch(/^\/lib\.[0-9a-f]{8}\.js$/);
+	expect(
+		fs.existsSync(path.join(__dirname, imports["_chunks/lib"].slice(1)))
+	).toBe(true);
+});
+
+it("should map the import externals", () => {
+	const { imports } = readImportMap();
+	expect(imports.react).toBe("https://esm.sh/react");
+	expect(imports["react-dom"]).toBe("https://cdn.example.com/react-dom.js");
+});
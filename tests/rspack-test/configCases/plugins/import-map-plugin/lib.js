@@ -0,0 +1,2 @@
+export const loadReact = () => import("react");
+export const loadReactDom = () => import("react-dom");
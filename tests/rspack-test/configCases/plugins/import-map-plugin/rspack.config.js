@@ -0,0 +1,28 @@
+const { ImportMapRspackPlugin } = require("@rspack/core");
+
+/** @type {import("@rspack/core").Configuration} */
+module.exports = {
+	entry: {
+		main: "./index",
+		lib: {
+			import: "./lib",
+			filename: "lib.[contenthash:8].js"
+		}
+	},
+	output: {
+		filename: "[name].js",
+		publicPath: "/"
+	},
+	externals: {
+		react: "import react",
+		"react-dom": "import react-dom"
+	},
+	plugins: [
+		new ImportMapRspackPlugin({
+			externalUrl: "https://esm.sh/[request]",
+			imports: {
+				"react-dom": "https://cdn.example.com/react-dom.js"
+			}
+		})
+	]
+};
@@ -0,0 +1,4 @@
+/** @type {import("../../../..").TConfigCaseConfig} */
+module.exports = {
+	findBundle: () => ["main.js"]
+};
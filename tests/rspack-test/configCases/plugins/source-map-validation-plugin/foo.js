@@ -0,0 +1,3 @@
+export function foo() {
+	return "foo";
+}
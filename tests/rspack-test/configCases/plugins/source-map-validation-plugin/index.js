@@ -0,0 +1,5 @@
+import { foo } from "./foo";
+
+it("should validate the source maps without warnings", () => {
+	expect(foo()).toBe("foo");
+});
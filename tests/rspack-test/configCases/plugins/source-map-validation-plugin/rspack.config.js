@@ -0,0 +1,44 @@
+const { SourceMapValidationPlugin } = require("@rspack/core");
+
+class Plugin {
+	/**
+	 * @param {import('@rspack/core').Compiler} compiler
+	 */
+	apply(compiler) {
+		compiler.hooks.afterEmit.tap("Test", compilation => {
+			const warnings = compilation
+				.getWarnings()
+				.filter(warning => warning.name === "SourceMapValidationWarning");
+			expect(warnings).toEqual([]);
+
+			const dump = compilation.getAsset("source-map-chain/._foo.js.json");
+			expect(dump.info.development).toBe(true);
+			const { module, concatenatedModule, chain } = JSON.parse(
+				dump.source.source().toString()
+			);
+			expect(module).toBe("./foo.js");
+			expect(concatenatedModule).toContain("./index.js");
+			expect(chain.map(item => item.stage)).toContain("asset");
+			expect(compilation.getAsset("source-map-chain/._index.js.json")).toBe(
+				undefined
+			);
+		});
+	}
+}
+
+/** @type {import("@rspack/core").Configuration} */
+module.exports = {
+	mode: "production",
+	devtool: "source-map",
+	optimization: {
+		minimize: false,
+		concatenateModules: true
+	},
+	plugins: [
+		new SourceMapValidationPlugin({
+			test: /\.js$/,
+			dumpModules: /foo\.js$/
+		}),
+		new Plugin()
+	]
+};
@@ -0,0 +1,46 @@
+const {
+	experiments: { RslibPlugin }
+} = require("@rspack/core");
+
+/** @type {import("@rspack/core").Configuration[]} */
+module.exports = [
+	{
+		entry: {
+			index: "./src/index.ts"
+		},
+		target: "node",
+		resolve: {
+			extensions: [".ts", "..."]
+		},
+		module: {
+			rules: [
+				{
+					test: /\.ts$/,
+					loader: "builtin:swc-loader",
+					options: {
+						jsc: {
+							parser: {
+								syntax: "typescript"
+							}
+						}
+					}
+				}
+			]
+		},
+		output: {
+			library: {
+				type: "commonjs"
+			}
+		},
+		plugins: [new RslibPlugin({ emitDts: true })]
+	},
+	// Test entry
+	{
+		entry: "./test.js",
+		target: "node",
+		node: {
+			__filename: false,
+			__dirname: false
+		}
+	}
+];
@@ -0,0 +1,7 @@
+interface Props {
+	label: string;
+}
+
+export function createButton(props: Props): string {
+	return `<button>${props.label}</button>`;
+}
@@ -0,0 +1,10 @@
+import type { Options } from "./options";
+import { createButton } from "./button";
+
+export * from "./utils";
+export { createButton };
+export type { Options };
+
+export default function render(options: Options): string {
+	return createButton({ label: options.label });
+}
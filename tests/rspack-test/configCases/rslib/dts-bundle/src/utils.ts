@@ -0,0 +1,7 @@
+interface Props {
+	size: number;
+}
+
+export function size(props: Props): number {
+	return props.size;
+}
@@ -0,0 +1,22 @@
+const path = require("path");
+const fs = require("fs");
+
+const dts = fs.readFileSync(path.resolve(__dirname, "index.d.ts"), "utf-8");
+
+it("should roll up the declarations of the entry", () => {
+	expect(dts).toContain("interface Options");
+	expect(dts).toContain("declare function createButton(props: Props): string;");
+	expect(dts).toContain("declare function render(options: Options): string;");
+	expect(dts).not.toMatch(/from "\.\//);
+});
+
+it("should rename the conflicting declarations of different modules", () => {
+	expect(dts).toContain("interface Props {");
+	expect(dts).toContain("interface Props_1 {");
+	expect(dts).toMatch(/declare function size\(props: Props(_1)?\): number;/);
+});
+
+it("should export the exports of the entry", () => {
+	const exports = dts.match(/export \{ (.*) \};/)[1].split(", ").sort();
+	expect(exports).toEqual(["Options", "createButton", "render as default", "size"]);
+});
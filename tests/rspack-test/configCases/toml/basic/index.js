@@ -0,0 +1,23 @@
+import data from "./data.toml";
+import { name, server } from "./named.toml";
+
+it("should import toml as json data", () => {
+	expect(data).toEqual({
+		title: "rspack",
+		version: 1,
+		features: ["yaml", "toml"],
+		released: "2024-01-01T00:00:00Z",
+		server: { port: 8080, debug: false }
+	});
+});
+
+it("should provide named exports per top-level key", () => {
+	expect(name).toBe("rspack");
+	expect(server.port).toBe(8080);
+});
+
+it("should remove unused keys", () => {
+	const fs = require("fs");
+	const content = fs.readFileSync(__filename, "utf-8");
+	expect(content.includes(["removed", "toml", "value"].join("_"))).toBe(false);
+});
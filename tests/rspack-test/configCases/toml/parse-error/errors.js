@@ -0,0 +1,3 @@
+module.exports = [
+	[{ moduleName: /invalid\.toml/, message: /TOML parse error/ }]
+];
@@ -0,0 +1,10 @@
+it("should report TOML parse error", () => {
+	let errored = false;
+	try {
+		require("./invalid.toml");
+	} catch (err) {
+		errored = true;
+		expect(err.message).toContain("TOML parse error");
+	}
+	expect(errored).toBeTruthy();
+});
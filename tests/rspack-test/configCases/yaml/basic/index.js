@@ -0,0 +1,22 @@
+import data from "./data.yaml";
+import { name, server } from "./named.yaml";
+
+it("should import yaml as json data", () => {
+	expect(data).toEqual({
+		name: "rspack",
+		version: 1,
+		features: ["yaml", "toml"],
+		server: { port: 8080, debug: false }
+	});
+});
+
+it("should provide named exports per top-level key", () => {
+	expect(name).toBe("rspack");
+	expect(server.port).toBe(8080);
+});
+
+it("should remove unused keys", () => {
+	const fs = require("fs");
+	const content = fs.readFileSync(__filename, "utf-8");
+	expect(content.includes(["removed", "yaml", "value"].join("_"))).toBe(false);
+});
@@ -0,0 +1,16 @@
+/** @type {import("@rspack/core").Configuration} */
+module.exports = {
+	mode: "production",
+	optimization: {
+		minimize: false,
+		concatenateModules: false
+	},
+	module: {
+		rules: [
+			{
+				test: /\.ya?ml$/,
+				type: "yaml"
+			}
+		]
+	}
+};
@@ -0,0 +1,3 @@
+module.exports = [
+	[{ moduleName: /invalid\.yaml/, message: /YAML parse error/ }]
+];
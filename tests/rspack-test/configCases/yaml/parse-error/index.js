@@ -0,0 +1,10 @@
+it("should report YAML parse error", () => {
+	let errored = false;
+	try {
+		require("./invalid.yaml");
+	} catch (err) {
+		errored = true;
+		expect(err.message).toContain("YAML parse error");
+	}
+	expect(errored).toBeTruthy();
+});
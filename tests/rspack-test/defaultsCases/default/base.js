@@ -220,6 +220,12 @@ module.exports = {
 			      json: Object {
 			        exportsDepth: 9007199254740991,
 			      },
+			      toml: Object {
+			        exportsDepth: 9007199254740991,
+			      },
+			      yaml: Object {
+			        exportsDepth: 9007199254740991,
+			      },
 			    },
 			    rules: Array [],
 			    unsafeCache: false,
@@ -292,6 +298,7 @@ module.exports = {
 			  output: Object {
 			    assetModuleFilename: [hash][ext][query],
 			    asyncChunks: true,
+			    atomicEmit: false,
 			    charset: false,
 			    chunkFilename: [name].js,
 			    chunkFormat: array-push,
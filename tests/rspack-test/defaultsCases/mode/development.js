@@ -23,6 +23,12 @@ module.exports = {
 			-         "exportsDepth": 9007199254740991,
 			+         "exportsDepth": 1,
 			@@ ... @@
+			-         "exportsDepth": 9007199254740991,
+			+         "exportsDepth": 1,
+			@@ ... @@
+			-         "exportsDepth": 9007199254740991,
+			+         "exportsDepth": 1,
+			@@ ... @@
 			-     "unsafeCache": false,
 			+     "unsafeCache": /[\\\\/]node_modules[\\\\/]/,
 			@@ ... @@
@@ -0,0 +1,5 @@
+export const value = 42
+
+it('should render commonjs for the commonjs-static library', () => {
+	expect(__non_webpack_require__('./main.js').value).toBe(42)
+})
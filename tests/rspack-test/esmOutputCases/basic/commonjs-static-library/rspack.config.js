@@ -0,0 +1,10 @@
+/**@type {import('rspack').Configuration} */
+module.exports = {
+	output: {
+		filename: '[name].js',
+		module: false,
+		library: {
+			type: 'commonjs-static'
+		},
+	},
+}
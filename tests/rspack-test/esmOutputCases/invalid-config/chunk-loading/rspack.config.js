@@ -2,7 +2,7 @@ module.exports = {
 	output: {
 		chunkLoading: 'jsonp',
 		library: {
-			type: 'commonjs'
+			type: 'module'
 		},
 	},
 	optimization: {
@@ -0,0 +1,2 @@
+// other1
+console.log.bind(console)
@@ -0,0 +1,2 @@
+// other2
+console.log.bind(console)
@@ -0,0 +1,8 @@
+/**@type {import('rspack').Configuration} */
+module.exports = {
+	entry: './src/index.js',
+	output: {
+		filename: '[name].js',
+		module: false,
+	},
+}
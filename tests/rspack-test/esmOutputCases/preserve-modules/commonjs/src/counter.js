@@ -0,0 +1,11 @@
+import { total, getName } from './demo'
+
+export let count = 0
+
+// `./demo` is still being required when this module is evaluated
+export const label = `${getName()} counter`
+
+export function increment() {
+  count++
+  return total()
+}
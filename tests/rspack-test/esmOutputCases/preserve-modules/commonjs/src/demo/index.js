@@ -0,0 +1,8 @@
+import { count } from '../counter'
+const demo = () => 'demo'
+import '../../other2'
+export const total = () => count
+export function getName() {
+  return 'demo'
+}
+export default demo
@@ -0,0 +1,18 @@
+import demo from './demo'
+import { count, increment, label } from './counter'
+import '../other1'
+
+it('should preserve modules in commonjs', () => {
+  expect(demo()).toBe('demo')
+  expect(__non_webpack_require__('./demo/index.js').__esModule).toBe(true)
+})
+
+it('should keep live bindings across circular requires', () => {
+  expect(count).toBe(0)
+  expect(increment()).toBe(1)
+  expect(count).toBe(1)
+})
+
+it('should call exported functions during circular requires', () => {
+  expect(label).toBe('demo counter')
+})
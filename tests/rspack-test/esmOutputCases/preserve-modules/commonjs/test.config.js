@@ -0,0 +1,11 @@
+const path = require('path')
+
+module.exports = {
+	findBundle() {
+		return ['index.js']
+	},
+	esmLibPluginOptions: {
+		preserveModules: path.resolve(__dirname, 'src'),
+		format: 'commonjs',
+	},
+}
@@ -0,0 +1,65 @@
+const { createFsFromVolume, Volume } = require("memfs");
+
+/** @type {import('@rspack/test-tools').TMultiCompilerCaseConfig[]} */
+module.exports = {
+  description: "should merge the diagnostics reports of children",
+  options(context) {
+    return [
+      {
+        name: "first",
+        context: __dirname,
+        entry: "../fixtures/errors/missingFile"
+      },
+      {
+        name: "second",
+        context: __dirname,
+        entry: "../fixtures/errors/missingFile"
+      }
+    ];
+  },
+  compiler(context, compiler) {
+    compiler.outputFileSystem = createFsFromVolume(new Volume());
+  },
+  async build(context, compiler) {
+    return new Promise((resolve, reject) => {
+      compiler.run((err, stats) => {
+        if (err) return reject(err);
+        try {
+          const diagnostics = JSON.parse(
+            stats.toString({ diagnosticsFormat: "json" })
+          );
+          expect(diagnostics).toHaveLength(4);
+          expect(diagnostics.map(d => d.compilerPath)).toEqual([
+            "first",
+            "first",
+            "second",
+            "second"
+          ]);
+
+          const sarif = JSON.parse(
+            stats.toString({ diagnosticsFormat: "sarif" })
+          );
+          expect(sarif.version).toBe("2.1.0");
+          expect(sarif.runs).toHaveLength(2);
+          expect(sarif.runs.map(run => run.automationDetails.id)).toEqual([
+            "first",
+            "second"
+          ]);
+          expect(sarif.runs[0].results).toHaveLength(2);
+
+          expect(() =>
+            stats.toString({
+              children: [
+                { diagnosticsFormat: "json" },
+                { diagnosticsFormat: "sarif" }
+              ]
+            })
+          ).toThrow(/same "stats.diagnosticsFormat"/);
+          resolve();
+        } catch (e) {
+          reject(e);
+        }
+      });
+    });
+  }
+};
@@ -0,0 +1,37 @@
+/** @type {import('@rspack/test-tools').TStatsAPICaseConfig} */
+module.exports = {
+	description: "should output diagnostics as JSON and SARIF",
+	options(context) {
+		return {
+			context: context.getSource(),
+			entry: "./fixtures/errors/missingFile"
+		};
+	},
+	async check(stats) {
+		const diagnostics = JSON.parse(
+			stats.toString({ diagnosticsFormat: "json" })
+		);
+		expect(diagnostics).toHaveLength(2);
+		const [missing] = diagnostics;
+		expect(missing.severity).toBe("error");
+		expect(missing.message).toContain("Can't resolve './missing'");
+		expect(missing.file).toBe("./fixtures/errors/missingFile.js");
+		expect(missing.range).toEqual({
+			start: { line: 4, column: 8 },
+			end: { line: 4, column: 19 }
+		});
+
+		const sarif = JSON.parse(stats.toString({ diagnosticsFormat: "sarif" }));
+		expect(sarif.version).toBe("2.1.0");
+		const [run] = sarif.runs;
+		expect(run.tool.driver.name).toBe("rspack");
+		expect(run.results).toHaveLength(2);
+		const [result] = run.results;
+		expect(result.level).toBe("error");
+		expect(run.tool.driver.rules).toContainEqual({ id: result.ruleId });
+		expect(result.locations[0].physicalLocation).toEqual({
+			artifactLocation: { uri: "./fixtures/errors/missingFile.js" },
+			region: { startLine: 4, startColumn: 9, endLine: 4, endColumn: 20 }
+		});
+	}
+};
@@ -0,0 +1,49 @@
+/** @type {import('@rspack/test-tools').TStatsAPICaseConfig} */
+module.exports = {
+	description: "should have loader and package timings when profile is true",
+	options(context) {
+		return {
+			context: context.getSource(),
+			entry: "./fixtures/abc",
+			profile: true,
+			module: {
+				rules: [
+					{
+						test: /fixtures[\\/]a\.js$/,
+						use: "./fixtures/delay-loader"
+					}
+				]
+			}
+		};
+	},
+	async check(stats) {
+		const json = stats?.toJson({ all: false, modules: true, timings: true });
+
+		const a = json.modules.find(m => m.name === "./fixtures/a.js");
+		expect(a.profile.loaders).toHaveLength(1);
+		expect(a.profile.loaders[0].loader).toMatch(/delay-loader\.js$/);
+		expect(a.profile.loaders[0].normal).toBeGreaterThanOrEqual(400);
+		expect(a.profile.building).toBeGreaterThanOrEqual(400);
+		for (const m of json.modules) {
+			for (const key of [
+				"factory",
+				"resolving",
+				"building",
+				"parsing",
+				"codeGeneration",
+				"hashing"
+			]) {
+				expect(typeof m.profile[key]).toBe("number");
+			}
+		}
+
+		const [loader] = json.profile.loaders;
+		expect(loader.loader).toMatch(/delay-loader\.js$/);
+		expect(loader.modules).toBe(1);
+		expect(loader.normal).toBeGreaterThanOrEqual(400);
+
+		const tests = json.profile.packages.find(p => p.name === "@rspack/tests");
+		expect(tests.modules).toBe(4);
+		expect(tests.loaders).toBeGreaterThanOrEqual(400);
+	}
+};
@@ -13,13 +13,13 @@ module.exports = {
 			stats?.toString({ all: false, modules: true }).replace(/\d+ ms/g, "X ms")
 		).toMatchInlineSnapshot(`
 		./fixtures/abc.js 83 bytes [built] [code generated]
-		  X ms (resolving: X ms, building: X ms)
+		  X ms (factory: X ms, resolving: X ms, building: X ms, parsing: X ms, code generation: X ms, hashing: X ms)
 		./fixtures/a.js 55 bytes [built] [code generated]
-		  X ms (resolving: X ms, building: X ms)
+		  X ms (factory: X ms, resolving: X ms, building: X ms, parsing: X ms, code generation: X ms, hashing: X ms)
 		./fixtures/b.js 94 bytes [built] [code generated]
-		  X ms (resolving: X ms, building: X ms)
+		  X ms (factory: X ms, resolving: X ms, building: X ms, parsing: X ms, code generation: X ms, hashing: X ms)
 		./fixtures/c.js 72 bytes [built] [code generated]
-		  X ms (resolving: X ms, building: X ms)
+		  X ms (factory: X ms, resolving: X ms, building: X ms, parsing: X ms, code generation: X ms, hashing: X ms)
 	`);
 	}
 };